@@ -29,7 +29,7 @@ use libc::{c_char, c_void};
 use crate::errors::{CplErrType, Result};
 use crate::utils::_string;
 use once_cell::sync::Lazy;
-use std::ffi::CString;
+use std::ffi::{CStr, CString};
 use std::sync::Mutex;
 
 /// Set a GDAL library configuration option
@@ -113,6 +113,67 @@ pub fn clear_thread_local_config_option(key: &str) -> Result<()> {
     Ok(())
 }
 
+/// Get the maximum size of GDAL's block cache, in bytes.
+///
+/// See: [`GDALGetCacheMax64`](https://gdal.org/api/raster_c_api.html#_CPPv417GDALGetCacheMax64v)
+pub fn get_cache_max() -> usize {
+    let rv = unsafe { gdal_sys::GDALGetCacheMax64() };
+    rv.try_into().unwrap_or(0)
+}
+
+/// Set the maximum size of GDAL's block cache, in bytes.
+///
+/// This overrides the `GDAL_CACHEMAX` configuration option. When the cache is full, the
+/// least recently used blocks are flushed and evicted.
+///
+/// See: [`GDALSetCacheMax64`](https://gdal.org/api/raster_c_api.html#_CPPv417GDALSetCacheMax647GIntBig)
+pub fn set_cache_max(bytes: usize) {
+    let bytes = bytes.try_into().unwrap_or(i64::MAX);
+    unsafe { gdal_sys::GDALSetCacheMax64(bytes) };
+}
+
+/// Get the size of the blocks currently held in GDAL's block cache, in bytes.
+///
+/// See: [`GDALGetCacheUsed64`](https://gdal.org/api/raster_c_api.html#_CPPv418GDALGetCacheUsed64v)
+pub fn get_cache_used() -> usize {
+    let rv = unsafe { gdal_sys::GDALGetCacheUsed64() };
+    rv.try_into().unwrap_or(0)
+}
+
+/// Sets thread-local configuration options, restoring their previous values when dropped.
+pub(crate) struct ThreadLocalConfigGuard {
+    previous: Vec<(CString, Option<CString>)>,
+}
+
+impl ThreadLocalConfigGuard {
+    pub(crate) fn set<K: AsRef<str>, V: AsRef<str>>(options: &[(K, V)]) -> Result<Self> {
+        let mut guard = ThreadLocalConfigGuard {
+            previous: Vec::with_capacity(options.len()),
+        };
+        for (key, value) in options {
+            let c_key = CString::new(key.as_ref())?;
+            let c_val = CString::new(value.as_ref())?;
+            let previous = unsafe {
+                let rv =
+                    gdal_sys::CPLGetThreadLocalConfigOption(c_key.as_ptr(), ::std::ptr::null());
+                (!rv.is_null()).then(|| CStr::from_ptr(rv).to_owned())
+            };
+            unsafe { gdal_sys::CPLSetThreadLocalConfigOption(c_key.as_ptr(), c_val.as_ptr()) };
+            guard.previous.push((c_key, previous));
+        }
+        Ok(guard)
+    }
+}
+
+impl Drop for ThreadLocalConfigGuard {
+    fn drop(&mut self) {
+        for (key, previous) in self.previous.iter().rev() {
+            let value = previous.as_ref().map_or(::std::ptr::null(), |v| v.as_ptr());
+            unsafe { gdal_sys::CPLSetThreadLocalConfigOption(key.as_ptr(), value) };
+        }
+    }
+}
+
 type ErrorCallbackType = dyn FnMut(CplErrType, i32, &str) + 'static + Send;
 // We have to double-`Box` the type because we need two things:
 // 1. A stable pointer for moving the data in and out of the `Mutex`. This is done by the outer `Box`.
@@ -210,6 +271,16 @@ mod tests {
         test_clear_option_thread_local();
     }
 
+    #[test]
+    fn test_cache_max() {
+        let previous = get_cache_max();
+        set_cache_max(previous + 1024 * 1024);
+        assert_eq!(get_cache_max(), previous + 1024 * 1024);
+        assert!(get_cache_used() <= get_cache_max());
+        set_cache_max(previous);
+        assert_eq!(get_cache_max(), previous);
+    }
+
     fn test_set_get_option() {
         assert!(set_config_option("GDAL_CACHEMAX", "128").is_ok());
         assert_eq!(
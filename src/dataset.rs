@@ -1,6 +1,7 @@
-use std::{ffi::CString, ffi::NulError, path::Path, ptr};
+use std::{ffi::CString, ffi::NulError, marker::PhantomData, ops::Deref, path::Path, ptr};
 
 use gdal_sys::{self, CPLErr, GDALDatasetH, GDALMajorObjectH};
+use libc::c_uint;
 
 use crate::cpl::CslStringList;
 use crate::errors::*;
@@ -55,7 +56,7 @@ impl Dataset {
     /// Open a dataset at the given `path` with default
     /// options.
     pub fn open<P: AsRef<Path>>(path: P) -> Result<Dataset> {
-        Self::_open_ex(path.as_ref(), DatasetOptions::default())
+        Self::_open_ex(path.as_ref(), DatasetOptions::default(), 0)
     }
 
     /// Open a dataset with extended options. See
@@ -63,14 +64,27 @@ impl Dataset {
     ///
     /// [`GDALOpenEx`]: https://gdal.org/doxygen/gdal_8h.html#a9cb8585d0b3c16726b08e25bcc94274a
     pub fn open_ex<P: AsRef<Path>>(path: P, options: DatasetOptions) -> Result<Dataset> {
-        Self::_open_ex(path.as_ref(), options)
+        Self::_open_ex(path.as_ref(), options, 0)
     }
 
-    fn _open_ex(path: &Path, options: DatasetOptions) -> Result<Dataset> {
+    /// Open a dataset in shared mode (`GDAL_OF_SHARED`).
+    ///
+    /// Opening the same path with the same access mode again, on the same thread and while
+    /// a handle is still alive, returns the same underlying GDAL dataset instead of opening
+    /// the file again. See [`SharedDataset`].
+    pub fn open_shared<P: AsRef<Path>>(path: P, options: DatasetOptions) -> Result<SharedDataset> {
+        let dataset = Self::_open_ex(path.as_ref(), options, GDAL_OF_SHARED)?;
+        Ok(SharedDataset {
+            dataset,
+            _not_send: PhantomData,
+        })
+    }
+
+    fn _open_ex(path: &Path, options: DatasetOptions, extra_flags: c_uint) -> Result<Dataset> {
         crate::driver::_register_drivers();
 
         let c_filename = _path_to_c_string(path)?;
-        let c_open_flags = options.open_flags.bits();
+        let c_open_flags = options.open_flags.bits() | extra_flags;
 
         // handle driver params:
         // we need to keep the CStrings and the pointers around
@@ -181,8 +195,24 @@ impl Dataset {
         Ok(())
     }
 
+    /// Drop the cached blocks of all the bands, without writing the modified ones to disk
+    /// (GDAL >= 3.9).
+    ///
+    /// See [`gdal_sys::GDALDropCache`].
+    #[cfg(any(all(major_ge_3, minor_ge_9), major_ge_4))]
+    pub fn drop_cache(&mut self) -> Result<()> {
+        let rv = unsafe { gdal_sys::GDALDropCache(self.c_dataset) };
+        if rv != CPLErr::CE_None {
+            return Err(_last_cpl_err(rv));
+        }
+        Ok(())
+    }
+
     /// Close the dataset.
     ///
+    /// Unlike dropping the dataset, which ignores them, this reports the errors that occur
+    /// while writing the remaining cached data, e.g. when running out of disk space.
+    ///
     /// See [`gdal_sys::GDALClose`].
     ///
     /// Note: on GDAL versions older than 3.7.0, this function always succeeds.
@@ -339,6 +369,67 @@ impl MajorObject for Dataset {
 
 impl Metadata for Dataset {}
 
+/// Open flag for shared datasets, not part of [`GdalOpenFlags`](crate::GdalOpenFlags) as
+/// shared datasets must not be sent to other threads.
+const GDAL_OF_SHARED: c_uint = 0x20;
+
+/// A reference-counted handle to a dataset opened with [`Dataset::open_shared`].
+///
+/// Cloning the handle increments the reference count of the GDAL dataset
+/// (`GDALReferenceDataset`), and dropping it decrements the count, closing the dataset when
+/// the last handle is dropped. Opening the same file again with [`Dataset::open_shared`]
+/// also returns a handle to the same dataset.
+///
+/// GDAL only shares datasets between the handles of a thread, so unlike [`Dataset`],
+/// `SharedDataset` is not [`Send`].
+///
+/// # Example
+///
+/// ```rust, no_run
+/// use gdal::Dataset;
+/// # fn main() -> gdal::errors::Result<()> {
+/// let ds = Dataset::open_shared("fixtures/tinymarble.tif", Default::default())?;
+/// let other = Dataset::open_shared("fixtures/tinymarble.tif", Default::default())?;
+/// assert_eq!(ds.c_dataset(), other.c_dataset());
+/// let clone = ds.clone();
+/// drop(ds);
+/// assert_eq!(clone.raster_count(), 3);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct SharedDataset {
+    dataset: Dataset,
+    _not_send: PhantomData<*const ()>,
+}
+
+impl SharedDataset {
+    /// Drop this handle, closing the dataset if it is the last one.
+    ///
+    /// See [`Dataset::close`].
+    pub fn close(self) -> Result<()> {
+        self.dataset.close()
+    }
+}
+
+impl Clone for SharedDataset {
+    fn clone(&self) -> Self {
+        unsafe { gdal_sys::GDALReferenceDataset(self.dataset.c_dataset) };
+        SharedDataset {
+            dataset: unsafe { Dataset::from_c_dataset(self.dataset.c_dataset) },
+            _not_send: PhantomData,
+        }
+    }
+}
+
+impl Deref for SharedDataset {
+    type Target = Dataset;
+
+    fn deref(&self) -> &Self::Target {
+        &self.dataset
+    }
+}
+
 impl Drop for Dataset {
     fn drop(&mut self) {
         if !self.closed {
@@ -364,6 +455,45 @@ mod tests {
         dataset.close().unwrap();
     }
 
+    #[test]
+    fn test_flush_and_close() {
+        let path = "/vsimem/test_flush_and_close.tif";
+        let driver = crate::DriverManager::get_driver_by_name("GTiff").unwrap();
+        let mut dataset = driver.create(path, 16, 16, 1).unwrap();
+        let mut band = dataset.rasterband(1).unwrap();
+        band.fill(7., None).unwrap();
+        dataset.flush_cache().unwrap();
+        dataset.close().unwrap();
+
+        let dataset = Dataset::open(path).unwrap();
+        let stats = dataset
+            .rasterband(1)
+            .unwrap()
+            .compute_raster_min_max(false)
+            .unwrap();
+        assert_eq!((stats.min, stats.max), (7., 7.));
+        dataset.close().unwrap();
+        crate::vsi::unlink_mem_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_open_shared() {
+        let path = fixture("tinymarble.tif");
+        let ds = Dataset::open_shared(&path, Default::default()).unwrap();
+        let other = Dataset::open_shared(&path, Default::default()).unwrap();
+        assert_eq!(ds.c_dataset(), other.c_dataset());
+        let clone = ds.clone();
+        assert_eq!(clone.c_dataset(), ds.c_dataset());
+        drop(ds);
+        other.close().unwrap();
+        assert_eq!(clone.raster_count(), 3);
+        assert_eq!(clone.rasterband(1).unwrap().size(), (100, 50));
+
+        // non-shared handles are independent
+        let ds = Dataset::open(&path).unwrap();
+        assert_ne!(ds.c_dataset(), clone.c_dataset());
+    }
+
     #[test]
     fn test_open_ex_ro_vector() {
         Dataset::open_ex(
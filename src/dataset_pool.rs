@@ -0,0 +1,253 @@
+use std::collections::HashMap;
+use std::ops::Deref;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use crate::errors::*;
+use crate::{Dataset, DatasetOptions};
+
+/// Owned version of the parts of [`DatasetOptions`] that identify an open handle.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct PoolKey {
+    path: PathBuf,
+    open_flags: libc::c_uint,
+    allowed_drivers: Option<Vec<String>>,
+    open_options: Option<Vec<String>>,
+    sibling_files: Option<Vec<String>>,
+}
+
+impl PoolKey {
+    fn new(path: &Path, options: &DatasetOptions) -> Self {
+        fn owned(list: Option<&[&str]>) -> Option<Vec<String>> {
+            list.map(|l| l.iter().map(|s| s.to_string()).collect())
+        }
+        PoolKey {
+            path: path.to_path_buf(),
+            open_flags: options.open_flags.bits(),
+            allowed_drivers: owned(options.allowed_drivers),
+            open_options: owned(options.open_options),
+            sibling_files: owned(options.sibling_files),
+        }
+    }
+}
+
+/// A pool of [`Dataset`] handles, for sharing read access to a file between threads.
+///
+/// A [`Dataset`] may only be used by one thread at a time, so concurrent readers
+/// (e.g. parallel tile rendering) would otherwise have to serialize on a single handle.
+/// `DatasetPool` instead hands out a [`PooledDataset`] guard wrapping a handle that
+/// no other thread is using, opening a new one when all idle handles are taken.
+/// When the guard is dropped, the handle is returned to the pool for reuse,
+/// so the pool ends up holding about one handle per concurrently active thread.
+///
+/// Handles are keyed by path and open options, so the same pool can serve several files.
+///
+/// # Example
+///
+/// ```rust, no_run
+/// use std::sync::Arc;
+/// use gdal::DatasetPool;
+/// # fn main() -> gdal::errors::Result<()> {
+/// let pool = Arc::new(DatasetPool::new());
+/// let handles: Vec<_> = (0..4)
+///     .map(|_| {
+///         let pool = pool.clone();
+///         std::thread::spawn(move || {
+///             let ds = pool.get("fixtures/tinymarble.tif").unwrap();
+///             ds.rasterband(1).unwrap().read_band_as::<u8>().unwrap().len()
+///         })
+///     })
+///     .collect();
+/// for h in handles {
+///     assert_eq!(h.join().unwrap(), 100 * 50);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Default)]
+pub struct DatasetPool {
+    idle: Mutex<HashMap<PoolKey, Vec<Dataset>>>,
+}
+
+impl DatasetPool {
+    /// Create an empty pool.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Check out a handle to the dataset at `path`, opened with default options.
+    ///
+    /// See [`Dataset::open`].
+    pub fn get<P: AsRef<Path>>(&self, path: P) -> Result<PooledDataset<'_>> {
+        self.get_ex(path, DatasetOptions::default())
+    }
+
+    /// Check out a handle to the dataset at `path`, opened with the given options.
+    ///
+    /// Handles opened with different `options` are never shared.
+    ///
+    /// See [`Dataset::open_ex`].
+    pub fn get_ex<P: AsRef<Path>>(
+        &self,
+        path: P,
+        options: DatasetOptions,
+    ) -> Result<PooledDataset<'_>> {
+        let path = path.as_ref();
+        let key = PoolKey::new(path, &options);
+
+        let idle = self
+            .idle
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .get_mut(&key)
+            .and_then(Vec::pop);
+
+        let dataset = match idle {
+            Some(ds) => ds,
+            // Open outside the lock, so a slow open doesn't block other threads.
+            None => Dataset::open_ex(path, options)?,
+        };
+
+        Ok(PooledDataset {
+            pool: self,
+            key: Some(key),
+            dataset: Some(dataset),
+        })
+    }
+
+    /// Number of handles currently idle in the pool.
+    pub fn idle_count(&self) -> usize {
+        self.idle
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .values()
+            .map(Vec::len)
+            .sum()
+    }
+
+    /// Close all idle handles.
+    ///
+    /// Handles currently checked out are unaffected, and are returned to the pool when released.
+    pub fn clear(&self) {
+        self.idle.lock().unwrap_or_else(|e| e.into_inner()).clear();
+    }
+
+    fn release(&self, key: PoolKey, dataset: Dataset) {
+        self.idle
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .entry(key)
+            .or_default()
+            .push(dataset);
+    }
+}
+
+/// A [`Dataset`] handle checked out of a [`DatasetPool`].
+///
+/// Dereferences to [`Dataset`], and returns the handle to the pool when dropped.
+///
+/// Only shared access is given, so that the next borrower doesn't inherit a handle left in a
+/// modified state. Use [`detach`](Self::detach) to modify the handle, which then doesn't return
+/// to the pool.
+#[derive(Debug)]
+pub struct PooledDataset<'p> {
+    pool: &'p DatasetPool,
+    key: Option<PoolKey>,
+    dataset: Option<Dataset>,
+}
+
+impl PooledDataset<'_> {
+    /// Take the handle out of the pool for good.
+    pub fn detach(mut self) -> Dataset {
+        self.key = None;
+        self.dataset.take().expect("dataset is present until drop")
+    }
+}
+
+impl Deref for PooledDataset<'_> {
+    type Target = Dataset;
+
+    fn deref(&self) -> &Self::Target {
+        self.dataset
+            .as_ref()
+            .expect("dataset is present until drop")
+    }
+}
+
+impl Drop for PooledDataset<'_> {
+    fn drop(&mut self) {
+        if let (Some(key), Some(dataset)) = (self.key.take(), self.dataset.take()) {
+            self.pool.release(key, dataset);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::test_utils::fixture;
+
+    #[test]
+    fn test_reuse() {
+        let pool = DatasetPool::new();
+        {
+            let a = pool.get(fixture("tinymarble.tif")).unwrap();
+            let b = pool.get(fixture("tinymarble.tif")).unwrap();
+            assert_ne!(a.c_dataset(), b.c_dataset());
+            assert_eq!(pool.idle_count(), 0);
+        }
+        assert_eq!(pool.idle_count(), 2);
+
+        let c = pool.get(fixture("tinymarble.tif")).unwrap();
+        assert_eq!(pool.idle_count(), 1);
+        assert_eq!(c.raster_count(), 3);
+
+        // different options never share handles
+        let _d = pool
+            .get_ex(
+                fixture("tinymarble.tif"),
+                DatasetOptions {
+                    allowed_drivers: Some(&["GTiff"]),
+                    ..DatasetOptions::default()
+                },
+            )
+            .unwrap();
+        assert_eq!(pool.idle_count(), 1);
+
+        let ds = c.detach();
+        assert_eq!(ds.raster_count(), 3);
+        assert_eq!(pool.idle_count(), 1);
+
+        pool.clear();
+        assert_eq!(pool.idle_count(), 0);
+    }
+
+    #[test]
+    fn test_threads() {
+        let pool = Arc::new(DatasetPool::new());
+        let handles = (0..4)
+            .map(|_| {
+                let pool = pool.clone();
+                std::thread::spawn(move || {
+                    let ds = pool.get(fixture("tinymarble.tif")).unwrap();
+                    let band = ds.rasterband(1).unwrap();
+                    band.read_band_as::<u8>().unwrap().len()
+                })
+            })
+            .collect::<Vec<_>>();
+        for h in handles {
+            assert_eq!(h.join().unwrap(), 100 * 50);
+        }
+        assert!(pool.idle_count() >= 1);
+    }
+
+    #[test]
+    fn test_open_error() {
+        let pool = DatasetPool::new();
+        let _nolog = crate::test_utils::SuppressGDALErrorLog::new();
+        assert!(pool.get(fixture("does-not-exist.tif")).is_err());
+        assert_eq!(pool.idle_count(), 0);
+    }
+}
@@ -4,10 +4,12 @@ use std::sync::Once;
 
 use gdal_sys::{self, CPLErr, GDALDriverH, GDALMajorObjectH};
 
+use crate::cpl::CslStringList;
 use crate::dataset::Dataset;
 use crate::gdal_major_object::MajorObject;
 use crate::metadata::Metadata;
-use crate::raster::{GdalDataType, GdalType, RasterCreationOptions};
+use crate::options::GdalOpenFlags;
+use crate::raster::{BandCreationOptions, GdalDataType, GdalType, RasterCreationOptions};
 use crate::utils::{_last_cpl_err, _last_null_pointer_err, _path_to_c_string, _string};
 
 use crate::errors::*;
@@ -79,6 +81,88 @@ impl Driver {
         _string(rv)
     }
 
+    fn has_capability(&self, key: &str) -> bool {
+        self.metadata_item(key, "")
+            .map_or(false, |v| v.eq_ignore_ascii_case("YES"))
+    }
+
+    /// Returns `true` if the driver supports raster datasets (`DCAP_RASTER`).
+    pub fn is_raster(&self) -> bool {
+        self.has_capability("DCAP_RASTER")
+    }
+
+    /// Returns `true` if the driver supports vector datasets (`DCAP_VECTOR`).
+    pub fn is_vector(&self) -> bool {
+        self.has_capability("DCAP_VECTOR")
+    }
+
+    /// Returns `true` if the driver supports multidimensional datasets (`DCAP_MULTIDIM_RASTER`).
+    pub fn is_multidimensional(&self) -> bool {
+        self.has_capability("DCAP_MULTIDIM_RASTER")
+    }
+
+    /// Returns `true` if the driver can create new datasets with [`create`](Self::create)
+    /// (`DCAP_CREATE`).
+    pub fn can_create(&self) -> bool {
+        self.has_capability("DCAP_CREATE")
+    }
+
+    /// Returns `true` if the driver can create copies of existing datasets (`DCAP_CREATECOPY`).
+    pub fn can_create_copy(&self) -> bool {
+        self.has_capability("DCAP_CREATECOPY")
+    }
+
+    /// Returns `true` if the driver can read and write virtual files, such as `/vsimem/` or
+    /// `/vsizip/` paths (`DCAP_VIRTUALIO`).
+    pub fn supports_virtual_io(&self) -> bool {
+        self.has_capability("DCAP_VIRTUALIO")
+    }
+
+    /// Returns the file extensions of the driver, without the leading dot (`DMD_EXTENSIONS`).
+    pub fn extensions(&self) -> Vec<String> {
+        self.metadata_item("DMD_EXTENSIONS", "")
+            .or_else(|| self.metadata_item("DMD_EXTENSION", ""))
+            .map(|e| e.split_whitespace().map(str::to_string).collect())
+            .unwrap_or_default()
+    }
+
+    /// Returns the URL of the driver's documentation (`DMD_HELPTOPIC`).
+    pub fn help_url(&self) -> Option<String> {
+        self.metadata_item("DMD_HELPTOPIC", "")
+            .map(|topic| format!("https://gdal.org/{topic}"))
+    }
+
+    /// Returns the band data types the driver can create (`DMD_CREATIONDATATYPES`).
+    ///
+    /// Unknown type names are skipped.
+    pub fn creation_data_types(&self) -> Vec<GdalDataType> {
+        self.metadata_item("DMD_CREATIONDATATYPES", "")
+            .map(|types| {
+                types
+                    .split_whitespace()
+                    .filter_map(|name| GdalDataType::from_name(name).ok())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Returns the XML description of the dataset creation options of the driver
+    /// (`DMD_CREATIONOPTIONLIST`).
+    pub fn creation_option_list(&self) -> Option<String> {
+        self.metadata_item("DMD_CREATIONOPTIONLIST", "")
+    }
+
+    /// Returns the XML description of the layer creation options of the driver
+    /// (`DS_LAYER_CREATIONOPTIONLIST`).
+    pub fn layer_creation_option_list(&self) -> Option<String> {
+        self.metadata_item("DS_LAYER_CREATIONOPTIONLIST", "")
+    }
+
+    /// Returns the XML description of the open options of the driver (`DMD_OPENOPTIONLIST`).
+    pub fn open_option_list(&self) -> Option<String> {
+        self.metadata_item("DMD_OPENOPTIONLIST", "")
+    }
+
     /// Create a new dataset of size (`size_x`, `size_y`) and `bands` band count,
     /// and [`u8`] as the cell data type.
     ///
@@ -218,16 +302,104 @@ impl Driver {
         Ok(unsafe { Dataset::from_c_dataset(c_dataset) })
     }
 
+    /// Create a new dataset of size (`size_x`, `size_y`) with one band of cell data type `T`
+    /// per element of `bands`, each configured with its [`BandCreationOptions`].
+    ///
+    /// `options` are the driver-specific creation options, as in
+    /// [`create_with_band_type_with_options`](Self::create_with_band_type_with_options).
+    ///
+    /// # Example
+    ///
+    /// ```rust, no_run
+    /// # fn main() -> gdal::errors::Result<()> {
+    /// use gdal::raster::{BandCreationOptions, ColorInterpretation};
+    /// use gdal::DriverManager;
+    /// let d = DriverManager::get_driver_by_name("GTiff")?;
+    /// let bands = [
+    ///     ColorInterpretation::RedBand,
+    ///     ColorInterpretation::GreenBand,
+    ///     ColorInterpretation::BlueBand,
+    /// ]
+    /// .map(|interp| {
+    ///     let mut band = BandCreationOptions::new();
+    ///     band.with_color_interpretation(interp).with_no_data_value(0.);
+    ///     band
+    /// });
+    /// let ds = d.create_multi_band::<u8, _>("/tmp/rgb.tif", 64, 64, &bands, &Default::default())?;
+    /// assert_eq!(ds.raster_count(), 3);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn create_multi_band<T: GdalType, P: AsRef<Path>>(
+        &self,
+        filename: P,
+        size_x: usize,
+        size_y: usize,
+        bands: &[BandCreationOptions],
+        options: &RasterCreationOptions,
+    ) -> Result<Dataset> {
+        let dataset = self.create_with_band_type_with_options::<T, _>(
+            filename,
+            size_x,
+            size_y,
+            bands.len(),
+            options,
+        )?;
+        for (band, band_options) in dataset.rasterbands().zip(bands) {
+            band_options.apply(&mut band?)?;
+        }
+        Ok(dataset)
+    }
+
     /// Convenience for creating a vector-only dataset from a compatible driver.
-    /// [Details](https://gdal.org/api/gdaldriver_cpp.html#_CPPv4N10GDALDriver6CreateEPKciii12GDALDataType12CSLConstList)
+    ///
+    /// See [`create_vector_only_with_options`](Self::create_vector_only_with_options).
     pub fn create_vector_only<P: AsRef<Path>>(&self, filename: P) -> Result<Dataset> {
+        self.create_vector_only_with_options(filename, &CslStringList::default())
+    }
+
+    /// Create a vector-only dataset, with driver-specific dataset creation options specified via `options`.
+    ///
+    /// Unlike [`create_with_band_type_with_options`](Self::create_with_band_type_with_options),
+    /// no raster size, band count or data type is involved.
+    /// Returns [`GdalError::BadArgument`] if the driver can't create vector datasets.
+    ///
+    /// [Details](https://gdal.org/api/gdaldriver_cpp.html#_CPPv4N10GDALDriver6CreateEPKciii12GDALDataType12CSLConstList)
+    ///
+    /// # Example
+    ///
+    /// ```rust, no_run
+    /// # fn main() -> gdal::errors::Result<()> {
+    /// use gdal::cpl::CslStringList;
+    /// use gdal::DriverManager;
+    /// let driver = DriverManager::get_driver_by_name("SQLite")?;
+    /// let options = CslStringList::from_iter(["SPATIALITE=YES"]);
+    /// let ds = driver.create_vector_only_with_options("/tmp/foo.sqlite", &options)?;
+    /// assert_eq!(ds.layer_count(), 0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn create_vector_only_with_options<P: AsRef<Path>>(
+        &self,
+        filename: P,
+        options: &CslStringList,
+    ) -> Result<Dataset> {
+        if self.metadata_item("DCAP_VECTOR", "").is_none()
+            || self.metadata_item("DCAP_CREATE", "").is_none()
+        {
+            return Err(GdalError::BadArgument(format!(
+                "driver {} can't create vector datasets",
+                self.short_name()
+            )));
+        }
+
         self._create_with_band_type_with_options(
             filename.as_ref(),
             0,
             0,
             0,
             GdalDataType::Unknown,
-            &RasterCreationOptions::default(),
+            options,
         )
     }
 
@@ -529,6 +701,85 @@ impl DriverManager {
             })
     }
 
+    /// Get the [`Driver`] that should be used to create a dataset at `path`, based on its
+    /// extension or connection prefix, like `ogr2ogr` and `gdal_translate` do when no
+    /// output format is given.
+    ///
+    /// This is [`get_output_driver_for_dataset_name`](Self::get_output_driver_for_dataset_name),
+    /// returning an error when no driver matches.
+    ///
+    /// # Example
+    ///
+    /// ```rust, no_run
+    /// use gdal::{DriverManager, DriverType};
+    /// # fn main() -> gdal::errors::Result<()> {
+    /// let driver = DriverManager::driver_for_path("output.gpkg", DriverType::Vector)?;
+    /// assert_eq!(driver.short_name(), "GPKG");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn driver_for_path<P: AsRef<Path>>(path: P, properties: DriverType) -> Result<Driver> {
+        let path = path.as_ref();
+        Self::get_output_driver_for_dataset_name(path, properties).ok_or_else(|| {
+            GdalError::BadArgument(format!(
+                "no {properties:?} driver can create '{}'",
+                path.display()
+            ))
+        })
+    }
+
+    /// Find the driver that would open the dataset at `path`, without opening it.
+    ///
+    /// Returns `None` if no driver recognizes the dataset.
+    ///
+    /// See also: [`identify_driver_ex`](Self::identify_driver_ex).
+    ///
+    /// # Example
+    ///
+    /// ```rust, no_run
+    /// use gdal::DriverManager;
+    /// # fn main() -> gdal::errors::Result<()> {
+    /// let driver = DriverManager::identify_driver("fixtures/tinymarble.tif")?;
+    /// assert_eq!(driver.unwrap().short_name(), "GTiff");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn identify_driver<P: AsRef<Path>>(path: P) -> Result<Option<Driver>> {
+        Self::identify_driver_ex(path, GdalOpenFlags::GDAL_OF_ALL, &[])
+    }
+
+    /// Find the driver that would open the dataset at `path`, like
+    /// [`identify_driver`](Self::identify_driver).
+    ///
+    /// Only the kinds of drivers given by `flags` (e.g. [`GdalOpenFlags::GDAL_OF_VECTOR`]) are
+    /// considered, and only the drivers named in `allowed_drivers` unless it's empty.
+    ///
+    /// Wraps [`GDALIdentifyDriverEx()`](https://gdal.org/api/raster_c_api.html#_CPPv420GDALIdentifyDriverExPKcjPPCKcPPCKc)
+    pub fn identify_driver_ex<P: AsRef<Path>>(
+        path: P,
+        flags: GdalOpenFlags,
+        allowed_drivers: &[&str],
+    ) -> Result<Option<Driver>> {
+        _register_drivers();
+        let c_path = _path_to_c_string(path.as_ref())?;
+        let mut c_allowed_drivers = CslStringList::new();
+        for driver in allowed_drivers {
+            c_allowed_drivers.add_string(driver)?;
+        }
+        let c_driver = unsafe {
+            gdal_sys::GDALIdentifyDriverEx(
+                c_path.as_ptr(),
+                flags.bits(),
+                c_allowed_drivers.as_ptr() as *const *const _,
+                std::ptr::null(),
+            )
+        };
+        if c_driver.is_null() {
+            return Ok(None);
+        }
+        Ok(Some(Driver { c_driver }))
+    }
+
     /// Register a driver for use.
     ///
     /// Wraps [`GDALRegisterDriver()`](https://gdal.org/api/raster_c_api.html#_CPPv418GDALRegisterDriver11GDALDriverH)
@@ -580,6 +831,7 @@ impl DriverManager {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum DriverType {
     Vector,
     Raster,
@@ -620,6 +872,102 @@ mod tests {
         assert!(DriverManager::get_driver(0).is_ok());
     }
 
+    #[test]
+    fn test_driver_capabilities() {
+        let driver = DriverManager::get_driver_by_name("GTiff").unwrap();
+        assert!(driver.is_raster());
+        assert!(!driver.is_vector());
+        assert!(driver.can_create());
+        assert!(driver.can_create_copy());
+        assert!(driver.supports_virtual_io());
+        assert!(driver.extensions().contains(&"tif".to_string()));
+        assert!(driver.help_url().unwrap().ends_with("gtiff.html"));
+        assert!(driver
+            .creation_data_types()
+            .contains(&GdalDataType::Float64));
+        assert!(driver
+            .creation_option_list()
+            .unwrap()
+            .contains("<Option name='COMPRESS'"));
+        assert!(driver.layer_creation_option_list().is_none());
+
+        let driver = DriverManager::get_driver_by_name("GPKG").unwrap();
+        assert!(driver.is_raster() && driver.is_vector());
+        assert!(driver.layer_creation_option_list().is_some());
+        assert!(driver.open_option_list().is_some());
+    }
+
+    #[test]
+    fn test_identify_driver() {
+        use crate::test_utils::fixture;
+
+        let driver = DriverManager::identify_driver(fixture("tinymarble.tif")).unwrap();
+        assert_eq!(driver.unwrap().short_name(), "GTiff");
+        assert!(DriverManager::identify_driver(fixture("color-relief.clr"))
+            .unwrap()
+            .is_none());
+
+        let driver = DriverManager::identify_driver_ex(
+            fixture("roads.geojson"),
+            GdalOpenFlags::GDAL_OF_VECTOR,
+            &["GeoJSON"],
+        )
+        .unwrap();
+        assert_eq!(driver.unwrap().short_name(), "GeoJSON");
+        assert!(DriverManager::identify_driver_ex(
+            fixture("roads.geojson"),
+            GdalOpenFlags::GDAL_OF_VECTOR,
+            &["GPKG"],
+        )
+        .unwrap()
+        .is_none());
+    }
+
+    #[test]
+    fn test_create_multi_band() {
+        use crate::raster::ColorInterpretation;
+
+        let driver = DriverManager::get_driver_by_name("MEM").unwrap();
+        let mut red = BandCreationOptions::new();
+        red.with_color_interpretation(ColorInterpretation::RedBand)
+            .with_no_data_value(-1.)
+            .with_description("red")
+            .with_metadata_item("WAVELENGTH", "665", "");
+        let mut nir = BandCreationOptions::new();
+        nir.with_description("nir");
+        let ds = driver
+            .create_multi_band::<f32, _>("", 8, 4, &[red, nir], &Default::default())
+            .unwrap();
+        assert_eq!(ds.raster_count(), 2);
+        assert_eq!(ds.raster_size(), (8, 4));
+
+        let band = ds.rasterband(1).unwrap();
+        assert_eq!(band.band_type(), GdalDataType::Float32);
+        assert_eq!(band.color_interpretation(), ColorInterpretation::RedBand);
+        assert_eq!(band.no_data_value(), Some(-1.));
+        assert_eq!(band.description().unwrap(), "red");
+        assert_eq!(band.metadata_item("WAVELENGTH", "").as_deref(), Some("665"));
+        let band = ds.rasterband(2).unwrap();
+        assert_eq!(band.no_data_value(), None);
+        assert_eq!(band.description().unwrap(), "nir");
+    }
+
+    #[test]
+    fn test_create_vector_only() {
+        let driver = DriverManager::get_driver_by_name("GTiff").unwrap();
+        assert!(driver.create_vector_only("/vsimem/vector.tif").is_err());
+
+        let driver = DriverManager::get_driver_by_name("GPKG").unwrap();
+        let options = CslStringList::from_iter(["VERSION=1.2"]);
+        let mut ds = driver
+            .create_vector_only_with_options("/vsimem/vector.gpkg", &options)
+            .unwrap();
+        assert_eq!(ds.raster_count(), 0);
+        assert!(ds.create_layer(Default::default()).is_ok());
+        drop(ds);
+        driver.delete("/vsimem/vector.gpkg").unwrap();
+    }
+
     #[test]
     fn test_driver_by_extension() {
         fn test_driver(d: &Driver, filename: &str, properties: DriverType) {
@@ -651,6 +999,17 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_driver_for_path() {
+        let driver = DriverManager::driver_for_path("out.tif", DriverType::Raster).unwrap();
+        assert_eq!(driver.short_name(), "GTiff");
+        let driver =
+            DriverManager::driver_for_path("/vsimem/out.GPKG", DriverType::Vector).unwrap();
+        assert_eq!(driver.short_name(), "GPKG");
+        assert!(DriverManager::driver_for_path("out.geojson", DriverType::Raster).is_err());
+        assert!(DriverManager::driver_for_path("out", DriverType::Vector).is_err());
+    }
+
     #[test]
     fn test_drivers_by_extension() {
         // convert the driver into short_name for testing purposes
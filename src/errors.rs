@@ -20,6 +20,14 @@ pub enum GdalError {
     #[cfg_attr(docsrs, doc(cfg(feature = "array")))]
     #[error("NdarrayShapeError")]
     NdarrayShapeError(#[from] ndarray::ShapeError),
+    #[cfg(feature = "serde")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+    #[error("JSON error: {0}")]
+    JsonError(String),
+    #[cfg(feature = "arrow")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "arrow")))]
+    #[error("Arrow error: {0}")]
+    ArrowError(String),
     #[error("CPL error class: '{class:?}', error number: '{number}', error msg: '{msg}'")]
     CplError {
         class: CPLErr::Type,
@@ -87,6 +95,13 @@ pub enum GdalError {
     IntConversionError(#[from] TryFromIntError),
     #[error("Buffer length {0} does not match raster size {1:?}")]
     BufferSizeMismatch(usize, (usize, usize)),
+    #[error("Operation was cancelled")]
+    Cancelled,
+    #[error("VSI method '{method_name}' failed on '{path}'")]
+    VsiError {
+        method_name: &'static str,
+        path: String,
+    },
 }
 
 /// A wrapper for [`CPLErr::Type`] that reflects it as an enum
@@ -106,6 +106,51 @@ impl From<&GcpRef<'_>> for Gcp {
     }
 }
 
+/// GCPs converted for the GDAL API, with the strings they point to.
+pub(crate) struct CGcps {
+    _strings: Vec<(CString, CString)>,
+    gcps: Vec<gdal_sys::GDAL_GCP>,
+}
+
+impl CGcps {
+    pub(crate) fn new(gcps: &[Gcp]) -> Result<Self> {
+        let strings = gcps
+            .iter()
+            .map(|gcp| {
+                Ok((
+                    CString::new(gcp.id.as_str())?,
+                    CString::new(gcp.info.as_str())?,
+                ))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let gcps = gcps
+            .iter()
+            .zip(&strings)
+            .map(|(gcp, (id, info))| gdal_sys::GDAL_GCP {
+                pszId: id.as_ptr() as *mut _,
+                pszInfo: info.as_ptr() as *mut _,
+                dfGCPPixel: gcp.pixel,
+                dfGCPLine: gcp.line,
+                dfGCPX: gcp.x,
+                dfGCPY: gcp.y,
+                dfGCPZ: gcp.z,
+            })
+            .collect();
+        Ok(Self {
+            _strings: strings,
+            gcps,
+        })
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.gcps.len()
+    }
+
+    pub(crate) fn as_ptr(&self) -> *const gdal_sys::GDAL_GCP {
+        self.gcps.as_ptr()
+    }
+}
+
 impl Dataset {
     /// Get output spatial reference system for GCPs.
     ///
@@ -164,49 +209,13 @@ impl Dataset {
             .try_into()
             .expect("only up to `INT_MAX` GCPs are supported");
 
-        struct CGcp {
-            id: CString,
-            info: CString,
-            pixel: f64,
-            line: f64,
-            x: f64,
-            y: f64,
-            z: f64,
-        }
-
-        let c_gcps = gcps
-            .into_iter()
-            .map(|gcp| {
-                Ok(CGcp {
-                    id: CString::new(gcp.id)?,
-                    info: CString::new(gcp.info)?,
-                    pixel: gcp.pixel,
-                    line: gcp.line,
-                    x: gcp.x,
-                    y: gcp.y,
-                    z: gcp.z,
-                })
-            })
-            .collect::<Result<Vec<_>>>()
-            .unwrap();
-        let gdal_gcps = c_gcps
-            .iter()
-            .map(|gcp| gdal_sys::GDAL_GCP {
-                pszId: gcp.id.as_ptr() as *mut _,
-                pszInfo: gcp.info.as_ptr() as *mut _,
-                dfGCPPixel: gcp.pixel,
-                dfGCPLine: gcp.line,
-                dfGCPX: gcp.x,
-                dfGCPY: gcp.y,
-                dfGCPZ: gcp.z,
-            })
-            .collect::<Vec<_>>();
+        let c_gcps = CGcps::new(&gcps)?;
 
         let rv = unsafe {
             gdal_sys::GDALSetGCPs2(
                 self.c_dataset(),
                 len,
-                gdal_gcps.as_ptr(),
+                c_gcps.as_ptr(),
                 spatial_ref.to_c_hsrs() as *mut _,
             )
         };
@@ -108,6 +108,7 @@ pub use version::version_info;
 pub mod config;
 pub mod cpl;
 mod dataset;
+mod dataset_pool;
 mod driver;
 pub mod errors;
 mod gcp;
@@ -116,16 +117,19 @@ mod geo_transform;
 mod metadata;
 mod options;
 pub mod programs;
+pub mod progress;
 pub mod raster;
 pub mod spatial_ref;
 #[cfg(test)]
 pub mod test_utils;
+pub mod testing;
 mod utils;
 pub mod vector;
 pub mod version;
 pub mod vsi;
 
-pub use dataset::Dataset;
+pub use dataset::{Dataset, SharedDataset};
+pub use dataset_pool::{DatasetPool, PooledDataset};
 pub use geo_transform::{GeoTransform, GeoTransformEx};
 pub use options::{DatasetOptions, GdalOpenFlags};
 
@@ -22,6 +22,7 @@ bitflags! {
     /// the [`Send`] implementation that allow passing the
     /// dataset the another thread. See
     /// https://github.com/georust/gdal/issues/154.
+    /// Use [`Dataset::open_shared`](crate::Dataset::open_shared) to open shared datasets.
     ///
     /// [`GDALOpenEx`]: https://gdal.org/doxygen/gdal_8h.html#a9cb8585d0b3c16726b08e25bcc94274a
     #[derive(Debug)]
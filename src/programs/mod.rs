@@ -1,3 +1,4 @@
 //! Rust wrappers for the [GDAL Programs](https://gdal.org/programs/index.html)
 
 pub mod raster;
+pub mod vector;
@@ -0,0 +1,483 @@
+use std::ptr::{null_mut, NonNull};
+
+use gdal_sys::GDALInfoOptions;
+
+use crate::cpl::CslStringList;
+use crate::errors::*;
+use crate::utils::{_last_null_pointer_err, _string};
+use crate::Dataset;
+
+/// Configuration options for [`Dataset::info`].
+///
+/// These map to the [`gdalinfo`] command line options. Arguments not covered by the
+/// setters can be parsed with [`from_args`](Self::from_args).
+///
+/// [`gdalinfo`]: https://gdal.org/programs/gdalinfo.html
+#[derive(Debug, Clone, Default)]
+pub struct InfoOptions {
+    args: Vec<String>,
+    json: bool,
+    min_max: bool,
+    stats: Option<bool>,
+    histogram: bool,
+    checksum: bool,
+    no_gcp: bool,
+    no_metadata: bool,
+    no_rat: bool,
+    no_color_table: bool,
+    no_file_list: bool,
+    metadata_domains: Vec<String>,
+    list_metadata_domains: bool,
+    wkt_format: Option<String>,
+}
+
+impl InfoOptions {
+    /// Create an empty options set, for the default text report.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Create an options set from `gdalinfo` command line arguments, excluding the dataset.
+    ///
+    /// The arguments are parsed by GDAL when reporting, which fails if they are invalid.
+    /// The setters add to these arguments.
+    pub fn from_args<S: Into<String>, I: IntoIterator<Item = S>>(args: I) -> Self {
+        Self {
+            args: args.into_iter().map(Into::into).collect(),
+            ..Default::default()
+        }
+    }
+
+    /// Report as JSON, instead of text.
+    pub fn with_json(&mut self) -> &mut Self {
+        self.json = true;
+        self
+    }
+
+    /// Compute and report the actual minimum and maximum of each band.
+    pub fn with_min_max(&mut self) -> &mut Self {
+        self.min_max = true;
+        self
+    }
+
+    /// Compute and report the statistics of each band, approximated from the overviews or a
+    /// subset of the blocks if `approx_ok` is set.
+    ///
+    /// The statistics are stored in the dataset, or in an `.aux.xml` file next to it.
+    pub fn with_stats(&mut self, approx_ok: bool) -> &mut Self {
+        self.stats = Some(approx_ok);
+        self
+    }
+
+    /// Report the histogram of each band.
+    pub fn with_histogram(&mut self) -> &mut Self {
+        self.histogram = true;
+        self
+    }
+
+    /// Compute and report the checksum of each band.
+    pub fn with_checksum(&mut self) -> &mut Self {
+        self.checksum = true;
+        self
+    }
+
+    /// Leave out the ground control points.
+    pub fn with_no_gcp(&mut self) -> &mut Self {
+        self.no_gcp = true;
+        self
+    }
+
+    /// Leave out the metadata.
+    pub fn with_no_metadata(&mut self) -> &mut Self {
+        self.no_metadata = true;
+        self
+    }
+
+    /// Leave out the raster attribute tables.
+    pub fn with_no_rat(&mut self) -> &mut Self {
+        self.no_rat = true;
+        self
+    }
+
+    /// Leave out the color tables.
+    pub fn with_no_color_table(&mut self) -> &mut Self {
+        self.no_color_table = true;
+        self
+    }
+
+    /// Only report the first file of the dataset, instead of listing all of them.
+    pub fn with_no_file_list(&mut self) -> &mut Self {
+        self.no_file_list = true;
+        self
+    }
+
+    /// Also report the metadata of these domains, or of all of them with `all`.
+    pub fn with_metadata_domains(&mut self, domains: &[&str]) -> &mut Self {
+        self.metadata_domains = domains.iter().map(|&d| d.to_owned()).collect();
+        self
+    }
+
+    /// Report the list of metadata domains.
+    pub fn with_list_metadata_domains(&mut self) -> &mut Self {
+        self.list_metadata_domains = true;
+        self
+    }
+
+    /// Set the WKT variant of the coordinate system, e.g. `WKT1` or `WKT2`.
+    pub fn with_wkt_format(&mut self, format: &str) -> &mut Self {
+        self.wkt_format = Some(format.to_owned());
+        self
+    }
+
+    /// Render these options into [`CslStringList`] values, as compatible with
+    /// [`gdal_sys::GDALInfoOptionsNew`].
+    pub fn to_options_list(&self) -> Result<CslStringList> {
+        let mut opts = CslStringList::default();
+
+        for arg in &self.args {
+            opts.add_string(arg)?;
+        }
+
+        if self.json {
+            opts.add_string("-json")?;
+        }
+
+        if self.min_max {
+            opts.add_string("-mm")?;
+        }
+
+        match self.stats {
+            Some(true) => opts.add_string("-approx_stats")?,
+            Some(false) => opts.add_string("-stats")?,
+            None => {}
+        }
+
+        if self.histogram {
+            opts.add_string("-hist")?;
+        }
+
+        if self.checksum {
+            opts.add_string("-checksum")?;
+        }
+
+        if self.no_gcp {
+            opts.add_string("-nogcp")?;
+        }
+
+        if self.no_metadata {
+            opts.add_string("-nomd")?;
+        }
+
+        if self.no_rat {
+            opts.add_string("-norat")?;
+        }
+
+        if self.no_color_table {
+            opts.add_string("-noct")?;
+        }
+
+        if self.no_file_list {
+            opts.add_string("-nofl")?;
+        }
+
+        for domain in &self.metadata_domains {
+            opts.add_string("-mdd")?;
+            opts.add_string(domain)?;
+        }
+
+        if self.list_metadata_domains {
+            opts.add_string("-listmdd")?;
+        }
+
+        if let Some(format) = &self.wkt_format {
+            opts.add_string("-wkt_format")?;
+            opts.add_string(format)?;
+        }
+
+        Ok(opts)
+    }
+}
+
+impl From<Vec<&str>> for InfoOptions {
+    fn from(value: Vec<&str>) -> Self {
+        InfoOptions::from_args(value)
+    }
+}
+
+/// Payload for [`GDALInfo`](gdal_sys::GDALInfo).
+struct GdalInfoOptions(NonNull<GDALInfoOptions>);
+
+impl GdalInfoOptions {
+    fn new(options: &InfoOptions) -> Result<Self> {
+        let opts = options.to_options_list()?;
+        let popts = unsafe { gdal_sys::GDALInfoOptionsNew(opts.as_ptr(), null_mut()) };
+        match NonNull::new(popts) {
+            Some(popts) => Ok(Self(popts)),
+            None => Err(_last_null_pointer_err("GDALInfoOptionsNew")),
+        }
+    }
+}
+
+impl Drop for GdalInfoOptions {
+    fn drop(&mut self) {
+        unsafe { gdal_sys::GDALInfoOptionsFree(self.0.as_ptr()) };
+    }
+}
+
+/// # Info
+impl Dataset {
+    /// Report the structure and metadata of this raster dataset, as text or JSON.
+    ///
+    /// Wraps [GDALInfo].
+    /// See the [program docs] for more details.
+    ///
+    /// # Example
+    ///
+    /// ```rust, no_run
+    /// # fn main() -> gdal::errors::Result<()> {
+    /// use gdal::programs::raster::InfoOptions;
+    /// use gdal::Dataset;
+    ///
+    /// let ds = Dataset::open("fixtures/tinymarble.tif")?;
+    /// let mut options = InfoOptions::new();
+    /// options.with_min_max().with_no_metadata();
+    /// println!("{}", ds.info(&options)?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [GDALInfo]: https://gdal.org/api/gdal_utils.html#_CPPv48GDALInfo12GDALDatasetHPK15GDALInfoOptions
+    /// [program docs]: https://gdal.org/programs/gdalinfo.html
+    pub fn info(&self, options: &InfoOptions) -> Result<String> {
+        let popts = GdalInfoOptions::new(options)?;
+        let c_info = unsafe { gdal_sys::GDALInfo(self.c_dataset(), popts.0.as_ptr()) };
+        if c_info.is_null() {
+            return Err(_last_null_pointer_err("GDALInfo"));
+        }
+        let info = _string(c_info);
+        unsafe { gdal_sys::VSIFree(c_info.cast::<std::ffi::c_void>()) };
+        Ok(info)
+    }
+
+    /// Report the structure and metadata of this raster dataset, deserialized from the JSON
+    /// report of [`info`](Self::info).
+    #[cfg(feature = "serde")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+    pub fn info_json(&self, options: &InfoOptions) -> Result<RasterInfo> {
+        let mut options = options.clone();
+        options.with_json();
+        let info = self.info(&options)?;
+        serde_json::from_str(&info).map_err(|e| GdalError::JsonError(e.to_string()))
+    }
+}
+
+#[cfg(feature = "serde")]
+pub use self::report::*;
+
+#[cfg(feature = "serde")]
+mod report {
+    use std::collections::BTreeMap;
+
+    use serde::{Deserialize, Deserializer};
+
+    /// The JSON report of [`Dataset::info_json`](crate::Dataset::info_json).
+    ///
+    /// The sections left out by the [options](super::InfoOptions), or which don't apply to
+    /// the dataset, are empty.
+    #[derive(Debug, Clone, PartialEq, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+    pub struct RasterInfo {
+        /// Description of the dataset, usually its path.
+        pub description: String,
+        /// Short name of the driver, e.g. `GTiff`.
+        pub driver_short_name: String,
+        /// Long name of the driver, e.g. `GeoTIFF`.
+        pub driver_long_name: String,
+        /// Files of the dataset.
+        #[serde(default)]
+        pub files: Vec<String>,
+        /// Size of the dataset, as `[cols, rows]`.
+        pub size: [usize; 2],
+        /// Spatial reference of the dataset.
+        pub coordinate_system: Option<CoordinateSystemInfo>,
+        /// Geo-transform of the dataset.
+        pub geo_transform: Option<[f64; 6]>,
+        /// Metadata, by domain.
+        #[serde(default)]
+        pub metadata: BTreeMap<String, serde_json::Value>,
+        /// Georeferenced coordinates of the corners and center of the dataset.
+        pub corner_coordinates: Option<CornerCoordinates>,
+        /// Extent of the dataset in WGS84, as a GeoJSON polygon.
+        pub wgs84_extent: Option<serde_json::Value>,
+        /// Bands of the dataset.
+        #[serde(default)]
+        pub bands: Vec<BandInfo>,
+    }
+
+    /// Spatial reference of a [`RasterInfo`].
+    #[derive(Debug, Clone, PartialEq, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+    pub struct CoordinateSystemInfo {
+        /// WKT definition.
+        pub wkt: String,
+        /// Mapping of the axes of the data to those of the spatial reference.
+        #[serde(default)]
+        pub data_axis_to_srs_axis_mapping: Vec<i32>,
+    }
+
+    /// Corners and center of a [`RasterInfo`], as `[x, y]`.
+    #[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+    pub struct CornerCoordinates {
+        pub upper_left: [f64; 2],
+        pub lower_left: [f64; 2],
+        pub lower_right: [f64; 2],
+        pub upper_right: [f64; 2],
+        pub center: [f64; 2],
+    }
+
+    /// Band of a [`RasterInfo`].
+    #[derive(Debug, Clone, PartialEq, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+    pub struct BandInfo {
+        /// Index of the band, starting at 1.
+        pub band: usize,
+        /// Block size, as `[cols, rows]`.
+        pub block: [usize; 2],
+        /// Name of the data type, e.g. `Byte`.
+        #[serde(rename = "type")]
+        pub data_type: String,
+        /// Name of the color interpretation, e.g. `Gray`.
+        pub color_interpretation: Option<String>,
+        /// Description of the band.
+        pub description: Option<String>,
+        /// Nodata value.
+        #[serde(default, deserialize_with = "f64_or_string")]
+        pub no_data_value: Option<f64>,
+        /// Minimum computed with [`with_min_max`](super::InfoOptions::with_min_max).
+        #[serde(default, deserialize_with = "f64_or_string")]
+        pub computed_min: Option<f64>,
+        /// Maximum computed with [`with_min_max`](super::InfoOptions::with_min_max).
+        #[serde(default, deserialize_with = "f64_or_string")]
+        pub computed_max: Option<f64>,
+        /// Minimum from the statistics.
+        #[serde(default, deserialize_with = "f64_or_string")]
+        pub minimum: Option<f64>,
+        /// Maximum from the statistics.
+        #[serde(default, deserialize_with = "f64_or_string")]
+        pub maximum: Option<f64>,
+        /// Mean from the statistics.
+        #[serde(default, deserialize_with = "f64_or_string")]
+        pub mean: Option<f64>,
+        /// Standard deviation from the statistics.
+        #[serde(default, deserialize_with = "f64_or_string")]
+        pub std_dev: Option<f64>,
+        /// Offset of the values.
+        pub offset: Option<f64>,
+        /// Scale of the values.
+        pub scale: Option<f64>,
+        /// Unit of the values.
+        pub unit: Option<String>,
+        /// Checksum computed with [`with_checksum`](super::InfoOptions::with_checksum).
+        pub checksum: Option<i32>,
+        /// Overviews of the band.
+        #[serde(default)]
+        pub overviews: Vec<OverviewInfo>,
+        /// Metadata, by domain.
+        #[serde(default)]
+        pub metadata: BTreeMap<String, serde_json::Value>,
+    }
+
+    /// Overview of a [`BandInfo`].
+    #[derive(Debug, Clone, PartialEq, Deserialize)]
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+    pub struct OverviewInfo {
+        /// Size of the overview, as `[cols, rows]`.
+        pub size: [usize; 2],
+    }
+
+    /// Deserialize a float which GDAL writes as a string when it isn't finite.
+    fn f64_or_string<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<f64>, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Value {
+            Number(f64),
+            String(String),
+        }
+
+        match Option::<Value>::deserialize(deserializer)? {
+            Some(Value::Number(v)) => Ok(Some(v)),
+            Some(Value::String(s)) => s
+                .parse()
+                .map(Some)
+                .map_err(|_| serde::de::Error::custom(format!("invalid float '{s}'"))),
+            None => Ok(None),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::raster::Buffer;
+    use crate::test_utils::SuppressGDALErrorLog;
+    use crate::DriverManager;
+
+    fn image() -> Dataset {
+        let driver = DriverManager::get_driver_by_name("MEM").unwrap();
+        let mut ds = driver.create_with_band_type::<u8, _>("", 3, 2, 1).unwrap();
+        ds.set_geo_transform(&[10., 1., 0., 20., 0., -1.]).unwrap();
+        let mut band = ds.rasterband(1).unwrap();
+        band.write(
+            (0, 0),
+            (3, 2),
+            &mut Buffer::new((3, 2), vec![1, 2, 3, 4, 5, 6]),
+        )
+        .unwrap();
+        band.set_no_data_value(Some(0.)).unwrap();
+        ds
+    }
+
+    #[test]
+    fn test_info() {
+        let ds = image();
+        let info = ds.info(&InfoOptions::new()).unwrap();
+        assert!(info.starts_with("Driver: MEM/In Memory"));
+        assert!(info.contains("Size is 3, 2"));
+        assert!(info.contains("NoData Value=0"));
+
+        let mut options = InfoOptions::new();
+        options.with_json().with_min_max();
+        let info = ds.info(&options).unwrap();
+        assert!(info.starts_with('{'));
+        assert!(info.contains("\"computedMax\""));
+
+        let _nolog = SuppressGDALErrorLog::new();
+        assert!(ds
+            .info(&InfoOptions::from_args(["-not-an-option"]))
+            .is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_info_json() {
+        let ds = image();
+        let mut options = InfoOptions::new();
+        options.with_min_max().with_checksum();
+        let info = ds.info_json(&options).unwrap();
+        assert_eq!(info.driver_short_name, "MEM");
+        assert_eq!(info.size, [3, 2]);
+        assert_eq!(info.geo_transform, Some([10., 1., 0., 20., 0., -1.]));
+        assert_eq!(info.corner_coordinates.unwrap().lower_right, [13., 18.]);
+        assert_eq!(info.bands.len(), 1);
+        let band = &info.bands[0];
+        assert_eq!(band.data_type, "Byte");
+        assert_eq!(band.no_data_value, Some(0.));
+        assert_eq!((band.computed_min, band.computed_max), (Some(1.), Some(6.)));
+        assert!(band.checksum.is_some());
+    }
+}
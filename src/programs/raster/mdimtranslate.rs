@@ -1,4 +1,5 @@
 use crate::{
+    cpl::CslStringList,
     errors::*,
     utils::{_last_null_pointer_err, _path_to_c_string},
     Dataset,
@@ -80,6 +81,194 @@ impl TryFrom<Vec<&str>> for MultiDimTranslateOptions {
     }
 }
 
+/// Selection of an array for [`MultiDimTranslateBuilder::with_array`], as with the `-array`
+/// option of `gdalmdimtranslate`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MultiDimArraySpec {
+    name: String,
+    dst_name: Option<String>,
+    transpose: Option<Vec<usize>>,
+    view: Option<String>,
+}
+
+impl MultiDimArraySpec {
+    /// Select the array with the full `name`, e.g. `/group/temperature`.
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            dst_name: None,
+            transpose: None,
+            view: None,
+        }
+    }
+
+    /// Set the name of the array in the output.
+    pub fn with_dst_name(&mut self, dst_name: &str) -> &mut Self {
+        self.dst_name = Some(dst_name.to_string());
+        self
+    }
+
+    /// Reorder the dimensions of the array, e.g. `&[1, 0]` to swap the two dimensions of a
+    /// 2D array.
+    pub fn with_transpose(&mut self, axes: &[usize]) -> &mut Self {
+        self.transpose = Some(axes.to_vec());
+        self
+    }
+
+    /// Slice the array with a NumPy-like `view` expression, e.g. `"[0,::2,10:20]"`.
+    pub fn with_view(&mut self, view: &str) -> &mut Self {
+        self.view = Some(view.to_string());
+        self
+    }
+
+    fn to_spec_string(&self) -> String {
+        let mut spec = format!("name={}", self.name);
+        if let Some(dst_name) = &self.dst_name {
+            spec.push_str(&format!(",dstname={dst_name}"));
+        }
+        if let Some(transpose) = &self.transpose {
+            let axes = transpose
+                .iter()
+                .map(|axis| axis.to_string())
+                .collect::<Vec<_>>();
+            spec.push_str(&format!(",transpose=[{}]", axes.join(",")));
+        }
+        if let Some(view) = &self.view {
+            spec.push_str(&format!(",view={view}"));
+        }
+        spec
+    }
+}
+
+/// Typed builder of [`MultiDimTranslateOptions`].
+///
+/// These map to the [`gdalmdimtranslate`] command line options.
+///
+/// # Example
+///
+/// ```rust, no_run
+/// # fn main() -> gdal::errors::Result<()> {
+/// use gdal::programs::raster::{
+///     multi_dim_translate, MultiDimArraySpec, MultiDimTranslateBuilder,
+/// };
+/// use gdal::{Dataset, DatasetOptions, GdalOpenFlags};
+///
+/// let options = DatasetOptions {
+///     open_flags: GdalOpenFlags::GDAL_OF_MULTIDIM_RASTER,
+///     ..Default::default()
+/// };
+/// let dataset = Dataset::open_ex("model.nc", options)?;
+/// let mut temperature = MultiDimArraySpec::new("/temperature");
+/// temperature.with_dst_name("t2m");
+/// let options = MultiDimTranslateBuilder::new()
+///     .with_output_format("Zarr")
+///     .with_array(&temperature)
+///     .with_subset("time", "\"2024-01-01\",\"2024-01-31\"")
+///     .with_scale_axis("lat", 2)
+///     .with_scale_axis("lon", 2)
+///     .build()?;
+/// multi_dim_translate(&[dataset], "/tmp/january.zarr".try_into()?, Some(options))?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`gdalmdimtranslate`]: https://gdal.org/programs/gdalmdimtranslate.html
+#[derive(Debug, Clone, Default)]
+pub struct MultiDimTranslateBuilder {
+    output_format: Option<String>,
+    arrays: Vec<MultiDimArraySpec>,
+    groups: Vec<String>,
+    subsets: Vec<String>,
+    scale_axes: Vec<String>,
+    creation_options: Vec<String>,
+}
+
+impl MultiDimTranslateBuilder {
+    /// Create an empty options set, which copies all the arrays as-is.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Specify the output format, as the short name of a driver (e.g. `Zarr`, `netCDF`, `GTiff`).
+    ///
+    /// Defaults to the format guessed from the output file extension.
+    pub fn with_output_format(&mut self, format: &str) -> &mut Self {
+        self.output_format = Some(format.to_string());
+        self
+    }
+
+    /// Copy only the selected arrays, instead of all of them. Can be called several times.
+    pub fn with_array(&mut self, array: &MultiDimArraySpec) -> &mut Self {
+        self.arrays.push(array.clone());
+        self
+    }
+
+    /// Copy only the group with the full `name`, e.g. `/forecast`. Can be called several times.
+    pub fn with_group(&mut self, name: &str) -> &mut Self {
+        self.groups.push(format!("name={name}"));
+        self
+    }
+
+    /// Keep only the part of the arrays where `dimension` is within `values`, which are the
+    /// indexing variable values `"begin,end"`, or a single value to remove the dimension.
+    ///
+    /// String values such as dates must be double-quoted, e.g. `"\"2024-01-01\""`.
+    pub fn with_subset(&mut self, dimension: &str, values: &str) -> &mut Self {
+        self.subsets.push(format!("{dimension}({values})"));
+        self
+    }
+
+    /// Downsample `dimension` by an integer `factor`.
+    pub fn with_scale_axis(&mut self, dimension: &str, factor: u32) -> &mut Self {
+        self.scale_axes.push(format!("{dimension}({factor})"));
+        self
+    }
+
+    /// Pass creation options to the output driver.
+    pub fn with_creation_options(&mut self, options: &CslStringList) -> &mut Self {
+        self.creation_options
+            .extend(options.iter().map(|entry| entry.to_string()));
+        self
+    }
+
+    /// Render these options into `gdalmdimtranslate` command line arguments.
+    pub fn to_options_list(&self) -> Result<CslStringList> {
+        let mut opts = CslStringList::new();
+
+        if let Some(format) = &self.output_format {
+            opts.add_string("-of")?;
+            opts.add_string(format)?;
+        }
+        for array in &self.arrays {
+            opts.add_string("-array")?;
+            opts.add_string(&array.to_spec_string())?;
+        }
+        for group in &self.groups {
+            opts.add_string("-group")?;
+            opts.add_string(group)?;
+        }
+        for subset in &self.subsets {
+            opts.add_string("-subset")?;
+            opts.add_string(subset)?;
+        }
+        if !self.scale_axes.is_empty() {
+            opts.add_string("-scaleaxes")?;
+            opts.add_string(&self.scale_axes.join(","))?;
+        }
+        for option in &self.creation_options {
+            opts.add_string("-co")?;
+            opts.add_string(option)?;
+        }
+
+        Ok(opts)
+    }
+
+    /// Create the [`MultiDimTranslateOptions`] for [`multi_dim_translate`].
+    pub fn build(&self) -> Result<MultiDimTranslateOptions> {
+        MultiDimTranslateOptions::new(self.to_options_list()?.iter().map(|e| e.to_string()))
+    }
+}
+
 pub enum MultiDimTranslateDestination {
     Path(CString),
     Dataset {
@@ -262,6 +451,80 @@ mod tests {
         assert_eq!(dataset.raster_count(), 1);
     }
 
+    #[test]
+    fn test_builder_options_list() {
+        let mut array = MultiDimArraySpec::new("/grid/t");
+        array
+            .with_dst_name("temperature")
+            .with_transpose(&[1, 0])
+            .with_view("[::2,1:3]");
+        let mut creation_options = CslStringList::new();
+        creation_options.set_name_value("COMPRESS", "ZSTD").unwrap();
+        let mut builder = MultiDimTranslateBuilder::new();
+        builder
+            .with_output_format("Zarr")
+            .with_array(&array)
+            .with_group("/grid")
+            .with_subset("time", "\"2024-01-01\"")
+            .with_scale_axis("x", 2)
+            .with_scale_axis("y", 4)
+            .with_creation_options(&creation_options);
+        let args = builder
+            .to_options_list()
+            .unwrap()
+            .iter()
+            .map(|e| e.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            args,
+            [
+                "-of",
+                "Zarr",
+                "-array",
+                "name=/grid/t,dstname=temperature,transpose=[1,0],view=[::2,1:3]",
+                "-group",
+                "name=/grid",
+                "-subset",
+                "time(\"2024-01-01\")",
+                "-scaleaxes",
+                "x(2),y(4)",
+                "-co",
+                "COMPRESS=ZSTD",
+            ]
+        );
+    }
+
+    #[test]
+    #[cfg_attr(not(all(major_ge_3, minor_ge_4)), ignore)]
+    fn test_build_tiff_with_builder() {
+        let fixture = "/vsizip/fixtures/cf_nasa_4326.zarr.zip";
+
+        let dataset_options = DatasetOptions {
+            open_flags: GdalOpenFlags::GDAL_OF_MULTIDIM_RASTER,
+            allowed_drivers: None,
+            open_options: None,
+            sibling_files: None,
+        };
+        let dataset = Dataset::open_ex(fixture, dataset_options).unwrap();
+        let mem_file_path = "/vsimem/test_build_tiff_with_builder.tif";
+
+        let mut array = MultiDimArraySpec::new("/science/grids/imagingGeometry/lookAngle");
+        array.with_view("[2,:,:]");
+        let options = MultiDimTranslateBuilder::new()
+            .with_output_format("GTiff")
+            .with_array(&array)
+            .build()
+            .unwrap();
+        let dataset =
+            multi_dim_translate(&[dataset], mem_file_path.try_into().unwrap(), Some(options))
+                .unwrap();
+
+        assert_eq!(dataset.raster_size(), (5, 7));
+        assert_eq!(dataset.raster_count(), 1);
+        drop(dataset);
+        crate::vsi::unlink_mem_file(mem_file_path).unwrap();
+    }
+
     #[test]
     #[cfg_attr(not(all(major_ge_3, minor_ge_4)), ignore)]
     fn test_build_tiff_from_dataset() {
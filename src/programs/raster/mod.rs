@@ -1,9 +1,27 @@
+mod info;
 #[cfg(all(major_ge_3, minor_ge_1))]
 mod mdimtranslate;
+mod nearblack;
+mod rasterize;
+#[cfg(any(all(major_ge_3, minor_ge_9), major_ge_4))]
+mod tileindex;
+mod translate;
 mod vrt;
+mod warp;
 
+pub use crate::raster::WarpResampleAlg;
+pub use info::InfoOptions;
+#[cfg(feature = "serde")]
+pub use info::{BandInfo, CoordinateSystemInfo, CornerCoordinates, OverviewInfo, RasterInfo};
 #[cfg(all(major_ge_3, minor_ge_1))]
 pub use mdimtranslate::{
-    multi_dim_translate, MultiDimTranslateDestination, MultiDimTranslateOptions,
+    multi_dim_translate, MultiDimArraySpec, MultiDimTranslateBuilder, MultiDimTranslateDestination,
+    MultiDimTranslateOptions,
 };
+pub use nearblack::{nearblack, nearblack_in_place, NearblackOptions};
+pub use rasterize::{rasterize, rasterize_into, RasterizeAppOptions, RasterizeBurn};
+#[cfg(any(all(major_ge_3, minor_ge_9), major_ge_4))]
+pub use tileindex::{tile_index, TileIndexMetadataField, TileIndexOptions, TileIndexSrsFormat};
+pub use translate::{translate, OutputSize, TranslateOptions};
 pub use vrt::*;
+pub use warp::{warp, warp_into, WarpOptions};
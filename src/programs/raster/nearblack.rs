@@ -0,0 +1,334 @@
+use std::path::Path;
+use std::ptr::{null, null_mut, NonNull};
+
+use gdal_sys::{GDALDatasetH, GDALNearblackOptions};
+use libc::c_int;
+
+use crate::cpl::CslStringList;
+use crate::errors::*;
+use crate::progress::{cancelled_or, progress_args, CancellationToken};
+use crate::raster::RasterCreationOptions;
+use crate::utils::{_last_null_pointer_err, _path_to_c_string};
+use crate::Dataset;
+
+/// Configuration options for [`nearblack()`].
+///
+/// These map to the [`nearblack`] command line options. Arguments not covered by the
+/// setters can be parsed with [`from_args`](Self::from_args).
+///
+/// [`nearblack`]: https://gdal.org/programs/nearblack.html
+#[derive(Debug, Clone, Default)]
+pub struct NearblackOptions {
+    args: Vec<String>,
+    output_format: Option<String>,
+    white: bool,
+    colors: Vec<Vec<i32>>,
+    near: Option<usize>,
+    non_black_pixels: Option<usize>,
+    set_alpha: bool,
+    set_mask: bool,
+    creation_options: Option<RasterCreationOptions>,
+    cancellation: Option<CancellationToken>,
+}
+
+impl NearblackOptions {
+    /// Create an empty options set: search for near-black collars.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Create an options set from `nearblack` command line arguments, excluding the
+    /// source and destination.
+    ///
+    /// The arguments are parsed by GDAL when processing, which fails if they are invalid.
+    /// The setters add to these arguments.
+    pub fn from_args<S: Into<String>, I: IntoIterator<Item = S>>(args: I) -> Self {
+        Self {
+            args: args.into_iter().map(Into::into).collect(),
+            ..Default::default()
+        }
+    }
+
+    /// Specify the output format, as the short name of a driver (e.g. `GTiff`, `MEM`).
+    ///
+    /// Defaults to GeoTIFF, or the format guessed from the output file extension.
+    pub fn with_output_format(&mut self, format: &str) -> &mut Self {
+        self.output_format = Some(format.to_owned());
+        self
+    }
+
+    /// Search for near-white (255) collars, instead of near-black (0) ones.
+    pub fn with_white(&mut self) -> &mut Self {
+        self.white = true;
+        self
+    }
+
+    /// Search for collars near these colors, with one value per band, instead of black.
+    pub fn with_colors(&mut self, colors: &[&[i32]]) -> &mut Self {
+        self.colors = colors.iter().map(|c| c.to_vec()).collect();
+        self
+    }
+
+    /// Set how far from black, white or the colors the collar values can be. Defaults to `15`.
+    pub fn with_near(&mut self, distance: usize) -> &mut Self {
+        self.near = Some(distance);
+        self
+    }
+
+    /// Set the number of non-black pixels in a row that end the collar. Defaults to `2`.
+    pub fn with_non_black_pixels(&mut self, count: usize) -> &mut Self {
+        self.non_black_pixels = Some(count);
+        self
+    }
+
+    /// Add an alpha band to the output, transparent on the collar.
+    pub fn with_set_alpha(&mut self) -> &mut Self {
+        self.set_alpha = true;
+        self
+    }
+
+    /// Add a mask band to the output, invalid on the collar.
+    pub fn with_set_mask(&mut self) -> &mut Self {
+        self.set_mask = true;
+        self
+    }
+
+    /// Pass creation options to the output driver.
+    pub fn with_creation_options(&mut self, options: &RasterCreationOptions) -> &mut Self {
+        self.creation_options = Some(options.clone());
+        self
+    }
+
+    /// Abort the processing with [`GdalError::Cancelled`] once `token` is cancelled.
+    pub fn with_cancellation(&mut self, token: &CancellationToken) -> &mut Self {
+        self.cancellation = Some(token.clone());
+        self
+    }
+
+    /// Render these options into [`CslStringList`] values, as compatible with
+    /// [`gdal_sys::GDALNearblackOptionsNew`].
+    pub fn to_options_list(&self) -> Result<CslStringList> {
+        let mut opts = CslStringList::default();
+
+        for arg in &self.args {
+            opts.add_string(arg)?;
+        }
+
+        if let Some(format) = &self.output_format {
+            opts.add_string("-of")?;
+            opts.add_string(format)?;
+        }
+
+        if self.white {
+            opts.add_string("-white")?;
+        }
+
+        for color in &self.colors {
+            let values = color.iter().map(|v| v.to_string()).collect::<Vec<_>>();
+            opts.add_string("-color")?;
+            opts.add_string(&values.join(","))?;
+        }
+
+        if let Some(near) = self.near {
+            opts.add_string("-near")?;
+            opts.add_string(&near.to_string())?;
+        }
+
+        if let Some(count) = self.non_black_pixels {
+            opts.add_string("-nb")?;
+            opts.add_string(&count.to_string())?;
+        }
+
+        if self.set_alpha {
+            opts.add_string("-setalpha")?;
+        }
+
+        if self.set_mask {
+            opts.add_string("-setmask")?;
+        }
+
+        if let Some(creation_options) = &self.creation_options {
+            for entry in creation_options.iter() {
+                opts.add_string("-co")?;
+                opts.add_string(&entry.to_string())?;
+            }
+        }
+
+        Ok(opts)
+    }
+}
+
+impl From<Vec<&str>> for NearblackOptions {
+    fn from(value: Vec<&str>) -> Self {
+        NearblackOptions::from_args(value)
+    }
+}
+
+/// Payload for [`GDALNearblack`](gdal_sys::GDALNearblack).
+struct GdalNearblackOptions(NonNull<GDALNearblackOptions>);
+
+impl GdalNearblackOptions {
+    fn new(options: &NearblackOptions) -> Result<Self> {
+        let opts = options.to_options_list()?;
+        let popts = unsafe { gdal_sys::GDALNearblackOptionsNew(opts.as_ptr(), null_mut()) };
+        let popts = match NonNull::new(popts) {
+            Some(popts) => Self(popts),
+            None => return Err(_last_null_pointer_err("GDALNearblackOptionsNew")),
+        };
+        if let Some(token) = &options.cancellation {
+            let (progress, progress_arg) = progress_args(Some(token));
+            unsafe {
+                gdal_sys::GDALNearblackOptionsSetProgress(popts.0.as_ptr(), progress, progress_arg)
+            };
+        }
+        Ok(popts)
+    }
+}
+
+impl Drop for GdalNearblackOptions {
+    fn drop(&mut self) {
+        unsafe { gdal_sys::GDALNearblackOptionsFree(self.0.as_ptr()) };
+    }
+}
+
+/// Copy `source` into a new dataset at `dest`, setting to exactly black (or white) the
+/// near-black collars scanned from its edges.
+///
+/// This cleans up the noisy collars left by lossy compression around mosaicked images, so that
+/// they can be treated as nodata.
+///
+/// Wraps [GDALNearblack].
+/// See the [program docs] for more details.
+///
+/// # Example
+///
+/// ```rust, no_run
+/// # fn main() -> gdal::errors::Result<()> {
+/// use gdal::programs::raster::{nearblack, NearblackOptions};
+/// use gdal::Dataset;
+///
+/// let mosaic = Dataset::open("fixtures/tinymarble.tif")?;
+/// let mut options = NearblackOptions::new();
+/// options.with_near(10).with_set_alpha();
+/// let cleaned = nearblack(&mosaic, "/tmp/cleaned.tif", &options)?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [GDALNearblack]: https://gdal.org/api/gdal_utils.html#_CPPv413GDALNearblackPKc12GDALDatasetH12GDALDatasetHPK20GDALNearblackOptionsPi
+/// [program docs]: https://gdal.org/programs/nearblack.html
+pub fn nearblack<P: AsRef<Path>>(
+    source: &Dataset,
+    dest: P,
+    options: &NearblackOptions,
+) -> Result<Dataset> {
+    let dest = _path_to_c_string(dest.as_ref())?;
+    let out_ds = _nearblack(Some(dest.as_ptr()), null_mut(), source.c_dataset(), options)?;
+    Ok(unsafe { Dataset::from_c_dataset(out_ds) })
+}
+
+/// Set the near-black collars of `dataset` to exactly black (or white) in place, like
+/// [`nearblack`].
+///
+/// The options about the output format, creation options, alpha and mask must not be set.
+pub fn nearblack_in_place(dataset: &mut Dataset, options: &NearblackOptions) -> Result<()> {
+    // GDALNearblack returns `dataset` itself, which we already own.
+    _nearblack(None, dataset.c_dataset(), dataset.c_dataset(), options)?;
+    Ok(())
+}
+
+fn _nearblack(
+    dest_path: Option<*const libc::c_char>,
+    dest_ds: GDALDatasetH,
+    source_ds: GDALDatasetH,
+    options: &NearblackOptions,
+) -> Result<GDALDatasetH> {
+    let popts = GdalNearblackOptions::new(options)?;
+
+    let mut pb_usage_error: c_int = 0;
+    let out_ds = unsafe {
+        gdal_sys::GDALNearblack(
+            dest_path.unwrap_or(null()),
+            dest_ds,
+            source_ds,
+            popts.0.as_ptr(),
+            &mut pb_usage_error,
+        )
+    };
+    if out_ds.is_null() {
+        let err = _last_null_pointer_err("GDALNearblack");
+        return Err(cancelled_or(options.cancellation.as_ref(), err));
+    }
+    Ok(out_ds)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::raster::Buffer;
+    use crate::test_utils::SuppressGDALErrorLog;
+    use crate::DriverManager;
+
+    /// A 5x1 image with a noisy black collar on the left and a noisy white one on the right.
+    fn image() -> Dataset {
+        let driver = DriverManager::get_driver_by_name("MEM").unwrap();
+        let ds = driver.create_with_band_type::<u8, _>("", 5, 1, 1).unwrap();
+        ds.rasterband(1)
+            .unwrap()
+            .write(
+                (0, 0),
+                (5, 1),
+                &mut Buffer::new((5, 1), vec![5, 3, 100, 200, 250]),
+            )
+            .unwrap();
+        ds
+    }
+
+    fn read(ds: &Dataset, band: usize) -> Vec<u8> {
+        ds.rasterband(band)
+            .unwrap()
+            .read_band_as::<u8>()
+            .unwrap()
+            .into_shape_and_vec()
+            .1
+    }
+
+    #[test]
+    fn test_nearblack() {
+        let source = image();
+        let mut options = NearblackOptions::new();
+        options.with_output_format("MEM").with_near(10);
+        let out = nearblack(&source, "", &options).unwrap();
+        assert_eq!(read(&out, 1), [0, 0, 100, 200, 250]);
+
+        options.with_set_alpha();
+        let out = nearblack(&source, "", &options).unwrap();
+        assert_eq!(out.raster_count(), 2);
+        assert_eq!(read(&out, 2), [0, 0, 255, 255, 255]);
+    }
+
+    #[test]
+    fn test_nearblack_in_place() {
+        let mut ds = image();
+        let mut options = NearblackOptions::new();
+        options.with_white().with_near(10);
+        nearblack_in_place(&mut ds, &options).unwrap();
+        assert_eq!(read(&ds, 1), [5, 3, 100, 200, 255]);
+    }
+
+    #[test]
+    fn test_nearblack_errors() {
+        let _nolog = SuppressGDALErrorLog::new();
+        let options = NearblackOptions::from_args(["-not-an-option"]);
+        assert!(nearblack(&image(), "", &options).is_err());
+
+        let token = CancellationToken::new();
+        token.cancel();
+        let mut options = NearblackOptions::new();
+        options.with_output_format("MEM").with_cancellation(&token);
+        assert!(matches!(
+            nearblack(&image(), "", &options),
+            Err(GdalError::Cancelled)
+        ));
+    }
+}
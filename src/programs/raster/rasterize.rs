@@ -0,0 +1,535 @@
+use std::path::Path;
+use std::ptr::{null, null_mut, NonNull};
+
+use gdal_sys::{GDALDatasetH, GDALRasterizeOptions};
+use libc::c_int;
+
+use crate::cpl::CslStringList;
+use crate::errors::*;
+use crate::progress::{cancelled_or, progress_args, CancellationToken};
+use crate::raster::{GdalDataType, RasterCreationOptions};
+use crate::utils::{_last_null_pointer_err, _path_to_c_string};
+use crate::Dataset;
+
+/// Value burnt by [`rasterize()`] into the output bands.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RasterizeBurn {
+    /// Fixed values, one per output band, or a single one for all of them (`-burn`).
+    Values(Vec<f64>),
+    /// The value of an attribute field of the features (`-a`).
+    Attribute(String),
+    /// The Z values of the geometries, added to the fixed values if any (`-3d`).
+    Z(Vec<f64>),
+}
+
+/// Configuration options for [`rasterize()`].
+///
+/// These map to the [`gdal_rasterize`] command line options. Arguments not covered by the
+/// setters can be parsed with [`from_args`](Self::from_args).
+///
+/// [`gdal_rasterize`]: https://gdal.org/programs/gdal_rasterize.html
+#[derive(Debug, Clone, Default)]
+pub struct RasterizeAppOptions {
+    args: Vec<String>,
+    output_format: Option<String>,
+    output_type: Option<GdalDataType>,
+    burn: Option<RasterizeBurn>,
+    layers: Vec<String>,
+    where_clause: Option<String>,
+    sql: Option<String>,
+    bands: Vec<usize>,
+    all_touched: bool,
+    add: bool,
+    invert: bool,
+    init_values: Vec<f64>,
+    no_data_value: Option<f64>,
+    target_srs: Option<String>,
+    extent: Option<[f64; 4]>,
+    resolution: Option<(f64, f64)>,
+    target_aligned_pixels: bool,
+    output_size: Option<(usize, usize)>,
+    creation_options: Option<RasterCreationOptions>,
+    cancellation: Option<CancellationToken>,
+}
+
+impl RasterizeAppOptions {
+    /// Create an empty options set.
+    ///
+    /// What to burn must be set with [`with_burn`](Self::with_burn).
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Create an options set from `gdal_rasterize` command line arguments, excluding the
+    /// source and destination.
+    ///
+    /// The arguments are parsed by GDAL when rasterizing, which fails if they are invalid.
+    /// The setters add to these arguments.
+    pub fn from_args<S: Into<String>, I: IntoIterator<Item = S>>(args: I) -> Self {
+        Self {
+            args: args.into_iter().map(Into::into).collect(),
+            ..Default::default()
+        }
+    }
+
+    /// Specify the output format, as the short name of a driver (e.g. `GTiff`, `MEM`).
+    ///
+    /// Defaults to GeoTIFF, or the format guessed from the output file extension.
+    pub fn with_output_format(&mut self, format: &str) -> &mut Self {
+        self.output_format = Some(format.to_owned());
+        self
+    }
+
+    /// Set the data type of the output bands. Defaults to `Float64`.
+    pub fn with_output_type(&mut self, data_type: GdalDataType) -> &mut Self {
+        self.output_type = Some(data_type);
+        self
+    }
+
+    /// Set the value burnt into the output bands.
+    pub fn with_burn(&mut self, burn: RasterizeBurn) -> &mut Self {
+        self.burn = Some(burn);
+        self
+    }
+
+    /// Only rasterize the given layers of the source. Defaults to all of them.
+    pub fn with_layers(&mut self, layers: &[&str]) -> &mut Self {
+        self.layers = layers.iter().map(|&l| l.to_owned()).collect();
+        self
+    }
+
+    /// Only rasterize the features matching this attribute filter.
+    pub fn with_where(&mut self, where_clause: &str) -> &mut Self {
+        self.where_clause = Some(where_clause.to_owned());
+        self
+    }
+
+    /// Rasterize the result of this SQL statement, instead of the layers of the source.
+    pub fn with_sql(&mut self, sql: &str) -> &mut Self {
+        self.sql = Some(sql.to_owned());
+        self
+    }
+
+    /// Select the (1-based) bands of an existing output to burn into. Defaults to all of them.
+    pub fn with_bands(&mut self, bands: &[usize]) -> &mut Self {
+        self.bands = bands.to_vec();
+        self
+    }
+
+    /// Burn all the pixels touched by the geometries, not only those whose center is inside.
+    pub fn with_all_touched(&mut self) -> &mut Self {
+        self.all_touched = true;
+        self
+    }
+
+    /// Add the burn values to the existing pixel values, instead of replacing them.
+    pub fn with_add(&mut self) -> &mut Self {
+        self.add = true;
+        self
+    }
+
+    /// Burn the pixels outside of the polygons, instead of those inside.
+    pub fn with_invert(&mut self) -> &mut Self {
+        self.invert = true;
+        self
+    }
+
+    /// Initialize the output bands to these values, one per band or a single one for all of them.
+    pub fn with_init_values(&mut self, values: &[f64]) -> &mut Self {
+        self.init_values = values.to_vec();
+        self
+    }
+
+    /// Set the nodata value of the output bands.
+    pub fn with_no_data_value(&mut self, no_data: f64) -> &mut Self {
+        self.no_data_value = Some(no_data);
+        self
+    }
+
+    /// Override the spatial reference of the output, in any format accepted by
+    /// [`SpatialRef::from_definition`](crate::spatial_ref::SpatialRef::from_definition).
+    pub fn with_target_srs(&mut self, srs: &str) -> &mut Self {
+        self.target_srs = Some(srs.to_owned());
+        self
+    }
+
+    /// Set the output extent, as `[xmin, ymin, xmax, ymax]`. Defaults to that of the source.
+    pub fn with_extent(&mut self, extent: [f64; 4]) -> &mut Self {
+        self.extent = Some(extent);
+        self
+    }
+
+    /// Set the output pixel size, in georeferenced units.
+    ///
+    /// Can't be combined with [`with_output_size`](Self::with_output_size).
+    pub fn with_resolution(&mut self, x_res: f64, y_res: f64) -> &mut Self {
+        self.resolution = Some((x_res, y_res));
+        self
+    }
+
+    /// Align the output extent to multiples of the resolution.
+    ///
+    /// Requires [`with_resolution`](Self::with_resolution).
+    pub fn with_target_aligned_pixels(&mut self) -> &mut Self {
+        self.target_aligned_pixels = true;
+        self
+    }
+
+    /// Set the output size, as `(cols, rows)`.
+    pub fn with_output_size(&mut self, cols: usize, rows: usize) -> &mut Self {
+        self.output_size = Some((cols, rows));
+        self
+    }
+
+    /// Pass creation options to the output driver.
+    pub fn with_creation_options(&mut self, options: &RasterCreationOptions) -> &mut Self {
+        self.creation_options = Some(options.clone());
+        self
+    }
+
+    /// Abort the rasterization with [`GdalError::Cancelled`] once `token` is cancelled.
+    pub fn with_cancellation(&mut self, token: &CancellationToken) -> &mut Self {
+        self.cancellation = Some(token.clone());
+        self
+    }
+
+    /// Render these options into [`CslStringList`] values, as compatible with
+    /// [`gdal_sys::GDALRasterizeOptionsNew`].
+    pub fn to_options_list(&self) -> Result<CslStringList> {
+        let mut opts = CslStringList::default();
+
+        for arg in &self.args {
+            opts.add_string(arg)?;
+        }
+
+        if let Some(format) = &self.output_format {
+            opts.add_string("-of")?;
+            opts.add_string(format)?;
+        }
+
+        if let Some(data_type) = self.output_type {
+            opts.add_string("-ot")?;
+            opts.add_string(&data_type.name())?;
+        }
+
+        match &self.burn {
+            Some(RasterizeBurn::Values(values)) => {
+                for value in values {
+                    opts.add_string("-burn")?;
+                    opts.add_string(&value.to_string())?;
+                }
+            }
+            Some(RasterizeBurn::Attribute(field)) => {
+                opts.add_string("-a")?;
+                opts.add_string(field)?;
+            }
+            Some(RasterizeBurn::Z(values)) => {
+                opts.add_string("-3d")?;
+                for value in values {
+                    opts.add_string("-burn")?;
+                    opts.add_string(&value.to_string())?;
+                }
+            }
+            None => {}
+        }
+
+        for layer in &self.layers {
+            opts.add_string("-l")?;
+            opts.add_string(layer)?;
+        }
+
+        if let Some(where_clause) = &self.where_clause {
+            opts.add_string("-where")?;
+            opts.add_string(where_clause)?;
+        }
+
+        if let Some(sql) = &self.sql {
+            opts.add_string("-sql")?;
+            opts.add_string(sql)?;
+        }
+
+        for band in &self.bands {
+            opts.add_string("-b")?;
+            opts.add_string(&band.to_string())?;
+        }
+
+        if self.all_touched {
+            opts.add_string("-at")?;
+        }
+
+        if self.add {
+            opts.add_string("-add")?;
+        }
+
+        if self.invert {
+            opts.add_string("-i")?;
+        }
+
+        for value in &self.init_values {
+            opts.add_string("-init")?;
+            opts.add_string(&value.to_string())?;
+        }
+
+        if let Some(no_data) = self.no_data_value {
+            opts.add_string("-a_nodata")?;
+            opts.add_string(&no_data.to_string())?;
+        }
+
+        if let Some(srs) = &self.target_srs {
+            opts.add_string("-a_srs")?;
+            opts.add_string(srs)?;
+        }
+
+        if let Some(extent) = self.extent {
+            opts.add_string("-te")?;
+            for v in extent {
+                opts.add_string(&v.to_string())?;
+            }
+        }
+
+        if let Some((x_res, y_res)) = self.resolution {
+            opts.add_string("-tr")?;
+            opts.add_string(&x_res.to_string())?;
+            opts.add_string(&y_res.to_string())?;
+        }
+
+        if self.target_aligned_pixels {
+            opts.add_string("-tap")?;
+        }
+
+        if let Some((cols, rows)) = self.output_size {
+            opts.add_string("-ts")?;
+            opts.add_string(&cols.to_string())?;
+            opts.add_string(&rows.to_string())?;
+        }
+
+        if let Some(creation_options) = &self.creation_options {
+            for entry in creation_options.iter() {
+                opts.add_string("-co")?;
+                opts.add_string(&entry.to_string())?;
+            }
+        }
+
+        Ok(opts)
+    }
+}
+
+impl From<Vec<&str>> for RasterizeAppOptions {
+    fn from(value: Vec<&str>) -> Self {
+        RasterizeAppOptions::from_args(value)
+    }
+}
+
+/// Payload for [`GDALRasterize`](gdal_sys::GDALRasterize).
+struct GdalRasterizeOptions(NonNull<GDALRasterizeOptions>);
+
+impl GdalRasterizeOptions {
+    fn new(options: &RasterizeAppOptions) -> Result<Self> {
+        let opts = options.to_options_list()?;
+        let popts = unsafe { gdal_sys::GDALRasterizeOptionsNew(opts.as_ptr(), null_mut()) };
+        let popts = match NonNull::new(popts) {
+            Some(popts) => Self(popts),
+            None => return Err(_last_null_pointer_err("GDALRasterizeOptionsNew")),
+        };
+        if let Some(token) = &options.cancellation {
+            let (progress, progress_arg) = progress_args(Some(token));
+            unsafe {
+                gdal_sys::GDALRasterizeOptionsSetProgress(popts.0.as_ptr(), progress, progress_arg)
+            };
+        }
+        Ok(popts)
+    }
+}
+
+impl Drop for GdalRasterizeOptions {
+    fn drop(&mut self) {
+        unsafe { gdal_sys::GDALRasterizeOptionsFree(self.0.as_ptr()) };
+    }
+}
+
+/// Burn the geometries of the vector dataset `source` into a new raster dataset.
+///
+/// The output extent and size must be set, either with
+/// [`with_output_size`](RasterizeAppOptions::with_output_size) or
+/// [`with_resolution`](RasterizeAppOptions::with_resolution).
+///
+/// Wraps [GDALRasterize].
+/// See the [program docs] for more details.
+///
+/// # Example
+///
+/// ```rust, no_run
+/// # fn main() -> gdal::errors::Result<()> {
+/// use gdal::Dataset;
+/// use gdal::programs::raster::{rasterize, RasterizeAppOptions, RasterizeBurn};
+/// use gdal::raster::GdalDataType;
+///
+/// let zones = Dataset::open("fixtures/roads.geojson")?;
+/// let mut options = RasterizeAppOptions::new();
+/// options
+///     .with_burn(RasterizeBurn::Attribute("sort_key".to_string()))
+///     .with_all_touched()
+///     .with_output_type(GdalDataType::Int16)
+///     .with_resolution(0.0001, 0.0001);
+/// let raster = rasterize(&zones, "/tmp/roads.tif", &options)?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [GDALRasterize]: https://gdal.org/api/gdal_utils.html#_CPPv413GDALRasterizePKc12GDALDatasetH12GDALDatasetHPK20GDALRasterizeOptionsPi
+/// [program docs]: https://gdal.org/programs/gdal_rasterize.html
+pub fn rasterize<P: AsRef<Path>>(
+    source: &Dataset,
+    dest: P,
+    options: &RasterizeAppOptions,
+) -> Result<Dataset> {
+    let dest = _path_to_c_string(dest.as_ref())?;
+    let out_ds = _rasterize(source, Some(dest.as_ptr()), null_mut(), options)?;
+    Ok(unsafe { Dataset::from_c_dataset(out_ds) })
+}
+
+/// Burn the geometries of the vector dataset `source` into the existing dataset `dest`,
+/// like [`rasterize`].
+///
+/// The options about the output format, extent and size must not be set.
+pub fn rasterize_into(
+    source: &Dataset,
+    dest: &mut Dataset,
+    options: &RasterizeAppOptions,
+) -> Result<()> {
+    // GDALRasterize returns `dest` itself, which we already own.
+    _rasterize(source, None, dest.c_dataset(), options)?;
+    Ok(())
+}
+
+fn _rasterize(
+    source: &Dataset,
+    dest_path: Option<*const libc::c_char>,
+    dest_ds: GDALDatasetH,
+    options: &RasterizeAppOptions,
+) -> Result<GDALDatasetH> {
+    let popts = GdalRasterizeOptions::new(options)?;
+
+    let mut pb_usage_error: c_int = 0;
+    let out_ds = unsafe {
+        gdal_sys::GDALRasterize(
+            dest_path.unwrap_or(null()),
+            dest_ds,
+            source.c_dataset(),
+            popts.0.as_ptr(),
+            &mut pb_usage_error,
+        )
+    };
+    if out_ds.is_null() {
+        let err = _last_null_pointer_err("GDALRasterize");
+        return Err(cancelled_or(options.cancellation.as_ref(), err));
+    }
+    Ok(out_ds)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::SuppressGDALErrorLog;
+    use crate::vector::{Geometry, LayerAccess, LayerOptions, OGRFieldType};
+    use crate::DriverManager;
+
+    /// A vector dataset with two squares, whose `value` fields are 1 and 2.
+    fn squares() -> Dataset {
+        let driver = DriverManager::get_driver_by_name("Memory").unwrap();
+        let mut ds = driver.create_vector_only("").unwrap();
+        let mut layer = ds
+            .create_layer(LayerOptions {
+                name: "squares",
+                ty: gdal_sys::OGRwkbGeometryType::wkbPolygon,
+                ..Default::default()
+            })
+            .unwrap();
+        layer
+            .create_defn_fields(&[("value", OGRFieldType::OFTInteger)])
+            .unwrap();
+        for (value, wkt) in [
+            (1, "POLYGON ((0 0,2 0,2 2,0 2,0 0))"),
+            (2, "POLYGON ((2 2,4 2,4 4,2 4,2 2))"),
+        ] {
+            layer
+                .create_feature_fields(
+                    Geometry::from_wkt(wkt).unwrap(),
+                    &["value"],
+                    &[crate::vector::FieldValue::IntegerValue(value)],
+                )
+                .unwrap();
+        }
+        ds
+    }
+
+    fn read(ds: &Dataset) -> Vec<u8> {
+        ds.rasterband(1)
+            .unwrap()
+            .read_band_as::<u8>()
+            .unwrap()
+            .into_shape_and_vec()
+            .1
+    }
+
+    #[test]
+    fn test_rasterize() {
+        let source = squares();
+        let mut options = RasterizeAppOptions::new();
+        options
+            .with_output_format("MEM")
+            .with_output_type(GdalDataType::UInt8)
+            .with_burn(RasterizeBurn::Attribute("value".to_string()))
+            .with_extent([0., 0., 4., 4.])
+            .with_resolution(1., 1.);
+        let out = rasterize(&source, "", &options).unwrap();
+        assert_eq!(out.raster_size(), (4, 4));
+        assert_eq!(read(&out), [0, 0, 2, 2, 0, 0, 2, 2, 1, 1, 0, 0, 1, 1, 0, 0]);
+
+        let options = RasterizeAppOptions::from_args([
+            "-of", "MEM", "-ot", "Byte", "-burn", "7", "-te", "0", "0", "4", "4", "-ts", "2", "2",
+        ]);
+        let out = rasterize(&source, "", &options).unwrap();
+        assert_eq!(read(&out), [0, 7, 7, 0]);
+    }
+
+    #[test]
+    fn test_rasterize_into() {
+        let source = squares();
+        let driver = DriverManager::get_driver_by_name("MEM").unwrap();
+        let mut dest = driver.create_with_band_type::<u8, _>("", 4, 4, 1).unwrap();
+        dest.set_geo_transform(&[0., 1., 0., 4., 0., -1.]).unwrap();
+        dest.rasterband(1).unwrap().fill(5., None).unwrap();
+
+        let mut options = RasterizeAppOptions::new();
+        options
+            .with_burn(RasterizeBurn::Values(vec![10.]))
+            .with_where("value = 2")
+            .with_add();
+        rasterize_into(&source, &mut dest, &options).unwrap();
+        assert_eq!(
+            read(&dest),
+            [5, 5, 15, 15, 5, 5, 15, 15, 5, 5, 5, 5, 5, 5, 5, 5]
+        );
+    }
+
+    #[test]
+    fn test_rasterize_errors() {
+        let _nolog = SuppressGDALErrorLog::new();
+        let options = RasterizeAppOptions::from_args(["-not-an-option"]);
+        assert!(rasterize(&squares(), "", &options).is_err());
+
+        let token = CancellationToken::new();
+        token.cancel();
+        let mut options = RasterizeAppOptions::new();
+        options
+            .with_output_format("MEM")
+            .with_output_size(4, 4)
+            .with_burn(RasterizeBurn::Values(vec![1.]))
+            .with_cancellation(&token);
+        assert!(matches!(
+            rasterize(&squares(), "", &options),
+            Err(GdalError::Cancelled)
+        ));
+    }
+}
@@ -0,0 +1,388 @@
+use std::path::Path;
+use std::ptr::{null, null_mut, NonNull};
+
+use gdal_sys::{GDALTileIndexOptions, OGRFieldType};
+use libc::c_int;
+
+use crate::cpl::CslStringList;
+use crate::errors::*;
+use crate::utils::{_last_null_pointer_err, _path_to_c_string};
+use crate::vector::field_type_to_name;
+use crate::Dataset;
+
+/// Format of the spatial references written by [`tile_index`] to the source SRS field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TileIndexSrsFormat {
+    /// `EPSG:<code>` when possible, or else WKT (the default).
+    Auto,
+    /// WKT.
+    Wkt,
+    /// `EPSG:<code>`.
+    Epsg,
+    /// PROJ string.
+    Proj,
+}
+
+/// A metadata item of the sources written by [`tile_index`] to a field.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TileIndexMetadataField {
+    /// The name of the metadata item, in the default domain.
+    pub item: String,
+    /// The name of the field.
+    pub field: String,
+    /// The type of the field.
+    pub field_type: OGRFieldType::Type,
+}
+
+/// Configuration options for [`tile_index`].
+///
+/// These map to the [`gdaltindex`] command line options. Arguments not covered by the
+/// setters can be parsed with [`from_args`](Self::from_args).
+///
+/// [`gdaltindex`]: https://gdal.org/programs/gdaltindex.html
+#[derive(Debug, Clone, Default)]
+pub struct TileIndexOptions {
+    args: Vec<String>,
+    output_format: Option<String>,
+    layer_name: Option<String>,
+    location_field: Option<String>,
+    write_absolute_path: bool,
+    skip_different_projection: bool,
+    target_srs: Option<String>,
+    src_srs_field: Option<String>,
+    src_srs_format: Option<TileIndexSrsFormat>,
+    metadata_fields: Vec<TileIndexMetadataField>,
+    layer_creation_options: Option<CslStringList>,
+    overwrite: bool,
+}
+
+impl TileIndexOptions {
+    /// Create an empty options set.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Create an options set from `gdaltindex` command line arguments, excluding the
+    /// destination and sources.
+    ///
+    /// The arguments are parsed by GDAL when building the index, which fails if they are
+    /// invalid. The setters add to these arguments.
+    pub fn from_args<S: Into<String>, I: IntoIterator<Item = S>>(args: I) -> Self {
+        Self {
+            args: args.into_iter().map(Into::into).collect(),
+            ..Default::default()
+        }
+    }
+
+    /// Specify the output format, as the short name of a vector driver (e.g. `GPKG`).
+    ///
+    /// Defaults to ESRI Shapefile, or the format guessed from the output file extension.
+    pub fn with_output_format(&mut self, format: &str) -> &mut Self {
+        self.output_format = Some(format.to_owned());
+        self
+    }
+
+    /// Set the name of the output layer. Defaults to the output file name.
+    pub fn with_layer_name(&mut self, name: &str) -> &mut Self {
+        self.layer_name = Some(name.to_owned());
+        self
+    }
+
+    /// Set the name of the field storing the path of each source. Defaults to `location`.
+    pub fn with_location_field(&mut self, name: &str) -> &mut Self {
+        self.location_field = Some(name.to_owned());
+        self
+    }
+
+    /// Store the absolute paths of the sources, instead of the given ones.
+    pub fn with_write_absolute_path(&mut self) -> &mut Self {
+        self.write_absolute_path = true;
+        self
+    }
+
+    /// Skip the sources whose spatial reference differs from that of the index.
+    pub fn with_skip_different_projection(&mut self) -> &mut Self {
+        self.skip_different_projection = true;
+        self
+    }
+
+    /// Reproject the footprints of the sources to a spatial reference in any format accepted
+    /// by [`SpatialRef::from_definition`](crate::spatial_ref::SpatialRef::from_definition).
+    ///
+    /// Defaults to the spatial reference of the first source.
+    pub fn with_target_srs(&mut self, srs: &str) -> &mut Self {
+        self.target_srs = Some(srs.to_owned());
+        self
+    }
+
+    /// Store the spatial reference of each source in the field `name`.
+    pub fn with_src_srs_field(&mut self, name: &str) -> &mut Self {
+        self.src_srs_field = Some(name.to_owned());
+        self
+    }
+
+    /// Set the format of the spatial references stored by
+    /// [`with_src_srs_field`](Self::with_src_srs_field).
+    pub fn with_src_srs_format(&mut self, format: TileIndexSrsFormat) -> &mut Self {
+        self.src_srs_format = Some(format);
+        self
+    }
+
+    /// Store metadata items of the sources in fields of the index.
+    pub fn with_metadata_fields(&mut self, fields: &[TileIndexMetadataField]) -> &mut Self {
+        self.metadata_fields = fields.to_vec();
+        self
+    }
+
+    /// Pass layer creation options to the output driver.
+    pub fn with_layer_creation_options(&mut self, options: &CslStringList) -> &mut Self {
+        self.layer_creation_options = Some(options.clone());
+        self
+    }
+
+    /// Replace the output layer if it already exists, instead of appending to it.
+    pub fn with_overwrite(&mut self) -> &mut Self {
+        self.overwrite = true;
+        self
+    }
+
+    /// Render these options into [`CslStringList`] values, as compatible with
+    /// [`gdal_sys::GDALTileIndexOptionsNew`].
+    pub fn to_options_list(&self) -> Result<CslStringList> {
+        let mut opts = CslStringList::default();
+
+        for arg in &self.args {
+            opts.add_string(arg)?;
+        }
+
+        if let Some(format) = &self.output_format {
+            opts.add_string("-f")?;
+            opts.add_string(format)?;
+        }
+
+        if let Some(name) = &self.layer_name {
+            opts.add_string("-lyr_name")?;
+            opts.add_string(name)?;
+        }
+
+        if let Some(name) = &self.location_field {
+            opts.add_string("-tileindex")?;
+            opts.add_string(name)?;
+        }
+
+        if self.write_absolute_path {
+            opts.add_string("-write_absolute_path")?;
+        }
+
+        if self.skip_different_projection {
+            opts.add_string("-skip_different_projection")?;
+        }
+
+        if let Some(srs) = &self.target_srs {
+            opts.add_string("-t_srs")?;
+            opts.add_string(srs)?;
+        }
+
+        if let Some(name) = &self.src_srs_field {
+            opts.add_string("-src_srs_name")?;
+            opts.add_string(name)?;
+        }
+
+        if let Some(format) = self.src_srs_format {
+            opts.add_string("-src_srs_format")?;
+            opts.add_string(match format {
+                TileIndexSrsFormat::Auto => "AUTO",
+                TileIndexSrsFormat::Wkt => "WKT",
+                TileIndexSrsFormat::Epsg => "EPSG",
+                TileIndexSrsFormat::Proj => "PROJ",
+            })?;
+        }
+
+        for field in &self.metadata_fields {
+            opts.add_string("-fetch_md")?;
+            opts.add_string(&field.item)?;
+            opts.add_string(&field.field)?;
+            opts.add_string(&field_type_to_name(field.field_type))?;
+        }
+
+        if let Some(creation_options) = &self.layer_creation_options {
+            for entry in creation_options.iter() {
+                opts.add_string("-lco")?;
+                opts.add_string(&entry.to_string())?;
+            }
+        }
+
+        if self.overwrite {
+            opts.add_string("-overwrite")?;
+        }
+
+        Ok(opts)
+    }
+}
+
+impl From<Vec<&str>> for TileIndexOptions {
+    fn from(value: Vec<&str>) -> Self {
+        TileIndexOptions::from_args(value)
+    }
+}
+
+/// Payload for [`GDALTileIndex`](gdal_sys::GDALTileIndex).
+struct GdalTileIndexOptions(NonNull<GDALTileIndexOptions>);
+
+impl GdalTileIndexOptions {
+    fn new(options: &TileIndexOptions) -> Result<Self> {
+        let opts = options.to_options_list()?;
+        let popts = unsafe { gdal_sys::GDALTileIndexOptionsNew(opts.as_ptr(), null_mut()) };
+        match NonNull::new(popts) {
+            Some(popts) => Ok(Self(popts)),
+            None => Err(_last_null_pointer_err("GDALTileIndexOptionsNew")),
+        }
+    }
+}
+
+impl Drop for GdalTileIndexOptions {
+    fn drop(&mut self) {
+        unsafe { gdal_sys::GDALTileIndexOptionsFree(self.0.as_ptr()) };
+    }
+}
+
+/// Build a vector tile index at `dest`, with the footprint and path of each raster of `paths`.
+///
+/// An existing index is opened and appended to. The sources are only opened by GDAL while
+/// building the index.
+///
+/// Wraps [GDALTileIndex].
+/// See the [program docs] for more details.
+///
+/// # Example
+///
+/// ```rust, no_run
+/// # fn main() -> gdal::errors::Result<()> {
+/// use gdal::programs::raster::{tile_index, TileIndexMetadataField, TileIndexOptions};
+/// use gdal::vector::OGRFieldType;
+///
+/// let tiles = ["tiles/0_0.tif", "tiles/0_1.tif", "tiles/1_0.tif", "tiles/1_1.tif"];
+/// let mut options = TileIndexOptions::new();
+/// options
+///     .with_output_format("GPKG")
+///     .with_skip_different_projection()
+///     .with_metadata_fields(&[TileIndexMetadataField {
+///         item: "TIFFTAG_DATETIME".to_string(),
+///         field: "acquired".to_string(),
+///         field_type: OGRFieldType::OFTString,
+///     }]);
+/// let index = tile_index("catalog.gpkg", &tiles, &options)?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [GDALTileIndex]: https://gdal.org/api/gdal_utils.html#_CPPv413GDALTileIndexPKciPPKcPK20GDALTileIndexOptionsPi
+/// [program docs]: https://gdal.org/programs/gdaltindex.html
+pub fn tile_index<D: AsRef<Path>, P: AsRef<Path>>(
+    dest: D,
+    paths: &[P],
+    options: &TileIndexOptions,
+) -> Result<Dataset> {
+    let popts = GdalTileIndexOptions::new(options)?;
+    let dest = _path_to_c_string(dest.as_ref())?;
+
+    let paths = paths
+        .iter()
+        .map(|p| _path_to_c_string(p.as_ref()))
+        .collect::<Result<Vec<_>>>()?;
+    let c_paths = paths
+        .iter()
+        .map(|p| p.as_ptr())
+        .chain(std::iter::once(null()))
+        .collect::<Vec<_>>();
+
+    let mut pb_usage_error: c_int = 0;
+    let out_ds = unsafe {
+        gdal_sys::GDALTileIndex(
+            dest.as_ptr(),
+            paths.len() as c_int,
+            c_paths.as_ptr(),
+            popts.0.as_ptr(),
+            &mut pb_usage_error,
+        )
+    };
+    if out_ds.is_null() {
+        return Err(_last_null_pointer_err("GDALTileIndex"));
+    }
+    Ok(unsafe { Dataset::from_c_dataset(out_ds) })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::raster::Buffer;
+    use crate::spatial_ref::SpatialRef;
+    use crate::test_utils::SuppressGDALErrorLog;
+    use crate::vector::LayerAccess;
+    use crate::{DriverManager, Metadata};
+
+    /// Create a 2x2 `u8` tile at `path`, with its upper-left corner at `(x, 2)`.
+    fn tile(path: &str, x: f64, epsg: u32, date: &str) {
+        let driver = DriverManager::get_driver_by_name("GTiff").unwrap();
+        let mut ds = driver
+            .create_with_band_type::<u8, _>(path, 2, 2, 1)
+            .unwrap();
+        ds.set_geo_transform(&[x, 1., 0., 2., 0., -1.]).unwrap();
+        ds.set_spatial_ref(&SpatialRef::from_epsg(epsg).unwrap())
+            .unwrap();
+        ds.set_metadata_item("DATE", date, "").unwrap();
+        ds.rasterband(1)
+            .unwrap()
+            .write((0, 0), (2, 2), &mut Buffer::new((2, 2), vec![1; 4]))
+            .unwrap();
+    }
+
+    #[test]
+    fn test_tile_index() {
+        let paths = [
+            "/vsimem/test_tile_index_0.tif",
+            "/vsimem/test_tile_index_1.tif",
+            "/vsimem/test_tile_index_2.tif",
+        ];
+        tile(paths[0], 0., 4326, "2024-01-01");
+        tile(paths[1], 2., 4326, "2024-02-01");
+        tile(paths[2], 4., 3857, "2024-03-01");
+
+        let mut options = TileIndexOptions::new();
+        options
+            .with_output_format("Memory")
+            .with_layer_name("tiles")
+            .with_skip_different_projection()
+            .with_metadata_fields(&[TileIndexMetadataField {
+                item: "DATE".to_string(),
+                field: "date".to_string(),
+                field_type: OGRFieldType::OFTString,
+            }]);
+        let index = {
+            let _nolog = SuppressGDALErrorLog::new();
+            tile_index("", &paths, &options).unwrap()
+        };
+        let mut layer = index.layer_by_name("tiles").unwrap();
+        let tiles = layer
+            .features()
+            .map(|f| {
+                (
+                    f.field_as_string_by_name("location").unwrap().unwrap(),
+                    f.field_as_string_by_name("date").unwrap().unwrap(),
+                    f.geometry().unwrap().envelope().MinX,
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            tiles,
+            [
+                (paths[0].to_string(), "2024-01-01".to_string(), 0.),
+                (paths[1].to_string(), "2024-02-01".to_string(), 2.),
+            ]
+        );
+
+        for path in paths {
+            crate::vsi::unlink_mem_file(path).unwrap();
+        }
+    }
+}
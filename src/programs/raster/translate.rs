@@ -0,0 +1,401 @@
+use std::path::Path;
+use std::ptr::{null_mut, NonNull};
+
+use gdal_sys::GDALTranslateOptions;
+use libc::c_int;
+
+use crate::cpl::CslStringList;
+use crate::errors::*;
+use crate::progress::{cancelled_or, progress_args, CancellationToken};
+use crate::raster::{GdalDataType, RasterCreationOptions};
+use crate::utils::{_last_null_pointer_err, _path_to_c_string};
+use crate::Dataset;
+
+/// Size of the output of [`translate`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OutputSize {
+    /// Size in pixels, as `(cols, rows)`.
+    ///
+    /// If one of them is `0`, it is computed to preserve the aspect ratio.
+    Pixels(usize, usize),
+    /// Size as a percentage of the input size, as `(cols, rows)`.
+    Percent(f64, f64),
+}
+
+/// Source and destination ranges of `-scale`, as `(min, max)`.
+#[derive(Debug, Clone, Copy)]
+struct ScaleRanges {
+    src: Option<(f64, f64)>,
+    dst: Option<(f64, f64)>,
+}
+
+/// Configuration options for [`translate()`].
+///
+/// These map to the [`gdal_translate`] command line options.
+///
+/// [`gdal_translate`]: https://gdal.org/programs/gdal_translate.html
+#[derive(Debug, Clone, Default)]
+pub struct TranslateOptions {
+    output_format: Option<String>,
+    output_type: Option<GdalDataType>,
+    bands: Vec<usize>,
+    output_size: Option<OutputSize>,
+    src_window: Option<(isize, isize, usize, usize)>,
+    projection_window: Option<[f64; 4]>,
+    projection_window_srs: Option<String>,
+    scale: Option<ScaleRanges>,
+    no_data_value: Option<f64>,
+    creation_options: Option<RasterCreationOptions>,
+    cancellation: Option<CancellationToken>,
+}
+
+impl TranslateOptions {
+    /// Create an empty options set, which copies the input dataset as-is.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Specify the output format, as the short name of a driver (e.g. `GTiff`, `PNG`, `MEM`).
+    ///
+    /// Defaults to GeoTIFF, or the format guessed from the output file extension.
+    pub fn with_output_format(&mut self, format: &str) -> &mut Self {
+        self.output_format = Some(format.to_owned());
+        self
+    }
+
+    /// Convert the output bands to `data_type`.
+    pub fn with_output_type(&mut self, data_type: GdalDataType) -> &mut Self {
+        self.output_type = Some(data_type);
+        self
+    }
+
+    /// Select the (1-based) input bands to write, in order.
+    ///
+    /// A band can be listed several times. Defaults to all bands.
+    pub fn with_bands(&mut self, bands: &[usize]) -> &mut Self {
+        self.bands = bands.to_vec();
+        self
+    }
+
+    /// Resample the output to the given size.
+    pub fn with_output_size(&mut self, size: OutputSize) -> &mut Self {
+        self.output_size = Some(size);
+        self
+    }
+
+    /// Only copy the window of `size` pixels starting at `offset`, in pixel/line coordinates.
+    pub fn with_src_window(&mut self, offset: (isize, isize), size: (usize, usize)) -> &mut Self {
+        self.src_window = Some((offset.0, offset.1, size.0, size.1));
+        self
+    }
+
+    /// Only copy the window given in georeferenced coordinates, as
+    /// `[upper_left_x, upper_left_y, lower_right_x, lower_right_y]`.
+    ///
+    /// The coordinates are in the dataset's spatial reference, unless
+    /// [`with_projection_window_srs`](Self::with_projection_window_srs) is used.
+    pub fn with_projection_window(&mut self, window: [f64; 4]) -> &mut Self {
+        self.projection_window = Some(window);
+        self
+    }
+
+    /// Set the spatial reference of the projection window, in any format accepted by
+    /// [`SpatialRef::from_definition`](crate::spatial_ref::SpatialRef::from_definition).
+    pub fn with_projection_window_srs(&mut self, srs: &str) -> &mut Self {
+        self.projection_window_srs = Some(srs.to_owned());
+        self
+    }
+
+    /// Rescale the input pixel values from `src_range` to `dst_range`, as `(min, max)`.
+    ///
+    /// If `src_range` is `None`, it is computed from the input data.
+    /// If `dst_range` is `None`, it defaults to `(0, 255)`.
+    /// Giving a `dst_range` requires a `src_range`.
+    pub fn with_scale(
+        &mut self,
+        src_range: Option<(f64, f64)>,
+        dst_range: Option<(f64, f64)>,
+    ) -> &mut Self {
+        self.scale = Some(ScaleRanges {
+            src: src_range,
+            dst: dst_range,
+        });
+        self
+    }
+
+    /// Set the nodata value of the output bands.
+    pub fn with_no_data_value(&mut self, no_data: f64) -> &mut Self {
+        self.no_data_value = Some(no_data);
+        self
+    }
+
+    /// Pass creation options to the output driver.
+    pub fn with_creation_options(&mut self, options: &RasterCreationOptions) -> &mut Self {
+        self.creation_options = Some(options.clone());
+        self
+    }
+
+    /// Abort the translation with [`GdalError::Cancelled`] once `token` is cancelled.
+    pub fn with_cancellation(&mut self, token: &CancellationToken) -> &mut Self {
+        self.cancellation = Some(token.clone());
+        self
+    }
+
+    /// Render these options into [`CslStringList`] values, as compatible with
+    /// [`gdal_sys::GDALTranslateOptionsNew`].
+    pub fn to_options_list(&self) -> Result<CslStringList> {
+        let mut opts = CslStringList::default();
+
+        if let Some(format) = &self.output_format {
+            opts.add_string("-of")?;
+            opts.add_string(format)?;
+        }
+
+        if let Some(data_type) = self.output_type {
+            opts.add_string("-ot")?;
+            opts.add_string(&data_type.name())?;
+        }
+
+        for band in &self.bands {
+            opts.add_string("-b")?;
+            opts.add_string(&band.to_string())?;
+        }
+
+        match self.output_size {
+            Some(OutputSize::Pixels(cols, rows)) => {
+                opts.add_string("-outsize")?;
+                opts.add_string(&cols.to_string())?;
+                opts.add_string(&rows.to_string())?;
+            }
+            Some(OutputSize::Percent(cols, rows)) => {
+                opts.add_string("-outsize")?;
+                opts.add_string(&format!("{cols}%"))?;
+                opts.add_string(&format!("{rows}%"))?;
+            }
+            None => {}
+        }
+
+        if let Some((x, y, cols, rows)) = self.src_window {
+            opts.add_string("-srcwin")?;
+            for v in [
+                x.to_string(),
+                y.to_string(),
+                cols.to_string(),
+                rows.to_string(),
+            ] {
+                opts.add_string(&v)?;
+            }
+        }
+
+        if let Some(window) = self.projection_window {
+            opts.add_string("-projwin")?;
+            for v in window {
+                opts.add_string(&v.to_string())?;
+            }
+        }
+
+        if let Some(srs) = &self.projection_window_srs {
+            opts.add_string("-projwin_srs")?;
+            opts.add_string(srs)?;
+        }
+
+        if let Some(ScaleRanges { src, dst }) = self.scale {
+            opts.add_string("-scale")?;
+            match (src, dst) {
+                (None, Some(_)) => {
+                    return Err(GdalError::BadArgument(
+                        "a destination scale range requires a source range".to_string(),
+                    ))
+                }
+                (src, dst) => {
+                    for (min, max) in src.into_iter().chain(dst) {
+                        opts.add_string(&min.to_string())?;
+                        opts.add_string(&max.to_string())?;
+                    }
+                }
+            }
+        }
+
+        if let Some(no_data) = self.no_data_value {
+            opts.add_string("-a_nodata")?;
+            opts.add_string(&no_data.to_string())?;
+        }
+
+        if let Some(creation_options) = &self.creation_options {
+            for entry in creation_options.iter() {
+                opts.add_string("-co")?;
+                opts.add_string(&entry.to_string())?;
+            }
+        }
+
+        Ok(opts)
+    }
+}
+
+/// Payload for [`GDALTranslate`](gdal_sys::GDALTranslate).
+struct GdalTranslateOptions(NonNull<GDALTranslateOptions>);
+
+impl GdalTranslateOptions {
+    fn new(opts: &CslStringList) -> Result<Self> {
+        let popts = unsafe { gdal_sys::GDALTranslateOptionsNew(opts.as_ptr(), null_mut()) };
+        match NonNull::new(popts) {
+            Some(popts) => Ok(Self(popts)),
+            None => Err(_last_null_pointer_err("GDALTranslateOptionsNew")),
+        }
+    }
+}
+
+impl Drop for GdalTranslateOptions {
+    fn drop(&mut self) {
+        unsafe { gdal_sys::GDALTranslateOptionsFree(self.0.as_ptr()) };
+    }
+}
+
+/// Convert `dataset` to another format, optionally subsetting, resampling and rescaling it.
+///
+/// Wraps [GDALTranslate].
+/// See the [program docs] for more details.
+///
+/// # Example
+///
+/// ```rust, no_run
+/// # fn main() -> gdal::errors::Result<()> {
+/// use gdal::Dataset;
+/// use gdal::programs::raster::{translate, OutputSize, TranslateOptions};
+/// use gdal::raster::GdalDataType;
+///
+/// let dataset = Dataset::open("fixtures/tinymarble.tif")?;
+/// let mut options = TranslateOptions::new();
+/// options
+///     .with_output_format("PNG")
+///     .with_bands(&[3, 2, 1])
+///     .with_output_size(OutputSize::Percent(50., 50.))
+///     .with_output_type(GdalDataType::UInt8);
+/// let png = translate(&dataset, "/tmp/tinymarble.png", &options)?;
+/// assert_eq!(png.raster_size(), (50, 25));
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [GDALTranslate]: https://gdal.org/api/gdal_utils.html#_CPPv413GDALTranslatePKc12GDALDatasetHPK20GDALTranslateOptionsPi
+/// [program docs]: https://gdal.org/programs/gdal_translate.html
+pub fn translate<P: AsRef<Path>>(
+    dataset: &Dataset,
+    dest: P,
+    options: &TranslateOptions,
+) -> Result<Dataset> {
+    let popts = GdalTranslateOptions::new(&options.to_options_list()?)?;
+    let token = options.cancellation.as_ref();
+    if token.is_some() {
+        let (progress, progress_arg) = progress_args(token);
+        unsafe {
+            gdal_sys::GDALTranslateOptionsSetProgress(popts.0.as_ptr(), progress, progress_arg)
+        };
+    }
+    let dest = _path_to_c_string(dest.as_ref())?;
+
+    let mut pb_usage_error: c_int = 0;
+    let out_ds = unsafe {
+        gdal_sys::GDALTranslate(
+            dest.as_ptr(),
+            dataset.c_dataset(),
+            popts.0.as_ptr(),
+            &mut pb_usage_error,
+        )
+    };
+    if out_ds.is_null() {
+        return Err(cancelled_or(token, _last_null_pointer_err("GDALTranslate")));
+    }
+    Ok(unsafe { Dataset::from_c_dataset(out_ds) })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{fixture, SuppressGDALErrorLog};
+
+    #[test]
+    fn test_translate() {
+        let dataset = Dataset::open(fixture("tinymarble.tif")).unwrap();
+
+        let mut options = TranslateOptions::new();
+        options
+            .with_output_format("MEM")
+            .with_bands(&[3, 1])
+            .with_output_size(OutputSize::Pixels(50, 0))
+            .with_output_type(GdalDataType::Float32)
+            .with_no_data_value(-1.);
+        let out = translate(&dataset, "", &options).unwrap();
+        assert_eq!(out.raster_size(), (50, 25));
+        assert_eq!(out.raster_count(), 2);
+        let band = out.rasterband(1).unwrap();
+        assert_eq!(band.band_type(), GdalDataType::Float32);
+        assert_eq!(band.no_data_value(), Some(-1.));
+
+        // same window, in pixels and georeferenced coordinates
+        let gt = dataset.geo_transform().unwrap();
+        let mut options = TranslateOptions::new();
+        options.with_output_format("MEM").with_projection_window([
+            gt[0] + 10. * gt[1],
+            gt[3] + 5. * gt[5],
+            gt[0] + 30. * gt[1],
+            gt[3] + 25. * gt[5],
+        ]);
+        let by_coords = translate(&dataset, "", &options).unwrap();
+        let mut options = TranslateOptions::new();
+        options
+            .with_output_format("MEM")
+            .with_src_window((10, 5), (20, 20));
+        let by_pixels = translate(&dataset, "", &options).unwrap();
+        assert_eq!(by_coords.raster_size(), (20, 20));
+        assert_eq!(
+            by_coords
+                .rasterband(2)
+                .unwrap()
+                .read_band_as::<u8>()
+                .unwrap(),
+            by_pixels
+                .rasterband(2)
+                .unwrap()
+                .read_band_as::<u8>()
+                .unwrap()
+        );
+
+        let mut options = TranslateOptions::new();
+        options
+            .with_output_format("MEM")
+            .with_bands(&[1])
+            .with_output_type(GdalDataType::Float64)
+            .with_scale(Some((0., 255.)), Some((0., 1.)));
+        let scaled = translate(&dataset, "", &options).unwrap();
+        let stats = scaled
+            .rasterband(1)
+            .unwrap()
+            .compute_raster_min_max(false)
+            .unwrap();
+        assert_eq!((stats.min, stats.max), (0., 1.));
+    }
+
+    #[test]
+    fn test_translate_errors() {
+        let dataset = Dataset::open(fixture("tinymarble.tif")).unwrap();
+        let _nolog = SuppressGDALErrorLog::new();
+
+        let mut options = TranslateOptions::new();
+        options.with_scale(None, Some((0., 1.)));
+        assert!(options.to_options_list().is_err());
+
+        let mut options = TranslateOptions::new();
+        options.with_output_format("MEM").with_bands(&[4]);
+        assert!(translate(&dataset, "", &options).is_err());
+
+        let token = CancellationToken::new();
+        token.cancel();
+        let mut options = TranslateOptions::new();
+        options.with_output_format("MEM").with_cancellation(&token);
+        assert!(matches!(
+            translate(&dataset, "", &options),
+            Err(GdalError::Cancelled)
+        ));
+    }
+}
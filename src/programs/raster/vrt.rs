@@ -1,4 +1,5 @@
 use crate::{
+    cpl::CslStringList,
     errors::*,
     utils::{_last_null_pointer_err, _path_to_c_string},
     Dataset,
@@ -63,6 +64,171 @@ impl Drop for BuildVRTOptions {
     }
 }
 
+impl TryFrom<&VrtOptions> for BuildVRTOptions {
+    type Error = GdalError;
+
+    fn try_from(options: &VrtOptions) -> Result<Self> {
+        let opts = options.to_options_list()?;
+        let c_options =
+            unsafe { gdal_sys::GDALBuildVRTOptionsNew(opts.as_ptr() as *mut _, null_mut()) };
+        if c_options.is_null() {
+            return Err(_last_null_pointer_err("GDALBuildVRTOptionsNew"));
+        }
+        Ok(Self { c_options })
+    }
+}
+
+/// Resolution of the VRT built by [`build_vrt`], when the sources have different ones.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum VrtResolution {
+    /// The smallest pixel size of the sources
+    Highest,
+    /// The largest pixel size of the sources
+    Lowest,
+    /// The average pixel size of the sources (the default)
+    Average,
+    /// The given pixel size, as `(x_res, y_res)`
+    User(f64, f64),
+}
+
+/// Typed configuration of [`build_vrt`], rendered into a [`BuildVRTOptions`] with `try_from`.
+///
+/// These map to the [`gdalbuildvrt`] command line options.
+///
+/// [`gdalbuildvrt`]: https://gdal.org/programs/gdalbuildvrt.html
+#[derive(Debug, Clone, Default)]
+pub struct VrtOptions {
+    resolution: Option<VrtResolution>,
+    target_aligned_pixels: bool,
+    extent: Option<[f64; 4]>,
+    separate: bool,
+    bands: Vec<usize>,
+    src_no_data: Option<f64>,
+    vrt_no_data: Option<f64>,
+    add_alpha: bool,
+}
+
+impl VrtOptions {
+    /// Create an empty options set, which mosaics all the bands of the sources.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Set how the resolution is chosen when the sources have different ones.
+    pub fn with_resolution(&mut self, resolution: VrtResolution) -> &mut Self {
+        self.resolution = Some(resolution);
+        self
+    }
+
+    /// Align the extent to multiples of the resolution.
+    ///
+    /// Requires a [`VrtResolution::User`] resolution.
+    pub fn with_target_aligned_pixels(&mut self) -> &mut Self {
+        self.target_aligned_pixels = true;
+        self
+    }
+
+    /// Set the extent of the VRT, as `[xmin, ymin, xmax, ymax]`, instead of that of all the sources.
+    pub fn with_extent(&mut self, extent: [f64; 4]) -> &mut Self {
+        self.extent = Some(extent);
+        self
+    }
+
+    /// Put each source into its own band, instead of mosaicking them.
+    pub fn with_separate(&mut self) -> &mut Self {
+        self.separate = true;
+        self
+    }
+
+    /// Select the (1-based) source bands to use, in order. Defaults to all bands.
+    pub fn with_bands(&mut self, bands: &[usize]) -> &mut Self {
+        self.bands = bands.to_vec();
+        self
+    }
+
+    /// Treat the source pixels equal to `no_data` as transparent, overriding the nodata
+    /// value of the sources.
+    pub fn with_src_no_data_value(&mut self, no_data: f64) -> &mut Self {
+        self.src_no_data = Some(no_data);
+        self
+    }
+
+    /// Set the nodata value of the VRT bands.
+    pub fn with_vrt_no_data_value(&mut self, no_data: f64) -> &mut Self {
+        self.vrt_no_data = Some(no_data);
+        self
+    }
+
+    /// Add an alpha band, marking the areas without source data as transparent.
+    pub fn with_add_alpha(&mut self) -> &mut Self {
+        self.add_alpha = true;
+        self
+    }
+
+    /// Render these options into [`CslStringList`] values, as compatible with
+    /// [`gdal_sys::GDALBuildVRTOptionsNew`].
+    pub fn to_options_list(&self) -> Result<CslStringList> {
+        let mut opts = CslStringList::default();
+
+        match self.resolution {
+            Some(VrtResolution::Highest) => {
+                opts.add_string("-resolution")?;
+                opts.add_string("highest")?;
+            }
+            Some(VrtResolution::Lowest) => {
+                opts.add_string("-resolution")?;
+                opts.add_string("lowest")?;
+            }
+            Some(VrtResolution::Average) => {
+                opts.add_string("-resolution")?;
+                opts.add_string("average")?;
+            }
+            Some(VrtResolution::User(x_res, y_res)) => {
+                opts.add_string("-tr")?;
+                opts.add_string(&x_res.to_string())?;
+                opts.add_string(&y_res.to_string())?;
+            }
+            None => {}
+        }
+
+        if self.target_aligned_pixels {
+            opts.add_string("-tap")?;
+        }
+
+        if let Some(extent) = self.extent {
+            opts.add_string("-te")?;
+            for v in extent {
+                opts.add_string(&v.to_string())?;
+            }
+        }
+
+        if self.separate {
+            opts.add_string("-separate")?;
+        }
+
+        for band in &self.bands {
+            opts.add_string("-b")?;
+            opts.add_string(&band.to_string())?;
+        }
+
+        if let Some(no_data) = self.src_no_data {
+            opts.add_string("-srcnodata")?;
+            opts.add_string(&no_data.to_string())?;
+        }
+
+        if let Some(no_data) = self.vrt_no_data {
+            opts.add_string("-vrtnodata")?;
+            opts.add_string(&no_data.to_string())?;
+        }
+
+        if self.add_alpha {
+            opts.add_string("-addalpha")?;
+        }
+
+        Ok(opts)
+    }
+}
+
 /// Build a VRT from a list of datasets.
 /// Wraps [GDALBuildVRT].
 /// See the [program docs] for more details.
@@ -121,3 +287,153 @@ fn _build_vrt(
 
     Ok(result)
 }
+
+/// Build a VRT from a list of dataset paths, like [`build_vrt`].
+///
+/// The sources are only opened by GDAL while building the VRT, which makes this much lighter
+/// than [`build_vrt`] for mosaicking many files.
+///
+/// # Example
+///
+/// ```rust, no_run
+/// # fn main() -> gdal::errors::Result<()> {
+/// use gdal::programs::raster::{build_vrt_from_paths, BuildVRTOptions, VrtOptions, VrtResolution};
+///
+/// let tiles = ["tiles/0_0.tif", "tiles/0_1.tif", "tiles/1_0.tif", "tiles/1_1.tif"];
+/// let mut options = VrtOptions::new();
+/// options
+///     .with_resolution(VrtResolution::Highest)
+///     .with_vrt_no_data_value(0.);
+/// let options = BuildVRTOptions::try_from(&options)?;
+/// let mosaic = build_vrt_from_paths(Some("mosaic.vrt".as_ref()), &tiles, Some(options))?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn build_vrt_from_paths<P: AsRef<Path>>(
+    dest: Option<&Path>,
+    paths: &[P],
+    options: Option<BuildVRTOptions>,
+) -> Result<Dataset> {
+    let dest = dest.map(_path_to_c_string).transpose()?;
+    let c_dest = dest.as_ref().map(|x| x.as_ptr()).unwrap_or(null());
+
+    let c_options = options
+        .as_ref()
+        .map(|x| x.c_options as *const GDALBuildVRTOptions)
+        .unwrap_or(null());
+
+    let paths = paths
+        .iter()
+        .map(|p| _path_to_c_string(p.as_ref()))
+        .collect::<Result<Vec<_>>>()?;
+    let c_paths = paths
+        .iter()
+        .map(|p| p.as_ptr())
+        .chain(std::iter::once(null()))
+        .collect::<Vec<_>>();
+
+    let dataset_out = unsafe {
+        gdal_sys::GDALBuildVRT(
+            c_dest,
+            paths.len() as c_int,
+            null_mut(),
+            c_paths.as_ptr(),
+            c_options,
+            null_mut(),
+        )
+    };
+
+    if dataset_out.is_null() {
+        return Err(_last_null_pointer_err("GDALBuildVRT"));
+    }
+
+    Ok(unsafe { Dataset::from_c_dataset(dataset_out) })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::raster::Buffer;
+    use crate::spatial_ref::SpatialRef;
+    use crate::DriverManager;
+
+    /// A 2x2 `u8` tile in EPSG:4326 with its upper-left corner at `(x, 2)`, filled with `value`.
+    fn tile(path: &str, x: f64, value: u8) -> Dataset {
+        let driver = DriverManager::get_driver_by_name("GTiff").unwrap();
+        let mut ds = driver
+            .create_with_band_type::<u8, _>(path, 2, 2, 1)
+            .unwrap();
+        ds.set_geo_transform(&[x, 1., 0., 2., 0., -1.]).unwrap();
+        ds.set_spatial_ref(&SpatialRef::from_epsg(4326).unwrap())
+            .unwrap();
+        ds.rasterband(1)
+            .unwrap()
+            .write((0, 0), (2, 2), &mut Buffer::new((2, 2), vec![value; 4]))
+            .unwrap();
+        ds
+    }
+
+    #[test]
+    fn test_build_vrt_options() {
+        let left = tile("/vsimem/test_build_vrt_options_left.tif", 0., 1);
+        let right = tile("/vsimem/test_build_vrt_options_right.tif", 2., 2);
+
+        let mut options = VrtOptions::new();
+        options.with_vrt_no_data_value(255.);
+        let vrt = build_vrt(
+            None,
+            &[&left, &right],
+            Some(BuildVRTOptions::try_from(&options).unwrap()),
+        )
+        .unwrap();
+        assert_eq!(vrt.raster_size(), (4, 2));
+        assert_eq!(vrt.rasterband(1).unwrap().no_data_value(), Some(255.));
+        assert_eq!(
+            vrt.rasterband(1)
+                .unwrap()
+                .read_band_as::<u8>()
+                .unwrap()
+                .data(),
+            [1, 1, 2, 2, 1, 1, 2, 2]
+        );
+
+        let mut options = VrtOptions::new();
+        options.with_separate();
+        let vrt = build_vrt(
+            None,
+            &[&left, &right],
+            Some(BuildVRTOptions::try_from(&options).unwrap()),
+        )
+        .unwrap();
+        assert_eq!(vrt.raster_size(), (4, 2));
+        assert_eq!(vrt.raster_count(), 2);
+
+        drop((left, right));
+        let mut options = VrtOptions::new();
+        options
+            .with_resolution(VrtResolution::User(0.5, 0.5))
+            .with_extent([1., 0., 3., 2.]);
+        let vrt = build_vrt_from_paths(
+            None,
+            &[
+                "/vsimem/test_build_vrt_options_left.tif",
+                "/vsimem/test_build_vrt_options_right.tif",
+            ],
+            Some(BuildVRTOptions::try_from(&options).unwrap()),
+        )
+        .unwrap();
+        assert_eq!(vrt.raster_size(), (4, 4));
+        assert_eq!(
+            vrt.rasterband(1)
+                .unwrap()
+                .read_band_as::<u8>()
+                .unwrap()
+                .data()[..4],
+            [1, 1, 2, 2]
+        );
+        drop(vrt);
+
+        crate::vsi::unlink_mem_file("/vsimem/test_build_vrt_options_left.tif").unwrap();
+        crate::vsi::unlink_mem_file("/vsimem/test_build_vrt_options_right.tif").unwrap();
+    }
+}
@@ -0,0 +1,498 @@
+use std::borrow::Borrow;
+use std::path::Path;
+use std::ptr::{null, null_mut, NonNull};
+
+use gdal_sys::{GDALDatasetH, GDALWarpAppOptions};
+use libc::c_int;
+
+use crate::cpl::CslStringList;
+use crate::errors::*;
+use crate::progress::{cancelled_or, progress_args, CancellationToken};
+use crate::raster::{GdalDataType, RasterCreationOptions, WarpResampleAlg};
+use crate::utils::{_last_null_pointer_err, _path_to_c_string};
+use crate::Dataset;
+
+/// Cutline used by [`warp`] to mask the source pixels.
+#[derive(Debug, Clone)]
+struct Cutline {
+    datasource: String,
+    layer: Option<String>,
+    crop: bool,
+}
+
+/// Configuration options for [`warp()`].
+///
+/// These map to the [`gdalwarp`] command line options.
+///
+/// [`gdalwarp`]: https://gdal.org/programs/gdalwarp.html
+#[derive(Debug, Clone, Default)]
+pub struct WarpOptions {
+    output_format: Option<String>,
+    output_type: Option<GdalDataType>,
+    source_srs: Option<String>,
+    target_srs: Option<String>,
+    resolution: Option<(f64, f64)>,
+    target_aligned_pixels: bool,
+    output_size: Option<(usize, usize)>,
+    extent: Option<[f64; 4]>,
+    extent_srs: Option<String>,
+    resampling: Option<WarpResampleAlg>,
+    cutline: Option<Cutline>,
+    src_no_data: Option<f64>,
+    dst_no_data: Option<f64>,
+    threads: Option<Option<usize>>,
+    creation_options: Option<RasterCreationOptions>,
+    cancellation: Option<CancellationToken>,
+}
+
+impl WarpOptions {
+    /// Create an empty options set, which keeps the spatial reference and resolution of the sources.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Specify the output format, as the short name of a driver (e.g. `GTiff`, `MEM`, `VRT`).
+    ///
+    /// Defaults to GeoTIFF, or the format guessed from the output file extension.
+    pub fn with_output_format(&mut self, format: &str) -> &mut Self {
+        self.output_format = Some(format.to_owned());
+        self
+    }
+
+    /// Convert the output bands to `data_type`.
+    pub fn with_output_type(&mut self, data_type: GdalDataType) -> &mut Self {
+        self.output_type = Some(data_type);
+        self
+    }
+
+    /// Override the spatial reference of the sources, in any format accepted by
+    /// [`SpatialRef::from_definition`](crate::spatial_ref::SpatialRef::from_definition).
+    pub fn with_source_srs(&mut self, srs: &str) -> &mut Self {
+        self.source_srs = Some(srs.to_owned());
+        self
+    }
+
+    /// Reproject to `srs`, in any format accepted by
+    /// [`SpatialRef::from_definition`](crate::spatial_ref::SpatialRef::from_definition).
+    pub fn with_target_srs(&mut self, srs: &str) -> &mut Self {
+        self.target_srs = Some(srs.to_owned());
+        self
+    }
+
+    /// Set the output pixel size, in target georeferenced units.
+    ///
+    /// Both values must be positive. Can't be combined with [`with_output_size`](Self::with_output_size).
+    pub fn with_resolution(&mut self, x_res: f64, y_res: f64) -> &mut Self {
+        self.resolution = Some((x_res, y_res));
+        self
+    }
+
+    /// Align the output extent to multiples of the resolution, so that tiles line up.
+    ///
+    /// Requires [`with_resolution`](Self::with_resolution).
+    pub fn with_target_aligned_pixels(&mut self) -> &mut Self {
+        self.target_aligned_pixels = true;
+        self
+    }
+
+    /// Set the output size, as `(cols, rows)`.
+    ///
+    /// If one of them is `0`, it is computed to preserve the aspect ratio.
+    pub fn with_output_size(&mut self, cols: usize, rows: usize) -> &mut Self {
+        self.output_size = Some((cols, rows));
+        self
+    }
+
+    /// Set the output extent, as `[xmin, ymin, xmax, ymax]`.
+    ///
+    /// The coordinates are in the target spatial reference, unless
+    /// [`with_extent_srs`](Self::with_extent_srs) is used.
+    pub fn with_extent(&mut self, extent: [f64; 4]) -> &mut Self {
+        self.extent = Some(extent);
+        self
+    }
+
+    /// Set the spatial reference of the output extent.
+    pub fn with_extent_srs(&mut self, srs: &str) -> &mut Self {
+        self.extent_srs = Some(srs.to_owned());
+        self
+    }
+
+    /// Set the resampling method.
+    pub fn with_resampling(&mut self, alg: WarpResampleAlg) -> &mut Self {
+        self.resampling = Some(alg);
+        self
+    }
+
+    /// Only keep the source pixels inside the polygons of a vector dataset.
+    ///
+    /// # Arguments
+    /// * `datasource` - path of the vector dataset, or a WKT polygon with GDAL >= 3.9
+    /// * `layer` - the layer holding the polygons, or `None` for the first one
+    /// * `crop` - whether to crop the output extent to the cutline's
+    pub fn with_cutline(&mut self, datasource: &str, layer: Option<&str>, crop: bool) -> &mut Self {
+        self.cutline = Some(Cutline {
+            datasource: datasource.to_owned(),
+            layer: layer.map(str::to_owned),
+            crop,
+        });
+        self
+    }
+
+    /// Set the nodata value of the sources, overriding the one of their bands.
+    pub fn with_src_no_data_value(&mut self, no_data: f64) -> &mut Self {
+        self.src_no_data = Some(no_data);
+        self
+    }
+
+    /// Set the nodata value of the output bands.
+    ///
+    /// Output pixels without source data are set to this value.
+    pub fn with_dst_no_data_value(&mut self, no_data: f64) -> &mut Self {
+        self.dst_no_data = Some(no_data);
+        self
+    }
+
+    /// Warp on several threads.
+    ///
+    /// I/O and computations overlap, and the computations use `threads` threads,
+    /// or all CPUs if `None`.
+    pub fn with_multithreading(&mut self, threads: Option<usize>) -> &mut Self {
+        self.threads = Some(threads);
+        self
+    }
+
+    /// Pass creation options to the output driver.
+    pub fn with_creation_options(&mut self, options: &RasterCreationOptions) -> &mut Self {
+        self.creation_options = Some(options.clone());
+        self
+    }
+
+    /// Abort the warp with [`GdalError::Cancelled`] once `token` is cancelled.
+    pub fn with_cancellation(&mut self, token: &CancellationToken) -> &mut Self {
+        self.cancellation = Some(token.clone());
+        self
+    }
+
+    /// Render these options into [`CslStringList`] values, as compatible with
+    /// [`gdal_sys::GDALWarpAppOptionsNew`].
+    pub fn to_options_list(&self) -> Result<CslStringList> {
+        let mut opts = CslStringList::default();
+
+        if let Some(format) = &self.output_format {
+            opts.add_string("-of")?;
+            opts.add_string(format)?;
+        }
+
+        if let Some(data_type) = self.output_type {
+            opts.add_string("-ot")?;
+            opts.add_string(&data_type.name())?;
+        }
+
+        if let Some(srs) = &self.source_srs {
+            opts.add_string("-s_srs")?;
+            opts.add_string(srs)?;
+        }
+
+        if let Some(srs) = &self.target_srs {
+            opts.add_string("-t_srs")?;
+            opts.add_string(srs)?;
+        }
+
+        if let Some((x_res, y_res)) = self.resolution {
+            opts.add_string("-tr")?;
+            opts.add_string(&x_res.to_string())?;
+            opts.add_string(&y_res.to_string())?;
+        }
+
+        if self.target_aligned_pixels {
+            opts.add_string("-tap")?;
+        }
+
+        if let Some((cols, rows)) = self.output_size {
+            opts.add_string("-ts")?;
+            opts.add_string(&cols.to_string())?;
+            opts.add_string(&rows.to_string())?;
+        }
+
+        if let Some(extent) = self.extent {
+            opts.add_string("-te")?;
+            for v in extent {
+                opts.add_string(&v.to_string())?;
+            }
+        }
+
+        if let Some(srs) = &self.extent_srs {
+            opts.add_string("-te_srs")?;
+            opts.add_string(srs)?;
+        }
+
+        if let Some(alg) = self.resampling {
+            opts.add_string("-r")?;
+            opts.add_string(alg.to_gdal_option())?;
+        }
+
+        if let Some(cutline) = &self.cutline {
+            opts.add_string("-cutline")?;
+            opts.add_string(&cutline.datasource)?;
+            if let Some(layer) = &cutline.layer {
+                opts.add_string("-cl")?;
+                opts.add_string(layer)?;
+            }
+            if cutline.crop {
+                opts.add_string("-crop_to_cutline")?;
+            }
+        }
+
+        if let Some(no_data) = self.src_no_data {
+            opts.add_string("-srcnodata")?;
+            opts.add_string(&no_data.to_string())?;
+        }
+
+        if let Some(no_data) = self.dst_no_data {
+            opts.add_string("-dstnodata")?;
+            opts.add_string(&no_data.to_string())?;
+        }
+
+        if let Some(threads) = self.threads {
+            opts.add_string("-multi")?;
+            opts.add_string("-wo")?;
+            match threads {
+                Some(n) => opts.add_string(&format!("NUM_THREADS={n}"))?,
+                None => opts.add_string("NUM_THREADS=ALL_CPUS")?,
+            }
+        }
+
+        if let Some(creation_options) = &self.creation_options {
+            for entry in creation_options.iter() {
+                opts.add_string("-co")?;
+                opts.add_string(&entry.to_string())?;
+            }
+        }
+
+        Ok(opts)
+    }
+}
+
+/// Payload for [`GDALWarp`](gdal_sys::GDALWarp).
+struct GdalWarpAppOptions(NonNull<GDALWarpAppOptions>);
+
+impl GdalWarpAppOptions {
+    fn new(options: &WarpOptions) -> Result<Self> {
+        let opts = options.to_options_list()?;
+        let popts = unsafe { gdal_sys::GDALWarpAppOptionsNew(opts.as_ptr(), null_mut()) };
+        let popts = match NonNull::new(popts) {
+            Some(popts) => Self(popts),
+            None => return Err(_last_null_pointer_err("GDALWarpAppOptionsNew")),
+        };
+        if let Some(token) = &options.cancellation {
+            let (progress, progress_arg) = progress_args(Some(token));
+            unsafe {
+                gdal_sys::GDALWarpAppOptionsSetProgress(popts.0.as_ptr(), progress, progress_arg)
+            };
+        }
+        Ok(popts)
+    }
+}
+
+impl Drop for GdalWarpAppOptions {
+    fn drop(&mut self) {
+        unsafe { gdal_sys::GDALWarpAppOptionsFree(self.0.as_ptr()) };
+    }
+}
+
+/// Reproject, resample, mosaic and/or clip `sources` into a new dataset.
+///
+/// Wraps [GDALWarp].
+/// See the [program docs] for more details.
+///
+/// # Example
+///
+/// ```rust, no_run
+/// # fn main() -> gdal::errors::Result<()> {
+/// use gdal::Dataset;
+/// use gdal::programs::raster::{warp, WarpOptions, WarpResampleAlg};
+///
+/// let dataset = Dataset::open("fixtures/m_3607824_se_17_1_20160620_sub.tif")?;
+/// let mut options = WarpOptions::new();
+/// options
+///     .with_target_srs("EPSG:4326")
+///     .with_resampling(WarpResampleAlg::Bilinear)
+///     .with_multithreading(None);
+/// let warped = warp(&[dataset], "/tmp/warped.tif", &options)?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [GDALWarp]: https://gdal.org/api/gdal_utils.html#_CPPv48GDALWarpPKc12GDALDatasetHiP12GDALDatasetHPK18GDALWarpAppOptionsPi
+/// [program docs]: https://gdal.org/programs/gdalwarp.html
+pub fn warp<D: Borrow<Dataset>, P: AsRef<Path>>(
+    sources: &[D],
+    dest: P,
+    options: &WarpOptions,
+) -> Result<Dataset> {
+    let dest = _path_to_c_string(dest.as_ref())?;
+    let out_ds = _warp(sources, Some(dest.as_ptr()), null_mut(), options)?;
+    Ok(unsafe { Dataset::from_c_dataset(out_ds) })
+}
+
+/// Warp `sources` into the existing dataset `dest`, like [`warp`].
+///
+/// The spatial reference, extent and resolution of `dest` are used, so
+/// the corresponding options must not be set.
+pub fn warp_into<D: Borrow<Dataset>>(
+    sources: &[D],
+    dest: &mut Dataset,
+    options: &WarpOptions,
+) -> Result<()> {
+    // GDALWarp returns `dest` itself, which we already own.
+    _warp(sources, None, dest.c_dataset(), options)?;
+    Ok(())
+}
+
+fn _warp<D: Borrow<Dataset>>(
+    sources: &[D],
+    dest_path: Option<*const libc::c_char>,
+    dest_ds: GDALDatasetH,
+    options: &WarpOptions,
+) -> Result<GDALDatasetH> {
+    let popts = GdalWarpAppOptions::new(options)?;
+    let mut c_sources: Vec<GDALDatasetH> = sources.iter().map(|d| d.borrow().c_dataset()).collect();
+
+    let mut pb_usage_error: c_int = 0;
+    let out_ds = unsafe {
+        gdal_sys::GDALWarp(
+            dest_path.unwrap_or(null()),
+            dest_ds,
+            c_sources.len().try_into()?,
+            c_sources.as_mut_ptr(),
+            popts.0.as_ptr(),
+            &mut pb_usage_error,
+        )
+    };
+    if out_ds.is_null() {
+        let err = _last_null_pointer_err("GDALWarp");
+        return Err(cancelled_or(options.cancellation.as_ref(), err));
+    }
+    Ok(out_ds)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::raster::Buffer;
+    use crate::spatial_ref::SpatialRef;
+    use crate::test_utils::SuppressGDALErrorLog;
+    use crate::vsi::{create_mem_file, unlink_mem_file};
+    use crate::DriverManager;
+
+    /// A 20x10 `u8` dataset in EPSG:4326, covering `[-10, -5, 10, 5]`.
+    fn source() -> Dataset {
+        let driver = DriverManager::get_driver_by_name("MEM").unwrap();
+        let mut ds = driver
+            .create_with_band_type::<u8, _>("", 20, 10, 1)
+            .unwrap();
+        ds.set_geo_transform(&[-10., 1., 0., 5., 0., -1.]).unwrap();
+        ds.set_spatial_ref(&SpatialRef::from_epsg(4326).unwrap())
+            .unwrap();
+        let data = (0..200).map(|i| (i % 20) as u8 + 1).collect();
+        ds.rasterband(1)
+            .unwrap()
+            .write((0, 0), (20, 10), &mut Buffer::new((20, 10), data))
+            .unwrap();
+        ds
+    }
+
+    #[test]
+    fn test_warp() {
+        let src = source();
+
+        let mut options = WarpOptions::new();
+        options
+            .with_output_format("MEM")
+            .with_target_srs("EPSG:3857")
+            .with_resampling(WarpResampleAlg::NearestNeighbour)
+            .with_multithreading(Some(2));
+        let warped = warp(&[&src], "", &options).unwrap();
+        assert_eq!(warped.spatial_ref().unwrap().auth_code().unwrap(), 3857);
+        assert_eq!(warped.raster_count(), 1);
+
+        let mut options = WarpOptions::new();
+        options
+            .with_output_format("MEM")
+            .with_extent([-5., -2., 5., 2.])
+            .with_resolution(0.5, 0.5)
+            .with_dst_no_data_value(255.);
+        let warped = warp(&[&src], "", &options).unwrap();
+        assert_eq!(warped.raster_size(), (20, 8));
+        assert_eq!(warped.geo_transform().unwrap()[0], -5.);
+        assert_eq!(warped.rasterband(1).unwrap().no_data_value(), Some(255.));
+
+        let mut options = WarpOptions::new();
+        options
+            .with_output_format("MEM")
+            .with_output_size(10, 0)
+            .with_resampling(WarpResampleAlg::Max);
+        let warped = warp(&[&src], "", &options).unwrap();
+        assert_eq!(warped.raster_size(), (10, 5));
+        let data = warped.rasterband(1).unwrap().read_band_as::<u8>().unwrap();
+        assert_eq!(data.data()[..3], [2, 4, 6]);
+    }
+
+    #[test]
+    fn test_warp_cutline() {
+        let src = source();
+        let cutline = "/vsimem/test_warp_cutline.geojson";
+        let geojson = r#"{"type": "FeatureCollection", "features": [{"type": "Feature", "properties": {},
+            "geometry": {"type": "Polygon", "coordinates": [[[-10, -5], [0, -5], [0, 5], [-10, 5], [-10, -5]]]}}]}"#;
+        create_mem_file(cutline, geojson.as_bytes().to_vec()).unwrap();
+
+        let mut options = WarpOptions::new();
+        options
+            .with_output_format("MEM")
+            .with_cutline(cutline, None, true);
+        let warped = warp(&[&src], "", &options).unwrap();
+        unlink_mem_file(cutline).unwrap();
+        assert_eq!(warped.raster_size(), (10, 10));
+        assert_eq!(warped.geo_transform().unwrap()[0], -10.);
+    }
+
+    #[test]
+    fn test_warp_into() {
+        let src = source();
+        let driver = DriverManager::get_driver_by_name("MEM").unwrap();
+        let mut dst = driver
+            .create_with_band_type::<u8, _>("", 20, 10, 1)
+            .unwrap();
+        dst.set_geo_transform(&src.geo_transform().unwrap())
+            .unwrap();
+        dst.set_spatial_ref(&src.spatial_ref().unwrap()).unwrap();
+
+        warp_into(&[&src], &mut dst, &WarpOptions::new()).unwrap();
+        assert_eq!(
+            dst.rasterband(1).unwrap().read_band_as::<u8>().unwrap(),
+            src.rasterband(1).unwrap().read_band_as::<u8>().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_warp_cancelled() {
+        let src = source();
+        let _nolog = SuppressGDALErrorLog::new();
+
+        let token = CancellationToken::new();
+        token.cancel();
+        let mut options = WarpOptions::new();
+        options.with_output_format("MEM").with_cancellation(&token);
+        assert!(matches!(
+            warp(&[&src], "", &options),
+            Err(GdalError::Cancelled)
+        ));
+
+        let mut options = WarpOptions::new();
+        options
+            .with_output_format("MEM")
+            .with_target_srs("not a srs");
+        assert!(warp(&[&src], "", &options).is_err());
+    }
+}
@@ -0,0 +1,428 @@
+use std::ptr::{null_mut, NonNull};
+
+use gdal_sys::GDALVectorInfoOptions;
+
+use crate::cpl::CslStringList;
+use crate::errors::*;
+use crate::utils::{_last_null_pointer_err, _string};
+use crate::vector::sql::Dialect;
+use crate::Dataset;
+
+/// Configuration options for [`Dataset::vector_info`].
+///
+/// These map to the [`ogrinfo`] command line options. Arguments not covered by the
+/// setters can be parsed with [`from_args`](Self::from_args).
+///
+/// [`ogrinfo`]: https://gdal.org/programs/ogrinfo.html
+#[derive(Debug, Clone, Default)]
+pub struct VectorInfoOptions {
+    args: Vec<String>,
+    json: bool,
+    summary_only: bool,
+    features: bool,
+    layers: Vec<String>,
+    where_clause: Option<String>,
+    sql: Option<(String, Dialect)>,
+    spatial_filter: Option<[f64; 4]>,
+    no_metadata: bool,
+    no_extent: bool,
+    wkt_format: Option<String>,
+}
+
+impl VectorInfoOptions {
+    /// Create an empty options set, for the default text report.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Create an options set from `ogrinfo` command line arguments, excluding the dataset.
+    ///
+    /// The arguments are parsed by GDAL when reporting, which fails if they are invalid.
+    /// The setters add to these arguments.
+    pub fn from_args<S: Into<String>, I: IntoIterator<Item = S>>(args: I) -> Self {
+        Self {
+            args: args.into_iter().map(Into::into).collect(),
+            ..Default::default()
+        }
+    }
+
+    /// Report as JSON, instead of text.
+    pub fn with_json(&mut self) -> &mut Self {
+        self.json = true;
+        self
+    }
+
+    /// Only report the summary of the layers, leaving out the features.
+    ///
+    /// This is implied by [`with_json`](Self::with_json), unless
+    /// [`with_features`](Self::with_features) is set.
+    pub fn with_summary_only(&mut self) -> &mut Self {
+        self.summary_only = true;
+        self
+    }
+
+    /// Also report the features in the JSON report.
+    pub fn with_features(&mut self) -> &mut Self {
+        self.features = true;
+        self
+    }
+
+    /// Only report the layer called `name`. Can be called several times.
+    pub fn with_layer(&mut self, name: &str) -> &mut Self {
+        self.layers.push(name.to_owned());
+        self
+    }
+
+    /// Only report the features matching the attribute filter `where_clause`.
+    pub fn with_where(&mut self, where_clause: &str) -> &mut Self {
+        self.where_clause = Some(where_clause.to_owned());
+        self
+    }
+
+    /// Report the result of the SQL query `sql` instead of the layers.
+    pub fn with_sql(&mut self, sql: &str, dialect: Dialect) -> &mut Self {
+        self.sql = Some((sql.to_owned(), dialect));
+        self
+    }
+
+    /// Only report the features intersecting `[xmin, ymin, xmax, ymax]`.
+    pub fn with_spatial_filter(&mut self, extent: [f64; 4]) -> &mut Self {
+        self.spatial_filter = Some(extent);
+        self
+    }
+
+    /// Leave out the metadata.
+    pub fn with_no_metadata(&mut self) -> &mut Self {
+        self.no_metadata = true;
+        self
+    }
+
+    /// Leave out the extents of the layers, which may be costly to compute.
+    pub fn with_no_extent(&mut self) -> &mut Self {
+        self.no_extent = true;
+        self
+    }
+
+    /// Set the WKT variant of the coordinate systems, e.g. `WKT1` or `WKT2`.
+    pub fn with_wkt_format(&mut self, format: &str) -> &mut Self {
+        self.wkt_format = Some(format.to_owned());
+        self
+    }
+
+    /// Render these options into [`CslStringList`] values, as compatible with
+    /// [`gdal_sys::GDALVectorInfoOptionsNew`].
+    pub fn to_options_list(&self) -> Result<CslStringList> {
+        let mut opts = CslStringList::default();
+
+        for arg in &self.args {
+            opts.add_string(arg)?;
+        }
+
+        if self.json {
+            opts.add_string("-json")?;
+        }
+
+        if self.summary_only {
+            opts.add_string("-so")?;
+        }
+
+        if self.features {
+            opts.add_string("-features")?;
+        }
+
+        if let Some(where_clause) = &self.where_clause {
+            opts.add_string("-where")?;
+            opts.add_string(where_clause)?;
+        }
+
+        if let Some((sql, dialect)) = &self.sql {
+            opts.add_string("-sql")?;
+            opts.add_string(sql)?;
+            let dialect = match dialect {
+                Dialect::DEFAULT => None,
+                Dialect::OGR => Some("OGRSQL"),
+                Dialect::SQLITE => Some("SQLITE"),
+                Dialect::INDIRECT_SQLITE => Some("INDIRECT_SQLITE"),
+            };
+            if let Some(dialect) = dialect {
+                opts.add_string("-dialect")?;
+                opts.add_string(dialect)?;
+            }
+        }
+
+        if let Some(extent) = self.spatial_filter {
+            opts.add_string("-spat")?;
+            for v in extent {
+                opts.add_string(&v.to_string())?;
+            }
+        }
+
+        if self.no_metadata {
+            opts.add_string("-nomd")?;
+        }
+
+        if self.no_extent {
+            opts.add_string("-noextent")?;
+        }
+
+        if let Some(format) = &self.wkt_format {
+            opts.add_string("-wkt_format")?;
+            opts.add_string(format)?;
+        }
+
+        // The remaining arguments are the names of the layers.
+        for layer in &self.layers {
+            opts.add_string(layer)?;
+        }
+
+        Ok(opts)
+    }
+}
+
+impl From<Vec<&str>> for VectorInfoOptions {
+    fn from(value: Vec<&str>) -> Self {
+        VectorInfoOptions::from_args(value)
+    }
+}
+
+/// Payload for [`GDALVectorInfo`](gdal_sys::GDALVectorInfo).
+struct GdalVectorInfoOptions(NonNull<GDALVectorInfoOptions>);
+
+impl GdalVectorInfoOptions {
+    fn new(options: &VectorInfoOptions) -> Result<Self> {
+        let opts = options.to_options_list()?;
+        let popts = unsafe { gdal_sys::GDALVectorInfoOptionsNew(opts.as_ptr(), null_mut()) };
+        match NonNull::new(popts) {
+            Some(popts) => Ok(Self(popts)),
+            None => Err(_last_null_pointer_err("GDALVectorInfoOptionsNew")),
+        }
+    }
+}
+
+impl Drop for GdalVectorInfoOptions {
+    fn drop(&mut self) {
+        unsafe { gdal_sys::GDALVectorInfoOptionsFree(self.0.as_ptr()) };
+    }
+}
+
+/// # Vector Info
+impl Dataset {
+    /// Report the layers, schemas, extents and spatial references of this vector dataset,
+    /// as text or JSON.
+    ///
+    /// Wraps [GDALVectorInfo].
+    /// See the [program docs] for more details.
+    ///
+    /// # Example
+    ///
+    /// ```rust, no_run
+    /// # fn main() -> gdal::errors::Result<()> {
+    /// use gdal::programs::vector::VectorInfoOptions;
+    /// use gdal::Dataset;
+    ///
+    /// let ds = Dataset::open("fixtures/roads.geojson")?;
+    /// let mut options = VectorInfoOptions::new();
+    /// options.with_summary_only().with_no_metadata();
+    /// println!("{}", ds.vector_info(&options)?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [GDALVectorInfo]: https://gdal.org/api/gdal_utils.html#_CPPv414GDALVectorInfo12GDALDatasetHPK21GDALVectorInfoOptions
+    /// [program docs]: https://gdal.org/programs/ogrinfo.html
+    pub fn vector_info(&self, options: &VectorInfoOptions) -> Result<String> {
+        let popts = GdalVectorInfoOptions::new(options)?;
+        let c_info = unsafe { gdal_sys::GDALVectorInfo(self.c_dataset(), popts.0.as_ptr()) };
+        if c_info.is_null() {
+            return Err(_last_null_pointer_err("GDALVectorInfo"));
+        }
+        let info = _string(c_info);
+        unsafe { gdal_sys::VSIFree(c_info.cast::<std::ffi::c_void>()) };
+        Ok(info)
+    }
+
+    /// Report the layers, schemas, extents and spatial references of this vector dataset,
+    /// deserialized from the JSON report of [`vector_info`](Self::vector_info).
+    #[cfg(feature = "serde")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+    pub fn vector_info_json(&self, options: &VectorInfoOptions) -> Result<VectorInfo> {
+        let mut options = options.clone();
+        options.with_json();
+        let info = self.vector_info(&options)?;
+        serde_json::from_str(&info).map_err(|e| GdalError::JsonError(e.to_string()))
+    }
+}
+
+#[cfg(feature = "serde")]
+pub use self::report::*;
+
+#[cfg(feature = "serde")]
+mod report {
+    use std::collections::BTreeMap;
+
+    use serde::Deserialize;
+
+    use crate::programs::raster::CoordinateSystemInfo;
+
+    /// The JSON report of [`Dataset::vector_info_json`](crate::Dataset::vector_info_json).
+    ///
+    /// The sections left out by the [options](super::VectorInfoOptions), or which don't apply
+    /// to the dataset, are empty.
+    #[derive(Debug, Clone, PartialEq, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+    pub struct VectorInfo {
+        /// Description of the dataset, usually its path.
+        pub description: String,
+        /// Short name of the driver, e.g. `GPKG`.
+        pub driver_short_name: String,
+        /// Long name of the driver, e.g. `GeoPackage`.
+        pub driver_long_name: String,
+        /// Layers of the dataset.
+        #[serde(default)]
+        pub layers: Vec<LayerInfo>,
+        /// Metadata, by domain.
+        #[serde(default)]
+        pub metadata: BTreeMap<String, serde_json::Value>,
+        /// Field domains, by name.
+        #[serde(default)]
+        pub domains: BTreeMap<String, serde_json::Value>,
+    }
+
+    /// Layer of a [`VectorInfo`].
+    #[derive(Debug, Clone, PartialEq, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+    pub struct LayerInfo {
+        /// Name of the layer.
+        pub name: String,
+        /// Number of features, matching the filters if any.
+        pub feature_count: Option<u64>,
+        /// Name of the FID column, if the layer has one.
+        pub fid_column_name: Option<String>,
+        /// Geometry fields of the layer.
+        #[serde(default)]
+        pub geometry_fields: Vec<GeometryFieldInfo>,
+        /// Attribute fields of the layer.
+        #[serde(default)]
+        pub fields: Vec<FieldInfo>,
+        /// Metadata, by domain.
+        #[serde(default)]
+        pub metadata: BTreeMap<String, serde_json::Value>,
+        /// Features, as GeoJSON, reported with
+        /// [`with_features`](super::VectorInfoOptions::with_features).
+        #[serde(default)]
+        pub features: Vec<serde_json::Value>,
+    }
+
+    /// Geometry field of a [`LayerInfo`].
+    #[derive(Debug, Clone, PartialEq, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+    pub struct GeometryFieldInfo {
+        /// Name of the field, which may be empty.
+        pub name: String,
+        /// Name of the geometry type, e.g. `LineString`.
+        #[serde(rename = "type")]
+        pub geometry_type: String,
+        /// Whether the field may be null.
+        #[serde(default)]
+        pub nullable: bool,
+        /// Extent of the field, as `[xmin, ymin, xmax, ymax]`.
+        pub extent: Option<[f64; 4]>,
+        /// Spatial reference of the field.
+        pub coordinate_system: Option<CoordinateSystemInfo>,
+    }
+
+    /// Attribute field of a [`LayerInfo`].
+    #[derive(Debug, Clone, PartialEq, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+    pub struct FieldInfo {
+        /// Name of the field.
+        pub name: String,
+        /// Name of the field type, e.g. `String` or `Integer64`.
+        #[serde(rename = "type")]
+        pub field_type: String,
+        /// Name of the field subtype, e.g. `Boolean`.
+        pub sub_type: Option<String>,
+        /// Width of the field.
+        pub width: Option<i32>,
+        /// Precision of the field.
+        pub precision: Option<i32>,
+        /// Whether the field may be null.
+        #[serde(default)]
+        pub nullable: bool,
+        /// Whether the values of the field must be unique.
+        #[serde(default)]
+        pub unique_constraint: bool,
+        /// Default value of the field.
+        pub default_value: Option<String>,
+        /// Alternative name of the field.
+        pub alternative_name: Option<String>,
+        /// Comment of the field.
+        pub comment: Option<String>,
+        /// Name of the field domain of the field.
+        pub domain_name: Option<String>,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{fixture, SuppressGDALErrorLog};
+
+    #[test]
+    fn test_vector_info() {
+        let ds = Dataset::open(fixture("roads.geojson")).unwrap();
+        let info = ds.vector_info(&VectorInfoOptions::new()).unwrap();
+        assert!(info.contains("1: roads (Line String)"));
+
+        let mut options = VectorInfoOptions::new();
+        options
+            .with_summary_only()
+            .with_where("highway = 'pedestrian'")
+            .with_layer("roads");
+        let info = ds.vector_info(&options).unwrap();
+        assert!(info.contains("Feature Count: 10"));
+
+        let mut options = VectorInfoOptions::new();
+        options.with_json();
+        let info = ds.vector_info(&options).unwrap();
+        assert!(info.starts_with('{'));
+        assert!(info.contains("\"featureCount\""));
+
+        let _nolog = SuppressGDALErrorLog::new();
+        assert!(ds
+            .vector_info(&VectorInfoOptions::from_args(["-not-an-option"]))
+            .is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_vector_info_json() {
+        let ds = Dataset::open(fixture("roads.geojson")).unwrap();
+        let info = ds.vector_info_json(&VectorInfoOptions::new()).unwrap();
+        assert_eq!(info.driver_short_name, "GeoJSON");
+        assert_eq!(info.layers.len(), 1);
+        let layer = &info.layers[0];
+        assert_eq!(layer.name, "roads");
+        assert_eq!(layer.feature_count, Some(21));
+        assert!(layer.features.is_empty());
+        assert_eq!(layer.geometry_fields[0].geometry_type, "LineString");
+        assert!(layer.geometry_fields[0].extent.is_some());
+        assert!(layer.geometry_fields[0]
+            .coordinate_system
+            .as_ref()
+            .unwrap()
+            .wkt
+            .contains("WGS 84"));
+        let highway = layer.fields.iter().find(|f| f.name == "highway").unwrap();
+        assert_eq!(highway.field_type, "String");
+
+        let mut options = VectorInfoOptions::new();
+        options.with_features().with_where("highway = 'primary'");
+        let info = ds.vector_info_json(&options).unwrap();
+        assert_eq!(info.layers[0].features.len(), 1);
+    }
+}
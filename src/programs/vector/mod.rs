@@ -0,0 +1,9 @@
+#[cfg(any(major_ge_4, all(major_is_3, minor_ge_7)))]
+mod info;
+mod translate;
+
+#[cfg(any(major_ge_4, all(major_is_3, minor_ge_7)))]
+pub use info::VectorInfoOptions;
+#[cfg(all(feature = "serde", any(major_ge_4, all(major_is_3, minor_ge_7))))]
+pub use info::{FieldInfo, GeometryFieldInfo, LayerInfo, VectorInfo};
+pub use translate::{translate, translate_into, VectorTranslateAccessMode, VectorTranslateOptions};
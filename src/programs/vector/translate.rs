@@ -0,0 +1,543 @@
+use std::borrow::Borrow;
+use std::path::Path;
+use std::ptr::{null, null_mut, NonNull};
+
+use gdal_sys::{GDALDatasetH, GDALVectorTranslateOptions};
+use libc::c_int;
+
+use crate::cpl::CslStringList;
+use crate::errors::*;
+use crate::progress::{cancelled_or, progress_args, CancellationToken};
+use crate::utils::{_last_null_pointer_err, _path_to_c_string};
+use crate::vector::sql::Dialect;
+use crate::vector::Geometry;
+use crate::Dataset;
+
+/// How [`translate`] and [`translate_into`] write to the output dataset.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum VectorTranslateAccessMode {
+    /// Create a new dataset.
+    #[default]
+    Create,
+    /// Open the existing dataset in update mode, adding new layers to it (`-update`).
+    Update,
+    /// Append the features to the existing layers (`-append`).
+    Append,
+    /// Delete and recreate the existing layers (`-overwrite`).
+    Overwrite,
+    /// Insert the features, or update them if they already exist (`-upsert`, GDAL >= 3.6).
+    Upsert,
+}
+
+/// Clipping geometry used by [`translate`].
+#[derive(Debug, Clone)]
+enum Clip {
+    Extent([f64; 4]),
+    Wkt(String),
+}
+
+impl Clip {
+    fn add_to(&self, opts: &mut CslStringList, flag: &str) -> Result<()> {
+        opts.add_string(flag)?;
+        match self {
+            Clip::Extent(extent) => {
+                for v in extent {
+                    opts.add_string(&v.to_string())?;
+                }
+            }
+            Clip::Wkt(wkt) => opts.add_string(wkt)?,
+        }
+        Ok(())
+    }
+}
+
+/// Configuration options for [`translate()`].
+///
+/// These map to the [`ogr2ogr`] command line options.
+///
+/// [`ogr2ogr`]: https://gdal.org/programs/ogr2ogr.html
+#[derive(Debug, Clone, Default)]
+pub struct VectorTranslateOptions {
+    output_format: Option<String>,
+    access_mode: VectorTranslateAccessMode,
+    layers: Vec<String>,
+    new_layer_name: Option<String>,
+    geometry_type: Option<String>,
+    source_srs: Option<String>,
+    target_srs: Option<String>,
+    assigned_srs: Option<String>,
+    spatial_filter: Option<[f64; 4]>,
+    clip_source: Option<Clip>,
+    clip_destination: Option<Clip>,
+    sql: Option<(String, Dialect)>,
+    where_clause: Option<String>,
+    selected_fields: Option<Vec<String>>,
+    limit: Option<u64>,
+    explode_collections: bool,
+    skip_failures: bool,
+    dataset_creation_options: Option<CslStringList>,
+    layer_creation_options: Option<CslStringList>,
+    cancellation: Option<CancellationToken>,
+}
+
+impl VectorTranslateOptions {
+    /// Create an empty options set, which copies all the layers of the source as they are.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Specify the output format, as the short name of a driver (e.g. `GPKG`, `Memory`,
+    /// `FlatGeobuf`).
+    ///
+    /// Defaults to the format guessed from the output file extension.
+    pub fn with_output_format(&mut self, format: &str) -> &mut Self {
+        self.output_format = Some(format.to_owned());
+        self
+    }
+
+    /// Set how the output dataset is written, see [`VectorTranslateAccessMode`].
+    pub fn with_access_mode(&mut self, mode: VectorTranslateAccessMode) -> &mut Self {
+        self.access_mode = mode;
+        self
+    }
+
+    /// Only copy the source layer called `name`. Can be called several times.
+    pub fn with_layer(&mut self, name: &str) -> &mut Self {
+        self.layers.push(name.to_owned());
+        self
+    }
+
+    /// Set the name of the output layer (`-nln`).
+    pub fn with_new_layer_name(&mut self, name: &str) -> &mut Self {
+        self.new_layer_name = Some(name.to_owned());
+        self
+    }
+
+    /// Set or convert the geometry type of the output layer (`-nlt`), e.g. `POINT`,
+    /// `MULTIPOLYGON`, `PROMOTE_TO_MULTI` or `CONVERT_TO_LINEAR`.
+    pub fn with_geometry_type(&mut self, geometry_type: &str) -> &mut Self {
+        self.geometry_type = Some(geometry_type.to_owned());
+        self
+    }
+
+    /// Override the spatial reference of the source, in any format accepted by
+    /// [`SpatialRef::from_definition`](crate::spatial_ref::SpatialRef::from_definition).
+    pub fn with_source_srs(&mut self, srs: &str) -> &mut Self {
+        self.source_srs = Some(srs.to_owned());
+        self
+    }
+
+    /// Reproject to `srs`, in any format accepted by
+    /// [`SpatialRef::from_definition`](crate::spatial_ref::SpatialRef::from_definition).
+    pub fn with_target_srs(&mut self, srs: &str) -> &mut Self {
+        self.target_srs = Some(srs.to_owned());
+        self
+    }
+
+    /// Assign `srs` to the output without reprojecting.
+    pub fn with_assigned_srs(&mut self, srs: &str) -> &mut Self {
+        self.assigned_srs = Some(srs.to_owned());
+        self
+    }
+
+    /// Only copy the features intersecting `[xmin, ymin, xmax, ymax]`, in the source spatial
+    /// reference (`-spat`).
+    pub fn with_spatial_filter(&mut self, extent: [f64; 4]) -> &mut Self {
+        self.spatial_filter = Some(extent);
+        self
+    }
+
+    /// Clip the geometries to `[xmin, ymin, xmax, ymax]`, in the source spatial reference
+    /// (`-clipsrc`).
+    pub fn with_clip_source_extent(&mut self, extent: [f64; 4]) -> &mut Self {
+        self.clip_source = Some(Clip::Extent(extent));
+        self
+    }
+
+    /// Clip the geometries to `geometry`, in the source spatial reference (`-clipsrc`).
+    pub fn with_clip_source_geometry(&mut self, geometry: &Geometry) -> Result<&mut Self> {
+        self.clip_source = Some(Clip::Wkt(geometry.wkt()?));
+        Ok(self)
+    }
+
+    /// Clip the geometries to `[xmin, ymin, xmax, ymax]` after reprojection, in the target
+    /// spatial reference (`-clipdst`).
+    pub fn with_clip_destination_extent(&mut self, extent: [f64; 4]) -> &mut Self {
+        self.clip_destination = Some(Clip::Extent(extent));
+        self
+    }
+
+    /// Clip the geometries to `geometry` after reprojection, in the target spatial
+    /// reference (`-clipdst`).
+    pub fn with_clip_destination_geometry(&mut self, geometry: &Geometry) -> Result<&mut Self> {
+        self.clip_destination = Some(Clip::Wkt(geometry.wkt()?));
+        Ok(self)
+    }
+
+    /// Copy the result of the SQL query `sql` instead of the source layers (`-sql`).
+    pub fn with_sql(&mut self, sql: &str, dialect: Dialect) -> &mut Self {
+        self.sql = Some((sql.to_owned(), dialect));
+        self
+    }
+
+    /// Only copy the features matching the attribute filter `where_clause` (`-where`).
+    pub fn with_where(&mut self, where_clause: &str) -> &mut Self {
+        self.where_clause = Some(where_clause.to_owned());
+        self
+    }
+
+    /// Only copy the fields called `fields` (`-select`).
+    pub fn with_selected_fields(&mut self, fields: &[&str]) -> &mut Self {
+        self.selected_fields = Some(fields.iter().map(|&f| f.to_owned()).collect());
+        self
+    }
+
+    /// Copy at most `limit` features per layer (`-limit`).
+    pub fn with_limit(&mut self, limit: u64) -> &mut Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Split geometry collections into one feature per part (`-explodecollections`).
+    pub fn with_explode_collections(&mut self) -> &mut Self {
+        self.explode_collections = true;
+        self
+    }
+
+    /// Skip the features that fail to be written instead of aborting (`-skipfailures`).
+    pub fn with_skip_failures(&mut self) -> &mut Self {
+        self.skip_failures = true;
+        self
+    }
+
+    /// Pass dataset creation options to the output driver (`-dsco`).
+    pub fn with_dataset_creation_options(&mut self, options: &CslStringList) -> &mut Self {
+        self.dataset_creation_options = Some(options.clone());
+        self
+    }
+
+    /// Pass layer creation options to the output driver (`-lco`).
+    pub fn with_layer_creation_options(&mut self, options: &CslStringList) -> &mut Self {
+        self.layer_creation_options = Some(options.clone());
+        self
+    }
+
+    /// Abort the translation with [`GdalError::Cancelled`] once `token` is cancelled.
+    pub fn with_cancellation(&mut self, token: &CancellationToken) -> &mut Self {
+        self.cancellation = Some(token.clone());
+        self
+    }
+
+    /// Render these options into [`CslStringList`] values, as compatible with
+    /// [`gdal_sys::GDALVectorTranslateOptionsNew`].
+    pub fn to_options_list(&self) -> Result<CslStringList> {
+        let mut opts = CslStringList::default();
+
+        if let Some(format) = &self.output_format {
+            opts.add_string("-f")?;
+            opts.add_string(format)?;
+        }
+
+        match self.access_mode {
+            VectorTranslateAccessMode::Create => {}
+            VectorTranslateAccessMode::Update => opts.add_string("-update")?,
+            VectorTranslateAccessMode::Append => opts.add_string("-append")?,
+            VectorTranslateAccessMode::Overwrite => opts.add_string("-overwrite")?,
+            VectorTranslateAccessMode::Upsert => opts.add_string("-upsert")?,
+        }
+
+        if let Some(name) = &self.new_layer_name {
+            opts.add_string("-nln")?;
+            opts.add_string(name)?;
+        }
+
+        if let Some(geometry_type) = &self.geometry_type {
+            opts.add_string("-nlt")?;
+            opts.add_string(geometry_type)?;
+        }
+
+        if let Some(srs) = &self.source_srs {
+            opts.add_string("-s_srs")?;
+            opts.add_string(srs)?;
+        }
+
+        if let Some(srs) = &self.target_srs {
+            opts.add_string("-t_srs")?;
+            opts.add_string(srs)?;
+        }
+
+        if let Some(srs) = &self.assigned_srs {
+            opts.add_string("-a_srs")?;
+            opts.add_string(srs)?;
+        }
+
+        if let Some(extent) = self.spatial_filter {
+            opts.add_string("-spat")?;
+            for v in extent {
+                opts.add_string(&v.to_string())?;
+            }
+        }
+
+        if let Some(clip) = &self.clip_source {
+            clip.add_to(&mut opts, "-clipsrc")?;
+        }
+
+        if let Some(clip) = &self.clip_destination {
+            clip.add_to(&mut opts, "-clipdst")?;
+        }
+
+        if let Some((sql, dialect)) = &self.sql {
+            opts.add_string("-sql")?;
+            opts.add_string(sql)?;
+            let dialect = match dialect {
+                Dialect::DEFAULT => None,
+                Dialect::OGR => Some("OGRSQL"),
+                Dialect::SQLITE => Some("SQLITE"),
+                Dialect::INDIRECT_SQLITE => Some("INDIRECT_SQLITE"),
+            };
+            if let Some(dialect) = dialect {
+                opts.add_string("-dialect")?;
+                opts.add_string(dialect)?;
+            }
+        }
+
+        if let Some(where_clause) = &self.where_clause {
+            opts.add_string("-where")?;
+            opts.add_string(where_clause)?;
+        }
+
+        if let Some(fields) = &self.selected_fields {
+            opts.add_string("-select")?;
+            opts.add_string(&fields.join(","))?;
+        }
+
+        if let Some(limit) = self.limit {
+            opts.add_string("-limit")?;
+            opts.add_string(&limit.to_string())?;
+        }
+
+        if self.explode_collections {
+            opts.add_string("-explodecollections")?;
+        }
+
+        if self.skip_failures {
+            opts.add_string("-skipfailures")?;
+        }
+
+        if let Some(options) = &self.dataset_creation_options {
+            for entry in options.iter() {
+                opts.add_string("-dsco")?;
+                opts.add_string(&entry.to_string())?;
+            }
+        }
+
+        if let Some(options) = &self.layer_creation_options {
+            for entry in options.iter() {
+                opts.add_string("-lco")?;
+                opts.add_string(&entry.to_string())?;
+            }
+        }
+
+        // The remaining arguments are the names of the source layers.
+        for layer in &self.layers {
+            opts.add_string(layer)?;
+        }
+
+        Ok(opts)
+    }
+}
+
+/// Payload for [`GDALVectorTranslate`](gdal_sys::GDALVectorTranslate).
+struct GdalVectorTranslateOptions(NonNull<GDALVectorTranslateOptions>);
+
+impl GdalVectorTranslateOptions {
+    fn new(options: &VectorTranslateOptions) -> Result<Self> {
+        let opts = options.to_options_list()?;
+        let popts = unsafe { gdal_sys::GDALVectorTranslateOptionsNew(opts.as_ptr(), null_mut()) };
+        let popts = match NonNull::new(popts) {
+            Some(popts) => Self(popts),
+            None => return Err(_last_null_pointer_err("GDALVectorTranslateOptionsNew")),
+        };
+        if let Some(token) = &options.cancellation {
+            let (progress, progress_arg) = progress_args(Some(token));
+            unsafe {
+                gdal_sys::GDALVectorTranslateOptionsSetProgress(
+                    popts.0.as_ptr(),
+                    progress,
+                    progress_arg,
+                )
+            };
+        }
+        Ok(popts)
+    }
+}
+
+impl Drop for GdalVectorTranslateOptions {
+    fn drop(&mut self) {
+        unsafe { gdal_sys::GDALVectorTranslateOptionsFree(self.0.as_ptr()) };
+    }
+}
+
+/// Convert, reproject, clip and/or filter the vector dataset `source` into `dest`.
+///
+/// With [`VectorTranslateAccessMode::Create`], a new dataset is created at `dest`. With the
+/// other access modes, the existing dataset at `dest` is opened in update mode.
+///
+/// Wraps [GDALVectorTranslate].
+/// See the [program docs] for more details.
+///
+/// # Example
+///
+/// ```rust, no_run
+/// # fn main() -> gdal::errors::Result<()> {
+/// use gdal::Dataset;
+/// use gdal::programs::vector::{translate, VectorTranslateOptions};
+///
+/// let dataset = Dataset::open("fixtures/roads.geojson")?;
+/// let mut options = VectorTranslateOptions::new();
+/// options
+///     .with_output_format("GPKG")
+///     .with_target_srs("EPSG:3857")
+///     .with_where("highway = 'pedestrian'")
+///     .with_selected_fields(&["highway", "sort_key"]);
+/// let translated = translate(&dataset, "/tmp/roads.gpkg", &options)?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [GDALVectorTranslate]: https://gdal.org/api/gdal_utils.html#_CPPv419GDALVectorTranslatePKc12GDALDatasetHiP12GDALDatasetHPK26GDALVectorTranslateOptionsPi
+/// [program docs]: https://gdal.org/programs/ogr2ogr.html
+pub fn translate<D: Borrow<Dataset>, P: AsRef<Path>>(
+    source: D,
+    dest: P,
+    options: &VectorTranslateOptions,
+) -> Result<Dataset> {
+    let dest = _path_to_c_string(dest.as_ref())?;
+    let out_ds = _translate(source.borrow(), Some(dest.as_ptr()), null_mut(), options)?;
+    Ok(unsafe { Dataset::from_c_dataset(out_ds) })
+}
+
+/// Translate `source` into the existing dataset `dest`, like [`translate`].
+///
+/// The layers are added to `dest`, unless another access mode than
+/// [`VectorTranslateAccessMode::Create`] is set.
+pub fn translate_into<D: Borrow<Dataset>>(
+    source: D,
+    dest: &mut Dataset,
+    options: &VectorTranslateOptions,
+) -> Result<()> {
+    // GDALVectorTranslate returns `dest` itself, which we already own.
+    _translate(source.borrow(), None, dest.c_dataset(), options)?;
+    Ok(())
+}
+
+fn _translate(
+    source: &Dataset,
+    dest_path: Option<*const libc::c_char>,
+    dest_ds: GDALDatasetH,
+    options: &VectorTranslateOptions,
+) -> Result<GDALDatasetH> {
+    let popts = GdalVectorTranslateOptions::new(options)?;
+    let mut c_source = source.c_dataset();
+
+    let mut pb_usage_error: c_int = 0;
+    let out_ds = unsafe {
+        gdal_sys::GDALVectorTranslate(
+            dest_path.unwrap_or(null()),
+            dest_ds,
+            1,
+            &mut c_source,
+            popts.0.as_ptr(),
+            &mut pb_usage_error,
+        )
+    };
+    if out_ds.is_null() {
+        let err = _last_null_pointer_err("GDALVectorTranslate");
+        return Err(cancelled_or(options.cancellation.as_ref(), err));
+    }
+    Ok(out_ds)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{fixture, SuppressGDALErrorLog};
+    use crate::vector::LayerAccess;
+    use crate::DriverManager;
+
+    #[test]
+    fn test_translate() {
+        let src = Dataset::open(fixture("roads.geojson")).unwrap();
+
+        let mut options = VectorTranslateOptions::new();
+        options
+            .with_output_format("Memory")
+            .with_new_layer_name("roads")
+            .with_target_srs("EPSG:3857")
+            .with_where("highway = 'pedestrian'")
+            .with_selected_fields(&["highway", "sort_key"]);
+        let translated = translate(&src, "", &options).unwrap();
+        let layer = translated.layer(0).unwrap();
+        assert_eq!(layer.name(), "roads");
+        assert_eq!(layer.feature_count(), 10);
+        assert_eq!(layer.defn().fields().count(), 2);
+        assert_eq!(layer.spatial_ref().unwrap().auth_code().unwrap(), 3857);
+
+        let mut options = VectorTranslateOptions::new();
+        options
+            .with_output_format("Memory")
+            .with_sql("SELECT * FROM roads LIMIT 3", Dialect::SQLITE);
+        let translated = translate(&src, "", &options).unwrap();
+        assert_eq!(translated.layer(0).unwrap().feature_count(), 3);
+
+        let mut options = VectorTranslateOptions::new();
+        options
+            .with_output_format("Memory")
+            .with_clip_source_extent([26.1017, 44.4297, 26.1025, 44.4303]);
+        let translated = translate(&src, "", &options).unwrap();
+        let layer = translated.layer(0).unwrap();
+        assert!(layer.feature_count() > 0);
+        let extent = layer.get_extent().unwrap();
+        assert!(extent.MinX >= 26.1017 && extent.MaxX <= 26.1025);
+    }
+
+    #[test]
+    fn test_translate_into() {
+        let src = Dataset::open(fixture("roads.geojson")).unwrap();
+        let driver = DriverManager::get_driver_by_name("Memory").unwrap();
+        let mut dst = driver.create_vector_only("").unwrap();
+
+        let mut options = VectorTranslateOptions::new();
+        options.with_new_layer_name("roads").with_limit(5);
+        translate_into(&src, &mut dst, &options).unwrap();
+        assert_eq!(dst.layer(0).unwrap().feature_count(), 5);
+
+        options.with_access_mode(VectorTranslateAccessMode::Append);
+        translate_into(&src, &mut dst, &options).unwrap();
+        assert_eq!(dst.layer_count(), 1);
+        assert_eq!(dst.layer(0).unwrap().feature_count(), 10);
+    }
+
+    #[test]
+    fn test_translate_cancelled() {
+        let src = Dataset::open(fixture("roads.geojson")).unwrap();
+        let _nolog = SuppressGDALErrorLog::new();
+
+        let token = CancellationToken::new();
+        token.cancel();
+        let mut options = VectorTranslateOptions::new();
+        options
+            .with_output_format("Memory")
+            .with_cancellation(&token);
+        assert!(matches!(
+            translate(&src, "", &options),
+            Err(GdalError::Cancelled)
+        ));
+
+        let mut options = VectorTranslateOptions::new();
+        options
+            .with_output_format("Memory")
+            .with_target_srs("not a srs");
+        assert!(translate(&src, "", &options).is_err());
+    }
+}
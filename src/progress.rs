@@ -0,0 +1,167 @@
+//! Cancellation of long-running GDAL operations.
+//!
+//! Many GDAL algorithms periodically report their progress to a callback, and stop as soon as
+//! that callback asks them to. [`CancellationToken`] plugs into that mechanism: the `*_with_cancellation`
+//! variants of operations such as [`Dataset::build_overviews`](crate::Dataset::build_overviews),
+//! [`reproject`](crate::raster::reproject) or
+//! [`RasterBand::compute_statistics`](crate::raster::RasterBand::compute_statistics) take a token,
+//! and fail with [`GdalError::Cancelled`] once it has been cancelled, either explicitly from
+//! another thread or because its deadline has passed.
+
+use std::ffi::c_void;
+use std::ptr;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use gdal_sys::GDALProgressFunc;
+use libc::{c_char, c_int};
+
+use crate::errors::GdalError;
+
+#[derive(Debug)]
+struct TokenState {
+    cancelled: AtomicBool,
+    deadline: Option<Instant>,
+}
+
+impl TokenState {
+    fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+            || self.deadline.map_or(false, |d| Instant::now() >= d)
+    }
+}
+
+/// A handle for aborting GDAL operations, from another thread or after a deadline.
+///
+/// Clones share their state, so a clone can be moved to another thread and cancelled there
+/// while the operation runs on the original token.
+///
+/// GDAL only checks the token when reporting progress, so an operation may keep running
+/// for a little while after the token was cancelled.
+///
+/// # Example
+///
+/// ```rust, no_run
+/// # fn main() -> gdal::errors::Result<()> {
+/// use std::time::Duration;
+/// use gdal::Dataset;
+/// use gdal::errors::GdalError;
+/// use gdal::progress::CancellationToken;
+///
+/// let mut dataset = Dataset::open("fixtures/tinymarble.tif")?;
+/// let token = CancellationToken::with_timeout(Duration::from_secs(10));
+/// match dataset.build_overviews_with_cancellation("NEAREST", &[2, 4], &[], &token) {
+///     Err(GdalError::Cancelled) => println!("timed out"),
+///     other => other?,
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct CancellationToken {
+    state: Arc<TokenState>,
+}
+
+impl CancellationToken {
+    /// Create a token that is only cancelled by calling [`cancel`](Self::cancel).
+    pub fn new() -> Self {
+        Self::with_optional_deadline(None)
+    }
+
+    /// Create a token that is cancelled at `deadline`, or when [`cancel`](Self::cancel) is called.
+    pub fn with_deadline(deadline: Instant) -> Self {
+        Self::with_optional_deadline(Some(deadline))
+    }
+
+    /// Create a token that is cancelled after `timeout`, or when [`cancel`](Self::cancel) is called.
+    pub fn with_timeout(timeout: Duration) -> Self {
+        Self::with_deadline(Instant::now() + timeout)
+    }
+
+    fn with_optional_deadline(deadline: Option<Instant>) -> Self {
+        Self {
+            state: Arc::new(TokenState {
+                cancelled: AtomicBool::new(false),
+                deadline,
+            }),
+        }
+    }
+
+    /// Cancel the operations using this token or any of its clones.
+    pub fn cancel(&self) {
+        self.state.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    /// Returns `true` if [`cancel`](Self::cancel) was called or the deadline has passed.
+    pub fn is_cancelled(&self) -> bool {
+        self.state.is_cancelled()
+    }
+
+    /// Returns the deadline of this token, if any.
+    pub fn deadline(&self) -> Option<Instant> {
+        self.state.deadline
+    }
+}
+
+impl Default for CancellationToken {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+unsafe extern "C" fn cancellation_progress(
+    _complete: f64,
+    _message: *const c_char,
+    arg: *mut c_void,
+) -> c_int {
+    let state = &*(arg as *const TokenState);
+    c_int::from(!state.is_cancelled())
+}
+
+/// Get the `pfnProgress` and `pProgressData` arguments checking `token`.
+///
+/// The returned pointer is only valid while `token` is borrowed.
+pub(crate) fn progress_args(token: Option<&CancellationToken>) -> (GDALProgressFunc, *mut c_void) {
+    match token {
+        Some(token) => (
+            Some(cancellation_progress),
+            Arc::as_ptr(&token.state) as *mut c_void,
+        ),
+        None => (None, ptr::null_mut()),
+    }
+}
+
+/// Replace `err` by [`GdalError::Cancelled`] if it was caused by `token`.
+pub(crate) fn cancelled_or(token: Option<&CancellationToken>, err: GdalError) -> GdalError {
+    match token {
+        Some(token) if token.is_cancelled() => GdalError::Cancelled,
+        _ => err,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cancellation_token() {
+        let token = CancellationToken::new();
+        assert!(!token.is_cancelled());
+        assert_eq!(token.deadline(), None);
+        let clone = token.clone();
+        std::thread::spawn(move || clone.cancel()).join().unwrap();
+        assert!(token.is_cancelled());
+
+        let token = CancellationToken::with_timeout(Duration::from_secs(3600));
+        assert!(!token.is_cancelled());
+        let token = CancellationToken::with_deadline(Instant::now());
+        assert!(token.is_cancelled());
+
+        let (progress, arg) = progress_args(Some(&token));
+        assert_eq!(unsafe { progress.unwrap()(0.5, ptr::null(), arg) }, 0);
+        let token = CancellationToken::new();
+        let (progress, arg) = progress_args(Some(&token));
+        assert_eq!(unsafe { progress.unwrap()(0.5, ptr::null(), arg) }, 1);
+    }
+}
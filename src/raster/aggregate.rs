@@ -0,0 +1,194 @@
+use crate::errors::*;
+use crate::raster::{Buffer, RasterBand};
+
+/// Statistic computed over each block of source pixels by [`aggregate`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum AggStatistic {
+    Min,
+    Max,
+    Mean,
+    Median,
+    Sum,
+}
+
+impl AggStatistic {
+    /// Compute the statistic over `values`, which must not contain NaNs.
+    ///
+    /// Returns NaN if `values` is empty.
+    fn compute(&self, values: &mut [f64]) -> f64 {
+        if values.is_empty() {
+            return f64::NAN;
+        }
+        match self {
+            AggStatistic::Min => values.iter().copied().fold(f64::INFINITY, f64::min),
+            AggStatistic::Max => values.iter().copied().fold(f64::NEG_INFINITY, f64::max),
+            AggStatistic::Mean => values.iter().sum::<f64>() / values.len() as f64,
+            AggStatistic::Median => {
+                values.sort_unstable_by(|a, b| a.partial_cmp(b).expect("no NaNs"));
+                let mid = values.len() / 2;
+                if values.len() % 2 == 0 {
+                    (values[mid - 1] + values[mid]) / 2.0
+                } else {
+                    values[mid]
+                }
+            }
+            AggStatistic::Sum => values.iter().sum(),
+        }
+    }
+}
+
+/// Size of the output cells of [`aggregate`].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum AggregateScale {
+    /// Each output cell covers `factor` x `factor` source pixels.
+    Factor(usize),
+    /// Each output cell has the given size, in georeferenced units.
+    ///
+    /// The resolution must be an integer multiple of the band's pixel size,
+    /// as taken from the geo-transform of its dataset.
+    Resolution(f64),
+}
+
+impl AggregateScale {
+    /// Get the number of source `(cols, rows)` per output cell.
+    fn factors(&self, band: &RasterBand) -> Result<(usize, usize)> {
+        match *self {
+            AggregateScale::Factor(factor) if factor > 0 => Ok((factor, factor)),
+            AggregateScale::Factor(_) => Err(GdalError::BadArgument(
+                "aggregation factor must be positive".to_string(),
+            )),
+            AggregateScale::Resolution(resolution) => {
+                let dataset = band.dataset();
+                let gt = dataset.geo_transform()?;
+                // overviews have coarser pixels than the dataset
+                let (ds_cols, ds_rows) = dataset.raster_size();
+                let (cols, rows) = band.size();
+                let pixel_size = (
+                    gt[1].abs() * ds_cols as f64 / cols as f64,
+                    gt[5].abs() * ds_rows as f64 / rows as f64,
+                );
+
+                let factor = |pixel_size: f64| {
+                    let factor = resolution / pixel_size;
+                    let rounded = factor.round();
+                    if rounded < 1.0 || (factor - rounded).abs() > 1e-6 * rounded {
+                        return Err(GdalError::BadArgument(format!(
+                            "resolution {resolution} is not a multiple of the pixel size {pixel_size}"
+                        )));
+                    }
+                    Ok(rounded as usize)
+                };
+                Ok((factor(pixel_size.0)?, factor(pixel_size.1)?))
+            }
+        }
+    }
+}
+
+/// Downsample `band` by computing `statistic` over each block of source pixels.
+///
+/// Unlike the resampling kernels of [`reproject`](crate::raster::reproject) or
+/// [`RasterBand::read_as`], every source pixel in a block contributes to the result.
+/// Blocks at the right and bottom edges may be smaller than the others, when the band size
+/// isn't a multiple of the block size.
+///
+/// Pixels equal to the band's nodata value, and NaNs, are ignored. Output cells without any
+/// valid pixels are set to NaN.
+///
+/// The band is read one row of blocks at a time. The output covers the same extent as
+/// the band, so its geo-transform is that of the dataset with the pixel sizes multiplied
+/// by the block size.
+///
+/// # Example
+///
+/// ```rust, no_run
+/// # fn main() -> gdal::errors::Result<()> {
+/// use gdal::Dataset;
+/// use gdal::raster::{aggregate, AggStatistic, AggregateScale};
+/// let dataset = Dataset::open("fixtures/dem-hills.tiff")?;
+/// let band = dataset.rasterband(1)?;
+/// let max_per_cell = aggregate(&band, AggregateScale::Factor(10), AggStatistic::Max)?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn aggregate(
+    band: &RasterBand,
+    scale: AggregateScale,
+    statistic: AggStatistic,
+) -> Result<Buffer<f64>> {
+    let (factor_x, factor_y) = scale.factors(band)?;
+    let (cols, rows) = band.size();
+    let out_cols = (cols + factor_x - 1) / factor_x;
+    let out_rows = (rows + factor_y - 1) / factor_y;
+    let nodata = band.no_data_value();
+
+    let mut out = Vec::with_capacity(out_cols * out_rows);
+    let mut block = Vec::with_capacity(factor_x * factor_y);
+    for out_row in 0..out_rows {
+        let y = out_row * factor_y;
+        let height = factor_y.min(rows - y);
+        let strip =
+            band.read_as::<f64>((0, y.try_into()?), (cols, height), (cols, height), None)?;
+
+        for out_col in 0..out_cols {
+            let x = out_col * factor_x;
+            let width = factor_x.min(cols - x);
+            block.clear();
+            for row in strip.data().chunks_exact(cols) {
+                block.extend(
+                    row[x..x + width]
+                        .iter()
+                        .copied()
+                        .filter(|&v| !v.is_nan() && Some(v) != nodata),
+                );
+            }
+            out.push(statistic.compute(&mut block));
+        }
+    }
+
+    Ok(Buffer::new((out_cols, out_rows), out))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DriverManager;
+
+    #[test]
+    fn test_aggregate() {
+        let driver = DriverManager::get_driver_by_name("MEM").unwrap();
+        let mut ds = driver.create_with_band_type::<f64, _>("", 5, 4, 1).unwrap();
+        ds.set_geo_transform(&[0., 2., 0., 8., 0., -2.]).unwrap();
+        let mut band = ds.rasterband(1).unwrap();
+        #[rustfmt::skip]
+        let data = vec![
+            1., 2., 3., 4., 5.,
+            6., 7., 8., 9., 10.,
+            11., -1., 13., 14., 15.,
+            16., 17., 18., 19., 20.,
+        ];
+        band.write((0, 0), (5, 4), &mut Buffer::new((5, 4), data))
+            .unwrap();
+        band.set_no_data_value(Some(-1.)).unwrap();
+
+        let max = aggregate(&band, AggregateScale::Factor(2), AggStatistic::Max).unwrap();
+        assert_eq!(max.shape(), (3, 2));
+        assert_eq!(max.data(), [7., 9., 10., 17., 19., 20.]);
+
+        let sum = aggregate(&band, AggregateScale::Resolution(4.), AggStatistic::Sum).unwrap();
+        assert_eq!(sum.data(), [16., 24., 15., 44., 64., 35.]);
+
+        let min = aggregate(&band, AggregateScale::Factor(2), AggStatistic::Min).unwrap();
+        assert_eq!(min.data(), [1., 3., 5., 11., 13., 15.]);
+
+        let mean = aggregate(&band, AggregateScale::Factor(2), AggStatistic::Mean).unwrap();
+        assert_eq!(mean.data()[2], 7.5);
+        assert_eq!(mean.data()[3], 44. / 3.);
+
+        let median = aggregate(&band, AggregateScale::Factor(4), AggStatistic::Median).unwrap();
+        assert_eq!(median.shape(), (2, 1));
+        assert_eq!(median.data(), [9., 12.5]);
+
+        assert!(aggregate(&band, AggregateScale::Factor(0), AggStatistic::Max).is_err());
+        assert!(aggregate(&band, AggregateScale::Resolution(3.), AggStatistic::Max).is_err());
+    }
+}
@@ -0,0 +1,200 @@
+//! [`ndarray`] integration for raster I/O.
+
+use std::ffi::c_void;
+use std::mem::size_of;
+
+use gdal_sys::{CPLErr, GDALRWFlag, GDALRasterIOExtraArg};
+use libc::c_int;
+use ndarray::{Array2, Array3, ArrayView2};
+
+use crate::dataset::Dataset;
+use crate::errors::*;
+use crate::raster::rasterband::RasterIOExtraArg;
+use crate::raster::{GdalType, RasterBand, ResampleAlg};
+use crate::utils::_last_cpl_err;
+
+#[cfg_attr(docsrs, doc(cfg(feature = "array")))]
+/// Position of the band axis in arrays returned by [`Dataset::read_as_array`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum BandAxis {
+    /// Band-sequential layout, with shape `(bands, rows, cols)`.
+    First,
+    /// Pixel-interleaved layout, with shape `(rows, cols, bands)`, as used by most image libraries.
+    Last,
+}
+
+impl Dataset {
+    #[cfg_attr(docsrs, doc(cfg(feature = "array")))]
+    /// Read a window of several bands into an [`Array3<T>`], where `T` implements [`GdalType`].
+    ///
+    /// GDAL writes directly into the array's memory, so no intermediate [`Buffer`](crate::raster::Buffer) is allocated.
+    ///
+    /// # Arguments
+    /// * `window` - the window position from top left
+    /// * `window_size` - the window size (GDAL will interpolate data if `window_size` != `shape`)
+    /// * `shape` - the desired size to read, as `(cols, rows)`
+    /// * `bands` - 1-based indexes of the bands to read, or empty for all bands
+    /// * `band_axis` - whether the band axis comes first or last in the resulting array
+    /// * `e_resample_alg` - the resample algorithm used for the interpolation. Default: `NearestNeighbor`.
+    ///
+    /// # Example
+    ///
+    /// ```rust, no_run
+    /// # fn main() -> gdal::errors::Result<()> {
+    /// use gdal::Dataset;
+    /// use gdal::raster::BandAxis;
+    /// let dataset = Dataset::open("fixtures/tinymarble.tif")?;
+    /// let rgb = dataset.read_as_array::<u8>((0, 0), dataset.raster_size(), dataset.raster_size(), &[], BandAxis::Last, None)?;
+    /// assert_eq!(rgb.shape(), [50, 100, 3]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn read_as_array<T: Copy + GdalType>(
+        &self,
+        window: (isize, isize),
+        window_size: (usize, usize),
+        shape: (usize, usize),
+        bands: &[usize],
+        band_axis: BandAxis,
+        e_resample_alg: Option<ResampleAlg>,
+    ) -> Result<Array3<T>> {
+        let mut band_map = if bands.is_empty() {
+            (1..=self.raster_count())
+                .map(c_int::try_from)
+                .collect::<std::result::Result<Vec<_>, _>>()?
+        } else {
+            bands
+                .iter()
+                .map(|&b| c_int::try_from(b))
+                .collect::<std::result::Result<Vec<_>, _>>()?
+        };
+        let (cols, rows) = shape;
+        let band_count = band_map.len();
+        let pixels = band_count * rows * cols;
+        let mut data: Vec<T> = Vec::with_capacity(pixels);
+
+        let elem = size_of::<T>() as i64;
+        let (pixel_space, line_space, band_space) = match band_axis {
+            BandAxis::First => (elem, elem * cols as i64, elem * (cols * rows) as i64),
+            BandAxis::Last => (
+                elem * band_count as i64,
+                elem * (band_count * cols) as i64,
+                elem,
+            ),
+        };
+
+        let mut extra_arg = RasterIOExtraArg::default();
+        extra_arg.e_resample_alg = e_resample_alg.unwrap_or(ResampleAlg::NearestNeighbour);
+        let mut options: GDALRasterIOExtraArg = extra_arg.into();
+
+        // Safety: `GDALDatasetRasterIOEx` writes exactly `pixels` elements before we read them.
+        let rv = unsafe {
+            gdal_sys::GDALDatasetRasterIOEx(
+                self.c_dataset(),
+                GDALRWFlag::GF_Read,
+                window.0.try_into()?,
+                window.1.try_into()?,
+                window_size.0.try_into()?,
+                window_size.1.try_into()?,
+                data.as_mut_ptr() as *mut c_void,
+                cols.try_into()?,
+                rows.try_into()?,
+                T::gdal_ordinal(),
+                band_count.try_into()?,
+                band_map.as_mut_ptr(),
+                pixel_space,
+                line_space,
+                band_space,
+                &mut options,
+            )
+        };
+        if rv != CPLErr::CE_None {
+            return Err(_last_cpl_err(rv));
+        }
+
+        unsafe {
+            data.set_len(pixels);
+        };
+
+        let array_shape = match band_axis {
+            BandAxis::First => (band_count, rows, cols),
+            BandAxis::Last => (rows, cols, band_count),
+        };
+        Ok(Array3::from_shape_vec(array_shape, data)?)
+    }
+}
+
+impl<'a> RasterBand<'a> {
+    #[cfg_attr(docsrs, doc(cfg(feature = "array")))]
+    /// Read an [`Array2<T>`] from this band, where `T` implements [`GdalType`].
+    ///
+    /// The array has shape `(rows, cols)`, and takes ownership of the data read by
+    /// [`read_as`](Self::read_as) without copying it.
+    ///
+    /// # Arguments
+    /// * `window` - the window position from top left
+    /// * `window_size` - the window size (GDAL will interpolate data if `window_size` != `shape`)
+    /// * `shape` - the desired size to read, as `(cols, rows)`
+    /// * `e_resample_alg` - the resample algorithm used for the interpolation. Default: `NearestNeighbor`.
+    pub fn read_as_array<T: Copy + GdalType>(
+        &self,
+        window: (isize, isize),
+        window_size: (usize, usize),
+        shape: (usize, usize),
+        e_resample_alg: Option<ResampleAlg>,
+    ) -> Result<Array2<T>> {
+        self.read_as::<T>(window, window_size, shape, e_resample_alg)?
+            .to_array()
+    }
+
+    #[cfg_attr(docsrs, doc(cfg(feature = "array")))]
+    /// Write an [`ArrayView2<T>`] of shape `(rows, cols)` into this band.
+    ///
+    /// The array's strides are passed on to GDAL, so non-contiguous views (e.g. slices of
+    /// a larger array, or transposed arrays) are written without an intermediate copy.
+    ///
+    /// # Arguments
+    /// * `window` - the window position from top left
+    /// * `window_size` - the window size (GDAL will interpolate data if `window_size` != the array's size)
+    /// * `data` - the data to write into the window
+    pub fn write_array<T: Copy + GdalType>(
+        &mut self,
+        window: (isize, isize),
+        window_size: (usize, usize),
+        data: ArrayView2<T>,
+    ) -> Result<()> {
+        // GDAL can't handle negative spacings, so those views are copied first.
+        if data.strides().iter().any(|&s| s < 0) {
+            let owned = data.as_standard_layout().into_owned();
+            return self.write_array(window, window_size, owned.view());
+        }
+
+        let (rows, cols) = data.dim();
+        let elem = size_of::<T>() as i64;
+        let strides = data.strides();
+
+        // `GF_Write` only copies out of the buffer, so handing GDAL a pointer
+        // derived from a shared view is fine.
+        let rv = unsafe {
+            gdal_sys::GDALRasterIOEx(
+                self.c_rasterband(),
+                GDALRWFlag::GF_Write,
+                window.0.try_into()?,
+                window.1.try_into()?,
+                window_size.0.try_into()?,
+                window_size.1.try_into()?,
+                data.as_ptr() as *mut c_void,
+                cols.try_into()?,
+                rows.try_into()?,
+                T::gdal_ordinal(),
+                strides[1] as i64 * elem,
+                strides[0] as i64 * elem,
+                std::ptr::null_mut(),
+            )
+        };
+        if rv != CPLErr::CE_None {
+            return Err(_last_cpl_err(rv));
+        }
+        Ok(())
+    }
+}
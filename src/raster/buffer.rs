@@ -1,10 +1,15 @@
-use crate::raster::GdalType;
+use crate::raster::{GdalDataType, GdalType};
+use libc::c_int;
 use std::ops::{Index, IndexMut};
 use std::slice::{Iter, IterMut};
 use std::vec::IntoIter;
 
+#[cfg(all(feature = "half", any(all(major_ge_3, minor_ge_11), major_ge_4)))]
+use half::f16;
 #[cfg(feature = "ndarray")]
 use ndarray::Array2;
+#[cfg(feature = "num-complex")]
+use num_complex::Complex;
 
 /// `Buffer<T>` manages cell values in in raster I/O operations.
 ///
@@ -185,6 +190,251 @@ impl<'a, T: GdalType> IntoIterator for &'a mut Buffer<T> {
 
 pub type ByteBuffer = Buffer<u8>;
 
+/// Convert the values of `src` to the data type `D`, into a new buffer of the same shape.
+///
+/// Values out of the range of `D` are clamped, and floating point values are rounded to the
+/// nearest integer, as when reading a band with a different data type.
+///
+/// # Example
+///
+/// ```rust
+/// use gdal::raster::{convert_buffer, Buffer};
+///
+/// let src = Buffer::new((3, 1), vec![-1.5f64, 127.4, 300.]);
+/// let dst: Buffer<u8> = convert_buffer(&src);
+/// assert_eq!(dst.data(), [0, 127, 255]);
+/// ```
+///
+/// See: [`GDALCopyWords64`](https://gdal.org/api/raster_c_api.html#_CPPv415GDALCopyWords64PKv12GDALDataTypeiPv12GDALDataTypei10GPtrDiff_t)
+pub fn convert_buffer<S: Copy + GdalType, D: Copy + GdalType>(src: &Buffer<S>) -> Buffer<D> {
+    let len = src.len();
+    let mut data: Vec<D> = Vec::with_capacity(len);
+    // Safety: `GDALCopyWords64` writes exactly `len` elements
+    unsafe {
+        copy_words_unchecked(src.data().as_ptr(), 1, data.as_mut_ptr(), 1, len);
+        data.set_len(len);
+    }
+    Buffer::new(src.shape(), data)
+}
+
+/// Convert `count` values from `src` into `dst`, taking every `src_stride`-th value of `src`
+/// and writing every `dst_stride`-th value of `dst`, like [`convert_buffer`].
+///
+/// The strides are counted in elements, which allows e.g. copying one band of
+/// pixel-interleaved data.
+///
+/// # Panics
+///
+/// Panics if `src` or `dst` are too short for `count` values with their stride.
+///
+/// See: [`GDALCopyWords64`](https://gdal.org/api/raster_c_api.html#_CPPv415GDALCopyWords64PKv12GDALDataTypeiPv12GDALDataTypei10GPtrDiff_t)
+pub fn copy_words<S: Copy + GdalType, D: Copy + GdalType>(
+    src: &[S],
+    src_stride: usize,
+    dst: &mut [D],
+    dst_stride: usize,
+    count: usize,
+) {
+    if count == 0 {
+        return;
+    }
+    let last = |stride: usize| {
+        (count - 1)
+            .checked_mul(stride)
+            .expect("stride overflows the address space")
+    };
+    assert!(
+        last(src_stride) < src.len(),
+        "source of length {} is too short for {count} values with a stride of {src_stride}",
+        src.len()
+    );
+    assert!(
+        last(dst_stride) < dst.len(),
+        "destination of length {} is too short for {count} values with a stride of {dst_stride}",
+        dst.len()
+    );
+    unsafe {
+        copy_words_unchecked(
+            src.as_ptr(),
+            src_stride,
+            dst.as_mut_ptr(),
+            dst_stride,
+            count,
+        )
+    };
+}
+
+/// # Safety
+/// `src` and `dst` must be valid for `count` values with their strides.
+unsafe fn copy_words_unchecked<S: GdalType, D: GdalType>(
+    src: *const S,
+    src_stride: usize,
+    dst: *mut D,
+    dst_stride: usize,
+    count: usize,
+) {
+    let src_offset = src_stride.saturating_mul(std::mem::size_of::<S>());
+    let dst_offset = dst_stride.saturating_mul(std::mem::size_of::<D>());
+    gdal_sys::GDALCopyWords64(
+        src as *const libc::c_void,
+        S::gdal_ordinal(),
+        c_int::try_from(src_offset).expect("source stride fits in an int"),
+        dst as *mut libc::c_void,
+        D::gdal_ordinal(),
+        c_int::try_from(dst_offset).expect("destination stride fits in an int"),
+        count as _,
+    );
+}
+
+/// A [`Buffer`] of any of the data types supported by [`GdalType`].
+///
+/// Returned by [`RasterBand::read_generic`](crate::raster::RasterBand::read_generic),
+/// for code that handles bands of arbitrary data types.
+///
+/// # Example
+///
+/// ```rust, no_run
+/// # fn main() -> gdal::errors::Result<()> {
+/// use gdal::Dataset;
+/// use gdal::raster::AnyBuffer;
+/// let dataset = Dataset::open("fixtures/tinymarble.tif")?;
+/// let band = dataset.rasterband(1)?;
+/// match band.read_generic((0, 0), band.size(), band.size(), None)? {
+///     AnyBuffer::UInt8(buf) => println!("{} bytes", buf.len()),
+///     other => println!("{} values of type {}", other.len(), other.data_type()),
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum AnyBuffer {
+    UInt8(Buffer<u8>),
+    #[cfg(any(all(major_ge_3, minor_ge_7), major_ge_4))]
+    Int8(Buffer<i8>),
+    UInt16(Buffer<u16>),
+    Int16(Buffer<i16>),
+    UInt32(Buffer<u32>),
+    Int32(Buffer<i32>),
+    #[cfg(all(major_ge_3, minor_ge_5))]
+    UInt64(Buffer<u64>),
+    #[cfg(all(major_ge_3, minor_ge_5))]
+    Int64(Buffer<i64>),
+    #[cfg(all(feature = "half", any(all(major_ge_3, minor_ge_11), major_ge_4)))]
+    Float16(Buffer<f16>),
+    Float32(Buffer<f32>),
+    Float64(Buffer<f64>),
+    #[cfg(feature = "num-complex")]
+    CInt16(Buffer<Complex<i16>>),
+    #[cfg(feature = "num-complex")]
+    CInt32(Buffer<Complex<i32>>),
+    #[cfg(feature = "num-complex")]
+    CFloat32(Buffer<Complex<f32>>),
+    #[cfg(feature = "num-complex")]
+    CFloat64(Buffer<Complex<f64>>),
+}
+
+/// Evaluate `$body` with `$buf` bound to the buffer held by any [`AnyBuffer`] variant.
+macro_rules! with_any_buffer {
+    ($any:expr, $buf:ident => $body:expr) => {
+        match $any {
+            AnyBuffer::UInt8($buf) => $body,
+            #[cfg(any(all(major_ge_3, minor_ge_7), major_ge_4))]
+            AnyBuffer::Int8($buf) => $body,
+            AnyBuffer::UInt16($buf) => $body,
+            AnyBuffer::Int16($buf) => $body,
+            AnyBuffer::UInt32($buf) => $body,
+            AnyBuffer::Int32($buf) => $body,
+            #[cfg(all(major_ge_3, minor_ge_5))]
+            AnyBuffer::UInt64($buf) => $body,
+            #[cfg(all(major_ge_3, minor_ge_5))]
+            AnyBuffer::Int64($buf) => $body,
+            #[cfg(all(feature = "half", any(all(major_ge_3, minor_ge_11), major_ge_4)))]
+            AnyBuffer::Float16($buf) => $body,
+            AnyBuffer::Float32($buf) => $body,
+            AnyBuffer::Float64($buf) => $body,
+            #[cfg(feature = "num-complex")]
+            AnyBuffer::CInt16($buf) => $body,
+            #[cfg(feature = "num-complex")]
+            AnyBuffer::CInt32($buf) => $body,
+            #[cfg(feature = "num-complex")]
+            AnyBuffer::CFloat32($buf) => $body,
+            #[cfg(feature = "num-complex")]
+            AnyBuffer::CFloat64($buf) => $body,
+        }
+    };
+}
+
+impl AnyBuffer {
+    /// Get the data type of the buffer's elements.
+    pub fn data_type(&self) -> GdalDataType {
+        fn data_type_of<T: GdalType>(_: &Buffer<T>) -> GdalDataType {
+            T::datatype()
+        }
+        with_any_buffer!(self, buf => data_type_of(buf))
+    }
+
+    /// Gets the 2-d shape of the buffer.
+    ///
+    /// Returns `(cols, rows)`
+    pub fn shape(&self) -> (usize, usize) {
+        with_any_buffer!(self, buf => buf.shape())
+    }
+
+    /// Get the number of elements in the buffer
+    pub fn len(&self) -> usize {
+        with_any_buffer!(self, buf => buf.len())
+    }
+
+    /// Determine if the buffer has no elements.
+    pub fn is_empty(&self) -> bool {
+        with_any_buffer!(self, buf => buf.is_empty())
+    }
+
+    /// Convert the values of the buffer to the data type `D`, like [`convert_buffer`].
+    pub fn convert<D: Copy + GdalType>(&self) -> Buffer<D> {
+        with_any_buffer!(self, buf => convert_buffer(buf))
+    }
+}
+
+macro_rules! impl_any_buffer_from {
+    ($($(#[$attr:meta])* $ty:ty => $variant:ident),+ $(,)?) => {
+        $(
+            $(#[$attr])*
+            impl From<Buffer<$ty>> for AnyBuffer {
+                fn from(value: Buffer<$ty>) -> Self {
+                    AnyBuffer::$variant(value)
+                }
+            }
+        )+
+    };
+}
+
+impl_any_buffer_from!(
+    u8 => UInt8,
+    #[cfg(any(all(major_ge_3, minor_ge_7), major_ge_4))]
+    i8 => Int8,
+    u16 => UInt16,
+    i16 => Int16,
+    u32 => UInt32,
+    i32 => Int32,
+    #[cfg(all(major_ge_3, minor_ge_5))]
+    u64 => UInt64,
+    #[cfg(all(major_ge_3, minor_ge_5))]
+    i64 => Int64,
+    #[cfg(all(feature = "half", any(all(major_ge_3, minor_ge_11), major_ge_4)))]
+    f16 => Float16,
+    f32 => Float32,
+    f64 => Float64,
+    #[cfg(feature = "num-complex")]
+    Complex<i16> => CInt16,
+    #[cfg(feature = "num-complex")]
+    Complex<i32> => CInt32,
+    #[cfg(feature = "num-complex")]
+    Complex<f32> => CFloat32,
+    #[cfg(feature = "num-complex")]
+    Complex<f64> => CFloat64,
+);
+
 #[cfg(feature = "ndarray")]
 impl<T: GdalType> TryFrom<Buffer<T>> for Array2<T> {
     type Error = crate::errors::GdalError;
@@ -295,6 +545,12 @@ mod tests {
         assert_eq!(99, b[(0, 1)]);
     }
 
+    #[test]
+    #[should_panic(expected = "stride overflows")]
+    fn copy_words_stride_overflow() {
+        super::copy_words(&[0u8; 4], usize::MAX / 2 + 1, &mut [0u8; 4], 1, 3);
+    }
+
     #[test]
     #[should_panic]
     fn index_bounds_panic() {
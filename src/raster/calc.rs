@@ -0,0 +1,292 @@
+//! Pixel-wise computations over several rasters.
+//!
+//! The inputs of such computations rarely share the same grid. [`aligned_evaluate`] first
+//! warps them onto a common grid, so that products with different extents, resolutions or
+//! spatial references can be combined directly.
+
+use crate::dataset::Dataset;
+use crate::errors::*;
+use crate::raster::{reproject_with_options, Buffer, WarpOperationOptions};
+use crate::spatial_ref::{CoordTransform, SpatialRef};
+use crate::DriverManager;
+use crate::GeoTransform;
+
+/// Grid on which [`aligned_evaluate`] resamples its inputs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AlignPolicy {
+    /// The grid of the input at this index.
+    Reference(usize),
+    /// The extent covered by all the inputs, with the pixels of the first one.
+    Intersection,
+    /// The extent covered by any of the inputs, with the pixels of the first one.
+    Union,
+}
+
+/// Number of points per edge when computing the extent of an input in another spatial reference.
+const DENSIFY_PTS: usize = 21;
+
+/// Evaluate `expr` over the first band of each of `inputs`, after warping them onto a common grid.
+///
+/// `expr` is called for each output pixel, with the values of the inputs in the same order as
+/// `inputs`. The inputs are resampled with nearest neighbour, so that their values are kept as-is,
+/// and their spatial references are reprojected to that of the grid.
+///
+/// Pixels outside of an input, or equal to its nodata value, are missing. Output pixels with a
+/// missing input are set to NaN, without calling `expr`.
+///
+/// Returns an in-memory dataset with a single `f64` band, whose nodata value is NaN.
+///
+/// # Example
+///
+/// ```rust, no_run
+/// # fn main() -> gdal::errors::Result<()> {
+/// use gdal::Dataset;
+/// use gdal::raster::calc::{aligned_evaluate, AlignPolicy};
+///
+/// let before = Dataset::open("before.tif")?;
+/// let after = Dataset::open("after.tif")?;
+/// let difference = aligned_evaluate(|v| v[1] - v[0], &[&before, &after], AlignPolicy::Intersection)?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn aligned_evaluate<F>(expr: F, inputs: &[&Dataset], policy: AlignPolicy) -> Result<Dataset>
+where
+    F: Fn(&[f64]) -> f64,
+{
+    let first = inputs
+        .first()
+        .ok_or_else(|| GdalError::BadArgument("at least one input is required".to_string()))?;
+    let reference = match policy {
+        AlignPolicy::Reference(index) => inputs.get(index).ok_or_else(|| {
+            GdalError::BadArgument(format!(
+                "reference index {index} is out of bounds for {} inputs",
+                inputs.len()
+            ))
+        })?,
+        AlignPolicy::Intersection | AlignPolicy::Union => first,
+    };
+    let srs = reference.spatial_ref()?;
+    let reference_gt = reference.geo_transform()?;
+    if reference_gt[2] != 0. || reference_gt[4] != 0. {
+        return Err(GdalError::BadArgument(
+            "the reference grid must be north-up".to_string(),
+        ));
+    }
+
+    let (gt, (cols, rows)) = match policy {
+        AlignPolicy::Reference(_) => (reference_gt, reference.raster_size()),
+        AlignPolicy::Intersection | AlignPolicy::Union => {
+            let mut extent = bounds_in(first, &srs)?;
+            for input in &inputs[1..] {
+                let bounds = bounds_in(input, &srs)?;
+                extent = if policy == AlignPolicy::Intersection {
+                    [
+                        extent[0].max(bounds[0]),
+                        extent[1].max(bounds[1]),
+                        extent[2].min(bounds[2]),
+                        extent[3].min(bounds[3]),
+                    ]
+                } else {
+                    [
+                        extent[0].min(bounds[0]),
+                        extent[1].min(bounds[1]),
+                        extent[2].max(bounds[2]),
+                        extent[3].max(bounds[3]),
+                    ]
+                };
+            }
+            if extent[0] >= extent[2] || extent[1] >= extent[3] {
+                return Err(GdalError::BadArgument(
+                    "the inputs don't overlap".to_string(),
+                ));
+            }
+            snap_to_grid(&reference_gt, extent)
+        }
+    };
+
+    let driver = DriverManager::get_driver_by_name("MEM")?;
+    let create_grid = || -> Result<Dataset> {
+        let mut ds = driver.create_with_band_type::<f64, _>("", cols, rows, 1)?;
+        ds.set_geo_transform(&gt)?;
+        ds.set_spatial_ref(&srs)?;
+        let mut band = ds.rasterband(1)?;
+        band.set_no_data_value(Some(f64::NAN))?;
+        band.fill(f64::NAN, None)?;
+        Ok(ds)
+    };
+
+    let mut values = Vec::with_capacity(inputs.len());
+    for input in inputs {
+        let aligned = create_grid()?;
+        let mut options = WarpOperationOptions::new();
+        options
+            .with_band_mapping(&[(1, 1)])
+            .with_dst_no_data_value(f64::NAN);
+        if let Some(no_data) = input.rasterband(1)?.no_data_value() {
+            options.with_src_no_data_value(no_data);
+        }
+        reproject_with_options(input, &aligned, &options)?;
+        values.push(aligned.rasterband(1)?.read_band_as::<f64>()?);
+    }
+
+    let mut pixel = vec![0.; inputs.len()];
+    let data = (0..cols * rows)
+        .map(|i| {
+            for (v, input) in pixel.iter_mut().zip(&values) {
+                *v = input.data()[i];
+            }
+            if pixel.iter().any(|v| v.is_nan()) {
+                f64::NAN
+            } else {
+                expr(&pixel)
+            }
+        })
+        .collect();
+
+    let output = create_grid()?;
+    output
+        .rasterband(1)?
+        .write((0, 0), (cols, rows), &mut Buffer::new((cols, rows), data))?;
+    Ok(output)
+}
+
+/// Get the extent of `dataset` as `[xmin, ymin, xmax, ymax]`, in `srs`.
+fn bounds_in(dataset: &Dataset, srs: &SpatialRef) -> Result<[f64; 4]> {
+    let gt = dataset.geo_transform()?;
+    let (cols, rows) = dataset.raster_size();
+    let (cols, rows) = (cols as f64, rows as f64);
+
+    // the edges are densified, as they may be curved once reprojected
+    let steps = (0..DENSIFY_PTS).map(|i| i as f64 / (DENSIFY_PTS - 1) as f64);
+    let pixels = steps.flat_map(|t| {
+        [
+            (t * cols, 0.),
+            (t * cols, rows),
+            (0., t * rows),
+            (cols, t * rows),
+        ]
+    });
+    let (mut xs, mut ys): (Vec<f64>, Vec<f64>) = pixels
+        .map(|(col, row)| {
+            (
+                gt[0] + col * gt[1] + row * gt[2],
+                gt[3] + col * gt[4] + row * gt[5],
+            )
+        })
+        .unzip();
+
+    let dataset_srs = dataset.spatial_ref()?;
+    if dataset_srs != *srs {
+        CoordTransform::new(&dataset_srs, srs)?.transform_coords(&mut xs, &mut ys, &mut [])?;
+    }
+
+    let min_max = |v: &[f64]| {
+        v.iter()
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), &v| {
+                (min.min(v), max.max(v))
+            })
+    };
+    let (xmin, xmax) = min_max(&xs);
+    let (ymin, ymax) = min_max(&ys);
+    Ok([xmin, ymin, xmax, ymax])
+}
+
+/// Get the geo-transform and size of the smallest grid aligned on `gt` covering `extent`.
+fn snap_to_grid(gt: &GeoTransform, extent: [f64; 4]) -> (GeoTransform, (usize, usize)) {
+    // tolerate rounding errors, which would otherwise add a row or column of pixels
+    let snap = |v: f64, round: fn(f64) -> f64| {
+        let rounded = v.round();
+        if (v - rounded).abs() < 1e-6 {
+            rounded
+        } else {
+            round(v)
+        }
+    };
+    let col_min = snap((extent[0] - gt[0]) / gt[1], f64::floor);
+    let col_max = snap((extent[2] - gt[0]) / gt[1], f64::ceil);
+    // rows go from the top, so with a negative pixel height the y bounds swap
+    let (top, bottom) = if gt[5] < 0. {
+        (extent[3], extent[1])
+    } else {
+        (extent[1], extent[3])
+    };
+    let row_min = snap((top - gt[3]) / gt[5], f64::floor);
+    let row_max = snap((bottom - gt[3]) / gt[5], f64::ceil);
+
+    let snapped = [
+        gt[0] + col_min * gt[1],
+        gt[1],
+        0.,
+        gt[3] + row_min * gt[5],
+        0.,
+        gt[5],
+    ];
+    let size = ((col_max - col_min) as usize, (row_max - row_min) as usize);
+    (snapped, size)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A 4x4 dataset in EPSG:4326 with its upper-left corner at `origin`.
+    fn input(origin: (f64, f64), data: Vec<f64>) -> Dataset {
+        let driver = DriverManager::get_driver_by_name("MEM").unwrap();
+        let mut ds = driver.create_with_band_type::<f64, _>("", 4, 4, 1).unwrap();
+        ds.set_geo_transform(&[origin.0, 1., 0., origin.1, 0., -1.])
+            .unwrap();
+        ds.set_spatial_ref(&SpatialRef::from_epsg(4326).unwrap())
+            .unwrap();
+        ds.rasterband(1)
+            .unwrap()
+            .write((0, 0), (4, 4), &mut Buffer::new((4, 4), data))
+            .unwrap();
+        ds
+    }
+
+    fn read(ds: &Dataset) -> Vec<f64> {
+        ds.rasterband(1)
+            .unwrap()
+            .read_band_as::<f64>()
+            .unwrap()
+            .into_shape_and_vec()
+            .1
+    }
+
+    #[test]
+    fn test_aligned_evaluate() {
+        let a = input((0., 4.), (0..16).map(f64::from).collect());
+        let b = input((2., 6.), vec![100.; 16]);
+        let diff = |v: &[f64]| v[1] - v[0];
+
+        let out = aligned_evaluate(diff, &[&a, &b], AlignPolicy::Intersection).unwrap();
+        assert_eq!(out.raster_size(), (2, 2));
+        assert_eq!(out.geo_transform().unwrap(), [2., 1., 0., 4., 0., -1.]);
+        assert_eq!(read(&out), [98., 97., 94., 93.]);
+
+        let out = aligned_evaluate(diff, &[&a, &b], AlignPolicy::Union).unwrap();
+        assert_eq!(out.raster_size(), (6, 6));
+        assert_eq!(read(&out).iter().filter(|v| !v.is_nan()).count(), 4);
+
+        let out = aligned_evaluate(diff, &[&a, &b], AlignPolicy::Reference(1)).unwrap();
+        assert_eq!(out.raster_size(), (4, 4));
+        assert_eq!(out.geo_transform().unwrap(), b.geo_transform().unwrap());
+        let values = read(&out);
+        assert_eq!(values[8..10], [98., 97.]);
+        assert!(values[..8].iter().all(|v| v.is_nan()));
+
+        a.rasterband(1)
+            .unwrap()
+            .set_no_data_value(Some(3.))
+            .unwrap();
+        let out = aligned_evaluate(diff, &[&a, &b], AlignPolicy::Intersection).unwrap();
+        let values = read(&out);
+        assert!(values[1].is_nan());
+        assert_eq!(values[0], 98.);
+
+        assert!(aligned_evaluate(diff, &[], AlignPolicy::Union).is_err());
+        assert!(aligned_evaluate(diff, &[&a], AlignPolicy::Reference(1)).is_err());
+        let far = input((100., 50.), vec![0.; 16]);
+        assert!(aligned_evaluate(diff, &[&a, &far], AlignPolicy::Intersection).is_err());
+    }
+}
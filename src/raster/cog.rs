@@ -0,0 +1,535 @@
+//! Creation and validation of Cloud Optimized GeoTIFFs.
+//!
+//! [`create_cog`] writes a dataset as a [COG](https://gdal.org/drivers/raster/cog.html): a tiled
+//! GeoTIFF with internal overviews, laid out so that clients can read any part of it with a few
+//! HTTP range requests. [`validate_cog`] checks that an existing GeoTIFF has this layout.
+//!
+//! With GDAL >= 3.1, the files are written by the COG driver. With older versions, the dataset
+//! is copied to memory, its overviews are built there, and the copy is written by the GTiff
+//! driver with `COPY_SRC_OVERVIEWS=YES`.
+//!
+//! # Example
+//!
+//! ```rust, no_run
+//! # fn main() -> gdal::errors::Result<()> {
+//! use gdal::raster::cog::{create_cog, validate_cog, CogOptions};
+//! use gdal::raster::{OverviewCompression, OverviewResampling};
+//! use gdal::Dataset;
+//!
+//! let source = Dataset::open("fixtures/tinymarble.tif")?;
+//! let mut options = CogOptions::new();
+//! options
+//!     .with_compression(OverviewCompression::Deflate)
+//!     .with_predictor()
+//!     .with_resampling(OverviewResampling::Average)
+//!     .with_block_size(256);
+//! let cog = create_cog(&source, "/tmp/tinymarble-cog.tif", &options)?;
+//! assert!(validate_cog(&cog)?.is_valid());
+//! # Ok(())
+//! # }
+//! ```
+
+use std::path::Path;
+
+use crate::cpl::CslStringList;
+use crate::errors::*;
+use crate::progress::{cancelled_or, progress_args, CancellationToken};
+use crate::raster::{OverviewCompression, OverviewResampling};
+use crate::utils::{_last_null_pointer_err, _path_to_c_string};
+use crate::{Dataset, DriverManager, Metadata};
+
+/// Tiling scheme of a COG (GDAL >= 3.1).
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum CogTilingScheme {
+    /// Tiles aligned on the source grid (the default).
+    #[default]
+    Custom,
+    /// Tiles of the Web Mercator `GoogleMapsCompatible` tile matrix set, reprojecting the
+    /// source as needed.
+    GoogleMapsCompatible,
+    /// Tiles of another tile matrix set, by name or as a JSON definition.
+    Other(String),
+}
+
+/// How [`create_cog`] uses the overviews of the source.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CogOverviews {
+    /// Use the existing overviews of the source, or else generate them (the default).
+    #[default]
+    Auto,
+    /// Generate the overviews, even when the source has some.
+    IgnoreExisting,
+    /// Use the existing overviews of the source, even if they are incomplete (GDAL >= 3.1).
+    ForceUseExisting,
+    /// Don't write overviews.
+    None,
+}
+
+impl CogOverviews {
+    #[cfg(all(major_ge_3, minor_ge_1))]
+    fn to_gdal(self) -> &'static str {
+        match self {
+            CogOverviews::Auto => "AUTO",
+            CogOverviews::IgnoreExisting => "IGNORE_EXISTING",
+            CogOverviews::ForceUseExisting => "FORCE_USE_EXISTING",
+            CogOverviews::None => "NONE",
+        }
+    }
+}
+
+/// Options for [`create_cog`].
+///
+/// These map to the creation options of the [COG driver](https://gdal.org/drivers/raster/cog.html#creation-options).
+#[derive(Debug, Clone, Default)]
+pub struct CogOptions {
+    compression: Option<OverviewCompression>,
+    level: Option<u8>,
+    predictor: bool,
+    quality: Option<u8>,
+    block_size: Option<usize>,
+    resampling: Option<OverviewResampling>,
+    overviews: Option<CogOverviews>,
+    tiling_scheme: CogTilingScheme,
+    target_srs: Option<String>,
+    aligned_levels: Option<usize>,
+    big_tiff: Option<bool>,
+    num_threads: Option<usize>,
+    cancellation: Option<CancellationToken>,
+}
+
+impl CogOptions {
+    /// Create the default options: LZW compression, 512x512 tiles, overviews generated with
+    /// nearest neighbour resampling, or cubic for the bands with a color table.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Set the compression of the image and overviews (`COMPRESS`).
+    pub fn with_compression(&mut self, compression: OverviewCompression) -> &mut Self {
+        self.compression = Some(compression);
+        self
+    }
+
+    /// Set the DEFLATE, ZSTD or LZMA compression level (`LEVEL`).
+    pub fn with_level(&mut self, level: u8) -> &mut Self {
+        self.level = Some(level);
+        self
+    }
+
+    /// Use a predictor for LZW, DEFLATE and ZSTD compression (`PREDICTOR=YES`), chosen from
+    /// the data type.
+    pub fn with_predictor(&mut self) -> &mut Self {
+        self.predictor = true;
+        self
+    }
+
+    /// Set the JPEG or WEBP quality, from 1 to 100 (`QUALITY`).
+    pub fn with_quality(&mut self, quality: u8) -> &mut Self {
+        self.quality = Some(quality);
+        self
+    }
+
+    /// Set the size of the square tiles (`BLOCKSIZE`). Defaults to `512`.
+    pub fn with_block_size(&mut self, size: usize) -> &mut Self {
+        self.block_size = Some(size);
+        self
+    }
+
+    /// Set the resampling method of the overviews and of the reprojection (`RESAMPLING`).
+    pub fn with_resampling(&mut self, resampling: OverviewResampling) -> &mut Self {
+        self.resampling = Some(resampling);
+        self
+    }
+
+    /// Set how the overviews of the source are used (`OVERVIEWS`).
+    pub fn with_overviews(&mut self, overviews: CogOverviews) -> &mut Self {
+        self.overviews = Some(overviews);
+        self
+    }
+
+    /// Set the tiling scheme (`TILING_SCHEME`, GDAL >= 3.1).
+    pub fn with_tiling_scheme(&mut self, scheme: CogTilingScheme) -> &mut Self {
+        self.tiling_scheme = scheme;
+        self
+    }
+
+    /// Reproject the source to a spatial reference in any format accepted by
+    /// [`SpatialRef::from_definition`](crate::spatial_ref::SpatialRef::from_definition)
+    /// (`TARGET_SRS`, GDAL >= 3.1).
+    pub fn with_target_srs(&mut self, srs: &str) -> &mut Self {
+        self.target_srs = Some(srs.to_owned());
+        self
+    }
+
+    /// Align the boundaries of the `levels` largest zoom levels, i.e. the image and its largest
+    /// overviews, on the tiles of the tiling scheme (`ALIGNED_LEVELS`, GDAL >= 3.1).
+    ///
+    /// Requires a tiling scheme other than [`CogTilingScheme::Custom`].
+    pub fn with_aligned_levels(&mut self, levels: usize) -> &mut Self {
+        self.aligned_levels = Some(levels);
+        self
+    }
+
+    /// Force or prevent the BigTIFF format (`BIGTIFF`). Defaults to BigTIFF when needed.
+    pub fn with_big_tiff(&mut self, big_tiff: bool) -> &mut Self {
+        self.big_tiff = Some(big_tiff);
+        self
+    }
+
+    /// Compress with `threads` worker threads (`NUM_THREADS`), or all the CPUs with `0`.
+    pub fn with_num_threads(&mut self, threads: usize) -> &mut Self {
+        self.num_threads = Some(threads);
+        self
+    }
+
+    /// Abort the creation with [`GdalError::Cancelled`] once `token` is cancelled.
+    pub fn with_cancellation(&mut self, token: &CancellationToken) -> &mut Self {
+        self.cancellation = Some(token.clone());
+        self
+    }
+
+    /// Render the options common to the COG and GTiff drivers.
+    fn common_options(&self) -> Result<CslStringList> {
+        let mut opts = CslStringList::new();
+        if let Some(compression) = self.compression {
+            opts.set_name_value("COMPRESS", compression.to_gdal())?;
+        }
+        if let Some(big_tiff) = self.big_tiff {
+            opts.set_name_value("BIGTIFF", if big_tiff { "YES" } else { "NO" })?;
+        }
+        if let Some(threads) = self.num_threads {
+            let threads = match threads {
+                0 => "ALL_CPUS".to_string(),
+                n => n.to_string(),
+            };
+            opts.set_name_value("NUM_THREADS", &threads)?;
+        }
+        Ok(opts)
+    }
+
+    /// Render these options into the creation options of the COG driver.
+    #[cfg(all(major_ge_3, minor_ge_1))]
+    fn to_options_list(&self) -> Result<CslStringList> {
+        let mut opts = self.common_options()?;
+        if let Some(level) = self.level {
+            opts.set_name_value("LEVEL", &level.to_string())?;
+        }
+        if self.predictor {
+            opts.set_name_value("PREDICTOR", "YES")?;
+        }
+        if let Some(quality) = self.quality {
+            opts.set_name_value("QUALITY", &quality.to_string())?;
+        }
+        if let Some(size) = self.block_size {
+            opts.set_name_value("BLOCKSIZE", &size.to_string())?;
+        }
+        if let Some(resampling) = self.resampling {
+            opts.set_name_value("RESAMPLING", resampling.to_gdal())?;
+        }
+        if let Some(overviews) = self.overviews {
+            opts.set_name_value("OVERVIEWS", overviews.to_gdal())?;
+        }
+        match &self.tiling_scheme {
+            CogTilingScheme::Custom => {}
+            CogTilingScheme::GoogleMapsCompatible => {
+                opts.set_name_value("TILING_SCHEME", "GoogleMapsCompatible")?
+            }
+            CogTilingScheme::Other(scheme) => opts.set_name_value("TILING_SCHEME", scheme)?,
+        }
+        if let Some(srs) = &self.target_srs {
+            opts.set_name_value("TARGET_SRS", srs)?;
+        }
+        if let Some(levels) = self.aligned_levels {
+            opts.set_name_value("ALIGNED_LEVELS", &levels.to_string())?;
+        }
+        Ok(opts)
+    }
+}
+
+/// Write `source` as a Cloud Optimized GeoTIFF at `dest`.
+///
+/// See the [module documentation](self) for details.
+///
+/// See: [`GDALCreateCopy`](https://gdal.org/api/raster_c_api.html#_CPPv414GDALCreateCopy11GDALDriverHPKc12GDALDatasetHi12CSLConstList16GDALProgressFuncPv)
+pub fn create_cog<P: AsRef<Path>>(
+    source: &Dataset,
+    dest: P,
+    options: &CogOptions,
+) -> Result<Dataset> {
+    _create_cog(source, dest.as_ref(), options)
+}
+
+#[cfg(all(major_ge_3, minor_ge_1))]
+fn _create_cog(source: &Dataset, dest: &Path, options: &CogOptions) -> Result<Dataset> {
+    let driver = DriverManager::get_driver_by_name("COG")?;
+    let opts = options.to_options_list()?;
+    copy(source, &driver, dest, &opts, options.cancellation.as_ref())
+}
+
+#[cfg(not(all(major_ge_3, minor_ge_1)))]
+fn _create_cog(source: &Dataset, dest: &Path, options: &CogOptions) -> Result<Dataset> {
+    if options.tiling_scheme != CogTilingScheme::Custom
+        || options.target_srs.is_some()
+        || options.aligned_levels.is_some()
+    {
+        return Err(GdalError::BadArgument(
+            "tiling schemes and reprojection require GDAL >= 3.1".to_string(),
+        ));
+    }
+    if options.overviews == Some(CogOverviews::ForceUseExisting) {
+        return Err(GdalError::BadArgument(
+            "CogOverviews::ForceUseExisting requires GDAL >= 3.1".to_string(),
+        ));
+    }
+    let token = options.cancellation.as_ref();
+    let block_size = options.block_size.unwrap_or(512);
+
+    let mem = DriverManager::get_driver_by_name("MEM")?;
+    let mut copied = copy(source, &mem, Path::new(""), &CslStringList::new(), token)?;
+    let has_overviews = source.rasterband(1)?.overview_count()? > 0;
+    let build = match options.overviews.unwrap_or_default() {
+        CogOverviews::Auto => !has_overviews,
+        CogOverviews::IgnoreExisting => true,
+        CogOverviews::ForceUseExisting | CogOverviews::None => false,
+    };
+    if build {
+        let (cols, rows) = copied.raster_size();
+        let mut factors = Vec::new();
+        let mut factor = 2;
+        while cols.max(rows) / (factor / 2) > block_size {
+            factors.push(factor as i32);
+            factor *= 2;
+        }
+        let resampling = options.resampling.unwrap_or_default();
+        match token {
+            Some(token) => copied.build_overviews_with_cancellation(
+                resampling.to_gdal(),
+                &factors,
+                &[],
+                token,
+            )?,
+            None => copied.build_overviews(resampling.to_gdal(), &factors, &[])?,
+        }
+    }
+
+    let gtiff = DriverManager::get_driver_by_name("GTiff")?;
+    let mut opts = options.common_options()?;
+    if options.compression.is_none() {
+        opts.set_name_value("COMPRESS", "LZW")?;
+    }
+    opts.set_name_value("TILED", "YES")?;
+    opts.set_name_value("BLOCKXSIZE", &block_size.to_string())?;
+    opts.set_name_value("BLOCKYSIZE", &block_size.to_string())?;
+    if options.overviews != Some(CogOverviews::None) {
+        opts.set_name_value("COPY_SRC_OVERVIEWS", "YES")?;
+    }
+    if let Some(level) = options.level {
+        let key = match options.compression {
+            Some(OverviewCompression::Zstd) => "ZSTD_LEVEL",
+            Some(OverviewCompression::Lzma) => "LZMA_PRESET",
+            _ => "ZLEVEL",
+        };
+        opts.set_name_value(key, &level.to_string())?;
+    }
+    if options.predictor {
+        opts.set_name_value("PREDICTOR", "2")?;
+    }
+    if let Some(quality) = options.quality {
+        opts.set_name_value("JPEG_QUALITY", &quality.to_string())?;
+    }
+    copy(&copied, &gtiff, dest, &opts, token)
+}
+
+fn copy(
+    source: &Dataset,
+    driver: &crate::Driver,
+    dest: &Path,
+    options: &CslStringList,
+    token: Option<&CancellationToken>,
+) -> Result<Dataset> {
+    let c_dest = _path_to_c_string(dest)?;
+    let (progress, progress_arg) = progress_args(token);
+    let c_dataset = unsafe {
+        gdal_sys::GDALCreateCopy(
+            driver.c_driver(),
+            c_dest.as_ptr(),
+            source.c_dataset(),
+            0,
+            options.as_ptr(),
+            progress,
+            progress_arg,
+        )
+    };
+    if c_dataset.is_null() {
+        return Err(cancelled_or(
+            token,
+            _last_null_pointer_err("GDALCreateCopy"),
+        ));
+    }
+    Ok(unsafe { Dataset::from_c_dataset(c_dataset) })
+}
+
+/// The problems found by [`validate_cog`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CogValidation {
+    /// Problems which prevent efficient access to the file.
+    pub errors: Vec<String>,
+    /// Problems which make the access less efficient, e.g. missing overviews.
+    pub warnings: Vec<String>,
+}
+
+impl CogValidation {
+    /// Whether the file is a valid COG, i.e. has no errors.
+    pub fn is_valid(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
+
+/// Check that `dataset` is a Cloud Optimized GeoTIFF.
+///
+/// This follows the checks of GDAL's `validate_cloud_optimized_geotiff.py` script: the image
+/// and its overviews must be tiled, and the data of the overviews must come before that of the
+/// full resolution image, from the smallest overview to the largest one.
+pub fn validate_cog(dataset: &Dataset) -> Result<CogValidation> {
+    let mut report = CogValidation::default();
+    if dataset.driver().short_name() != "GTiff" {
+        report.errors.push("the file is not a GeoTIFF".to_string());
+        return Ok(report);
+    }
+    if dataset.raster_count() == 0 {
+        report.errors.push("the file has no band".to_string());
+        return Ok(report);
+    }
+
+    let band = dataset.rasterband(1)?;
+    let (cols, rows) = band.size();
+    if band.block_size().0 == cols && cols > 512 {
+        report
+            .errors
+            .push("the full resolution image is not tiled".to_string());
+    }
+    let overview_count = band.overview_count()?.max(0) as usize;
+    if overview_count == 0 && (cols > 512 || rows > 512) {
+        report
+            .warnings
+            .push("the file is greater than 512 pixels, but has no overviews".to_string());
+    }
+    if dataset
+        .metadata_item("LAYOUT", "IMAGE_STRUCTURE")
+        .as_deref()
+        != Some("COG")
+    {
+        report
+            .warnings
+            .push("the file has no COG layout header (LAYOUT=COG)".to_string());
+    }
+
+    // (level, IFD offset, offset of the first block), from the full resolution image
+    let mut levels = vec![(0, ifd_offset(&band), block_offset(&band))];
+    for index in 0..overview_count {
+        let overview = band.overview(index)?;
+        let (cols, _) = overview.size();
+        if overview.block_size().0 == cols && cols > 512 {
+            report.errors.push(format!("overview {index} is not tiled"));
+        }
+        levels.push((index + 1, ifd_offset(&overview), block_offset(&overview)));
+    }
+
+    for pair in levels.windows(2) {
+        let ((level_a, ifd_a, _), (level_b, ifd_b, _)) = (pair[0], pair[1]);
+        if let (Some(a), Some(b)) = (ifd_a, ifd_b) {
+            if a > b {
+                report.errors.push(format!(
+                    "the IFD of level {level_b} comes before that of level {level_a}"
+                ));
+            }
+        }
+    }
+    for pair in levels.windows(2) {
+        let ((level_a, _, data_a), (level_b, _, data_b)) = (pair[0], pair[1]);
+        if let (Some(a), Some(b)) = (data_a, data_b) {
+            if a < b {
+                report.errors.push(format!(
+                    "the data of level {level_a} comes before that of the smaller level {level_b}"
+                ));
+            }
+        }
+    }
+    Ok(report)
+}
+
+fn ifd_offset(band: &crate::raster::RasterBand) -> Option<u64> {
+    band.metadata_item("IFD_OFFSET", "TIFF")?.parse().ok()
+}
+
+fn block_offset(band: &crate::raster::RasterBand) -> Option<u64> {
+    band.metadata_item("BLOCK_OFFSET_0_0", "TIFF")?.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::raster::Buffer;
+    use crate::test_utils::TempFixture;
+
+    fn source() -> Dataset {
+        let driver = DriverManager::get_driver_by_name("MEM").unwrap();
+        let mut ds = driver
+            .create_with_band_type::<u8, _>("", 1024, 600, 1)
+            .unwrap();
+        ds.set_geo_transform(&[0., 1., 0., 600., 0., -1.]).unwrap();
+        let data = (0..1024 * 600).map(|i| (i % 251) as u8).collect();
+        ds.rasterband(1)
+            .unwrap()
+            .write((0, 0), (1024, 600), &mut Buffer::new((1024, 600), data))
+            .unwrap();
+        ds
+    }
+
+    #[test]
+    fn test_create_cog() {
+        let source = source();
+        let path = TempFixture::empty("cog.tif");
+        let mut options = CogOptions::new();
+        options
+            .with_compression(OverviewCompression::Deflate)
+            .with_block_size(256)
+            .with_resampling(OverviewResampling::Average);
+        let cog = create_cog(&source, &path, &options).unwrap();
+        let band = cog.rasterband(1).unwrap();
+        assert_eq!(band.block_size(), (256, 256));
+        assert!(band.overview_count().unwrap() >= 2);
+        assert_eq!(
+            cog.metadata_item("COMPRESSION", "IMAGE_STRUCTURE")
+                .as_deref(),
+            Some("DEFLATE")
+        );
+        assert_eq!(
+            band.checksum(None).unwrap(),
+            source.rasterband(1).unwrap().checksum(None).unwrap()
+        );
+        assert_eq!(validate_cog(&cog).unwrap(), CogValidation::default());
+    }
+
+    #[test]
+    fn test_validate_cog() {
+        let source = source();
+        let report = validate_cog(&source).unwrap();
+        assert!(!report.is_valid());
+
+        // a striped GeoTIFF without overviews
+        let path = TempFixture::empty("striped.tif");
+        let gtiff = DriverManager::get_driver_by_name("GTiff").unwrap();
+        let striped = source
+            .create_copy(&gtiff, &path, &Default::default())
+            .unwrap();
+        let report = validate_cog(&striped).unwrap();
+        assert_eq!(
+            report.errors,
+            ["the full resolution image is not tiled".to_string()]
+        );
+        assert!(report
+            .warnings
+            .contains(&"the file is greater than 512 pixels, but has no overviews".to_string()));
+    }
+}
@@ -0,0 +1,327 @@
+use std::ffi::CString;
+
+use gdal_sys::CPLErr;
+
+use crate::cpl::CslStringList;
+use crate::errors::*;
+use crate::progress::{cancelled_or, progress_args, CancellationToken};
+use crate::raster::RasterBand;
+use crate::utils::_last_cpl_err;
+use crate::vector::LayerAccess;
+
+/// Elevations at which [`contour`] generates its lines or polygon boundaries.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ContourLevels {
+    /// Every `interval`, starting from `base` (`LEVEL_INTERVAL` and `LEVEL_BASE`).
+    Interval { interval: f64, base: f64 },
+    /// The given elevations (`FIXED_LEVELS`).
+    Fixed(Vec<f64>),
+    /// The powers of `base`: 1, `base`, `base²`... (`LEVEL_EXP_BASE`).
+    Exponential(f64),
+}
+
+/// Options for [`contour`].
+///
+/// The attribute fields are referenced by name, and must already exist in the target layer.
+#[derive(Debug, Clone)]
+pub struct ContourOptions {
+    levels: ContourLevels,
+    no_data_value: Option<f64>,
+    id_field: Option<String>,
+    elevation_field: Option<String>,
+    elevation_range_fields: Option<(String, String)>,
+    polygonize: bool,
+    cancellation: Option<CancellationToken>,
+}
+
+impl ContourOptions {
+    /// Create options generating contour lines at `levels`, without attributes.
+    pub fn new(levels: ContourLevels) -> Self {
+        Self {
+            levels,
+            no_data_value: None,
+            id_field: None,
+            elevation_field: None,
+            elevation_range_fields: None,
+            polygonize: false,
+            cancellation: None,
+        }
+    }
+
+    /// Ignore the pixels equal to `no_data_value`, instead of the band's nodata value.
+    pub fn with_no_data_value(&mut self, no_data_value: f64) -> &mut Self {
+        self.no_data_value = Some(no_data_value);
+        self
+    }
+
+    /// Write a unique identifier of each feature to the integer field `name`.
+    pub fn with_id_field(&mut self, name: &str) -> &mut Self {
+        self.id_field = Some(name.to_string());
+        self
+    }
+
+    /// Write the elevation of each contour line to the real field `name`.
+    pub fn with_elevation_field(&mut self, name: &str) -> &mut Self {
+        self.elevation_field = Some(name.to_string());
+        self
+    }
+
+    /// Write the elevation range of each contour polygon to the real fields `min` and `max`.
+    pub fn with_elevation_range_fields(&mut self, min: &str, max: &str) -> &mut Self {
+        self.elevation_range_fields = Some((min.to_string(), max.to_string()));
+        self
+    }
+
+    /// Generate polygons of the areas between consecutive levels, instead of lines.
+    pub fn with_polygons(&mut self, polygonize: bool) -> &mut Self {
+        self.polygonize = polygonize;
+        self
+    }
+
+    /// Abort the generation with [`GdalError::Cancelled`] once `token` is cancelled.
+    pub fn with_cancellation(&mut self, token: &CancellationToken) -> &mut Self {
+        self.cancellation = Some(token.clone());
+        self
+    }
+
+    /// Render these options into the [`CslStringList`] expected by
+    /// [`GDALContourGenerateEx`](https://gdal.org/api/gdal_alg.html#_CPPv421GDALContourGenerateEx15GDALRasterBandHPv12CSLConstList16GDALProgressFuncPv),
+    /// resolving the field names against `layer`.
+    fn to_options_list<L: LayerAccess>(&self, layer: &L) -> Result<CslStringList> {
+        let mut opts = CslStringList::new();
+        match &self.levels {
+            ContourLevels::Interval { interval, base } => {
+                opts.set_name_value("LEVEL_INTERVAL", &interval.to_string())?;
+                opts.set_name_value("LEVEL_BASE", &base.to_string())?;
+            }
+            ContourLevels::Fixed(levels) => {
+                if levels.is_empty() {
+                    return Err(GdalError::BadArgument(
+                        "at least one fixed level is required".to_string(),
+                    ));
+                }
+                let levels = levels
+                    .iter()
+                    .map(|level| level.to_string())
+                    .collect::<Vec<_>>();
+                opts.set_name_value("FIXED_LEVELS", &levels.join(","))?;
+            }
+            ContourLevels::Exponential(base) => {
+                opts.set_name_value("LEVEL_EXP_BASE", &base.to_string())?;
+            }
+        }
+        if let Some(no_data_value) = self.no_data_value {
+            opts.set_name_value("NODATA", &no_data_value.to_string())?;
+        }
+        let fields = [
+            ("ID_FIELD", self.id_field.as_deref()),
+            ("ELEV_FIELD", self.elevation_field.as_deref()),
+            (
+                "ELEV_FIELD_MIN",
+                self.elevation_range_fields.as_ref().map(|f| f.0.as_str()),
+            ),
+            (
+                "ELEV_FIELD_MAX",
+                self.elevation_range_fields.as_ref().map(|f| f.1.as_str()),
+            ),
+        ];
+        for (option, name) in fields {
+            if let Some(name) = name {
+                let index = field_index(layer, name)?;
+                opts.set_name_value(option, &index.to_string())?;
+            }
+        }
+        if self.polygonize {
+            opts.set_name_value("POLYGONIZE", "YES")?;
+        }
+        Ok(opts)
+    }
+}
+
+/// Get the index of the field `name` of `layer`.
+pub(super) fn field_index<L: LayerAccess>(layer: &L, name: &str) -> Result<i32> {
+    let c_name = CString::new(name)?;
+    let index = unsafe { gdal_sys::OGR_L_FindFieldIndex(layer.c_layer(), c_name.as_ptr(), 1) };
+    if index == -1 {
+        return Err(GdalError::InvalidFieldName {
+            field_name: name.to_string(),
+            method_name: "OGR_L_FindFieldIndex",
+        });
+    }
+    Ok(index)
+}
+
+/// Generate the contours of `band` as features of `layer`.
+///
+/// Depending on `options`, the features are lines at each level, or polygons of the areas
+/// between consecutive levels. Their coordinates are georeferenced with the geo-transform of
+/// the band's dataset.
+///
+/// # Example
+///
+/// ```rust, no_run
+/// # fn main() -> gdal::errors::Result<()> {
+/// use gdal::raster::{contour, ContourLevels, ContourOptions};
+/// use gdal::vector::{LayerAccess, LayerOptions, OGRFieldType};
+/// use gdal::{Dataset, DriverManager};
+///
+/// let dem = Dataset::open("fixtures/dem-hills.tiff")?;
+/// let driver = DriverManager::get_driver_by_name("GPKG")?;
+/// let mut contours = driver.create_vector_only("target/contours.gpkg")?;
+/// let srs = dem.spatial_ref()?;
+/// let mut layer = contours.create_layer(LayerOptions {
+///     name: "contours",
+///     srs: Some(&srs),
+///     ..Default::default()
+/// })?;
+/// layer.create_defn_fields(&[("elev", OGRFieldType::OFTReal)])?;
+///
+/// let mut options = ContourOptions::new(ContourLevels::Interval {
+///     interval: 10.,
+///     base: 0.,
+/// });
+/// options.with_elevation_field("elev");
+/// contour(&dem.rasterband(1)?, &options, &mut layer)?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// See: [`GDALContourGenerateEx`](https://gdal.org/api/gdal_alg.html#_CPPv421GDALContourGenerateEx15GDALRasterBandHPv12CSLConstList16GDALProgressFuncPv)
+pub fn contour<L: LayerAccess>(
+    band: &RasterBand,
+    options: &ContourOptions,
+    layer: &mut L,
+) -> Result<()> {
+    let opts = options.to_options_list(layer)?;
+    let token = options.cancellation.as_ref();
+    let (progress, progress_arg) = progress_args(token);
+    let rv = unsafe {
+        gdal_sys::GDALContourGenerateEx(
+            band.c_rasterband(),
+            layer.c_layer(),
+            opts.as_ptr(),
+            progress,
+            progress_arg,
+        )
+    };
+    if rv != CPLErr::CE_None {
+        return Err(cancelled_or(token, _last_cpl_err(rv)));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::raster::Buffer;
+    use crate::vector::{LayerOptions, OGRFieldType};
+    use crate::{Dataset, DriverManager};
+
+    /// A 10x10 raster whose values are their column index.
+    fn ramp() -> Dataset {
+        let driver = DriverManager::get_driver_by_name("MEM").unwrap();
+        let mut ds = driver
+            .create_with_band_type::<f64, _>("", 10, 10, 1)
+            .unwrap();
+        ds.set_geo_transform(&[0., 1., 0., 10., 0., -1.]).unwrap();
+        let data = (0..100).map(|i| f64::from(i % 10)).collect();
+        ds.rasterband(1)
+            .unwrap()
+            .write((0, 0), (10, 10), &mut Buffer::new((10, 10), data))
+            .unwrap();
+        ds
+    }
+
+    #[test]
+    fn test_contour_lines() {
+        let ds = ramp();
+        let driver = DriverManager::get_driver_by_name("Memory").unwrap();
+        let mut out = driver.create_vector_only("").unwrap();
+        let mut layer = out
+            .create_layer(LayerOptions {
+                name: "contours",
+                ty: gdal_sys::OGRwkbGeometryType::wkbLineString,
+                ..Default::default()
+            })
+            .unwrap();
+        layer
+            .create_defn_fields(&[
+                ("id", OGRFieldType::OFTInteger),
+                ("elev", OGRFieldType::OFTReal),
+            ])
+            .unwrap();
+
+        let mut options = ContourOptions::new(ContourLevels::Interval {
+            interval: 2.,
+            base: 0.5,
+        });
+        options.with_id_field("id").with_elevation_field("elev");
+        contour(&ds.rasterband(1).unwrap(), &options, &mut layer).unwrap();
+        let mut levels = layer
+            .features()
+            .map(|f| f.field_as_double_by_name("elev").unwrap().unwrap())
+            .collect::<Vec<_>>();
+        levels.sort_by(f64::total_cmp);
+        assert_eq!(levels, [0.5, 2.5, 4.5, 6.5, 8.5]);
+
+        let mut layer = out
+            .create_layer(LayerOptions {
+                name: "fixed",
+                ty: gdal_sys::OGRwkbGeometryType::wkbLineString,
+                ..Default::default()
+            })
+            .unwrap();
+        let options = ContourOptions::new(ContourLevels::Fixed(vec![3.5]));
+        contour(&ds.rasterband(1).unwrap(), &options, &mut layer).unwrap();
+        assert_eq!(layer.feature_count(), 1);
+        {
+            let feature = layer.features().next().unwrap();
+            let line = feature.geometry().unwrap();
+            // the pixel centers of the columns 3 and 4 are at x = 3.5 and 4.5
+            assert!(line.get_point_vec().iter().all(|p| p.0 == 4.));
+        }
+
+        let mut options = ContourOptions::new(ContourLevels::Fixed(vec![3.5]));
+        options.with_elevation_field("missing");
+        assert!(matches!(
+            contour(&ds.rasterband(1).unwrap(), &options, &mut layer),
+            Err(GdalError::InvalidFieldName { .. })
+        ));
+    }
+
+    #[test]
+    fn test_contour_polygons() {
+        let ds = ramp();
+        let driver = DriverManager::get_driver_by_name("Memory").unwrap();
+        let mut out = driver.create_vector_only("").unwrap();
+        let mut layer = out
+            .create_layer(LayerOptions {
+                name: "contours",
+                ty: gdal_sys::OGRwkbGeometryType::wkbMultiPolygon,
+                ..Default::default()
+            })
+            .unwrap();
+        layer
+            .create_defn_fields(&[
+                ("min", OGRFieldType::OFTReal),
+                ("max", OGRFieldType::OFTReal),
+            ])
+            .unwrap();
+
+        let mut options = ContourOptions::new(ContourLevels::Fixed(vec![2.5, 6.5]));
+        options
+            .with_elevation_range_fields("min", "max")
+            .with_polygons(true);
+        contour(&ds.rasterband(1).unwrap(), &options, &mut layer).unwrap();
+        let ranges = layer
+            .features()
+            .map(|f| {
+                (
+                    f.field_as_double_by_name("min").unwrap().unwrap(),
+                    f.field_as_double_by_name("max").unwrap().unwrap(),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert!(ranges.contains(&(2.5, 6.5)));
+    }
+}
@@ -1,7 +1,79 @@
 use crate::cpl::CslStringList;
+use crate::errors::Result;
+use crate::metadata::Metadata;
+use crate::raster::{ColorInterpretation, RasterBand};
 
 /// Key/value pairs of options for passing driver-specific creation flags to
 /// [`Driver::create_with_band_type_with_options`](crate::Driver::create_with_band_type_with_options`).
 ///
 /// See `papszOptions` in [GDAL's `Create(...)` API documentation](https://gdal.org/api/gdaldriver_cpp.html#_CPPv4N10GDALDriver6CreateEPKciii12GDALDataType12CSLConstList).
 pub type RasterCreationOptions = CslStringList;
+
+/// Settings applied to one band of a dataset created by
+/// [`Driver::create_multi_band`](crate::Driver::create_multi_band).
+///
+/// # Example
+///
+/// ```rust, no_run
+/// use gdal::raster::{BandCreationOptions, ColorInterpretation};
+///
+/// let mut red = BandCreationOptions::new();
+/// red.with_color_interpretation(ColorInterpretation::RedBand)
+///     .with_no_data_value(0.)
+///     .with_description("red")
+///     .with_metadata_item("WAVELENGTH", "665", "");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct BandCreationOptions {
+    no_data_value: Option<f64>,
+    color_interpretation: Option<ColorInterpretation>,
+    description: Option<String>,
+    metadata: Vec<(String, String, String)>,
+}
+
+impl BandCreationOptions {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Set the no-data value of the band.
+    pub fn with_no_data_value(&mut self, no_data_value: f64) -> &mut Self {
+        self.no_data_value = Some(no_data_value);
+        self
+    }
+
+    /// Set the color interpretation of the band.
+    pub fn with_color_interpretation(&mut self, interp: ColorInterpretation) -> &mut Self {
+        self.color_interpretation = Some(interp);
+        self
+    }
+
+    /// Set the description of the band.
+    pub fn with_description(&mut self, description: &str) -> &mut Self {
+        self.description = Some(description.to_string());
+        self
+    }
+
+    /// Set the metadata item `key` of `domain` (`""` for the default domain) on the band.
+    pub fn with_metadata_item(&mut self, key: &str, value: &str, domain: &str) -> &mut Self {
+        self.metadata
+            .push((key.to_string(), value.to_string(), domain.to_string()));
+        self
+    }
+
+    pub(crate) fn apply(&self, band: &mut RasterBand) -> Result<()> {
+        if let Some(no_data_value) = self.no_data_value {
+            band.set_no_data_value(Some(no_data_value))?;
+        }
+        if let Some(interp) = self.color_interpretation {
+            band.set_color_interpretation(interp)?;
+        }
+        if let Some(description) = &self.description {
+            band.set_description(description)?;
+        }
+        for (key, value, domain) in &self.metadata {
+            band.set_metadata_item(key, value, domain)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,234 @@
+use std::ptr;
+
+use gdal_sys::CPLErr;
+
+use crate::cpl::CslStringList;
+use crate::errors::*;
+use crate::progress::{cancelled_or, progress_args, CancellationToken};
+use crate::raster::RasterBand;
+use crate::utils::_last_cpl_err;
+
+/// Pixels considered as neighbours when grouping pixels into regions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Connectedness {
+    /// Only the horizontal and vertical neighbours.
+    #[default]
+    Four,
+    /// The diagonal neighbours too.
+    Eight,
+}
+
+impl Connectedness {
+    fn to_gdal(self) -> libc::c_int {
+        match self {
+            Connectedness::Four => 4,
+            Connectedness::Eight => 8,
+        }
+    }
+}
+
+/// Interpolation used by [`RasterBand::fill_nodata`].
+#[cfg(any(all(major_ge_3, minor_ge_9), major_ge_4))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FillNodataInterpolation {
+    /// Inverse distance weighting of the valid pixels found in each direction (the default).
+    #[default]
+    InverseDistance,
+    /// The value of the nearest valid pixel.
+    Nearest,
+}
+
+/// Options for [`RasterBand::fill_nodata`].
+#[derive(Debug, Clone, Default)]
+pub struct FillNodataOptions {
+    #[cfg(any(all(major_ge_3, minor_ge_9), major_ge_4))]
+    interpolation: Option<FillNodataInterpolation>,
+    temp_file_driver: Option<String>,
+    temp_file_compression: Option<String>,
+}
+
+impl FillNodataOptions {
+    /// Create the default options: inverse distance weighting, with GeoTIFF temporary files.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Set the interpolation method (GDAL >= 3.9).
+    #[cfg(any(all(major_ge_3, minor_ge_9), major_ge_4))]
+    pub fn with_interpolation(&mut self, interpolation: FillNodataInterpolation) -> &mut Self {
+        self.interpolation = Some(interpolation);
+        self
+    }
+
+    /// Set the driver of the temporary files used on large rasters (`TEMP_FILE_DRIVER`),
+    /// e.g. `MEM` to keep them in memory.
+    pub fn with_temp_file_driver(&mut self, driver: &str) -> &mut Self {
+        self.temp_file_driver = Some(driver.to_string());
+        self
+    }
+
+    /// Set the compression of the temporary GeoTIFF files (`COMPRESS`), e.g. `LZW`.
+    pub fn with_temp_file_compression(&mut self, compression: &str) -> &mut Self {
+        self.temp_file_compression = Some(compression.to_string());
+        self
+    }
+
+    fn to_options_list(&self) -> Result<CslStringList> {
+        let mut opts = CslStringList::new();
+        #[cfg(any(all(major_ge_3, minor_ge_9), major_ge_4))]
+        if let Some(interpolation) = self.interpolation {
+            let value = match interpolation {
+                FillNodataInterpolation::InverseDistance => "INV_DIST",
+                FillNodataInterpolation::Nearest => "NEAREST",
+            };
+            opts.set_name_value("INTERPOLATION", value)?;
+        }
+        if let Some(driver) = &self.temp_file_driver {
+            opts.set_name_value("TEMP_FILE_DRIVER", driver)?;
+        }
+        if let Some(compression) = &self.temp_file_compression {
+            opts.set_name_value("COMPRESS", compression)?;
+        }
+        Ok(opts)
+    }
+}
+
+/// # Filters
+///
+/// These methods rewrite the pixels of a band in place.
+impl<'a> RasterBand<'a> {
+    /// Replace the regions smaller than `threshold` pixels by the value of their largest neighbour
+    /// region.
+    ///
+    /// Regions are groups of connected pixels sharing the same value. The pixels which are zero in
+    /// `mask` are left out; use [`open_mask_band`](Self::open_mask_band) to leave out the nodata
+    /// pixels.
+    ///
+    /// Stops with [`GdalError::Cancelled`] once `token` is cancelled.
+    ///
+    /// See: [`GDALSieveFilter`](https://gdal.org/api/gdal_alg.html#_CPPv415GDALSieveFilter15GDALRasterBandH15GDALRasterBandH15GDALRasterBandHiiPPc16GDALProgressFuncPv)
+    pub fn sieve_filter(
+        &mut self,
+        threshold: usize,
+        connectedness: Connectedness,
+        mask: Option<&RasterBand>,
+        token: Option<&CancellationToken>,
+    ) -> Result<()> {
+        let c_mask = mask.map_or(ptr::null_mut(), |mask| unsafe { mask.c_rasterband() });
+        let (progress, progress_arg) = progress_args(token);
+        let rv = unsafe {
+            gdal_sys::GDALSieveFilter(
+                self.c_rasterband(),
+                c_mask,
+                self.c_rasterband(),
+                threshold.try_into()?,
+                connectedness.to_gdal(),
+                ptr::null_mut(),
+                progress,
+                progress_arg,
+            )
+        };
+        if rv != CPLErr::CE_None {
+            return Err(cancelled_or(token, _last_cpl_err(rv)));
+        }
+        Ok(())
+    }
+
+    /// Fill the nodata pixels by interpolating from the valid pixels around them, searching up to
+    /// `max_search_distance` pixels away.
+    ///
+    /// The pixels to fill are those which are zero in `mask`, or by default those which are
+    /// invalid in the band's [mask](Self::open_mask_band), e.g. equal to its nodata value.
+    /// After the interpolation, `smoothing_iterations` passes of a 3x3 average filter smooth
+    /// out the filled pixels.
+    ///
+    /// Stops with [`GdalError::Cancelled`] once `token` is cancelled.
+    ///
+    /// See: [`GDALFillNodata`](https://gdal.org/api/gdal_alg.html#_CPPv414GDALFillNodata15GDALRasterBandH15GDALRasterBandHdiiPPc16GDALProgressFuncPv)
+    pub fn fill_nodata(
+        &mut self,
+        mask: Option<&RasterBand>,
+        max_search_distance: f64,
+        smoothing_iterations: usize,
+        options: &FillNodataOptions,
+        token: Option<&CancellationToken>,
+    ) -> Result<()> {
+        let c_mask = mask.map_or(ptr::null_mut(), |mask| unsafe { mask.c_rasterband() });
+        let opts = options.to_options_list()?;
+        let (progress, progress_arg) = progress_args(token);
+        let rv = unsafe {
+            gdal_sys::GDALFillNodata(
+                self.c_rasterband(),
+                c_mask,
+                max_search_distance,
+                0,
+                smoothing_iterations.try_into()?,
+                opts.as_ptr(),
+                progress,
+                progress_arg,
+            )
+        };
+        if rv != CPLErr::CE_None {
+            return Err(cancelled_or(token, _last_cpl_err(rv)));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::raster::Buffer;
+    use crate::DriverManager;
+
+    #[test]
+    fn test_sieve_filter() {
+        let driver = DriverManager::get_driver_by_name("MEM").unwrap();
+        let ds = driver.create_with_band_type::<u8, _>("", 4, 4, 1).unwrap();
+        #[rustfmt::skip]
+        let data = vec![
+            1, 1, 2, 2,
+            1, 3, 2, 2,
+            1, 1, 2, 2,
+            4, 1, 2, 5,
+        ];
+        let mut band = ds.rasterband(1).unwrap();
+        band.write((0, 0), (4, 4), &mut Buffer::new((4, 4), data))
+            .unwrap();
+
+        band.sieve_filter(2, Connectedness::Four, None, None)
+            .unwrap();
+        #[rustfmt::skip]
+        let expected = [
+            1, 1, 2, 2,
+            1, 1, 2, 2,
+            1, 1, 2, 2,
+            1, 1, 2, 2,
+        ];
+        assert_eq!(band.read_band_as::<u8>().unwrap().data(), expected);
+
+        let token = CancellationToken::new();
+        token.cancel();
+        assert!(matches!(
+            band.sieve_filter(2, Connectedness::Eight, None, Some(&token)),
+            Err(GdalError::Cancelled)
+        ));
+    }
+
+    #[test]
+    fn test_fill_nodata() {
+        let driver = DriverManager::get_driver_by_name("MEM").unwrap();
+        let ds = driver.create_with_band_type::<f32, _>("", 3, 3, 1).unwrap();
+        let mut band = ds.rasterband(1).unwrap();
+        let mut data = vec![10.; 9];
+        data[4] = -1.;
+        band.write((0, 0), (3, 3), &mut Buffer::new((3, 3), data))
+            .unwrap();
+        band.set_no_data_value(Some(-1.)).unwrap();
+
+        let mut options = FillNodataOptions::new();
+        options.with_temp_file_driver("MEM");
+        band.fill_nodata(None, 10., 0, &options, None).unwrap();
+        assert_eq!(band.read_band_as::<f32>().unwrap().data(), [10.; 9]);
+    }
+}
@@ -0,0 +1,509 @@
+use std::path::Path;
+use std::ptr::{null, null_mut, NonNull};
+
+use gdal_sys::{GDALDatasetH, GDALFootprintOptions};
+use libc::c_int;
+
+use crate::cpl::CslStringList;
+use crate::errors::*;
+use crate::progress::{cancelled_or, progress_args, CancellationToken};
+use crate::utils::{_last_null_pointer_err, _path_to_c_string};
+use crate::Dataset;
+
+/// How the masks of several bands are combined by [`footprint`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FootprintCombine {
+    /// A pixel is valid if it is valid in any band (the default).
+    #[default]
+    Union,
+    /// A pixel is valid if it is valid in all the bands.
+    Intersection,
+}
+
+/// Coordinate space of the geometries computed by [`footprint`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FootprintCoordinates {
+    /// Georeferenced coordinates, from the geo-transform of the dataset (the default).
+    #[default]
+    Georeferenced,
+    /// Pixel and line coordinates.
+    Pixel,
+}
+
+/// Configuration options for [`footprint`].
+///
+/// These map to the [`gdal_footprint`] command line options. Arguments not covered by the
+/// setters can be parsed with [`from_args`](Self::from_args).
+///
+/// [`gdal_footprint`]: https://gdal.org/programs/gdal_footprint.html
+#[derive(Debug, Clone)]
+pub struct FootprintOptions {
+    args: Vec<String>,
+    output_format: String,
+    bands: Vec<usize>,
+    combine: Option<FootprintCombine>,
+    overview: Option<usize>,
+    src_no_data: Vec<f64>,
+    coordinates: Option<FootprintCoordinates>,
+    target_srs: Option<String>,
+    split_polygons: bool,
+    convex_hull: bool,
+    densify: Option<f64>,
+    simplify: Option<f64>,
+    min_ring_area: Option<f64>,
+    max_points: Option<Option<usize>>,
+    layer_name: Option<String>,
+    location_field: Option<Option<String>>,
+    overwrite: bool,
+    cancellation: Option<CancellationToken>,
+}
+
+impl Default for FootprintOptions {
+    fn default() -> Self {
+        Self {
+            args: Vec::new(),
+            output_format: "Memory".to_string(),
+            bands: Vec::new(),
+            combine: None,
+            overview: None,
+            src_no_data: Vec::new(),
+            coordinates: None,
+            target_srs: None,
+            split_polygons: false,
+            convex_hull: false,
+            densify: None,
+            simplify: None,
+            min_ring_area: None,
+            max_points: None,
+            layer_name: None,
+            location_field: None,
+            overwrite: false,
+            cancellation: None,
+        }
+    }
+}
+
+impl FootprintOptions {
+    /// Create the default options: the footprint of the mask of all the bands, into an
+    /// in-memory vector dataset.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Create an options set from `gdal_footprint` command line arguments, excluding the
+    /// source and destination.
+    ///
+    /// The arguments are parsed by GDAL when computing the footprint, which fails if they are
+    /// invalid. The setters add to these arguments.
+    pub fn from_args<S: Into<String>, I: IntoIterator<Item = S>>(args: I) -> Self {
+        Self {
+            args: args.into_iter().map(Into::into).collect(),
+            ..Default::default()
+        }
+    }
+
+    /// Specify the output format, as the short name of a vector driver (e.g. `GPKG`).
+    ///
+    /// Defaults to `Memory`, in which case the destination path can be empty.
+    pub fn with_output_format(&mut self, format: &str) -> &mut Self {
+        self.output_format = format.to_owned();
+        self
+    }
+
+    /// Only use the masks of the given (1-based) bands. Defaults to all of them.
+    pub fn with_bands(&mut self, bands: &[usize]) -> &mut Self {
+        self.bands = bands.to_vec();
+        self
+    }
+
+    /// Set how the masks of the bands are combined.
+    pub fn with_combine_bands(&mut self, combine: FootprintCombine) -> &mut Self {
+        self.combine = Some(combine);
+        self
+    }
+
+    /// Compute the footprint on the (0-based) overview `index`, for speed.
+    pub fn with_overview(&mut self, index: usize) -> &mut Self {
+        self.overview = Some(index);
+        self
+    }
+
+    /// Consider the pixels with these values as invalid, instead of using the mask bands.
+    pub fn with_src_no_data(&mut self, values: &[f64]) -> &mut Self {
+        self.src_no_data = values.to_vec();
+        self
+    }
+
+    /// Set the coordinate space of the output geometries.
+    pub fn with_coordinates(&mut self, coordinates: FootprintCoordinates) -> &mut Self {
+        self.coordinates = Some(coordinates);
+        self
+    }
+
+    /// Reproject the output geometries, to a spatial reference in any format accepted by
+    /// [`SpatialRef::from_definition`](crate::spatial_ref::SpatialRef::from_definition).
+    pub fn with_target_srs(&mut self, srs: &str) -> &mut Self {
+        self.target_srs = Some(srs.to_owned());
+        self
+    }
+
+    /// Write each polygon of the footprint as a separate feature, instead of a single
+    /// multi-polygon.
+    pub fn with_split_polygons(&mut self) -> &mut Self {
+        self.split_polygons = true;
+        self
+    }
+
+    /// Replace the footprint by its convex hull, which removes its holes.
+    pub fn with_convex_hull(&mut self) -> &mut Self {
+        self.convex_hull = true;
+        self
+    }
+
+    /// Densify the footprint, with points at most `distance` apart, before reprojecting it.
+    pub fn with_densify(&mut self, distance: f64) -> &mut Self {
+        self.densify = Some(distance);
+        self
+    }
+
+    /// Simplify the footprint, with the given tolerance, in the output units.
+    pub fn with_simplify(&mut self, tolerance: f64) -> &mut Self {
+        self.simplify = Some(tolerance);
+        self
+    }
+
+    /// Drop the parts and holes of the footprint smaller than `area`, in the output units.
+    pub fn with_min_ring_area(&mut self, area: f64) -> &mut Self {
+        self.min_ring_area = Some(area);
+        self
+    }
+
+    /// Set the maximum number of points of each polygon, simplifying it as needed, or `None` for
+    /// no limit. Defaults to `Some(100)`.
+    pub fn with_max_points(&mut self, max_points: Option<usize>) -> &mut Self {
+        self.max_points = Some(max_points);
+        self
+    }
+
+    /// Set the name of the output layer. Defaults to `footprint`.
+    pub fn with_layer_name(&mut self, name: &str) -> &mut Self {
+        self.layer_name = Some(name.to_owned());
+        self
+    }
+
+    /// Set the name of the field storing the path of the source, or `None` to leave it out.
+    /// Defaults to `location`.
+    pub fn with_location_field(&mut self, name: Option<&str>) -> &mut Self {
+        self.location_field = Some(name.map(str::to_owned));
+        self
+    }
+
+    /// Replace the output layer if it already exists, instead of appending to it.
+    pub fn with_overwrite(&mut self) -> &mut Self {
+        self.overwrite = true;
+        self
+    }
+
+    /// Abort the computation with [`GdalError::Cancelled`] once `token` is cancelled.
+    pub fn with_cancellation(&mut self, token: &CancellationToken) -> &mut Self {
+        self.cancellation = Some(token.clone());
+        self
+    }
+
+    /// Render these options into [`CslStringList`] values, as compatible with
+    /// [`gdal_sys::GDALFootprintOptionsNew`].
+    ///
+    /// The output format is only rendered when `with_format` is set, as it does not apply to an
+    /// existing dataset.
+    fn to_options_list(&self, with_format: bool) -> Result<CslStringList> {
+        let mut opts = CslStringList::default();
+
+        for arg in &self.args {
+            opts.add_string(arg)?;
+        }
+
+        if with_format {
+            opts.add_string("-of")?;
+            opts.add_string(&self.output_format)?;
+        }
+
+        for band in &self.bands {
+            opts.add_string("-b")?;
+            opts.add_string(&band.to_string())?;
+        }
+
+        if let Some(combine) = self.combine {
+            opts.add_string("-combine_bands")?;
+            opts.add_string(match combine {
+                FootprintCombine::Union => "union",
+                FootprintCombine::Intersection => "intersection",
+            })?;
+        }
+
+        if let Some(overview) = self.overview {
+            opts.add_string("-ovr")?;
+            opts.add_string(&overview.to_string())?;
+        }
+
+        if !self.src_no_data.is_empty() {
+            let values = self
+                .src_no_data
+                .iter()
+                .map(|v| v.to_string())
+                .collect::<Vec<_>>();
+            opts.add_string("-srcnodata")?;
+            opts.add_string(&values.join(" "))?;
+        }
+
+        if let Some(coordinates) = self.coordinates {
+            opts.add_string("-t_cs")?;
+            opts.add_string(match coordinates {
+                FootprintCoordinates::Georeferenced => "georef",
+                FootprintCoordinates::Pixel => "pixel",
+            })?;
+        }
+
+        if let Some(srs) = &self.target_srs {
+            opts.add_string("-t_srs")?;
+            opts.add_string(srs)?;
+        }
+
+        if self.split_polygons {
+            opts.add_string("-split_polys")?;
+        }
+
+        if self.convex_hull {
+            opts.add_string("-convex_hull")?;
+        }
+
+        if let Some(distance) = self.densify {
+            opts.add_string("-densify")?;
+            opts.add_string(&distance.to_string())?;
+        }
+
+        if let Some(tolerance) = self.simplify {
+            opts.add_string("-simplify")?;
+            opts.add_string(&tolerance.to_string())?;
+        }
+
+        if let Some(area) = self.min_ring_area {
+            opts.add_string("-min_ring_area")?;
+            opts.add_string(&area.to_string())?;
+        }
+
+        if let Some(max_points) = self.max_points {
+            opts.add_string("-max_points")?;
+            match max_points {
+                Some(max_points) => opts.add_string(&max_points.to_string())?,
+                None => opts.add_string("unlimited")?,
+            }
+        }
+
+        if let Some(name) = &self.layer_name {
+            opts.add_string("-lyr_name")?;
+            opts.add_string(name)?;
+        }
+
+        match &self.location_field {
+            Some(Some(name)) => {
+                opts.add_string("-location_field_name")?;
+                opts.add_string(name)?;
+            }
+            Some(None) => opts.add_string("-no_location")?,
+            None => {}
+        }
+
+        if self.overwrite {
+            opts.add_string("-overwrite")?;
+        }
+
+        Ok(opts)
+    }
+}
+
+impl From<Vec<&str>> for FootprintOptions {
+    fn from(value: Vec<&str>) -> Self {
+        FootprintOptions::from_args(value)
+    }
+}
+
+/// Payload for [`GDALFootprint`](gdal_sys::GDALFootprint).
+struct GdalFootprintOptions(NonNull<GDALFootprintOptions>);
+
+impl GdalFootprintOptions {
+    fn new(options: &FootprintOptions, with_format: bool) -> Result<Self> {
+        let opts = options.to_options_list(with_format)?;
+        let popts = unsafe { gdal_sys::GDALFootprintOptionsNew(opts.as_ptr(), null_mut()) };
+        let popts = match NonNull::new(popts) {
+            Some(popts) => Self(popts),
+            None => return Err(_last_null_pointer_err("GDALFootprintOptionsNew")),
+        };
+        if let Some(token) = &options.cancellation {
+            let (progress, progress_arg) = progress_args(Some(token));
+            unsafe {
+                gdal_sys::GDALFootprintOptionsSetProgress(popts.0.as_ptr(), progress, progress_arg)
+            };
+        }
+        Ok(popts)
+    }
+}
+
+impl Drop for GdalFootprintOptions {
+    fn drop(&mut self) {
+        unsafe { gdal_sys::GDALFootprintOptionsFree(self.0.as_ptr()) };
+    }
+}
+
+/// Compute the footprint of the valid pixels of `source`, into a new vector dataset at `dest`.
+///
+/// The footprint is the polygonized mask of the bands, with its holes, written as a feature
+/// of a `footprint` layer.
+///
+/// Wraps [GDALFootprint].
+/// See the [program docs] for more details.
+///
+/// # Example
+///
+/// ```rust, no_run
+/// # fn main() -> gdal::errors::Result<()> {
+/// use gdal::raster::{footprint, FootprintOptions};
+/// use gdal::Dataset;
+///
+/// let image = Dataset::open("fixtures/m_3607824_se_17_1_20160620_sub.tif")?;
+/// let mut options = FootprintOptions::new();
+/// options
+///     .with_output_format("GPKG")
+///     .with_target_srs("EPSG:4326")
+///     .with_densify(10.)
+///     .with_simplify(0.0001)
+///     .with_min_ring_area(1e-8);
+/// let catalog = footprint(&image, "/tmp/footprints.gpkg", &options)?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [GDALFootprint]: https://gdal.org/api/gdal_utils.html#_CPPv413GDALFootprintPKc12GDALDatasetH12GDALDatasetHPK20GDALFootprintOptionsPi
+/// [program docs]: https://gdal.org/programs/gdal_footprint.html
+pub fn footprint<P: AsRef<Path>>(
+    source: &Dataset,
+    dest: P,
+    options: &FootprintOptions,
+) -> Result<Dataset> {
+    let dest = _path_to_c_string(dest.as_ref())?;
+    let out_ds = _footprint(source, Some(dest.as_ptr()), null_mut(), options)?;
+    Ok(unsafe { Dataset::from_c_dataset(out_ds) })
+}
+
+/// Compute the footprint of the valid pixels of `source` into the existing vector dataset
+/// `dest`, like [`footprint`].
+///
+/// The footprint is appended to the output layer, e.g. to catalog several images.
+pub fn footprint_into(
+    source: &Dataset,
+    dest: &mut Dataset,
+    options: &FootprintOptions,
+) -> Result<()> {
+    // GDALFootprint returns `dest` itself, which we already own.
+    _footprint(source, None, dest.c_dataset(), options)?;
+    Ok(())
+}
+
+fn _footprint(
+    source: &Dataset,
+    dest_path: Option<*const libc::c_char>,
+    dest_ds: GDALDatasetH,
+    options: &FootprintOptions,
+) -> Result<GDALDatasetH> {
+    let popts = GdalFootprintOptions::new(options, dest_ds.is_null())?;
+
+    let mut pb_usage_error: c_int = 0;
+    let out_ds = unsafe {
+        gdal_sys::GDALFootprint(
+            dest_path.unwrap_or(null()),
+            dest_ds,
+            source.c_dataset(),
+            popts.0.as_ptr(),
+            &mut pb_usage_error,
+        )
+    };
+    if out_ds.is_null() {
+        let err = _last_null_pointer_err("GDALFootprint");
+        return Err(cancelled_or(options.cancellation.as_ref(), err));
+    }
+    Ok(out_ds)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::raster::Buffer;
+    use crate::test_utils::SuppressGDALErrorLog;
+    use crate::vector::LayerAccess;
+    use crate::DriverManager;
+
+    /// A 4x4 raster whose left half is valid, but for a nodata notch at (1, 1).
+    fn image() -> Dataset {
+        let driver = DriverManager::get_driver_by_name("MEM").unwrap();
+        let mut ds = driver.create_with_band_type::<u8, _>("", 4, 4, 1).unwrap();
+        ds.set_geo_transform(&[0., 1., 0., 4., 0., -1.]).unwrap();
+        #[rustfmt::skip]
+        let data = vec![
+            1, 1, 0, 0,
+            1, 0, 0, 0,
+            1, 1, 0, 0,
+            1, 1, 0, 0,
+        ];
+        let mut band = ds.rasterband(1).unwrap();
+        band.write((0, 0), (4, 4), &mut Buffer::new((4, 4), data))
+            .unwrap();
+        band.set_no_data_value(Some(0.)).unwrap();
+        ds
+    }
+
+    fn areas(ds: &Dataset) -> Vec<f64> {
+        let mut layer = ds.layer_by_name("footprint").unwrap();
+        layer
+            .features()
+            .map(|f| f.geometry().unwrap().area())
+            .collect()
+    }
+
+    #[test]
+    fn test_footprint() {
+        let source = image();
+        let out = footprint(&source, "", &FootprintOptions::new()).unwrap();
+        assert_eq!(areas(&out), [7.]);
+
+        let mut options = FootprintOptions::new();
+        options.with_convex_hull().with_location_field(None);
+        let mut out = footprint(&source, "", &options).unwrap();
+        assert_eq!(areas(&out), [8.]);
+        assert_eq!(
+            out.layer_by_name("footprint")
+                .unwrap()
+                .defn()
+                .fields()
+                .count(),
+            0
+        );
+
+        footprint_into(&source, &mut out, &options).unwrap();
+        assert_eq!(areas(&out), [8., 8.]);
+    }
+
+    #[test]
+    fn test_footprint_errors() {
+        let _nolog = SuppressGDALErrorLog::new();
+        let options = FootprintOptions::from_args(["-not-an-option"]);
+        assert!(footprint(&image(), "", &options).is_err());
+
+        let token = CancellationToken::new();
+        token.cancel();
+        let mut options = FootprintOptions::new();
+        options.with_cancellation(&token);
+        assert!(matches!(
+            footprint(&image(), "", &options),
+            Err(GdalError::Cancelled)
+        ));
+    }
+}
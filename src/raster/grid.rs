@@ -0,0 +1,561 @@
+//! Interpolation of scattered points into a raster.
+//!
+//! This module provides bindings to the algorithms of the
+//! [`gdal_grid` tool](https://gdal.org/programs/gdal_grid.html). The points are either the
+//! features of a vector dataset, with [`grid`], or given as coordinates, with [`grid_points`].
+//!
+//! # Example
+//!
+//! ```rust, no_run
+//! # fn main() -> gdal::errors::Result<()> {
+//! use gdal::raster::grid::{grid_points, GridAlgorithm, GridOptions, InverseDistanceOptions};
+//!
+//! let x = [0.5, 4.2, 8.9];
+//! let y = [1.5, 7.3, 3.4];
+//! let temperatures = [12.1, 13.4, 11.8];
+//! let mut options = GridOptions::new(GridAlgorithm::InverseDistance(InverseDistanceOptions {
+//!     power: 2.,
+//!     max_points: 12,
+//!     ..Default::default()
+//! }));
+//! options.with_extent([0., 0., 10., 10.]).with_output_size(100, 100);
+//! let temperature_grid = grid_points(&x, &y, &temperatures, "", &options)?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt::Write;
+use std::path::Path;
+use std::ptr::{null_mut, NonNull};
+
+use gdal_sys::{GDALGridOptions, OGRwkbGeometryType};
+use libc::c_int;
+
+use crate::cpl::CslStringList;
+use crate::errors::*;
+use crate::progress::{cancelled_or, progress_args, CancellationToken};
+use crate::raster::GdalDataType;
+use crate::utils::{_last_null_pointer_err, _path_to_c_string};
+use crate::vector::{Geometry, LayerAccess, LayerOptions};
+use crate::{Dataset, DriverManager};
+
+/// Options of [`GridAlgorithm::InverseDistance`].
+///
+/// The search ellipse is given by its radiuses and angle. With the default radiuses of `0`,
+/// all the points are used.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct InverseDistanceOptions {
+    /// Weighting power.
+    pub power: f64,
+    /// Smoothing parameter.
+    pub smoothing: f64,
+    /// First radius (X axis if rotation angle is 0) of the search ellipse.
+    pub radius1: f64,
+    /// Second radius (Y axis if rotation angle is 0) of the search ellipse.
+    pub radius2: f64,
+    /// Angle of the search ellipse rotation in degrees (counter clockwise).
+    pub angle: f64,
+    /// Maximum number of points to use, the closest ones, or `0` for all of them.
+    pub max_points: usize,
+    /// Minimum number of points to use, below which the node is set to `no_data`.
+    pub min_points: usize,
+    /// Value of the nodes without enough points.
+    pub no_data: f64,
+}
+
+impl Default for InverseDistanceOptions {
+    fn default() -> Self {
+        Self {
+            power: 2.,
+            smoothing: 0.,
+            radius1: 0.,
+            radius2: 0.,
+            angle: 0.,
+            max_points: 0,
+            min_points: 0,
+            no_data: 0.,
+        }
+    }
+}
+
+/// Options of [`GridAlgorithm::InverseDistanceNearestNeighbor`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct InverseDistanceNearestNeighborOptions {
+    /// Weighting power.
+    pub power: f64,
+    /// Smoothing parameter.
+    pub smoothing: f64,
+    /// Radius of the search circle.
+    pub radius: f64,
+    /// Maximum number of points to use, the closest ones.
+    pub max_points: usize,
+    /// Minimum number of points to use, below which the node is set to `no_data`.
+    pub min_points: usize,
+    /// Value of the nodes without enough points.
+    pub no_data: f64,
+}
+
+impl Default for InverseDistanceNearestNeighborOptions {
+    fn default() -> Self {
+        Self {
+            power: 2.,
+            smoothing: 0.,
+            radius: 1.,
+            max_points: 12,
+            min_points: 0,
+            no_data: 0.,
+        }
+    }
+}
+
+/// Options of [`GridAlgorithm::MovingAverage`] and [`GridAlgorithm::Metric`].
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct MovingAverageOptions {
+    /// First radius (X axis if rotation angle is 0) of the search ellipse.
+    pub radius1: f64,
+    /// Second radius (Y axis if rotation angle is 0) of the search ellipse.
+    pub radius2: f64,
+    /// Angle of the search ellipse rotation in degrees (counter clockwise).
+    pub angle: f64,
+    /// Minimum number of points to use, below which the node is set to `no_data`.
+    pub min_points: usize,
+    /// Value of the nodes without enough points.
+    pub no_data: f64,
+}
+
+/// Options of [`GridAlgorithm::NearestNeighbor`].
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct NearestNeighborOptions {
+    /// First radius (X axis if rotation angle is 0) of the search ellipse.
+    pub radius1: f64,
+    /// Second radius (Y axis if rotation angle is 0) of the search ellipse.
+    pub radius2: f64,
+    /// Angle of the search ellipse rotation in degrees (counter clockwise).
+    pub angle: f64,
+    /// Value of the nodes without points in the search ellipse.
+    pub no_data: f64,
+}
+
+/// Options of [`GridAlgorithm::Linear`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LinearOptions {
+    /// Maximum distance to the nearest point of the nodes outside of the triangulation, which
+    /// are interpolated with it. `0` sets them to `no_data`, and `-1` always uses the nearest point.
+    pub radius: f64,
+    /// Value of the nodes outside of the triangulation.
+    pub no_data: f64,
+}
+
+impl Default for LinearOptions {
+    fn default() -> Self {
+        Self {
+            radius: -1.,
+            no_data: 0.,
+        }
+    }
+}
+
+/// Statistic computed by [`GridAlgorithm::Metric`] over the points of the search ellipse.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GridMetric {
+    /// Minimum value.
+    Minimum,
+    /// Maximum value.
+    Maximum,
+    /// Difference between the maximum and minimum values.
+    Range,
+    /// Number of points.
+    Count,
+    /// Average distance between the node and the points.
+    AverageDistance,
+    /// Average distance between the points.
+    AverageDistancePts,
+}
+
+impl GridMetric {
+    fn to_gdal(self) -> &'static str {
+        match self {
+            GridMetric::Minimum => "minimum",
+            GridMetric::Maximum => "maximum",
+            GridMetric::Range => "range",
+            GridMetric::Count => "count",
+            GridMetric::AverageDistance => "average_distance",
+            GridMetric::AverageDistancePts => "average_distance_pts",
+        }
+    }
+}
+
+/// Interpolation algorithm, with its options.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GridAlgorithm {
+    /// Inverse distance to a power (`invdist`).
+    InverseDistance(InverseDistanceOptions),
+    /// Inverse distance to a power, with a faster nearest neighbour search (`invdistnn`).
+    InverseDistanceNearestNeighbor(InverseDistanceNearestNeighborOptions),
+    /// Average of the points of the search ellipse (`average`).
+    MovingAverage(MovingAverageOptions),
+    /// Value of the nearest point (`nearest`).
+    NearestNeighbor(NearestNeighborOptions),
+    /// Linear interpolation over a Delaunay triangulation of the points (`linear`).
+    Linear(LinearOptions),
+    /// Data metric over the points of the search ellipse.
+    Metric(GridMetric, MovingAverageOptions),
+}
+
+impl GridAlgorithm {
+    /// Render this algorithm as the argument of the `-a` option of `gdal_grid`.
+    fn to_gdal_option(self) -> String {
+        let mut s = String::new();
+        // writing to a `String` can't fail
+        let _ = match self {
+            GridAlgorithm::InverseDistance(o) => write!(
+                s,
+                "invdist:power={}:smoothing={}:radius1={}:radius2={}:angle={}:max_points={}:min_points={}:nodata={}",
+                o.power, o.smoothing, o.radius1, o.radius2, o.angle, o.max_points, o.min_points, o.no_data
+            ),
+            GridAlgorithm::InverseDistanceNearestNeighbor(o) => write!(
+                s,
+                "invdistnn:power={}:smoothing={}:radius={}:max_points={}:min_points={}:nodata={}",
+                o.power, o.smoothing, o.radius, o.max_points, o.min_points, o.no_data
+            ),
+            GridAlgorithm::MovingAverage(o) => write!(
+                s,
+                "average:radius1={}:radius2={}:angle={}:min_points={}:nodata={}",
+                o.radius1, o.radius2, o.angle, o.min_points, o.no_data
+            ),
+            GridAlgorithm::NearestNeighbor(o) => write!(
+                s,
+                "nearest:radius1={}:radius2={}:angle={}:nodata={}",
+                o.radius1, o.radius2, o.angle, o.no_data
+            ),
+            GridAlgorithm::Linear(o) => {
+                write!(s, "linear:radius={}:nodata={}", o.radius, o.no_data)
+            }
+            GridAlgorithm::Metric(metric, o) => write!(
+                s,
+                "{}:radius1={}:radius2={}:angle={}:min_points={}:nodata={}",
+                metric.to_gdal(),
+                o.radius1,
+                o.radius2,
+                o.angle,
+                o.min_points,
+                o.no_data
+            ),
+        };
+        s
+    }
+}
+
+/// Configuration options for [`grid`] and [`grid_points`].
+///
+/// These map to the [`gdal_grid`] command line options.
+///
+/// [`gdal_grid`]: https://gdal.org/programs/gdal_grid.html
+#[derive(Debug, Clone)]
+pub struct GridOptions {
+    algorithm: GridAlgorithm,
+    output_format: Option<String>,
+    output_type: Option<GdalDataType>,
+    output_size: Option<(usize, usize)>,
+    extent: Option<[f64; 4]>,
+    z_field: Option<String>,
+    layers: Vec<String>,
+    where_clause: Option<String>,
+    cancellation: Option<CancellationToken>,
+}
+
+impl GridOptions {
+    /// Create options interpolating with `algorithm`, into an in-memory `Float64` raster.
+    pub fn new(algorithm: GridAlgorithm) -> Self {
+        Self {
+            algorithm,
+            output_format: None,
+            output_type: None,
+            output_size: None,
+            extent: None,
+            z_field: None,
+            layers: Vec::new(),
+            where_clause: None,
+            cancellation: None,
+        }
+    }
+
+    /// Specify the output format, as the short name of a driver (e.g. `GTiff`). Defaults to `MEM`.
+    pub fn with_output_format(&mut self, format: &str) -> &mut Self {
+        self.output_format = Some(format.to_owned());
+        self
+    }
+
+    /// Set the data type of the output band. Defaults to `Float64`.
+    pub fn with_output_type(&mut self, data_type: GdalDataType) -> &mut Self {
+        self.output_type = Some(data_type);
+        self
+    }
+
+    /// Set the output size, as `(cols, rows)`. Defaults to 256x256.
+    pub fn with_output_size(&mut self, cols: usize, rows: usize) -> &mut Self {
+        self.output_size = Some((cols, rows));
+        self
+    }
+
+    /// Set the output extent, as `[xmin, ymin, xmax, ymax]`. Defaults to that of the points.
+    pub fn with_extent(&mut self, extent: [f64; 4]) -> &mut Self {
+        self.extent = Some(extent);
+        self
+    }
+
+    /// Read the values of the points from this attribute field, instead of their Z coordinate.
+    ///
+    /// Not used by [`grid_points`].
+    pub fn with_z_field(&mut self, field: &str) -> &mut Self {
+        self.z_field = Some(field.to_owned());
+        self
+    }
+
+    /// Only read the points of the given layers. Defaults to the first layer.
+    ///
+    /// Not used by [`grid_points`].
+    pub fn with_layers(&mut self, layers: &[&str]) -> &mut Self {
+        self.layers = layers.iter().map(|&l| l.to_owned()).collect();
+        self
+    }
+
+    /// Only read the points matching this attribute filter.
+    ///
+    /// Not used by [`grid_points`].
+    pub fn with_where(&mut self, where_clause: &str) -> &mut Self {
+        self.where_clause = Some(where_clause.to_owned());
+        self
+    }
+
+    /// Abort the interpolation with [`GdalError::Cancelled`] once `token` is cancelled.
+    pub fn with_cancellation(&mut self, token: &CancellationToken) -> &mut Self {
+        self.cancellation = Some(token.clone());
+        self
+    }
+
+    /// Render these options into [`CslStringList`] values, as compatible with
+    /// [`gdal_sys::GDALGridOptionsNew`].
+    pub fn to_options_list(&self) -> Result<CslStringList> {
+        let mut opts = CslStringList::default();
+
+        opts.add_string("-a")?;
+        opts.add_string(&self.algorithm.to_gdal_option())?;
+
+        opts.add_string("-of")?;
+        opts.add_string(self.output_format.as_deref().unwrap_or("MEM"))?;
+
+        if let Some(data_type) = self.output_type {
+            opts.add_string("-ot")?;
+            opts.add_string(&data_type.name())?;
+        }
+
+        if let Some((cols, rows)) = self.output_size {
+            opts.add_string("-outsize")?;
+            opts.add_string(&cols.to_string())?;
+            opts.add_string(&rows.to_string())?;
+        }
+
+        if let Some([xmin, ymin, xmax, ymax]) = self.extent {
+            opts.add_string("-txe")?;
+            opts.add_string(&xmin.to_string())?;
+            opts.add_string(&xmax.to_string())?;
+            opts.add_string("-tye")?;
+            opts.add_string(&ymin.to_string())?;
+            opts.add_string(&ymax.to_string())?;
+        }
+
+        if let Some(field) = &self.z_field {
+            opts.add_string("-zfield")?;
+            opts.add_string(field)?;
+        }
+
+        for layer in &self.layers {
+            opts.add_string("-l")?;
+            opts.add_string(layer)?;
+        }
+
+        if let Some(where_clause) = &self.where_clause {
+            opts.add_string("-where")?;
+            opts.add_string(where_clause)?;
+        }
+
+        Ok(opts)
+    }
+}
+
+/// Payload for [`GDALGrid`](gdal_sys::GDALGrid).
+struct GdalGridOptions(NonNull<GDALGridOptions>);
+
+impl GdalGridOptions {
+    fn new(options: &GridOptions) -> Result<Self> {
+        let opts = options.to_options_list()?;
+        let popts = unsafe { gdal_sys::GDALGridOptionsNew(opts.as_ptr(), null_mut()) };
+        let popts = match NonNull::new(popts) {
+            Some(popts) => Self(popts),
+            None => return Err(_last_null_pointer_err("GDALGridOptionsNew")),
+        };
+        if let Some(token) = &options.cancellation {
+            let (progress, progress_arg) = progress_args(Some(token));
+            unsafe {
+                gdal_sys::GDALGridOptionsSetProgress(popts.0.as_ptr(), progress, progress_arg)
+            };
+        }
+        Ok(popts)
+    }
+}
+
+impl Drop for GdalGridOptions {
+    fn drop(&mut self) {
+        unsafe { gdal_sys::GDALGridOptionsFree(self.0.as_ptr()) };
+    }
+}
+
+/// Interpolate the points of the vector dataset `source` into a new raster dataset at `dest`.
+///
+/// The values are the Z coordinates of the points, or an attribute given by
+/// [`GridOptions::with_z_field`]. With the default `MEM` output format, `dest` can be empty.
+///
+/// Wraps [GDALGrid].
+/// See the [program docs] for more details.
+///
+/// [GDALGrid]: https://gdal.org/api/gdal_utils.html#_CPPv48GDALGridPKc12GDALDatasetHPK15GDALGridOptionsPi
+/// [program docs]: https://gdal.org/programs/gdal_grid.html
+pub fn grid<P: AsRef<Path>>(source: &Dataset, dest: P, options: &GridOptions) -> Result<Dataset> {
+    let popts = GdalGridOptions::new(options)?;
+    let dest = _path_to_c_string(dest.as_ref())?;
+
+    let mut pb_usage_error: c_int = 0;
+    let out_ds = unsafe {
+        gdal_sys::GDALGrid(
+            dest.as_ptr(),
+            source.c_dataset(),
+            popts.0.as_ptr(),
+            &mut pb_usage_error,
+        )
+    };
+    if out_ds.is_null() {
+        let err = _last_null_pointer_err("GDALGrid");
+        return Err(cancelled_or(options.cancellation.as_ref(), err));
+    }
+    Ok(unsafe { Dataset::from_c_dataset(out_ds) })
+}
+
+/// Interpolate the values `z` of the points at `x` and `y` into a new raster dataset at `dest`,
+/// like [`grid`].
+///
+/// The three slices must have the same length.
+pub fn grid_points<P: AsRef<Path>>(
+    x: &[f64],
+    y: &[f64],
+    z: &[f64],
+    dest: P,
+    options: &GridOptions,
+) -> Result<Dataset> {
+    if x.len() != y.len() || x.len() != z.len() {
+        return Err(GdalError::BadArgument(format!(
+            "x ({}), y ({}) and z ({}) must have the same length",
+            x.len(),
+            y.len(),
+            z.len()
+        )));
+    }
+
+    let driver = DriverManager::get_driver_by_name("Memory")?;
+    let mut source = driver.create_vector_only("")?;
+    let mut layer = source.create_layer(LayerOptions {
+        name: "points",
+        ty: OGRwkbGeometryType::wkbPoint25D,
+        ..Default::default()
+    })?;
+    for ((&x, &y), &z) in x.iter().zip(y).zip(z) {
+        let mut point = Geometry::empty(OGRwkbGeometryType::wkbPoint25D)?;
+        point.add_point((x, y, z));
+        layer.create_feature(point)?;
+    }
+
+    let mut options = options.clone();
+    options.z_field = None;
+    options.layers.clear();
+    options.where_clause = None;
+    grid(&source, dest, &options)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::SuppressGDALErrorLog;
+
+    fn read(ds: &Dataset) -> Vec<f64> {
+        ds.rasterband(1)
+            .unwrap()
+            .read_band_as::<f64>()
+            .unwrap()
+            .into_shape_and_vec()
+            .1
+    }
+
+    #[test]
+    fn test_algorithm_option() {
+        let algorithm = GridAlgorithm::Linear(Default::default());
+        assert_eq!(algorithm.to_gdal_option(), "linear:radius=-1:nodata=0");
+        let algorithm = GridAlgorithm::Metric(
+            GridMetric::AverageDistance,
+            MovingAverageOptions {
+                radius1: 2.5,
+                radius2: 2.5,
+                ..Default::default()
+            },
+        );
+        assert_eq!(
+            algorithm.to_gdal_option(),
+            "average_distance:radius1=2.5:radius2=2.5:angle=0:min_points=0:nodata=0"
+        );
+    }
+
+    #[test]
+    fn test_grid_points() {
+        // the values only depend on x, so that the tests don't depend on the rows order
+        let x = [2.5, 2.5, 7.5, 7.5];
+        let y = [2.5, 7.5, 2.5, 7.5];
+        let z = [1., 1., 3., 3.];
+
+        let mut options = GridOptions::new(GridAlgorithm::NearestNeighbor(Default::default()));
+        options
+            .with_extent([0., 0., 10., 10.])
+            .with_output_size(2, 2);
+        let out = grid_points(&x, &y, &z, "", &options).unwrap();
+        assert_eq!(out.raster_size(), (2, 2));
+        assert_eq!(read(&out), [1., 3., 1., 3.]);
+
+        let mut options = GridOptions::new(GridAlgorithm::Metric(
+            GridMetric::Count,
+            MovingAverageOptions {
+                radius1: 100.,
+                radius2: 100.,
+                ..Default::default()
+            },
+        ));
+        options
+            .with_extent([0., 0., 10., 10.])
+            .with_output_size(2, 2);
+        let out = grid_points(&x, &y, &z, "", &options).unwrap();
+        assert_eq!(read(&out), [4.; 4]);
+
+        let options = GridOptions::new(GridAlgorithm::Linear(Default::default()));
+        assert!(grid_points(&x, &y, &z[..3], "", &options).is_err());
+    }
+
+    #[test]
+    fn test_grid_cancellation() {
+        let _nolog = SuppressGDALErrorLog::new();
+        let token = CancellationToken::new();
+        token.cancel();
+        let mut options = GridOptions::new(GridAlgorithm::InverseDistance(Default::default()));
+        options.with_output_size(16, 16).with_cancellation(&token);
+        assert!(matches!(
+            grid_points(&[0., 1.], &[0., 1.], &[0., 1.], "", &options),
+            Err(GdalError::Cancelled)
+        ));
+    }
+}
@@ -0,0 +1,220 @@
+//! [`image`](::image) integration for 8- and 16-bit rasters.
+
+use ::image::{DynamicImage, ImageBuffer, Pixel, Primitive};
+
+use crate::dataset::Dataset;
+use crate::errors::*;
+use crate::raster::{Buffer, ColorInterpretation, GdalDataType, GdalType};
+use crate::DriverManager;
+
+impl Dataset {
+    /// Convert this dataset into an [`image::DynamicImage`](::image::DynamicImage).
+    ///
+    /// The dataset must have 1 (gray), 3 (RGB) or 4 (RGBA) bands, all of type `u8` or `u16`.
+    /// If the bands of a 3- or 4-band dataset carry red, green, blue (and alpha) color
+    /// interpretations, they are reordered accordingly; otherwise they are used in band order.
+    ///
+    /// For 1- and 3-band datasets where at least one band has a nodata value, an alpha
+    /// channel is added, which is transparent where every band with a nodata value
+    /// is equal to it.
+    ///
+    /// # Example
+    ///
+    /// ```rust, no_run
+    /// # fn main() -> gdal::errors::Result<()> {
+    /// use gdal::Dataset;
+    /// let dataset = Dataset::open("fixtures/tinymarble.tif")?;
+    /// let image = dataset.to_image()?;
+    /// assert_eq!((image.width(), image.height()), (100, 50));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn to_image(&self) -> Result<DynamicImage> {
+        let band_count = self.raster_count();
+        let wanted: &[ColorInterpretation] = match band_count {
+            1 => &[],
+            3 => &[
+                ColorInterpretation::RedBand,
+                ColorInterpretation::GreenBand,
+                ColorInterpretation::BlueBand,
+            ],
+            4 => &[
+                ColorInterpretation::RedBand,
+                ColorInterpretation::GreenBand,
+                ColorInterpretation::BlueBand,
+                ColorInterpretation::AlphaBand,
+            ],
+            n => {
+                return Err(GdalError::BadArgument(format!(
+                    "can't convert a dataset with {n} bands to an image, expected 1, 3 or 4"
+                )))
+            }
+        };
+
+        let interps = self
+            .rasterbands()
+            .map(|b| Ok(b?.color_interpretation()))
+            .collect::<Result<Vec<_>>>()?;
+        let band_indexes = wanted
+            .iter()
+            .map(|w| interps.iter().position(|i| i == w).map(|i| i + 1))
+            .collect::<Option<Vec<_>>>()
+            .filter(|order| !order.is_empty())
+            .unwrap_or_else(|| (1..=band_count).collect());
+
+        let band_type = self.rasterband(1)?.band_type();
+        for index in 2..=band_count {
+            if self.rasterband(index)?.band_type() != band_type {
+                return Err(GdalError::BadArgument(
+                    "can't convert a dataset with mixed band types to an image".to_string(),
+                ));
+            }
+        }
+        let nodata_to_alpha = band_count != 4
+            && self
+                .rasterbands()
+                .any(|b| b.map_or(false, |b| b.no_data_value().is_some()));
+
+        let (width, height) = self.raster_size();
+        let (width, height) = (width.try_into()?, height.try_into()?);
+        let channels = band_count + nodata_to_alpha as usize;
+
+        let image = match band_type {
+            GdalDataType::UInt8 => {
+                let data = self.read_interleaved::<u8>(&band_indexes, nodata_to_alpha)?;
+                match channels {
+                    1 => DynamicImage::ImageLuma8(image_buffer(width, height, data)),
+                    2 => DynamicImage::ImageLumaA8(image_buffer(width, height, data)),
+                    3 => DynamicImage::ImageRgb8(image_buffer(width, height, data)),
+                    _ => DynamicImage::ImageRgba8(image_buffer(width, height, data)),
+                }
+            }
+            GdalDataType::UInt16 => {
+                let data = self.read_interleaved::<u16>(&band_indexes, nodata_to_alpha)?;
+                match channels {
+                    1 => DynamicImage::ImageLuma16(image_buffer(width, height, data)),
+                    2 => DynamicImage::ImageLumaA16(image_buffer(width, height, data)),
+                    3 => DynamicImage::ImageRgb16(image_buffer(width, height, data)),
+                    _ => DynamicImage::ImageRgba16(image_buffer(width, height, data)),
+                }
+            }
+            t => {
+                return Err(GdalError::BadArgument(format!(
+                    "can't convert a dataset with band type {t} to an image"
+                )))
+            }
+        };
+        Ok(image)
+    }
+
+    /// Create an in-memory dataset from an [`image::DynamicImage`](::image::DynamicImage).
+    ///
+    /// Gray, gray + alpha, RGB and RGBA images with 8- or 16-bit channels are supported,
+    /// and each band's color interpretation is set to match its channel.
+    ///
+    /// Use [`Driver::create_copy`](crate::Driver::create_copy) on the result to write it to a file.
+    pub fn from_image(image: &DynamicImage) -> Result<Dataset> {
+        use ColorInterpretation::*;
+
+        match image {
+            DynamicImage::ImageLuma8(buf) => dataset_from_buffer(buf, vec![GrayIndex]),
+            DynamicImage::ImageLumaA8(buf) => dataset_from_buffer(buf, vec![GrayIndex, AlphaBand]),
+            DynamicImage::ImageRgb8(buf) => {
+                dataset_from_buffer(buf, vec![RedBand, GreenBand, BlueBand])
+            }
+            DynamicImage::ImageRgba8(buf) => {
+                dataset_from_buffer(buf, vec![RedBand, GreenBand, BlueBand, AlphaBand])
+            }
+            DynamicImage::ImageLuma16(buf) => dataset_from_buffer(buf, vec![GrayIndex]),
+            DynamicImage::ImageLumaA16(buf) => dataset_from_buffer(buf, vec![GrayIndex, AlphaBand]),
+            DynamicImage::ImageRgb16(buf) => {
+                dataset_from_buffer(buf, vec![RedBand, GreenBand, BlueBand])
+            }
+            DynamicImage::ImageRgba16(buf) => {
+                dataset_from_buffer(buf, vec![RedBand, GreenBand, BlueBand, AlphaBand])
+            }
+            other => Err(GdalError::BadArgument(format!(
+                "can't create a dataset from an image with color type {:?}",
+                other.color()
+            ))),
+        }
+    }
+
+    /// Read the given bands into a pixel-interleaved vector, optionally appending an alpha
+    /// channel derived from the bands' nodata values.
+    fn read_interleaved<T: Copy + GdalType + Primitive + Into<f64>>(
+        &self,
+        band_indexes: &[usize],
+        nodata_to_alpha: bool,
+    ) -> Result<Vec<T>> {
+        let mut buffers = Vec::with_capacity(band_indexes.len());
+        let mut nodata = Vec::with_capacity(band_indexes.len());
+        for &index in band_indexes {
+            let band = self.rasterband(index)?;
+            buffers.push(band.read_band_as::<T>()?);
+            nodata.push(band.no_data_value());
+        }
+
+        let (width, height) = self.raster_size();
+        let pixels = width * height;
+        let channels = band_indexes.len() + nodata_to_alpha as usize;
+        let mut data = Vec::with_capacity(pixels * channels);
+        for i in 0..pixels {
+            let mut is_nodata = true;
+            for (buffer, nodata) in buffers.iter().zip(&nodata) {
+                let value = buffer.data()[i];
+                data.push(value);
+                if let Some(nodata) = nodata {
+                    is_nodata &= value.into() == *nodata;
+                }
+            }
+            if nodata_to_alpha {
+                data.push(if is_nodata {
+                    T::DEFAULT_MIN_VALUE
+                } else {
+                    T::DEFAULT_MAX_VALUE
+                });
+            }
+        }
+        Ok(data)
+    }
+}
+
+fn image_buffer<P: Pixel>(
+    width: u32,
+    height: u32,
+    data: Vec<P::Subpixel>,
+) -> ImageBuffer<P, Vec<P::Subpixel>> {
+    ImageBuffer::from_raw(width, height, data).expect("buffer size matches image dimensions")
+}
+
+fn dataset_from_buffer<P: Pixel>(
+    image: &ImageBuffer<P, Vec<P::Subpixel>>,
+    interps: Vec<ColorInterpretation>,
+) -> Result<Dataset>
+where
+    P::Subpixel: GdalType,
+{
+    let (width, height) = (image.width() as usize, image.height() as usize);
+    let channels = interps.len();
+
+    let driver = DriverManager::get_driver_by_name("MEM")?;
+    let dataset = driver.create_with_band_type::<P::Subpixel, _>("", width, height, channels)?;
+    for (i, interp) in interps.into_iter().enumerate() {
+        let data = image
+            .as_raw()
+            .iter()
+            .skip(i)
+            .step_by(channels)
+            .copied()
+            .collect();
+        let mut band = dataset.rasterband(i + 1)?;
+        band.write(
+            (0, 0),
+            (width, height),
+            &mut Buffer::new((width, height), data),
+        )?;
+        band.set_color_interpretation(interp)?;
+    }
+    Ok(dataset)
+}
@@ -1,8 +1,10 @@
 use super::GdalType;
 use crate::errors::*;
 use crate::spatial_ref::SpatialRef;
-use crate::utils::{_last_cpl_err, _last_null_pointer_err, _string, _string_array};
-use crate::{cpl::CslStringList, Dataset};
+use crate::utils::{
+    _last_cpl_err, _last_null_pointer_err, _path_to_c_string, _string, _string_array,
+};
+use crate::{cpl::CslStringList, Dataset, Driver};
 use gdal_sys::{
     CPLErr, CSLDestroy, GDALAttributeGetDataType, GDALAttributeGetDimensionsSize, GDALAttributeH,
     GDALAttributeReadAsDouble, GDALAttributeReadAsDoubleArray, GDALAttributeReadAsInt,
@@ -21,9 +23,10 @@ use gdal_sys::{
 use libc::c_void;
 use std::ffi::CString;
 use std::os::raw::c_char;
+use std::path::Path;
 
 #[cfg(feature = "ndarray")]
-use ndarray::{ArrayD, IxDyn};
+use ndarray::{ArrayD, Axis, IxDyn};
 use std::fmt::{Debug, Display};
 
 /// Represent an MDArray in a Group
@@ -144,9 +147,10 @@ impl<'a> MDArray<'a> {
         buffer: *mut T,
         array_start_index: Vec<u64>,
         count: Vec<usize>,
+        array_step: Option<&[i64]>,
     ) -> Result<()> {
         // If set to nullptr, [1, 1, … 1] will be used as a default to indicate consecutive elements.
-        let array_step: *const i64 = std::ptr::null();
+        let array_step: *const i64 = array_step.map_or(std::ptr::null(), |step| step.as_ptr());
         // If set to nullptr, will be set so that pDstBuffer is written in a compact way,
         // with elements of the last / fastest varying dimension being consecutive.
         let buffer_stride: *const i64 = std::ptr::null();
@@ -214,7 +218,7 @@ impl<'a> MDArray<'a> {
         }
 
         // SAFETY: we checked the buffer length above.
-        unsafe { self.read(buffer.as_mut_ptr(), array_start_index, count) }
+        unsafe { self.read(buffer.as_mut_ptr(), array_start_index, count, None) }
     }
 
     /// Read a [`Vec<T>`] from this band, where `T` implements [`GdalType`].
@@ -239,7 +243,72 @@ impl<'a> MDArray<'a> {
         // in the standard library docs.
         // (https://doc.rust-lang.org/std/vec/struct.Vec.html#method.set_len)
         unsafe {
-            self.read(data.as_mut_ptr(), array_start_index, count)?;
+            self.read(data.as_mut_ptr(), array_start_index, count, None)?;
+            data.set_len(pixels);
+        };
+
+        Ok(data)
+    }
+
+    /// Read a strided hyperslab of this array into a [`Vec<T>`], where `T` implements [`GdalType`].
+    ///
+    /// # Arguments
+    /// * `array_start_index` - Values representing the starting index to read in each dimension (in `[0, aoDims[i].GetSize()-1]` range).
+    /// * `count` - Values representing the number of values to extract in each dimension.
+    /// * `array_step` - Values representing the spacing between the values to extract in each dimension.
+    ///   May be negative to read backwards, or 0 to repeat a value.
+    ///
+    /// All three must have [`num_dimensions`](Self::num_dimensions) values.
+    /// The values are returned in row-major order, i.e. with the last dimension varying fastest.
+    ///
+    /// # Example
+    ///
+    /// ```rust, no_run
+    /// use gdal::{Dataset, DatasetOptions, GdalOpenFlags};
+    /// use gdal::cpl::CslStringList;
+    /// # fn main() -> gdal::errors::Result<()> {
+    /// let options = DatasetOptions {
+    ///     open_flags: GdalOpenFlags::GDAL_OF_MULTIDIM_RASTER,
+    ///     ..Default::default()
+    /// };
+    /// let dataset = Dataset::open_ex("/vsizip/fixtures/byte_no_cf.zarr.zip", options)?;
+    /// let array = dataset
+    ///     .root_group()?
+    ///     .open_md_array("byte_no_cf", CslStringList::new())?;
+    /// // every other row and column
+    /// let values = array.read_as_strided::<u8>(vec![0, 0], vec![10, 10], vec![2, 2])?;
+    /// assert_eq!(values.len(), 100);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn read_as_strided<T: Copy + GdalType>(
+        &self,
+        array_start_index: Vec<u64>,
+        count: Vec<usize>,
+        array_step: Vec<i64>,
+    ) -> Result<Vec<T>> {
+        let num_dimensions = self.num_dimensions();
+        if array_start_index.len() != num_dimensions
+            || count.len() != num_dimensions
+            || array_step.len() != num_dimensions
+        {
+            return Err(GdalError::BadArgument(format!(
+                "array_start_index, count and array_step must have {num_dimensions} values"
+            )));
+        }
+
+        let pixels: usize = count.iter().product();
+        let mut data: Vec<T> = Vec::with_capacity(pixels);
+
+        // SAFETY: the `read` line below writes exactly `pixels` elements into the slice,
+        // before we read from this slice.
+        unsafe {
+            self.read(
+                data.as_mut_ptr(),
+                array_start_index,
+                count,
+                Some(&array_step),
+            )?;
             data.set_len(pixels);
         };
 
@@ -271,6 +340,64 @@ impl<'a> MDArray<'a> {
         Ok(ArrayD::from_shape_vec(dim, data)?)
     }
 
+    #[cfg(feature = "ndarray")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "array")))]
+    /// Read a hyperslab of this array into a [`LabeledArray<T>`], which keeps the names of the
+    /// dimensions along with an [`ArrayD<T>`] of shape `count`.
+    ///
+    /// # Arguments
+    /// * `array_start_index` - Values representing the starting index to read in each dimension.
+    /// * `count` - Values representing the number of values to extract in each dimension.
+    ///
+    /// Both must have [`num_dimensions`](Self::num_dimensions) values.
+    ///
+    /// # Example
+    ///
+    /// ```rust, no_run
+    /// use gdal::{Dataset, DatasetOptions, GdalOpenFlags};
+    /// use gdal::cpl::CslStringList;
+    /// # fn main() -> gdal::errors::Result<()> {
+    /// let options = DatasetOptions {
+    ///     open_flags: GdalOpenFlags::GDAL_OF_MULTIDIM_RASTER,
+    ///     ..Default::default()
+    /// };
+    /// let dataset = Dataset::open_ex("/vsizip/fixtures/byte_no_cf.zarr.zip", options)?;
+    /// let array = dataset
+    ///     .root_group()?
+    ///     .open_md_array("byte_no_cf", CslStringList::new())?;
+    /// let labeled = array.read_as_labeled_array::<u8>(vec![0, 0], vec![20, 20])?;
+    /// let x = labeled.axis("X").unwrap();
+    /// let column_sums = labeled.data.map(|&v| v as u32).sum_axis(x);
+    /// assert_eq!(column_sums.len(), 20);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn read_as_labeled_array<T: Copy + GdalType + Debug>(
+        &self,
+        array_start_index: Vec<u64>,
+        count: Vec<usize>,
+    ) -> Result<LabeledArray<T>> {
+        let dimension_names = self
+            .dimensions()?
+            .iter()
+            .map(Dimension::name)
+            .collect::<Vec<_>>();
+        if array_start_index.len() != dimension_names.len() || count.len() != dimension_names.len()
+        {
+            return Err(GdalError::BadArgument(format!(
+                "array_start_index and count must have {} values",
+                dimension_names.len()
+            )));
+        }
+
+        let data = self.read_as::<T>(array_start_index.clone(), count.clone())?;
+        Ok(LabeledArray {
+            data: ArrayD::from_shape_vec(IxDyn(&count), data)?,
+            dimension_names,
+            array_start_index,
+        })
+    }
+
     /// Read `MDArray` as one-dimensional string array
     pub fn read_as_string_array(&self) -> Result<Vec<String>> {
         let data_type = self.datatype();
@@ -394,6 +521,131 @@ impl<'a> MDArray<'a> {
         }
     }
 
+    /// Write a slice of `T` into this array, where `T` implements [`GdalType`].
+    ///
+    /// # Arguments
+    /// * `array_start_index` - Values representing the starting index to write in each dimension (in `[0, aoDims[i].GetSize()-1]` range).
+    /// * `count` - Values representing the number of values to write in each dimension.
+    /// * `data` - the values, in row-major order, i.e. with the last dimension varying fastest.
+    ///
+    /// `array_start_index` and `count` must have [`num_dimensions`](Self::num_dimensions) values.
+    pub fn write<T: Copy + GdalType>(
+        &mut self,
+        array_start_index: Vec<u64>,
+        count: Vec<usize>,
+        data: &[T],
+    ) -> Result<()> {
+        let num_dimensions = self.num_dimensions();
+        if array_start_index.len() != num_dimensions || count.len() != num_dimensions {
+            return Err(GdalError::BadArgument(format!(
+                "array_start_index and count must have {num_dimensions} values"
+            )));
+        }
+        let pixels: usize = count.iter().product();
+        if data.len() < pixels {
+            return Err(GdalError::BadArgument(format!(
+                "data length is {}, must be at least {}",
+                data.len(),
+                pixels
+            )));
+        }
+        if !self.datatype().class().is_numeric() {
+            return Err(GdalError::UnsupportedMdDataType {
+                data_type: self.datatype().class(),
+                method_name: "GDALMDArrayWrite",
+            });
+        }
+
+        let data_type = ExtendedDataType::numeric::<T>();
+        let rv = unsafe {
+            gdal_sys::GDALMDArrayWrite(
+                self.c_mdarray,
+                array_start_index.as_ptr(),
+                count.as_ptr(),
+                std::ptr::null(),
+                std::ptr::null(),
+                data_type.c_data_type,
+                data.as_ptr() as *const c_void,
+                data.as_ptr() as *const c_void,
+                std::mem::size_of_val(data),
+            )
+        };
+        bool_result(rv)
+    }
+
+    /// Set the unit of the values of this array, e.g. `"m"` or `"degC"`.
+    pub fn set_unit(&mut self, unit: &str) -> Result<()> {
+        let unit = CString::new(unit)?;
+        bool_result(unsafe { gdal_sys::GDALMDArraySetUnit(self.c_mdarray, unit.as_ptr()) })
+    }
+
+    /// Set the no-data value of this array.
+    pub fn set_no_data_value_as_double(&mut self, no_data_value: f64) -> Result<()> {
+        bool_result(unsafe {
+            gdal_sys::GDALMDArraySetNoDataValueAsDouble(self.c_mdarray, no_data_value)
+        })
+    }
+
+    /// Returns the scale applied to the raw values of this array to get the actual values,
+    /// if any.
+    pub fn scale(&self) -> Option<f64> {
+        let mut has_value = 0;
+        let scale = unsafe { gdal_sys::GDALMDArrayGetScale(self.c_mdarray, &mut has_value) };
+        (has_value != 0).then(|| scale)
+    }
+
+    /// Set the scale applied to the raw values of this array to get the actual values.
+    pub fn set_scale(&mut self, scale: f64) -> Result<()> {
+        bool_result(unsafe { gdal_sys::GDALMDArraySetScale(self.c_mdarray, scale) })
+    }
+
+    /// Returns the offset added to the scaled raw values of this array to get the actual
+    /// values, if any.
+    pub fn offset(&self) -> Option<f64> {
+        let mut has_value = 0;
+        let offset = unsafe { gdal_sys::GDALMDArrayGetOffset(self.c_mdarray, &mut has_value) };
+        (has_value != 0).then(|| offset)
+    }
+
+    /// Set the offset added to the scaled raw values of this array to get the actual values.
+    pub fn set_offset(&mut self, offset: f64) -> Result<()> {
+        bool_result(unsafe { gdal_sys::GDALMDArraySetOffset(self.c_mdarray, offset) })
+    }
+
+    /// Set the spatial reference system of this array.
+    pub fn set_spatial_ref(&mut self, spatial_ref: &SpatialRef) -> Result<()> {
+        bool_result(unsafe {
+            gdal_sys::GDALMDArraySetSpatialRef(self.c_mdarray, spatial_ref.to_c_hsrs())
+        })
+    }
+
+    /// Create an attribute of this array, with the given dimension sizes (empty for a scalar)
+    /// and data type.
+    ///
+    /// The value must then be written with one of the `write` methods of [`Attribute`].
+    pub fn create_attribute(
+        &mut self,
+        name: &str,
+        dimensions: &[u64],
+        data_type: &ExtendedDataType,
+    ) -> Result<Attribute> {
+        let name = CString::new(name)?;
+        let c_attribute = unsafe {
+            gdal_sys::GDALMDArrayCreateAttribute(
+                self.c_mdarray,
+                name.as_ptr(),
+                dimensions.len(),
+                dimensions.as_ptr(),
+                data_type.c_data_type,
+                std::ptr::null_mut(),
+            )
+        };
+        if c_attribute.is_null() {
+            return Err(_last_null_pointer_err("GDALMDArrayCreateAttribute"));
+        }
+        Ok(Attribute::from_c_attribute(c_attribute))
+    }
+
     /// Fetch statistics.
     ///
     /// Returns the minimum, maximum, mean and standard deviation of all pixel values in this array.
@@ -445,6 +697,204 @@ impl<'a> MDArray<'a> {
             _ => Err(_last_cpl_err(rv)),
         }
     }
+
+    /// Compute the minimum, maximum, mean and standard deviation of all the values in this
+    /// array, scanning the data.
+    ///
+    /// If `is_approx_ok` is `true`, they may be computed from a subset of the data.
+    /// The statistics are stored by drivers that support it, so that
+    /// [`get_statistics`](Self::get_statistics) can return them without scanning the data.
+    ///
+    /// This method is a wrapper for [`GDALMDArrayComputeStatistics`](https://gdal.org/api/gdalmdarray_cpp.html#_CPPv4N11GDALMDArray17ComputeStatisticsEbPdPdPdPdP7GUInt6416GDALProgressFuncPv12CSLConstList).
+    #[cfg(any(all(major_is_3, minor_ge_2), major_ge_4))]
+    pub fn compute_statistics(&self, is_approx_ok: bool) -> Result<MdStatisticsAll> {
+        let mut statistics = MdStatisticsAll {
+            min: 0.,
+            max: 0.,
+            mean: 0.,
+            std_dev: 0.,
+            valid_count: 0,
+        };
+
+        let rv = unsafe {
+            gdal_sys::GDALMDArrayComputeStatistics(
+                self.c_mdarray,
+                self.c_dataset,
+                libc::c_int::from(is_approx_ok),
+                &mut statistics.min,
+                &mut statistics.max,
+                &mut statistics.mean,
+                &mut statistics.std_dev,
+                &mut statistics.valid_count,
+                None,
+                std::ptr::null_mut(),
+            )
+        };
+        bool_result(rv)?;
+
+        Ok(statistics)
+    }
+
+    /// Returns the number of elements along each dimension of this array, with the slowest
+    /// varying dimension first.
+    pub fn shape(&self) -> Result<Vec<usize>> {
+        Ok(self.dimensions()?.iter().map(Dimension::size).collect())
+    }
+
+    /// Returns the size of the blocks (or chunks) in which the array is stored, along each
+    /// dimension. A size of 0 means that the array isn't blocked along that dimension.
+    pub fn block_size(&self) -> Vec<u64> {
+        unsafe {
+            let mut num_dimensions = 0;
+            let c_block_size =
+                gdal_sys::GDALMDArrayGetBlockSize(self.c_mdarray, &mut num_dimensions);
+            if c_block_size.is_null() {
+                return Vec::new();
+            }
+
+            let block_size = std::slice::from_raw_parts(c_block_size, num_dimensions).to_vec();
+
+            VSIFree(c_block_size as *mut c_void);
+
+            block_size
+        }
+    }
+
+    /// Returns the size of the chunks, along each dimension, that fit in `max_chunk_memory`
+    /// bytes while being aligned on the [blocks](Self::block_size) of this array.
+    pub fn processing_chunk_size(&self, max_chunk_memory: usize) -> Vec<usize> {
+        unsafe {
+            let mut num_dimensions = 0;
+            let c_chunk_size = gdal_sys::GDALMDArrayGetProcessingChunkSize(
+                self.c_mdarray,
+                &mut num_dimensions,
+                max_chunk_memory,
+            );
+            if c_chunk_size.is_null() {
+                return Vec::new();
+            }
+
+            let chunk_size = std::slice::from_raw_parts(c_chunk_size, num_dimensions).to_vec();
+
+            VSIFree(c_chunk_size as *mut c_void);
+
+            chunk_size
+        }
+    }
+
+    /// Split this array into chunks of at most `max_chunk_memory` bytes, aligned on its
+    /// [blocks](Self::block_size), for efficient reading or writing.
+    ///
+    /// Returns an iterator over the `(array_start_index, count)` of the chunks, as accepted
+    /// by [`read_as`](Self::read_as) and [`write`](Self::write), in row-major order.
+    ///
+    /// # Example
+    ///
+    /// ```rust, no_run
+    /// use gdal::{Dataset, DatasetOptions, GdalOpenFlags};
+    /// use gdal::cpl::CslStringList;
+    /// # fn main() -> gdal::errors::Result<()> {
+    /// let options = DatasetOptions {
+    ///     open_flags: GdalOpenFlags::GDAL_OF_MULTIDIM_RASTER,
+    ///     ..Default::default()
+    /// };
+    /// let dataset = Dataset::open_ex("/vsizip/fixtures/byte_no_cf.zarr.zip", options)?;
+    /// let array = dataset
+    ///     .root_group()?
+    ///     .open_md_array("byte_no_cf", CslStringList::new())?;
+    /// let mut sum = 0u64;
+    /// for (start, count) in array.chunks(64 * 1024 * 1024)? {
+    ///     sum += array.read_as::<u8>(start, count)?.iter().map(|&v| v as u64).sum::<u64>();
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn chunks(
+        &self,
+        max_chunk_memory: usize,
+    ) -> Result<impl Iterator<Item = (Vec<u64>, Vec<usize>)>> {
+        let shape = self.shape()?;
+        let chunk_size = self.processing_chunk_size(max_chunk_memory);
+        if chunk_size.len() != shape.len() || chunk_size.contains(&0) {
+            return Err(_last_null_pointer_err("GDALMDArrayGetProcessingChunkSize"));
+        }
+
+        let mut next = if shape.contains(&0) {
+            None
+        } else {
+            Some(vec![0; shape.len()])
+        };
+        Ok(std::iter::from_fn(move || {
+            let start = next.take()?;
+            let count = start
+                .iter()
+                .zip(&shape)
+                .zip(&chunk_size)
+                .map(|((&start, &size), &chunk)| chunk.min(size - start))
+                .collect::<Vec<_>>();
+
+            // advance the fastest varying dimension first
+            let mut following = start.clone();
+            for dim in (0..shape.len()).rev() {
+                following[dim] += chunk_size[dim];
+                if following[dim] < shape[dim] {
+                    next = Some(following);
+                    break;
+                }
+                following[dim] = 0;
+            }
+
+            Some((start.iter().map(|&s| s as u64).collect(), count))
+        }))
+    }
+
+    /// Returns driver-specific information about how the array is stored, such as its
+    /// compression.
+    pub fn structural_info(&self) -> Vec<(String, String)> {
+        unsafe {
+            // should not be freed
+            let c_info = gdal_sys::GDALMDArrayGetStructuralInfo(self.c_mdarray);
+
+            _string_array(c_info)
+                .into_iter()
+                .filter_map(|entry| {
+                    entry
+                        .split_once('=')
+                        .map(|(key, value)| (key.to_string(), value.to_string()))
+                })
+                .collect()
+        }
+    }
+}
+
+/// A hyperslab read from an [`MDArray`] by [`MDArray::read_as_labeled_array`], with the names
+/// of its dimensions.
+#[cfg(feature = "ndarray")]
+#[cfg_attr(docsrs, doc(cfg(feature = "array")))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct LabeledArray<T> {
+    /// The values, with one axis per dimension, slowest varying first.
+    pub data: ArrayD<T>,
+    /// The names of the dimensions, in the order of the axes of `data`.
+    pub dimension_names: Vec<String>,
+    /// The index in the [`MDArray`] of the first value along each dimension.
+    pub array_start_index: Vec<u64>,
+}
+
+#[cfg(feature = "ndarray")]
+impl<T> LabeledArray<T> {
+    /// Returns the axis of `data` for the dimension called `name`.
+    pub fn axis(&self, name: &str) -> Option<Axis> {
+        self.dimension_names
+            .iter()
+            .position(|dimension| dimension == name)
+            .map(Axis)
+    }
+
+    /// Returns the values, dropping the dimension names.
+    pub fn into_inner(self) -> ArrayD<T> {
+        self.data
+    }
 }
 
 #[derive(Debug, PartialEq)]
@@ -585,6 +1035,153 @@ impl<'a> Group<'a> {
             Ok(dimensions)
         }
     }
+
+    /// Create a sub-group of this group.
+    ///
+    /// The driver-specific `options` are described in the driver documentation.
+    pub fn create_group(&self, name: &str, options: CslStringList) -> Result<Group<'a>> {
+        let name = CString::new(name)?;
+
+        unsafe {
+            let c_group =
+                gdal_sys::GDALGroupCreateGroup(self.c_group, name.as_ptr(), options.as_ptr());
+
+            if c_group.is_null() {
+                return Err(_last_null_pointer_err("GDALGroupCreateGroup"));
+            }
+
+            Ok(Group::from_c_group(self._dataset, c_group))
+        }
+    }
+
+    /// Create a dimension of `size` elements in this group.
+    ///
+    /// # Arguments
+    /// * `name` - the name of the dimension
+    /// * `dimension_type` - the type of the dimension, e.g. `"HORIZONTAL_X"`, `"HORIZONTAL_Y"`,
+    ///   `"VERTICAL"` or `"TEMPORAL"`, or `""`
+    /// * `direction` - the direction of the dimension, e.g. `"EAST"`, `"NORTH"`, `"UP"` or
+    ///   `"FUTURE"`, or `""`
+    /// * `size` - the number of elements along the dimension
+    pub fn create_dimension(
+        &self,
+        name: &str,
+        dimension_type: &str,
+        direction: &str,
+        size: u64,
+        options: CslStringList,
+    ) -> Result<Dimension> {
+        let name = CString::new(name)?;
+        let dimension_type = CString::new(dimension_type)?;
+        let direction = CString::new(direction)?;
+
+        unsafe {
+            let c_dimension = gdal_sys::GDALGroupCreateDimension(
+                self.c_group,
+                name.as_ptr(),
+                dimension_type.as_ptr(),
+                direction.as_ptr(),
+                size,
+                options.as_ptr(),
+            );
+
+            if c_dimension.is_null() {
+                return Err(_last_null_pointer_err("GDALGroupCreateDimension"));
+            }
+
+            Ok(Dimension::from_c_dimension(
+                GroupOrArray::Group { _group: self },
+                c_dimension,
+            ))
+        }
+    }
+
+    /// Create an array of `data_type` values in this group, indexed by `dimensions`, with the
+    /// slowest varying dimension first.
+    ///
+    /// The driver-specific `options`, e.g. the chunking and compression of Zarr arrays, are
+    /// described in the driver documentation.
+    ///
+    /// # Example
+    ///
+    /// ```rust, no_run
+    /// use gdal::cpl::CslStringList;
+    /// use gdal::raster::ExtendedDataType;
+    /// use gdal::DriverManager;
+    /// # fn main() -> gdal::errors::Result<()> {
+    /// let driver = DriverManager::get_driver_by_name("Zarr")?;
+    /// let dataset = driver.create_multidimensional("/tmp/out.zarr", CslStringList::new())?;
+    /// let root = dataset.root_group()?;
+    /// let y = root.create_dimension("y", "HORIZONTAL_Y", "", 2, CslStringList::new())?;
+    /// let x = root.create_dimension("x", "HORIZONTAL_X", "", 3, CslStringList::new())?;
+    /// let mut temperature = root.create_md_array(
+    ///     "temperature",
+    ///     &[y, x],
+    ///     &ExtendedDataType::numeric::<f32>(),
+    ///     CslStringList::new(),
+    /// )?;
+    /// temperature.set_unit("degC")?;
+    /// temperature.write(vec![0, 0], vec![2, 3], &[20.5f32, 21., 21.5, 19., 19.5, 20.])?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn create_md_array(
+        &self,
+        name: &str,
+        dimensions: &[Dimension],
+        data_type: &ExtendedDataType,
+        options: CslStringList,
+    ) -> Result<MDArray> {
+        let name = CString::new(name)?;
+        let mut c_dimensions = dimensions
+            .iter()
+            .map(|dimension| dimension.c_dimension)
+            .collect::<Vec<_>>();
+
+        unsafe {
+            let c_mdarray = gdal_sys::GDALGroupCreateMDArray(
+                self.c_group,
+                name.as_ptr(),
+                c_dimensions.len(),
+                c_dimensions.as_mut_ptr(),
+                data_type.c_data_type,
+                options.as_ptr(),
+            );
+
+            if c_mdarray.is_null() {
+                return Err(_last_null_pointer_err("GDALGroupCreateMDArray"));
+            }
+
+            Ok(MDArray::from_c_mdarray_and_group(self, c_mdarray))
+        }
+    }
+
+    /// Create an attribute of this group, with the given dimension sizes (empty for a scalar)
+    /// and data type.
+    ///
+    /// The value must then be written with one of the `write` methods of [`Attribute`].
+    pub fn create_attribute(
+        &self,
+        name: &str,
+        dimensions: &[u64],
+        data_type: &ExtendedDataType,
+    ) -> Result<Attribute> {
+        let name = CString::new(name)?;
+        let c_attribute = unsafe {
+            gdal_sys::GDALGroupCreateAttribute(
+                self.c_group,
+                name.as_ptr(),
+                dimensions.len(),
+                dimensions.as_ptr(),
+                data_type.c_data_type,
+                std::ptr::null_mut(),
+            )
+        };
+        if c_attribute.is_null() {
+            return Err(_last_null_pointer_err("GDALGroupCreateAttribute"));
+        }
+        Ok(Attribute::from_c_attribute(c_attribute))
+    }
 }
 
 /// A `GDALDimension` with name and size
@@ -698,6 +1295,18 @@ impl ExtendedDataType {
         Self { c_data_type }
     }
 
+    /// Create the numeric data type of `T`.
+    pub fn numeric<T: GdalType>() -> Self {
+        Self::from_c_extended_data_type(unsafe { GDALExtendedDataTypeCreate(T::gdal_ordinal()) })
+    }
+
+    /// Create a string data type, of unbounded length if `max_length` is 0.
+    pub fn string(max_length: usize) -> Self {
+        Self::from_c_extended_data_type(unsafe {
+            gdal_sys::GDALExtendedDataTypeCreateString(max_length)
+        })
+    }
+
     /// The result is only valid if the data type is numeric
     pub fn class(&self) -> ExtendedDataTypeClass {
         unsafe { GDALExtendedDataTypeGetClass(self.c_data_type) }.into()
@@ -817,6 +1426,40 @@ impl Attribute {
             float_array
         }
     }
+
+    /// Write a string value to this attribute.
+    pub fn write_string(&mut self, value: &str) -> Result<()> {
+        let value = CString::new(value)?;
+        bool_result(unsafe { gdal_sys::GDALAttributeWriteString(self.c_attribute, value.as_ptr()) })
+    }
+
+    /// Write an array of strings to this attribute.
+    pub fn write_string_array(&mut self, values: &[&str]) -> Result<()> {
+        let mut c_values = CslStringList::new();
+        for value in values {
+            c_values.add_string(value)?;
+        }
+        bool_result(unsafe {
+            gdal_sys::GDALAttributeWriteStringArray(self.c_attribute, c_values.as_ptr())
+        })
+    }
+
+    /// Write an integer value to this attribute.
+    pub fn write_i32(&mut self, value: i32) -> Result<()> {
+        bool_result(unsafe { gdal_sys::GDALAttributeWriteInt(self.c_attribute, value) })
+    }
+
+    /// Write a floating point value to this attribute.
+    pub fn write_f64(&mut self, value: f64) -> Result<()> {
+        bool_result(unsafe { gdal_sys::GDALAttributeWriteDouble(self.c_attribute, value) })
+    }
+
+    /// Write an array of floating point values to this attribute.
+    pub fn write_f64_array(&mut self, values: &[f64]) -> Result<()> {
+        bool_result(unsafe {
+            gdal_sys::GDALAttributeWriteDoubleArray(self.c_attribute, values.as_ptr(), values.len())
+        })
+    }
 }
 
 /// [Dataset] methods supporting multi-dimensional array operations.
@@ -839,6 +1482,42 @@ impl Dataset {
     }
 }
 
+impl Driver {
+    /// Create a new multidimensional dataset, whose content is then created from its
+    /// [`root_group`](Dataset::root_group).
+    ///
+    /// The driver-specific `options` are described in the driver documentation.
+    ///
+    /// See: [`GDALCreateMultiDimensional`](https://gdal.org/api/raster_c_api.html#_CPPv426GDALCreateMultiDimensional11GDALDriverHPKc12CSLConstList12CSLConstList)
+    pub fn create_multidimensional<P: AsRef<Path>>(
+        &self,
+        filename: P,
+        options: CslStringList,
+    ) -> Result<Dataset> {
+        let c_filename = _path_to_c_string(filename.as_ref())?;
+        let c_dataset = unsafe {
+            gdal_sys::GDALCreateMultiDimensional(
+                self.c_driver(),
+                c_filename.as_ptr(),
+                std::ptr::null_mut(),
+                options.as_ptr(),
+            )
+        };
+        if c_dataset.is_null() {
+            return Err(_last_null_pointer_err("GDALCreateMultiDimensional"));
+        }
+        Ok(unsafe { Dataset::from_c_dataset(c_dataset) })
+    }
+}
+
+/// Converts the boolean result of the multidimensional API into a [`Result`].
+fn bool_result(rv: libc::c_int) -> Result<()> {
+    if rv != 1 {
+        return Err(_last_cpl_err(CPLErr::CE_Failure));
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -1027,6 +1706,204 @@ mod tests {
             .expect_err("read_into_slice() with insufficient capacity should panic");
     }
 
+    #[test]
+    #[cfg_attr(not(all(major_ge_3, minor_ge_4)), ignore)]
+    #[cfg(all(feature = "ndarray", any(all(major_is_3, minor_ge_2), major_ge_4)))]
+    fn test_read_as_labeled_array() {
+        let fixture = "/vsizip/fixtures/byte_no_cf.zarr.zip";
+
+        let dataset_options = DatasetOptions {
+            open_flags: GdalOpenFlags::GDAL_OF_MULTIDIM_RASTER,
+            allowed_drivers: None,
+            open_options: None,
+            sibling_files: None,
+        };
+        let dataset = Dataset::open_ex(fixture, dataset_options).unwrap();
+
+        let root_group = dataset.root_group().unwrap();
+        let md_array = root_group
+            .open_md_array("byte_no_cf", CslStringList::new())
+            .unwrap();
+
+        let labeled = md_array
+            .read_as_labeled_array::<u8>(vec![1, 2], vec![3, 4])
+            .unwrap();
+        assert_eq!(labeled.data.shape(), [3, 4]);
+        assert_eq!(labeled.dimension_names, ["Y", "X"]);
+        assert_eq!(labeled.array_start_index, [1, 2]);
+        assert_eq!(labeled.axis("X"), Some(Axis(1)));
+        assert_eq!(labeled.axis("time"), None);
+
+        let values = md_array.read_as::<u8>(vec![1, 2], vec![3, 4]).unwrap();
+        assert_eq!(labeled.into_inner().into_raw_vec(), values);
+
+        md_array
+            .read_as_labeled_array::<u8>(vec![0], vec![20])
+            .expect_err("the dimension count doesn't match");
+    }
+
+    #[test]
+    #[cfg_attr(not(all(major_ge_3, minor_ge_4)), ignore)]
+    #[cfg(any(all(major_is_3, minor_ge_2), major_ge_4))]
+    fn test_read_strided() {
+        let fixture = "/vsizip/fixtures/byte_no_cf.zarr.zip";
+
+        let dataset_options = DatasetOptions {
+            open_flags: GdalOpenFlags::GDAL_OF_MULTIDIM_RASTER,
+            allowed_drivers: None,
+            open_options: None,
+            sibling_files: None,
+        };
+        let dataset = Dataset::open_ex(fixture, dataset_options).unwrap();
+
+        let root_group = dataset.root_group().unwrap();
+        let md_array = root_group
+            .open_md_array("byte_no_cf", CslStringList::new())
+            .unwrap();
+
+        let all = md_array.read_as::<u8>(vec![0, 0], vec![20, 20]).unwrap();
+        let values = md_array
+            .read_as_strided::<u8>(vec![1, 0], vec![3, 4], vec![5, 2])
+            .unwrap();
+        let expected = (0..3)
+            .flat_map(|i| (0..4).map(move |j| (1 + 5 * i) * 20 + 2 * j))
+            .map(|idx| all[idx])
+            .collect::<Vec<_>>();
+        assert_eq!(values, expected);
+
+        // backwards along the last dimension
+        let values = md_array
+            .read_as_strided::<u8>(vec![0, 19], vec![1, 20], vec![1, -1])
+            .unwrap();
+        let expected = all[..20].iter().rev().copied().collect::<Vec<_>>();
+        assert_eq!(values, expected);
+
+        assert!(md_array
+            .read_as_strided::<u8>(vec![0], vec![20], vec![1])
+            .is_err());
+    }
+
+    #[test]
+    fn test_create_and_write() {
+        let driver = crate::DriverManager::get_driver_by_name("MEM").unwrap();
+        let dataset = driver
+            .create_multidimensional("", CslStringList::new())
+            .unwrap();
+        let root = dataset.root_group().unwrap();
+        let group = root.create_group("model", CslStringList::new()).unwrap();
+        assert_eq!(root.group_names(CslStringList::new()), ["model"]);
+
+        let mut attribute = group
+            .create_attribute("title", &[], &ExtendedDataType::string(0))
+            .unwrap();
+        attribute.write_string("run 1").unwrap();
+        let mut attribute = group
+            .create_attribute("levels", &[3], &ExtendedDataType::numeric::<f64>())
+            .unwrap();
+        attribute.write_f64_array(&[1., 2.5, 4.]).unwrap();
+
+        let y = group
+            .create_dimension("y", "HORIZONTAL_Y", "", 2, CslStringList::new())
+            .unwrap();
+        let x = group
+            .create_dimension("x", "HORIZONTAL_X", "", 3, CslStringList::new())
+            .unwrap();
+        let mut array = group
+            .create_md_array(
+                "temperature",
+                &[y, x],
+                &ExtendedDataType::numeric::<i16>(),
+                CslStringList::new(),
+            )
+            .unwrap();
+        array
+            .write(vec![0, 0], vec![2, 3], &[1i16, 2, 3, 4, 5, 6])
+            .unwrap();
+        array.write(vec![1, 1], vec![1, 2], &[50i16, 60]).unwrap();
+        array.set_unit("degC").unwrap();
+        array.set_no_data_value_as_double(-1.).unwrap();
+        array.set_scale(0.5).unwrap();
+        array.set_offset(10.).unwrap();
+        array
+            .create_attribute("count", &[], &ExtendedDataType::numeric::<i32>())
+            .unwrap()
+            .write_i32(6)
+            .unwrap();
+        assert!(array.write(vec![0], vec![2], &[1i16, 2]).is_err());
+        assert!(array.write(vec![0, 0], vec![2, 3], &[1i16, 2]).is_err());
+        drop(array);
+
+        let group = root.open_group("model", CslStringList::new()).unwrap();
+        assert_eq!(group.attribute("title").unwrap().read_as_string(), "run 1");
+        assert_eq!(
+            group.attribute("levels").unwrap().read_as_f64_array(),
+            [1., 2.5, 4.]
+        );
+        let array = group
+            .open_md_array("temperature", CslStringList::new())
+            .unwrap();
+        assert_eq!(
+            array.read_as::<i16>(vec![0, 0], vec![2, 3]).unwrap(),
+            [1, 2, 3, 4, 50, 60]
+        );
+        assert_eq!(array.unit(), "degC");
+        assert_eq!(array.no_data_value_as_double(), Some(-1.));
+        assert_eq!(array.scale(), Some(0.5));
+        assert_eq!(array.offset(), Some(10.));
+        assert_eq!(array.attribute("count").unwrap().read_as_i64(), 6);
+    }
+
+    #[test]
+    #[cfg(any(all(major_is_3, minor_ge_2), major_ge_4))]
+    fn test_compute_statistics_and_chunks() {
+        let driver = crate::DriverManager::get_driver_by_name("MEM").unwrap();
+        let dataset = driver
+            .create_multidimensional("", CslStringList::new())
+            .unwrap();
+        let root = dataset.root_group().unwrap();
+        let y = root
+            .create_dimension("y", "", "", 5, CslStringList::new())
+            .unwrap();
+        let x = root
+            .create_dimension("x", "", "", 7, CslStringList::new())
+            .unwrap();
+        let mut array = root
+            .create_md_array(
+                "values",
+                &[y, x],
+                &ExtendedDataType::numeric::<u16>(),
+                CslStringList::new(),
+            )
+            .unwrap();
+        let values = (1..=35).collect::<Vec<u16>>();
+        array.write(vec![0, 0], vec![5, 7], &values).unwrap();
+
+        assert_eq!(array.shape().unwrap(), [5, 7]);
+        assert_eq!(array.block_size().len(), 2);
+
+        let statistics = array.compute_statistics(false).unwrap();
+        assert_eq!(statistics.min, 1.);
+        assert_eq!(statistics.max, 35.);
+        assert_eq!(statistics.mean, 18.);
+        assert_eq!(statistics.valid_count, 35);
+
+        // the chunks cover the whole array exactly once
+        let chunk_size = array.processing_chunk_size(6 * 2);
+        assert_eq!(chunk_size.len(), 2);
+        assert!(chunk_size.iter().product::<usize>() <= 6);
+        let mut sum = 0;
+        let mut elements = 0;
+        for (start, count) in array.chunks(6 * 2).unwrap() {
+            assert!(start[0] as usize + count[0] <= 5);
+            assert!(start[1] as usize + count[1] <= 7);
+            let chunk = array.read_as::<u16>(start, count).unwrap();
+            elements += chunk.len();
+            sum += chunk.iter().map(|&v| v as u32).sum::<u32>();
+        }
+        assert_eq!(elements, 35);
+        assert_eq!(sum, values.iter().map(|&v| v as u32).sum::<u32>());
+    }
+
     #[test]
     #[cfg_attr(not(all(major_ge_3, minor_ge_4)), ignore)]
     #[cfg(any(all(major_is_3, minor_ge_1), major_ge_4))]
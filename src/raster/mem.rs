@@ -0,0 +1,137 @@
+//! In-memory datasets backed by [`Buffer`]s.
+
+use std::marker::PhantomData;
+use std::ops::Deref;
+
+use gdal_sys::CPLErr;
+
+use crate::cpl::CslStringList;
+use crate::errors::*;
+use crate::raster::{Buffer, GdalType};
+use crate::spatial_ref::SpatialRef;
+use crate::utils::_last_cpl_err;
+use crate::{Dataset, DriverManager, GeoTransform};
+
+/// A `MEM` dataset whose single band reads and writes the pixels of a borrowed [`Buffer`],
+/// created by [`Dataset::from_buffer`].
+///
+/// Dereferences to the [`Dataset`], so it can be passed to any function that reads rasters.
+/// Only shared references are given out, so that the dataset can't be moved away from the
+/// buffer it points to.
+/// The buffer can't be accessed while the dataset is alive; writes to the band are visible
+/// in the buffer once it's dropped.
+#[derive(Debug)]
+pub struct BufferDataset<'a> {
+    dataset: Dataset,
+    _buffer: PhantomData<&'a mut [u8]>,
+}
+
+impl Deref for BufferDataset<'_> {
+    type Target = Dataset;
+
+    fn deref(&self) -> &Self::Target {
+        &self.dataset
+    }
+}
+
+impl Dataset {
+    /// Create a single band `MEM` dataset that uses the pixels of `buffer` directly, without
+    /// copying them.
+    ///
+    /// The optional `geo_transform` and `srs` are set on the dataset.
+    ///
+    /// # Example
+    ///
+    /// ```rust, no_run
+    /// # fn main() -> gdal::errors::Result<()> {
+    /// use gdal::raster::{Buffer, StatisticsMinMax};
+    /// use gdal::spatial_ref::SpatialRef;
+    /// use gdal::Dataset;
+    ///
+    /// let mut buffer = Buffer::new((3, 2), vec![1u8, 2, 3, 4, 5, 6]);
+    /// let srs = SpatialRef::from_epsg(4326)?;
+    /// let gt = [10., 1., 0., 50., 0., -1.];
+    /// let dataset = Dataset::from_buffer(&mut buffer, Some(&gt), Some(&srs))?;
+    /// let StatisticsMinMax { min, max } = dataset.rasterband(1)?.compute_raster_min_max(false)?;
+    /// assert_eq!((min, max), (1., 6.));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_buffer<'a, T: Copy + GdalType>(
+        buffer: &'a mut Buffer<T>,
+        geo_transform: Option<&GeoTransform>,
+        srs: Option<&SpatialRef>,
+    ) -> Result<BufferDataset<'a>> {
+        let (cols, rows) = buffer.shape();
+        let driver = DriverManager::get_driver_by_name("MEM")?;
+        let mut dataset = driver.create_with_band_type::<T, _>("", cols, rows, 0)?;
+
+        let mut options = CslStringList::new();
+        options.set_name_value(
+            "DATAPOINTER",
+            &format!("{:p}", buffer.data_mut().as_mut_ptr()),
+        )?;
+        let rv = unsafe {
+            gdal_sys::GDALAddBand(dataset.c_dataset(), T::gdal_ordinal(), options.as_ptr())
+        };
+        if rv != CPLErr::CE_None {
+            return Err(_last_cpl_err(rv));
+        }
+
+        if let Some(geo_transform) = geo_transform {
+            dataset.set_geo_transform(geo_transform)?;
+        }
+        if let Some(srs) = srs {
+            dataset.set_spatial_ref(srs)?;
+        }
+        Ok(BufferDataset {
+            dataset,
+            _buffer: PhantomData,
+        })
+    }
+
+    /// Read all the bands of this dataset into [`Buffer`]s of `T`, one per band.
+    ///
+    /// This is the reverse of [`Dataset::from_buffer`].
+    pub fn to_buffer<T: Copy + GdalType>(&self) -> Result<Vec<Buffer<T>>> {
+        self.rasterbands()
+            .map(|band| band?.read_band_as::<T>())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_buffer() {
+        let mut buffer = Buffer::new((3, 2), vec![1u16, 2, 3, 4, 5, 6]);
+        let srs = SpatialRef::from_epsg(4326).unwrap();
+        let gt = [10., 1., 0., 50., 0., -1.];
+        {
+            let dataset = Dataset::from_buffer(&mut buffer, Some(&gt), Some(&srs)).unwrap();
+            assert_eq!(dataset.driver().short_name(), "MEM");
+            assert_eq!(dataset.raster_size(), (3, 2));
+            assert_eq!(dataset.geo_transform().unwrap(), gt);
+            assert_eq!(dataset.spatial_ref().unwrap().auth_code().unwrap(), 4326);
+
+            let mut band = dataset.rasterband(1).unwrap();
+            let data = band.read_as::<u16>((1, 1), (2, 1), (2, 1), None).unwrap();
+            assert_eq!(data.data(), [5, 6]);
+            band.write((0, 0), (1, 1), &mut Buffer::new((1, 1), vec![42u16]))
+                .unwrap();
+        }
+        assert_eq!(buffer.data(), [42, 2, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn test_to_buffer() {
+        let mut buffer = Buffer::new((2, 2), vec![1.5f32, 2.5, 3.5, 4.5]);
+        let dataset = Dataset::from_buffer(&mut buffer, None, None).unwrap();
+        let buffers = dataset.to_buffer::<f64>().unwrap();
+        assert_eq!(buffers.len(), 1);
+        assert_eq!(buffers[0].shape(), (2, 2));
+        assert_eq!(buffers[0].data(), [1.5, 2.5, 3.5, 4.5]);
+    }
+}
@@ -74,28 +74,84 @@
 //!     ...
 //! ```
 
-pub use buffer::{Buffer, ByteBuffer};
-pub use create_options::RasterCreationOptions;
+pub use aggregate::{aggregate, AggStatistic, AggregateScale};
+#[cfg(feature = "ndarray")]
+pub use array::BandAxis;
+pub use buffer::{convert_buffer, copy_words, AnyBuffer, Buffer, ByteBuffer};
+pub use contour::{contour, ContourLevels, ContourOptions};
+pub use create_options::{BandCreationOptions, RasterCreationOptions};
+#[cfg(any(all(major_ge_3, minor_ge_9), major_ge_4))]
+pub use filters::FillNodataInterpolation;
+pub use filters::{Connectedness, FillNodataOptions};
+#[cfg(any(all(major_ge_3, minor_ge_8), major_ge_4))]
+pub use footprint::{
+    footprint, footprint_into, FootprintCombine, FootprintCoordinates, FootprintOptions,
+};
+#[cfg(all(feature = "ndarray", major_ge_3, minor_ge_1))]
+pub use mdarray::LabeledArray;
 #[cfg(all(major_ge_3, minor_ge_1))]
 pub use mdarray::{
     Attribute, Dimension, ExtendedDataType, ExtendedDataTypeClass, Group, MDArray, MdStatisticsAll,
 };
+pub use mem::BufferDataset;
+pub use overviews::{
+    BuildOverviewsOptions, OverviewCompression, OverviewPhotometric, OverviewResampling,
+};
+pub use pansharpen::{
+    create_pansharpened_vrt, PansharpenExtentAdjustment, PansharpenOptions, PansharpenedDataset,
+};
+pub use polygonize::{polygonize, PolygonizeOptions};
+pub use proximity::{compute_proximity, DistanceUnits, ProximityOptions};
+#[cfg(any(all(major_ge_3, minor_ge_7), major_ge_4))]
+pub use rasterband::CompressedData;
 pub use rasterband::{
-    CmykEntry, ColorEntry, ColorInterpretation, ColorTable, GrayEntry, Histogram, HlsEntry,
-    PaletteInterpretation, RasterBand, ResampleAlg, RgbaEntry, StatisticsAll, StatisticsMinMax,
+    CmykEntry, ColorEntry, ColorInterpretation, ColorTable, DataCoverageStatus, GrayEntry,
+    Histogram, HlsEntry, PaletteInterpretation, RasterBand, ResampleAlg, RgbaEntry, StatisticsAll,
+    StatisticsMinMax,
+};
+pub use rasterize::{
+    rasterize, rasterize_layers, BurnSource, LayerBurnValue, MergeAlgorithm, OptimizeMode,
+    RasterizeOptions,
 };
-pub use rasterize::{rasterize, BurnSource, MergeAlgorithm, OptimizeMode, RasterizeOptions};
+pub use transformer::GdalTransformer;
 pub use types::{AdjustedValue, GdalDataType, GdalType};
-pub use warp::reproject;
+#[cfg(all(major_ge_3, minor_ge_1))]
+pub use viewshed::{viewshed, ViewshedCellMode, ViewshedOptions, ViewshedOutputMode};
+pub use virtual_mem::VirtualMem;
+pub use warp::{
+    reproject, reproject_with_cancellation, reproject_with_options, suggested_warp_output,
+    Transformer, WarpOperation, WarpOperationOptions, WarpResampleAlg,
+};
 
+mod aggregate;
+#[cfg(feature = "ndarray")]
+mod array;
 mod buffer;
+pub mod calc;
+pub mod cog;
+mod contour;
 mod create_options;
+mod filters;
+#[cfg(any(all(major_ge_3, minor_ge_8), major_ge_4))]
+mod footprint;
+pub mod grid;
+#[cfg(feature = "image")]
+mod image;
 #[cfg(all(major_ge_3, minor_ge_1))]
 mod mdarray;
+mod mem;
+mod overviews;
+mod pansharpen;
+mod polygonize;
 pub mod processing;
+mod proximity;
 mod rasterband;
 mod rasterize;
 #[cfg(test)]
 mod tests;
+mod transformer;
 mod types;
+#[cfg(all(major_ge_3, minor_ge_1))]
+mod viewshed;
+mod virtual_mem;
 mod warp;
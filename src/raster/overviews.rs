@@ -0,0 +1,202 @@
+use crate::progress::CancellationToken;
+
+/// Resampling method used by [`Dataset::build_overviews_with_options`](crate::Dataset::build_overviews_with_options).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OverviewResampling {
+    /// Nearest neighbour (the default)
+    #[default]
+    Nearest,
+    /// Average of the contributing pixels
+    Average,
+    /// Root mean square of the contributing pixels (GDAL >= 3.3)
+    #[cfg(all(major_ge_3, minor_ge_3))]
+    Rms,
+    /// Bilinear convolution
+    Bilinear,
+    /// Cubic convolution
+    Cubic,
+    /// Cubic B-spline convolution
+    CubicSpline,
+    /// Lanczos windowed sinc convolution
+    Lanczos,
+    /// Gaussian kernel
+    Gauss,
+    /// Most frequent value of the contributing pixels
+    Mode,
+    /// Average of the magnitude and phase of complex data
+    AverageMagphase,
+    /// Only create the overviews, without computing their pixels
+    None,
+}
+
+impl OverviewResampling {
+    pub fn to_gdal(self) -> &'static str {
+        match self {
+            OverviewResampling::Nearest => "NEAREST",
+            OverviewResampling::Average => "AVERAGE",
+            #[cfg(all(major_ge_3, minor_ge_3))]
+            OverviewResampling::Rms => "RMS",
+            OverviewResampling::Bilinear => "BILINEAR",
+            OverviewResampling::Cubic => "CUBIC",
+            OverviewResampling::CubicSpline => "CUBICSPLINE",
+            OverviewResampling::Lanczos => "LANCZOS",
+            OverviewResampling::Gauss => "GAUSS",
+            OverviewResampling::Mode => "MODE",
+            OverviewResampling::AverageMagphase => "AVERAGE_MAGPHASE",
+            OverviewResampling::None => "NONE",
+        }
+    }
+}
+
+/// Compression of GeoTIFF overviews, set through the `COMPRESS_OVERVIEW` config option.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OverviewCompression {
+    None,
+    Jpeg,
+    Lzw,
+    Packbits,
+    Deflate,
+    Lzma,
+    Zstd,
+    Lerc,
+    LercDeflate,
+    LercZstd,
+    Webp,
+}
+
+impl OverviewCompression {
+    pub fn to_gdal(self) -> &'static str {
+        match self {
+            OverviewCompression::None => "NONE",
+            OverviewCompression::Jpeg => "JPEG",
+            OverviewCompression::Lzw => "LZW",
+            OverviewCompression::Packbits => "PACKBITS",
+            OverviewCompression::Deflate => "DEFLATE",
+            OverviewCompression::Lzma => "LZMA",
+            OverviewCompression::Zstd => "ZSTD",
+            OverviewCompression::Lerc => "LERC",
+            OverviewCompression::LercDeflate => "LERC_DEFLATE",
+            OverviewCompression::LercZstd => "LERC_ZSTD",
+            OverviewCompression::Webp => "WEBP",
+        }
+    }
+}
+
+/// Photometric interpretation of GeoTIFF overviews, set through the `PHOTOMETRIC_OVERVIEW`
+/// config option.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OverviewPhotometric {
+    MinIsBlack,
+    MinIsWhite,
+    Rgb,
+    Cmyk,
+    /// YCbCr, which makes JPEG compressed overviews much smaller
+    YCbCr,
+    CieLab,
+    IccLab,
+    ItuLab,
+}
+
+impl OverviewPhotometric {
+    pub fn to_gdal(self) -> &'static str {
+        match self {
+            OverviewPhotometric::MinIsBlack => "MINISBLACK",
+            OverviewPhotometric::MinIsWhite => "MINISWHITE",
+            OverviewPhotometric::Rgb => "RGB",
+            OverviewPhotometric::Cmyk => "CMYK",
+            OverviewPhotometric::YCbCr => "YCBCR",
+            OverviewPhotometric::CieLab => "CIELAB",
+            OverviewPhotometric::IccLab => "ICCLAB",
+            OverviewPhotometric::ItuLab => "ITULAB",
+        }
+    }
+}
+
+/// Options for [`Dataset::build_overviews_with_options`](crate::Dataset::build_overviews_with_options).
+///
+/// The GeoTIFF settings are passed to GDAL as thread-local config options, which are only set
+/// while the overviews are built. They apply to external (`.ovr`) overviews, and with
+/// GDAL >= 3.6 to internal ones too.
+#[derive(Debug, Clone, Default)]
+pub struct BuildOverviewsOptions {
+    pub(crate) resampling: OverviewResampling,
+    compression: Option<OverviewCompression>,
+    photometric: Option<OverviewPhotometric>,
+    jpeg_quality: Option<u8>,
+    predictor: Option<u8>,
+    block_size: Option<usize>,
+    pub(crate) cancellation: Option<CancellationToken>,
+}
+
+impl BuildOverviewsOptions {
+    /// Create the default options: nearest neighbour resampling, with the driver's defaults.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Set the resampling method.
+    pub fn with_resampling(&mut self, resampling: OverviewResampling) -> &mut Self {
+        self.resampling = resampling;
+        self
+    }
+
+    /// Set the compression of GeoTIFF overviews (`COMPRESS_OVERVIEW`).
+    pub fn with_compression(&mut self, compression: OverviewCompression) -> &mut Self {
+        self.compression = Some(compression);
+        self
+    }
+
+    /// Set the photometric interpretation of GeoTIFF overviews (`PHOTOMETRIC_OVERVIEW`).
+    pub fn with_photometric(&mut self, photometric: OverviewPhotometric) -> &mut Self {
+        self.photometric = Some(photometric);
+        self
+    }
+
+    /// Set the quality, from 1 to 100, of JPEG compressed GeoTIFF overviews (`JPEG_QUALITY_OVERVIEW`).
+    pub fn with_jpeg_quality(&mut self, quality: u8) -> &mut Self {
+        self.jpeg_quality = Some(quality);
+        self
+    }
+
+    /// Set the predictor of LZW, DEFLATE and ZSTD compressed GeoTIFF overviews
+    /// (`PREDICTOR_OVERVIEW`): 1 for none, 2 for horizontal differencing, 3 for floating point.
+    pub fn with_predictor(&mut self, predictor: u8) -> &mut Self {
+        self.predictor = Some(predictor);
+        self
+    }
+
+    /// Set the size of the square tiles of GeoTIFF overviews (`GDAL_TIFF_OVR_BLOCKSIZE`),
+    /// a power of two between 64 and 4096.
+    pub fn with_block_size(&mut self, size: usize) -> &mut Self {
+        self.block_size = Some(size);
+        self
+    }
+
+    /// Abort the build with [`GdalError::Cancelled`](crate::errors::GdalError::Cancelled)
+    /// once `token` is cancelled.
+    pub fn with_cancellation(&mut self, token: &CancellationToken) -> &mut Self {
+        self.cancellation = Some(token.clone());
+        self
+    }
+
+    /// Get the config options to set while building the overviews.
+    pub(crate) fn config_options(&self) -> Vec<(&'static str, String)> {
+        let mut config = Vec::new();
+        if let Some(compression) = self.compression {
+            config.push(("COMPRESS_OVERVIEW", compression.to_gdal().to_string()));
+        }
+        if let Some(photometric) = self.photometric {
+            config.push(("PHOTOMETRIC_OVERVIEW", photometric.to_gdal().to_string()));
+        }
+        if let Some(quality) = self.jpeg_quality {
+            config.push(("JPEG_QUALITY_OVERVIEW", quality.to_string()));
+        }
+        if let Some(predictor) = self.predictor {
+            config.push(("PREDICTOR_OVERVIEW", predictor.to_string()));
+        }
+        if let Some(size) = self.block_size {
+            config.push(("GDAL_TIFF_OVR_BLOCKSIZE", size.to_string()));
+        }
+        config
+    }
+}
@@ -0,0 +1,322 @@
+use std::ffi::CString;
+use std::fmt::Write;
+use std::marker::PhantomData;
+use std::ops::Deref;
+
+use gdal_sys::GDALRasterBandH;
+
+use crate::errors::*;
+use crate::raster::{RasterBand, ResampleAlg};
+use crate::utils::_last_null_pointer_err;
+use crate::Dataset;
+
+/// How [`create_pansharpened_vrt`] handles panchromatic and spectral bands with different
+/// extents.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PansharpenExtentAdjustment {
+    /// Use the union of the extents (the default).
+    #[default]
+    Union,
+    /// Use the intersection of the extents.
+    Intersection,
+    /// Use the extent of the panchromatic band, warning when the extents differ.
+    None,
+    /// Use the extent of the panchromatic band, without warnings.
+    NoneWithoutWarning,
+}
+
+impl PansharpenExtentAdjustment {
+    fn to_gdal(self) -> &'static str {
+        match self {
+            PansharpenExtentAdjustment::Union => "Union",
+            PansharpenExtentAdjustment::Intersection => "Intersection",
+            PansharpenExtentAdjustment::None => "None",
+            PansharpenExtentAdjustment::NoneWithoutWarning => "NoneWithoutWarning",
+        }
+    }
+}
+
+/// Configuration options for [`create_pansharpened_vrt`].
+///
+/// These map to the `PansharpeningOptions` element of a [pansharpened VRT], which uses the
+/// weighted Brovey algorithm.
+///
+/// [pansharpened VRT]: https://gdal.org/drivers/raster/vrt.html#pansharpened-vrt
+#[derive(Debug, Clone, Default)]
+pub struct PansharpenOptions {
+    weights: Vec<f64>,
+    resampling: Option<ResampleAlg>,
+    num_threads: Option<usize>,
+    bit_depth: Option<u32>,
+    no_data: Option<Option<f64>>,
+    extent_adjustment: Option<PansharpenExtentAdjustment>,
+}
+
+impl PansharpenOptions {
+    /// Create an options set with the VRT defaults.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Weigh the spectral bands with `weights`, one per band, to compute the pseudo
+    /// panchromatic intensity. Defaults to equal weights.
+    pub fn with_weights(&mut self, weights: &[f64]) -> &mut Self {
+        self.weights = weights.to_vec();
+        self
+    }
+
+    /// Resample the spectral bands to the panchromatic resolution with `resampling`.
+    /// Defaults to [`ResampleAlg::Cubic`].
+    pub fn with_resampling(&mut self, resampling: ResampleAlg) -> &mut Self {
+        self.resampling = Some(resampling);
+        self
+    }
+
+    /// Compute with `threads` worker threads, or all the CPUs with `0`. Defaults to one.
+    pub fn with_num_threads(&mut self, threads: usize) -> &mut Self {
+        self.num_threads = Some(threads);
+        self
+    }
+
+    /// Clamp the output values to `bits` bits, such as 12 for 12-bit imagery stored as
+    /// `u16`. Defaults to the `NBITS` metadata of the bands, or their data type.
+    pub fn with_bit_depth(&mut self, bits: u32) -> &mut Self {
+        self.bit_depth = Some(bits);
+        self
+    }
+
+    /// Set the nodata value of the inputs and output, or `None` to ignore the nodata values
+    /// of the bands. Defaults to the nodata value of the bands.
+    pub fn with_no_data(&mut self, no_data: Option<f64>) -> &mut Self {
+        self.no_data = Some(no_data);
+        self
+    }
+
+    /// Set how the output extent is computed when the bands have different extents.
+    pub fn with_extent_adjustment(&mut self, adjustment: PansharpenExtentAdjustment) -> &mut Self {
+        self.extent_adjustment = Some(adjustment);
+        self
+    }
+
+    /// Render these options into the VRT definition of `band_count` spectral bands, which
+    /// are all output.
+    fn to_xml(&self, band_count: usize) -> String {
+        let mut xml = String::from(
+            "<VRTDataset subClass=\"VRTPansharpenedDataset\"><PansharpeningOptions>\
+             <Algorithm>WeightedBrovey</Algorithm>",
+        );
+        if !self.weights.is_empty() {
+            let weights = self
+                .weights
+                .iter()
+                .map(|w| w.to_string())
+                .collect::<Vec<_>>();
+            write!(
+                xml,
+                "<AlgorithmOptions><Weights>{}</Weights></AlgorithmOptions>",
+                weights.join(",")
+            )
+            .unwrap();
+        }
+        if let Some(resampling) = self.resampling {
+            write!(
+                xml,
+                "<Resampling>{}</Resampling>",
+                resampling_name(resampling)
+            )
+            .unwrap();
+        }
+        if let Some(threads) = self.num_threads {
+            match threads {
+                0 => xml.push_str("<NumThreads>ALL_CPUS</NumThreads>"),
+                n => write!(xml, "<NumThreads>{n}</NumThreads>").unwrap(),
+            }
+        }
+        if let Some(bits) = self.bit_depth {
+            write!(xml, "<BitDepth>{bits}</BitDepth>").unwrap();
+        }
+        match self.no_data {
+            Some(Some(no_data)) => write!(xml, "<NoData>{no_data}</NoData>").unwrap(),
+            Some(None) => xml.push_str("<NoData>None</NoData>"),
+            None => {}
+        }
+        if let Some(adjustment) = self.extent_adjustment {
+            write!(
+                xml,
+                "<SpatialExtentAdjustment>{}</SpatialExtentAdjustment>",
+                adjustment.to_gdal()
+            )
+            .unwrap();
+        }
+        for band in 1..=band_count {
+            write!(xml, "<SpectralBand dstBand=\"{band}\"/>").unwrap();
+        }
+        xml.push_str("</PansharpeningOptions></VRTDataset>");
+        xml
+    }
+}
+
+/// Name of a resampling method, as parsed by `GDALRasterIOGetResampleAlg`.
+fn resampling_name(resampling: ResampleAlg) -> &'static str {
+    match resampling {
+        ResampleAlg::NearestNeighbour => "Nearest",
+        ResampleAlg::Bilinear => "Bilinear",
+        ResampleAlg::Cubic => "Cubic",
+        ResampleAlg::CubicSpline => "CubicSpline",
+        ResampleAlg::Lanczos => "Lanczos",
+        ResampleAlg::Average => "Average",
+        ResampleAlg::Mode => "Mode",
+        ResampleAlg::Gauss => "Gauss",
+    }
+}
+
+/// A pansharpened VRT created by [`create_pansharpened_vrt`].
+///
+/// Dereferences to [`Dataset`]. It reads the bands it was created from, so it cannot outlive
+/// their datasets.
+#[derive(Debug)]
+pub struct PansharpenedDataset<'a> {
+    dataset: Dataset,
+    _bands: PhantomData<&'a Dataset>,
+}
+
+impl Deref for PansharpenedDataset<'_> {
+    type Target = Dataset;
+
+    fn deref(&self) -> &Self::Target {
+        &self.dataset
+    }
+}
+
+/// Create a virtual dataset that sharpens the low resolution `spectral` bands with the
+/// high resolution `panchro` band.
+///
+/// The output has the resolution of the panchromatic band, and one band per spectral band.
+/// Its pixels are computed on the fly when read, so it is typically copied with
+/// [`Dataset::create_copy`] to write the result.
+///
+/// # Example
+///
+/// ```rust, no_run
+/// # fn main() -> gdal::errors::Result<()> {
+/// use gdal::raster::{create_pansharpened_vrt, PansharpenOptions, RasterCreationOptions};
+/// use gdal::{Dataset, DriverManager};
+///
+/// let pan = Dataset::open("/data/pan.tif")?;
+/// let ms = Dataset::open("/data/multispectral.tif")?;
+/// let spectral = (1..=3)
+///     .map(|i| ms.rasterband(i))
+///     .collect::<gdal::errors::Result<Vec<_>>>()?;
+/// let mut options = PansharpenOptions::new();
+/// options.with_weights(&[0.3, 0.4, 0.3]).with_num_threads(0);
+/// let vrt = create_pansharpened_vrt(&pan.rasterband(1)?, &spectral, &options)?;
+///
+/// let driver = DriverManager::get_driver_by_name("GTiff")?;
+/// vrt.create_copy(&driver, "/tmp/sharpened.tif", &RasterCreationOptions::new())?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// See: [`GDALCreatePansharpenedVRT`](https://gdal.org/api/raster_c_api.html#_CPPv425GDALCreatePansharpenedVRTPKc15GDALRasterBandHiP15GDALRasterBandH)
+pub fn create_pansharpened_vrt<'a>(
+    panchro: &RasterBand<'a>,
+    spectral: &[RasterBand<'a>],
+    options: &PansharpenOptions,
+) -> Result<PansharpenedDataset<'a>> {
+    if spectral.is_empty() {
+        return Err(GdalError::BadArgument(
+            "at least one spectral band is required".to_string(),
+        ));
+    }
+    let xml = CString::new(options.to_xml(spectral.len()))?;
+    let mut c_spectral = spectral
+        .iter()
+        .map(|band| unsafe { band.c_rasterband() })
+        .collect::<Vec<GDALRasterBandH>>();
+    let c_dataset = unsafe {
+        gdal_sys::GDALCreatePansharpenedVRT(
+            xml.as_ptr(),
+            panchro.c_rasterband(),
+            c_spectral.len().try_into()?,
+            c_spectral.as_mut_ptr(),
+        )
+    };
+    if c_dataset.is_null() {
+        return Err(_last_null_pointer_err("GDALCreatePansharpenedVRT"));
+    }
+    Ok(PansharpenedDataset {
+        dataset: unsafe { Dataset::from_c_dataset(c_dataset) },
+        _bands: PhantomData,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::raster::{Buffer, RasterCreationOptions};
+    use crate::test_utils::SuppressGDALErrorLog;
+    use crate::DriverManager;
+
+    /// A 4x4 panchromatic band of 100, and 2x2 spectral bands of 50, 100 and 150 over the
+    /// same extent.
+    fn inputs() -> (Dataset, Dataset) {
+        let driver = DriverManager::get_driver_by_name("MEM").unwrap();
+        let mut pan = driver.create_with_band_type::<u8, _>("", 4, 4, 1).unwrap();
+        pan.set_geo_transform(&[0., 1., 0., 4., 0., -1.]).unwrap();
+        pan.rasterband(1).unwrap().fill(100., None).unwrap();
+
+        let mut ms = driver.create_with_band_type::<u8, _>("", 2, 2, 3).unwrap();
+        ms.set_geo_transform(&[0., 2., 0., 4., 0., -2.]).unwrap();
+        for (i, value) in [50., 100., 150.].into_iter().enumerate() {
+            ms.rasterband(i + 1).unwrap().fill(value, None).unwrap();
+        }
+        (pan, ms)
+    }
+
+    fn spectral_bands(ms: &Dataset) -> Vec<RasterBand<'_>> {
+        (1..=ms.raster_count())
+            .map(|i| ms.rasterband(i).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_create_pansharpened_vrt() {
+        let (pan, ms) = inputs();
+        let mut options = PansharpenOptions::new();
+        options
+            .with_resampling(ResampleAlg::NearestNeighbour)
+            .with_weights(&[1. / 3., 1. / 3., 1. / 3.]);
+        let vrt =
+            create_pansharpened_vrt(&pan.rasterband(1).unwrap(), &spectral_bands(&ms), &options)
+                .unwrap();
+        assert_eq!(vrt.raster_size(), (4, 4));
+        assert_eq!(vrt.raster_count(), 3);
+
+        // the pseudo panchromatic intensity matches the panchromatic band, so the spectral
+        // values are kept
+        let driver = DriverManager::get_driver_by_name("MEM").unwrap();
+        let copy = vrt
+            .create_copy(&driver, "", &RasterCreationOptions::new())
+            .unwrap();
+        for (i, value) in [50, 100, 150].into_iter().enumerate() {
+            let buf: Buffer<u8> = copy.rasterband(i + 1).unwrap().read_band_as().unwrap();
+            assert!(buf.data().iter().all(|&v| v == value));
+        }
+    }
+
+    #[test]
+    fn test_create_pansharpened_vrt_errors() {
+        let _nolog = SuppressGDALErrorLog::new();
+        let (pan, ms) = inputs();
+        let panchro = pan.rasterband(1).unwrap();
+        let options = PansharpenOptions::new();
+        assert!(matches!(
+            create_pansharpened_vrt(&panchro, &[], &options),
+            Err(GdalError::BadArgument(_))
+        ));
+
+        let mut options = PansharpenOptions::new();
+        options.with_weights(&[0.5, 0.5]);
+        assert!(create_pansharpened_vrt(&panchro, &spectral_bands(&ms), &options).is_err());
+    }
+}
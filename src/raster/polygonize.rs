@@ -0,0 +1,192 @@
+use std::ptr;
+
+use gdal_sys::CPLErr;
+
+use crate::cpl::CslStringList;
+use crate::errors::*;
+use crate::progress::{cancelled_or, progress_args, CancellationToken};
+use crate::raster::contour::field_index;
+use crate::raster::RasterBand;
+use crate::utils::_last_cpl_err;
+use crate::vector::LayerAccess;
+
+/// Options for [`polygonize`].
+#[derive(Debug, Clone, Default)]
+pub struct PolygonizeOptions {
+    eight_connected: bool,
+    floating_point: bool,
+    cancellation: Option<CancellationToken>,
+}
+
+impl PolygonizeOptions {
+    /// Create the default options: 4-connected pixels, compared as integers.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Consider diagonal pixels as connected (`8CONNECTED=8`), instead of only the horizontal
+    /// and vertical ones.
+    pub fn with_eight_connectedness(&mut self, state: bool) -> &mut Self {
+        self.eight_connected = state;
+        self
+    }
+
+    /// Compare the pixel values as 32-bit floats with `GDALFPolygonize`, instead of as 32-bit
+    /// integers with `GDALPolygonize`, which truncates the fractional part of the values.
+    pub fn with_floating_point(&mut self, state: bool) -> &mut Self {
+        self.floating_point = state;
+        self
+    }
+
+    /// Abort the polygonization with [`GdalError::Cancelled`] once `token` is cancelled.
+    pub fn with_cancellation(&mut self, token: &CancellationToken) -> &mut Self {
+        self.cancellation = Some(token.clone());
+        self
+    }
+
+    fn to_options_list(&self) -> Result<CslStringList> {
+        let mut opts = CslStringList::new();
+        if self.eight_connected {
+            opts.set_name_value("8CONNECTED", "8")?;
+        }
+        Ok(opts)
+    }
+}
+
+/// Create a polygon feature in `layer` for each connected region of pixels of `band` sharing the
+/// same value.
+///
+/// The pixels which are zero in `mask` are skipped. Without a mask, all the pixels are polygonized,
+/// including those equal to the band's nodata value; pass
+/// [`RasterBand::open_mask_band`] to skip them.
+///
+/// If `pixel_value_field` is set, the value of the pixels of each polygon is written to the
+/// field of this name, which must already exist in `layer`.
+///
+/// # Example
+///
+/// ```rust, no_run
+/// # fn main() -> gdal::errors::Result<()> {
+/// use gdal::raster::{polygonize, PolygonizeOptions};
+/// use gdal::vector::{LayerAccess, LayerOptions, OGRFieldType};
+/// use gdal::{Dataset, DriverManager};
+///
+/// let classes = Dataset::open("classes.tif")?;
+/// let band = classes.rasterband(1)?;
+/// let driver = DriverManager::get_driver_by_name("GPKG")?;
+/// let mut polygons = driver.create_vector_only("target/classes.gpkg")?;
+/// let mut layer = polygons.create_layer(LayerOptions {
+///     name: "classes",
+///     ..Default::default()
+/// })?;
+/// layer.create_defn_fields(&[("class", OGRFieldType::OFTInteger)])?;
+///
+/// let mut options = PolygonizeOptions::new();
+/// options.with_eight_connectedness(true);
+/// polygonize(&band, Some(&band.open_mask_band()?), &mut layer, Some("class"), &options)?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// See: [`GDALPolygonize`](https://gdal.org/api/gdal_alg.html#_CPPv414GDALPolygonize15GDALRasterBandH15GDALRasterBandH9OGRLayerHiPPc16GDALProgressFuncPv)
+/// and [`GDALFPolygonize`](https://gdal.org/api/gdal_alg.html#_CPPv415GDALFPolygonize15GDALRasterBandH15GDALRasterBandH9OGRLayerHiPPc16GDALProgressFuncPv)
+pub fn polygonize<L: LayerAccess>(
+    band: &RasterBand,
+    mask: Option<&RasterBand>,
+    layer: &mut L,
+    pixel_value_field: Option<&str>,
+    options: &PolygonizeOptions,
+) -> Result<()> {
+    let field = match pixel_value_field {
+        Some(name) => field_index(layer, name)?,
+        None => -1,
+    };
+    let opts = options.to_options_list()?;
+    let c_mask = mask.map_or(ptr::null_mut(), |mask| unsafe { mask.c_rasterband() });
+    let polygonize_fn = if options.floating_point {
+        gdal_sys::GDALFPolygonize
+    } else {
+        gdal_sys::GDALPolygonize
+    };
+    let token = options.cancellation.as_ref();
+    let (progress, progress_arg) = progress_args(token);
+    let rv = unsafe {
+        polygonize_fn(
+            band.c_rasterband(),
+            c_mask,
+            layer.c_layer(),
+            field,
+            opts.as_ptr(),
+            progress,
+            progress_arg,
+        )
+    };
+    if rv != CPLErr::CE_None {
+        return Err(cancelled_or(token, _last_cpl_err(rv)));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::raster::Buffer;
+    use crate::vector::{LayerOptions, OGRFieldType};
+    use crate::{Dataset, DriverManager};
+
+    fn raster<T: crate::raster::GdalType + Copy>(data: Vec<T>) -> Dataset {
+        let driver = DriverManager::get_driver_by_name("MEM").unwrap();
+        let ds = driver.create_with_band_type::<T, _>("", 3, 3, 1).unwrap();
+        ds.rasterband(1)
+            .unwrap()
+            .write((0, 0), (3, 3), &mut Buffer::new((3, 3), data))
+            .unwrap();
+        ds
+    }
+
+    fn values(
+        ds: &Dataset,
+        options: &PolygonizeOptions,
+        field_type: OGRFieldType::Type,
+    ) -> Vec<f64> {
+        let band = ds.rasterband(1).unwrap();
+        let driver = DriverManager::get_driver_by_name("Memory").unwrap();
+        let mut out = driver.create_vector_only("").unwrap();
+        let mut layer = out
+            .create_layer(LayerOptions {
+                name: "polygons",
+                ty: gdal_sys::OGRwkbGeometryType::wkbPolygon,
+                ..Default::default()
+            })
+            .unwrap();
+        layer.create_defn_fields(&[("value", field_type)]).unwrap();
+        // the band is its own mask, skipping the zeros
+        polygonize(&band, Some(&band), &mut layer, Some("value"), options).unwrap();
+        let mut values = layer
+            .features()
+            .map(|f| f.field_as_double_by_name("value").unwrap().unwrap())
+            .collect::<Vec<_>>();
+        values.sort_by(f64::total_cmp);
+        values
+    }
+
+    #[test]
+    fn test_polygonize() {
+        let ds = raster::<u8>(vec![1, 0, 0, 0, 1, 0, 0, 0, 2]);
+        let mut options = PolygonizeOptions::new();
+        assert_eq!(
+            values(&ds, &options, OGRFieldType::OFTInteger),
+            [1., 1., 2.]
+        );
+        options.with_eight_connectedness(true);
+        assert_eq!(values(&ds, &options, OGRFieldType::OFTInteger), [1., 2.]);
+    }
+
+    #[test]
+    fn test_polygonize_floating_point() {
+        let ds = raster::<f32>(vec![1.5, 1.5, 1.5, 2.5, 2.5, 2.5, 0., 0., 0.]);
+        let mut options = PolygonizeOptions::new();
+        options.with_floating_point(true);
+        assert_eq!(values(&ds, &options, OGRFieldType::OFTReal), [1.5, 2.5]);
+    }
+}
@@ -11,7 +11,7 @@ use super::options::CommonOptions;
 #[derive(Debug, Clone)]
 pub struct ColorReliefOptions {
     common_options: CommonOptions,
-    color_config: PathBuf,
+    color_config: ColorConfig,
     alpha: Option<bool>,
     color_matching_mode: ColorMatchingMode,
 }
@@ -57,7 +57,31 @@ impl ColorReliefOptions {
     pub fn new<P: AsRef<Path>>(color_config: P) -> Self {
         Self {
             common_options: Default::default(),
-            color_config: color_config.as_ref().to_path_buf(),
+            color_config: ColorConfig::File(color_config.as_ref().to_path_buf()),
+            alpha: None,
+            color_matching_mode: Default::default(),
+        }
+    }
+
+    /// Create a DEM-color-relief options set from in-memory color entries, instead of a color
+    /// configuration file.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use gdal::raster::processing::dem::{ColorEntry, ColorReliefOptions};
+    ///
+    /// let opts = ColorReliefOptions::from_entries([
+    ///     ColorEntry::new(2600., [255, 255, 255, 255]),
+    ///     ColorEntry::percent(50., [190, 185, 135, 255]),
+    ///     ColorEntry::new(100., [50, 180, 50, 128]),
+    ///     ColorEntry::no_data([0, 0, 0, 0]),
+    /// ]);
+    /// ```
+    pub fn from_entries<I: IntoIterator<Item = ColorEntry>>(entries: I) -> Self {
+        Self {
+            common_options: Default::default(),
+            color_config: ColorConfig::Entries(entries.into_iter().collect()),
             alpha: None,
             color_matching_mode: Default::default(),
         }
@@ -79,7 +103,7 @@ impl ColorReliefOptions {
         self
     }
 
-    pub(crate) fn color_config(&self) -> &Path {
+    pub(crate) fn color_config(&self) -> &ColorConfig {
         &self.color_config
     }
 
@@ -104,6 +128,81 @@ impl ColorReliefOptions {
     }
 }
 
+/// Source of the colors of [`color_relief()`][super::color_relief()].
+#[derive(Debug, Clone)]
+pub(crate) enum ColorConfig {
+    /// A color configuration file.
+    File(PathBuf),
+    /// In-memory entries, written to a temporary `/vsimem` file when processing.
+    Entries(Vec<ColorEntry>),
+}
+
+impl ColorConfig {
+    /// Render `entries` in the syntax of color configuration files.
+    pub(crate) fn entries_to_text(entries: &[ColorEntry]) -> String {
+        entries
+            .iter()
+            .map(|entry| {
+                let [r, g, b, a] = entry.color;
+                let elevation = match entry.elevation {
+                    ColorElevation::Value(value) => value.to_string(),
+                    ColorElevation::Percent(percent) => format!("{percent}%"),
+                    ColorElevation::NoData => "nv".to_string(),
+                };
+                format!("{elevation} {r} {g} {b} {a}\n")
+            })
+            .collect()
+    }
+}
+
+/// Elevation of a [`ColorEntry`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ColorElevation {
+    /// An elevation value.
+    Value(f64),
+    /// A percentage, 0% being the minimum value found in the raster, 100% the maximum value.
+    Percent(f64),
+    /// The no-data value.
+    NoData,
+}
+
+/// Color of an elevation, as an in-memory line of a color configuration file.
+///
+/// See [`ColorReliefOptions::from_entries`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ColorEntry {
+    /// The elevation this color applies to.
+    pub elevation: ColorElevation,
+    /// The _Red_, _Green_, _Blue_ and _Alpha_ components.
+    pub color: [u8; 4],
+}
+
+impl ColorEntry {
+    /// Create the entry of an elevation value.
+    pub fn new(elevation: f64, color: [u8; 4]) -> Self {
+        Self {
+            elevation: ColorElevation::Value(elevation),
+            color,
+        }
+    }
+
+    /// Create the entry of a percentage of the elevation range of the raster.
+    pub fn percent(percent: f64, color: [u8; 4]) -> Self {
+        Self {
+            elevation: ColorElevation::Percent(percent),
+            color,
+        }
+    }
+
+    /// Create the entry of the no-data value.
+    pub fn no_data(color: [u8; 4]) -> Self {
+        Self {
+            elevation: ColorElevation::NoData,
+            color,
+        }
+    }
+}
+
 /// Color relief color matching mode
 #[derive(Debug, Clone, Copy, Default)]
 pub enum ColorMatchingMode {
@@ -149,6 +248,27 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_color_entries() -> Result<()> {
+        let entries = [
+            ColorEntry::new(2600., [255, 255, 255, 255]),
+            ColorEntry::percent(50.5, [190, 185, 135, 255]),
+            ColorEntry::no_data([0, 0, 0, 0]),
+        ];
+        assert_eq!(
+            ColorConfig::entries_to_text(&entries),
+            "2600 255 255 255 255\n50.5% 190 185 135 255\nnv 0 0 0 0\n"
+        );
+
+        let ds = Dataset::open(fixture("dem-hills.tiff"))?;
+        let mut opts = ColorReliefOptions::from_entries(entries);
+        opts.with_alpha(true);
+        let output = InMemoryFixture::new("dem-hills-relief-entries.tiff");
+        let cr = color_relief(&ds, output.path(), &opts)?;
+        assert_eq!(cr.raster_count(), 4);
+        Ok(())
+    }
+
     #[test]
     fn test_color_relief() -> Result<()> {
         let ds = Dataset::open(fixture("dem-hills.tiff"))?;
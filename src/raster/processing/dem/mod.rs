@@ -25,12 +25,14 @@
 #![deny(missing_docs)]
 
 use std::ffi::{CStr, CString};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::ptr;
+use std::sync::atomic::{AtomicUsize, Ordering};
 
 use libc::c_int;
 
 pub use aspect::*;
+use color_relief::ColorConfig;
 pub use color_relief::*;
 use gdal_sys::{CPLErr, GDALDEMProcessing};
 pub use hillshade::*;
@@ -43,6 +45,7 @@ pub use tri::*;
 use crate::cpl::CslStringList;
 use crate::errors::Result;
 use crate::utils::{_last_cpl_err, _path_to_c_string};
+use crate::vsi::{create_mem_file, unlink_mem_file};
 use crate::Dataset;
 
 mod aspect;
@@ -119,7 +122,8 @@ pub fn aspect<P: AsRef<Path>>(
 /// the elevation and a text-based color configuration file.
 ///
 /// The color configuration file contains associations between various elevation values
-/// and the corresponding desired color. See [`ColorReliefOptions::new`] for details.
+/// and the corresponding desired color. See [`ColorReliefOptions::new`] for details, and
+/// [`ColorReliefOptions::from_entries`] to pass them without a file.
 ///
 /// By default, the colors between the given elevation
 /// values are blended smoothly and the result is a nice colorized DEM.
@@ -163,14 +167,32 @@ pub fn color_relief<P: AsRef<Path>>(
     dest_file: P,
     options: &ColorReliefOptions,
 ) -> Result<Dataset> {
-    let colors = options.color_config();
-    dem_eval(
-        ds,
-        dest_file.as_ref(),
-        DemAlg::ColorRelief,
-        &options.to_options_list()?,
-        Some(colors),
-    )
+    let opts = options.to_options_list()?;
+    match options.color_config() {
+        ColorConfig::File(colors) => dem_eval(
+            ds,
+            dest_file.as_ref(),
+            DemAlg::ColorRelief,
+            &opts,
+            Some(colors),
+        ),
+        ColorConfig::Entries(entries) => {
+            static COLOR_FILE_ID: AtomicUsize = AtomicUsize::new(0);
+            let id = COLOR_FILE_ID.fetch_add(1, Ordering::Relaxed);
+            let colors = PathBuf::from(format!("/vsimem/gdal-rs-color-relief-{id}.txt"));
+            let text = ColorConfig::entries_to_text(entries);
+            create_mem_file(&colors, text.into_bytes())?;
+            let result = dem_eval(
+                ds,
+                dest_file.as_ref(),
+                DemAlg::ColorRelief,
+                &opts,
+                Some(&colors),
+            );
+            unlink_mem_file(&colors)?;
+            result
+        }
+    }
 }
 
 /// Performs hill-shade rendering of DEM data.
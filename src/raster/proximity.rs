@@ -0,0 +1,214 @@
+use gdal_sys::CPLErr;
+
+use crate::cpl::CslStringList;
+use crate::errors::*;
+use crate::progress::{cancelled_or, progress_args, CancellationToken};
+use crate::raster::RasterBand;
+use crate::utils::_last_cpl_err;
+
+/// Units of the distances computed by [`compute_proximity`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DistanceUnits {
+    /// Pixels (the default).
+    #[default]
+    Pixel,
+    /// Georeferenced units, from the geo-transform of the dataset.
+    Geo,
+}
+
+/// Options for [`compute_proximity`].
+#[derive(Debug, Clone, Default)]
+pub struct ProximityOptions {
+    target_values: Vec<f64>,
+    units: DistanceUnits,
+    max_distance: Option<f64>,
+    no_data_value: Option<f64>,
+    use_input_no_data: bool,
+    fixed_value: Option<f64>,
+    cancellation: Option<CancellationToken>,
+}
+
+impl ProximityOptions {
+    /// Create the default options: distances in pixels to the non-zero pixels, with no maximum.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Compute the distances to the pixels with one of these values (`VALUES`), instead of
+    /// to the non-zero pixels.
+    pub fn with_target_values(&mut self, values: &[f64]) -> &mut Self {
+        self.target_values = values.to_vec();
+        self
+    }
+
+    /// Set the units of the distances (`DISTUNITS`).
+    pub fn with_units(&mut self, units: DistanceUnits) -> &mut Self {
+        self.units = units;
+        self
+    }
+
+    /// Only compute the distances up to `distance` (`MAXDIST`), in the units of the distances.
+    ///
+    /// The farther pixels are set to the nodata value.
+    pub fn with_max_distance(&mut self, distance: f64) -> &mut Self {
+        self.max_distance = Some(distance);
+        self
+    }
+
+    /// Set the value of the pixels farther than the maximum distance (`NODATA`).
+    ///
+    /// Defaults to the nodata value of the output band, or else to `255` for bytes and `65535`
+    /// for the other types.
+    pub fn with_no_data_value(&mut self, no_data_value: f64) -> &mut Self {
+        self.no_data_value = Some(no_data_value);
+        self
+    }
+
+    /// Keep the nodata pixels of the input as nodata in the output (`USE_INPUT_NODATA`).
+    pub fn with_input_no_data(&mut self, state: bool) -> &mut Self {
+        self.use_input_no_data = state;
+        self
+    }
+
+    /// Write `value` to the pixels within the maximum distance (`FIXED_BUF_VAL`), instead of
+    /// their distance, e.g. to compute buffers.
+    pub fn with_fixed_value(&mut self, value: f64) -> &mut Self {
+        self.fixed_value = Some(value);
+        self
+    }
+
+    /// Abort the computation with [`GdalError::Cancelled`] once `token` is cancelled.
+    pub fn with_cancellation(&mut self, token: &CancellationToken) -> &mut Self {
+        self.cancellation = Some(token.clone());
+        self
+    }
+
+    fn to_options_list(&self) -> Result<CslStringList> {
+        let mut opts = CslStringList::new();
+        if !self.target_values.is_empty() {
+            let values = self
+                .target_values
+                .iter()
+                .map(|v| v.to_string())
+                .collect::<Vec<_>>();
+            opts.set_name_value("VALUES", &values.join(","))?;
+        }
+        if self.units == DistanceUnits::Geo {
+            opts.set_name_value("DISTUNITS", "GEO")?;
+        }
+        if let Some(distance) = self.max_distance {
+            opts.set_name_value("MAXDIST", &distance.to_string())?;
+        }
+        if let Some(no_data_value) = self.no_data_value {
+            opts.set_name_value("NODATA", &no_data_value.to_string())?;
+        }
+        if self.use_input_no_data {
+            opts.set_name_value("USE_INPUT_NODATA", "YES")?;
+        }
+        if let Some(value) = self.fixed_value {
+            opts.set_name_value("FIXED_BUF_VAL", &value.to_string())?;
+        }
+        Ok(opts)
+    }
+}
+
+/// Write to `dst_band` the distance of each pixel of `src_band` to the nearest target pixel.
+///
+/// Both bands must have the same size.
+///
+/// # Example
+///
+/// ```rust, no_run
+/// # fn main() -> gdal::errors::Result<()> {
+/// use gdal::raster::{compute_proximity, DistanceUnits, ProximityOptions};
+/// use gdal::{Dataset, DriverManager};
+///
+/// let roads = Dataset::open("roads.tif")?;
+/// let (cols, rows) = roads.raster_size();
+/// let driver = DriverManager::get_driver_by_name("GTiff")?;
+/// let mut distances = driver.create_with_band_type::<f32, _>("distances.tif", cols, rows, 1)?;
+/// distances.set_geo_transform(&roads.geo_transform()?)?;
+///
+/// let mut options = ProximityOptions::new();
+/// options
+///     .with_units(DistanceUnits::Geo)
+///     .with_max_distance(1000.);
+/// compute_proximity(&roads.rasterband(1)?, &mut distances.rasterband(1)?, &options)?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// See: [`GDALComputeProximity`](https://gdal.org/api/gdal_alg.html#_CPPv420GDALComputeProximity15GDALRasterBandH15GDALRasterBandHPPc16GDALProgressFuncPv)
+pub fn compute_proximity(
+    src_band: &RasterBand,
+    dst_band: &mut RasterBand,
+    options: &ProximityOptions,
+) -> Result<()> {
+    let opts = options.to_options_list()?;
+    let token = options.cancellation.as_ref();
+    let (progress, progress_arg) = progress_args(token);
+    let rv = unsafe {
+        gdal_sys::GDALComputeProximity(
+            src_band.c_rasterband(),
+            dst_band.c_rasterband(),
+            opts.as_ptr(),
+            progress,
+            progress_arg,
+        )
+    };
+    if rv != CPLErr::CE_None {
+        return Err(cancelled_or(token, _last_cpl_err(rv)));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::raster::Buffer;
+    use crate::{Dataset, DriverManager};
+
+    fn compute(src: &Dataset, options: &ProximityOptions) -> Vec<f32> {
+        let driver = DriverManager::get_driver_by_name("MEM").unwrap();
+        let mut dst = driver.create_with_band_type::<f32, _>("", 5, 1, 1).unwrap();
+        dst.set_geo_transform(&src.geo_transform().unwrap())
+            .unwrap();
+        let mut band = dst.rasterband(1).unwrap();
+        compute_proximity(&src.rasterband(1).unwrap(), &mut band, options).unwrap();
+        band.read_band_as::<f32>().unwrap().into_shape_and_vec().1
+    }
+
+    #[test]
+    fn test_compute_proximity() {
+        let driver = DriverManager::get_driver_by_name("MEM").unwrap();
+        let mut src = driver.create_with_band_type::<u8, _>("", 5, 1, 1).unwrap();
+        src.set_geo_transform(&[0., 10., 0., 0., 0., -10.]).unwrap();
+        src.rasterband(1)
+            .unwrap()
+            .write(
+                (0, 0),
+                (5, 1),
+                &mut Buffer::new((5, 1), vec![1, 0, 0, 0, 2]),
+            )
+            .unwrap();
+
+        let options = ProximityOptions::new();
+        assert_eq!(compute(&src, &options), [0., 1., 2., 1., 0.]);
+
+        let mut options = ProximityOptions::new();
+        options
+            .with_target_values(&[1.])
+            .with_units(DistanceUnits::Geo)
+            .with_max_distance(25.)
+            .with_no_data_value(-1.);
+        assert_eq!(compute(&src, &options), [0., 10., 20., -1., -1.]);
+
+        let mut options = ProximityOptions::new();
+        options
+            .with_target_values(&[2.])
+            .with_max_distance(1.)
+            .with_fixed_value(9.)
+            .with_no_data_value(0.);
+        assert_eq!(compute(&src, &options), [0., 0., 0., 9., 9.]);
+    }
+}
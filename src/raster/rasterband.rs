@@ -1,7 +1,8 @@
+use crate::config::ThreadLocalConfigGuard;
 use crate::dataset::Dataset;
 use crate::gdal_major_object::MajorObject;
 use crate::metadata::Metadata;
-use crate::raster::{GdalDataType, GdalType};
+use crate::raster::{BuildOverviewsOptions, GdalDataType, GdalType};
 use crate::utils::{_last_cpl_err, _last_null_pointer_err, _string};
 use gdal_sys::{
     self, CPLErr, GDALColorEntry, GDALColorInterp, GDALColorTableH, GDALComputeRasterMinMax,
@@ -17,10 +18,13 @@ use std::marker::PhantomData;
 use std::str::FromStr;
 
 use crate::errors::*;
-use crate::raster::buffer::Buffer;
+use crate::progress::{cancelled_or, progress_args, CancellationToken};
+use crate::raster::buffer::{AnyBuffer, Buffer};
 use crate::raster::ResampleAlg::{
     Average, Bilinear, Cubic, CubicSpline, Gauss, Lanczos, Mode, NearestNeighbour,
 };
+#[cfg(feature = "num-complex")]
+use num_complex::Complex;
 
 /// [Dataset] methods for raster datasets.
 impl Dataset {
@@ -29,8 +33,15 @@ impl Dataset {
     /// Applies to raster datasets, and fetches the band at the given _1-based_ index.
     ///
     /// # Errors
-    /// Returns an error if the band cannot be read, including in the case the index is 0.
+    /// Returns [`GdalError::BadArgument`] if the index is 0 or greater than
+    /// [`raster_count`](Self::raster_count), or an error if the band cannot be read.
     pub fn rasterband(&self, band_index: usize) -> Result<RasterBand> {
+        let raster_count = self.raster_count();
+        if band_index == 0 || band_index > raster_count {
+            return Err(GdalError::BadArgument(format!(
+                "band index {band_index} is out of range, band indexes are 1-based and this dataset has {raster_count} band(s)"
+            )));
+        }
         let band_index = libc::c_int::try_from(band_index)?;
 
         unsafe {
@@ -44,7 +55,8 @@ impl Dataset {
 
     /// Get the bands of a dataset.
     ///
-    /// Returns an iterator over the [`RasterBands`]s in the current [`Dataset`].
+    /// Returns an iterator over the [`RasterBand`]s in the current [`Dataset`], in order of
+    /// their 1-based index.
     ///
     /// # Example
     ///
@@ -60,6 +72,33 @@ impl Dataset {
         (1..=self.raster_count()).map(|idx| self.rasterband(idx))
     }
 
+    /// Find the first band whose description is `description`.
+    ///
+    /// Band descriptions are often used to name the bands of multispectral images or of
+    /// stacked time series.
+    ///
+    /// # Example
+    ///
+    /// ```rust, no_run
+    /// use gdal::Dataset;
+    /// # fn main() -> gdal::errors::Result<()> {
+    /// let ds = Dataset::open("stack.tif")?;
+    /// if let Some(band) = ds.rasterband_by_description("nir")? {
+    ///     println!("{:?}", band.size());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn rasterband_by_description(&self, description: &str) -> Result<Option<RasterBand>> {
+        for band in self.rasterbands() {
+            let band = band?;
+            if band.description()? == description {
+                return Ok(Some(band));
+            }
+        }
+        Ok(None)
+    }
+
     /// Builds overviews for the current `Dataset`. See [`GDALBuildOverviews`].
     ///
     /// # Arguments
@@ -73,8 +112,69 @@ impl Dataset {
         resampling: &str,
         overviews: &[i32],
         bands: &[i32],
+    ) -> Result<()> {
+        self._build_overviews(resampling, overviews, bands, None)
+    }
+
+    /// Builds overviews like [`build_overviews`](Self::build_overviews), stopping with
+    /// [`GdalError::Cancelled`] once `token` is cancelled.
+    pub fn build_overviews_with_cancellation(
+        &mut self,
+        resampling: &str,
+        overviews: &[i32],
+        bands: &[i32],
+        token: &CancellationToken,
+    ) -> Result<()> {
+        self._build_overviews(resampling, overviews, bands, Some(token))
+    }
+
+    /// Builds overviews like [`build_overviews`](Self::build_overviews), with a typed resampling
+    /// method and GeoTIFF settings.
+    ///
+    /// # Example
+    ///
+    /// ```rust, no_run
+    /// # fn main() -> gdal::errors::Result<()> {
+    /// use gdal::Dataset;
+    /// use gdal::raster::{
+    ///     BuildOverviewsOptions, OverviewCompression, OverviewPhotometric, OverviewResampling,
+    /// };
+    ///
+    /// let mut dataset = Dataset::open("fixtures/tinymarble.tif")?;
+    /// let mut options = BuildOverviewsOptions::new();
+    /// options
+    ///     .with_resampling(OverviewResampling::Average)
+    ///     .with_compression(OverviewCompression::Jpeg)
+    ///     .with_photometric(OverviewPhotometric::YCbCr)
+    ///     .with_jpeg_quality(85);
+    /// dataset.build_overviews_with_options(&[2, 4, 8], &[], &options)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn build_overviews_with_options(
+        &mut self,
+        overviews: &[i32],
+        bands: &[i32],
+        options: &BuildOverviewsOptions,
+    ) -> Result<()> {
+        let _config = ThreadLocalConfigGuard::set(&options.config_options())?;
+        self._build_overviews(
+            options.resampling.to_gdal(),
+            overviews,
+            bands,
+            options.cancellation.as_ref(),
+        )
+    }
+
+    fn _build_overviews(
+        &mut self,
+        resampling: &str,
+        overviews: &[i32],
+        bands: &[i32],
+        token: Option<&CancellationToken>,
     ) -> Result<()> {
         let c_resampling = CString::new(resampling)?;
+        let (progress, progress_arg) = progress_args(token);
         let rv = unsafe {
             gdal_sys::GDALBuildOverviews(
                 self.c_dataset(),
@@ -83,12 +183,12 @@ impl Dataset {
                 overviews.as_ptr() as *mut i32,
                 bands.len() as i32,
                 bands.as_ptr() as *mut i32,
-                None,
-                std::ptr::null_mut(),
+                progress,
+                progress_arg,
             )
         };
         if rv != CPLErr::CE_None {
-            return Err(_last_cpl_err(rv));
+            return Err(cancelled_or(token, _last_cpl_err(rv)));
         }
         Ok(())
     }
@@ -104,6 +204,133 @@ impl Dataset {
         let size_y = unsafe { gdal_sys::GDALGetRasterYSize(self.c_dataset()) } as usize;
         (size_x, size_y)
     }
+
+    /// List the compression formats in which a window of the raster can be read
+    /// without decompression, using [`Dataset::read_compressed_data`].
+    ///
+    /// # Arguments
+    /// * `window` - the window position from top left
+    /// * `window_size` - the window size
+    /// * `bands` - 1-based indexes of the bands to read, or empty for all bands
+    ///
+    /// Returns an empty list if the window cannot be read in compressed form, e.g. because it is
+    /// not aligned on a block, or the underlying data isn't compressed in a supported format.
+    /// Formats are returned as MIME types, e.g. `"JPEG"` or `"WEBP"`, possibly followed by
+    /// semicolon-separated details.
+    ///
+    /// See [`GDALDatasetGetCompressionFormats`](https://gdal.org/api/raster_c_api.html#_CPPv432GDALDatasetGetCompressionFormats12GDALDatasetHiiiiiPKi).
+    #[cfg(any(all(major_ge_3, minor_ge_7), major_ge_4))]
+    pub fn compression_formats(
+        &self,
+        window: (usize, usize),
+        window_size: (usize, usize),
+        bands: &[usize],
+    ) -> Result<Vec<String>> {
+        let bands = bands
+            .iter()
+            .map(|&b| c_int::try_from(b))
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        let formats = unsafe {
+            gdal_sys::GDALDatasetGetCompressionFormats(
+                self.c_dataset(),
+                window.0.try_into()?,
+                window.1.try_into()?,
+                window_size.0.try_into()?,
+                window_size.1.try_into()?,
+                bands.len().try_into()?,
+                if bands.is_empty() {
+                    std::ptr::null()
+                } else {
+                    bands.as_ptr()
+                },
+            )
+        };
+        let result = crate::utils::_string_array(formats);
+        unsafe { gdal_sys::CSLDestroy(formats) };
+        Ok(result)
+    }
+
+    /// Read a window of the raster in its compressed form, without decompressing it.
+    ///
+    /// This is mostly useful to pass through already compressed tiles, e.g. from a
+    /// Cloud Optimized GeoTIFF to a tile server client, without re-encoding them.
+    ///
+    /// # Arguments
+    /// * `format` - the requested format, one of [`compression_formats`](Self::compression_formats)
+    /// * `window` - the window position from top left
+    /// * `window_size` - the window size
+    /// * `bands` - 1-based indexes of the bands to read, or empty for all bands
+    ///
+    /// See [`GDALDatasetReadCompressedData`](https://gdal.org/api/raster_c_api.html#_CPPv429GDALDatasetReadCompressedData12GDALDatasetHPKciiiiiPKiPPvP6size_tPPc).
+    #[cfg(any(all(major_ge_3, minor_ge_7), major_ge_4))]
+    pub fn read_compressed_data(
+        &self,
+        format: &str,
+        window: (usize, usize),
+        window_size: (usize, usize),
+        bands: &[usize],
+    ) -> Result<CompressedData> {
+        let c_format = CString::new(format)?;
+        let bands = bands
+            .iter()
+            .map(|&b| c_int::try_from(b))
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        let mut buffer: *mut c_void = std::ptr::null_mut();
+        let mut buffer_size: usize = 0;
+        let mut detailed_format: *mut libc::c_char = std::ptr::null_mut();
+        let rv = unsafe {
+            gdal_sys::GDALDatasetReadCompressedData(
+                self.c_dataset(),
+                c_format.as_ptr(),
+                window.0.try_into()?,
+                window.1.try_into()?,
+                window_size.0.try_into()?,
+                window_size.1.try_into()?,
+                bands.len().try_into()?,
+                if bands.is_empty() {
+                    std::ptr::null()
+                } else {
+                    bands.as_ptr()
+                },
+                &mut buffer,
+                &mut buffer_size,
+                &mut detailed_format,
+            )
+        };
+        if rv != CPLErr::CE_None {
+            return Err(_last_cpl_err(rv));
+        }
+
+        let data = if buffer.is_null() {
+            Vec::new()
+        } else {
+            let data =
+                unsafe { std::slice::from_raw_parts(buffer as *const u8, buffer_size) }.to_vec();
+            unsafe { gdal_sys::VSIFree(buffer) };
+            data
+        };
+        let format = if detailed_format.is_null() {
+            format.to_string()
+        } else {
+            let f = _string(detailed_format);
+            unsafe { gdal_sys::VSIFree(detailed_format as *mut c_void) };
+            f
+        };
+
+        Ok(CompressedData { data, format })
+    }
+}
+
+/// A window of raster data in its original compressed form.
+///
+/// Returned by [`Dataset::read_compressed_data`] and [`RasterBand::read_compressed_block`].
+#[cfg(any(all(major_ge_3, minor_ge_7), major_ge_4))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompressedData {
+    /// The compressed bytes, e.g. a complete JPEG file.
+    pub data: Vec<u8>,
+    /// The detailed format of `data`, e.g. `"JPEG;frame_type=SOF0_baseline;bit_depth=8;num_components=3"`.
+    pub format: String,
 }
 
 /// Resampling algorithms used throughout various GDAL raster I/O operations.
@@ -228,6 +455,47 @@ impl GdalMaskFlags {
     }
 }
 
+/// Whether a window of a band holds data, as returned by [`RasterBand::data_coverage_status`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DataCoverageStatus {
+    flags: i32,
+    data_percent: f64,
+}
+
+impl DataCoverageStatus {
+    const UNIMPLEMENTED: i32 = 0x01;
+    const DATA: i32 = 0x02;
+    const EMPTY: i32 = 0x04;
+
+    /// Returns `true` if the driver can't tell empty areas apart, in which case the window
+    /// is reported as entirely data.
+    pub fn is_unimplemented(&self) -> bool {
+        self.flags & Self::UNIMPLEMENTED != 0
+    }
+
+    /// Returns `true` if (part of) the window holds data.
+    pub fn has_data(&self) -> bool {
+        self.flags & Self::DATA != 0
+    }
+
+    /// Returns `true` if (part of) the window is empty, such as unwritten sparse tiles or the
+    /// gaps of a VRT mosaic. Reading it would return the nodata value, or zeros.
+    pub fn has_empty(&self) -> bool {
+        self.flags & Self::EMPTY != 0
+    }
+
+    /// Returns `true` if the window is known to be entirely empty, so that reading it can be
+    /// skipped.
+    pub fn is_empty(&self) -> bool {
+        self.has_empty() && !self.has_data()
+    }
+
+    /// Returns the percentage of the window holding data, between 0 and 100.
+    pub fn data_percent(&self) -> f64 {
+        self.data_percent
+    }
+}
+
 /// Extra options used to read a raster.
 ///
 /// For documentation, see `gdal_sys::GDALRasterIOExtraArg`.
@@ -308,6 +576,11 @@ impl<'a> RasterBand<'a> {
         self.c_rasterband
     }
 
+    /// The dataset owning this band.
+    pub(crate) fn dataset(&self) -> &'a Dataset {
+        self.dataset
+    }
+
     /// Create a RasterBand from a wrapped C pointer
     ///
     /// # Safety
@@ -426,6 +699,61 @@ impl<'a> RasterBand<'a> {
         Ok(())
     }
 
+    /// Advise the driver of an upcoming read of `window`, with the same arguments as
+    /// [`read_as`](Self::read_as), so that it can fetch or decode the data ahead.
+    ///
+    /// Drivers that don't use the hint ignore it, so this never needs to be called, but it
+    /// can speed up sequential scans of remote or wavelet-compressed (e.g. JPEG 2000, ECW)
+    /// rasters, such as by advising the next row of tiles before processing the current one.
+    ///
+    /// # Example
+    ///
+    /// ```rust, no_run
+    /// # fn main() -> gdal::errors::Result<()> {
+    /// use gdal::Dataset;
+    /// let dataset = Dataset::open("/vsicurl/https://example.com/large.tif")?;
+    /// let band = dataset.rasterband(1)?;
+    /// let (cols, rows) = band.size();
+    /// for row in (0..rows).step_by(256) {
+    ///     let height = 256.min(rows - row);
+    ///     if row + height < rows {
+    ///         let next_height = 256.min(rows - row - height);
+    ///         band.advise_read::<u8>((0, (row + height) as isize), (cols, next_height), None)?;
+    ///     }
+    ///     let buf = band.read_as::<u8>((0, row as isize), (cols, height), (cols, height), None)?;
+    ///     // process `buf`
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// See: [`GDALRasterBand::AdviseRead`](https://gdal.org/api/gdalrasterband_cpp.html#_CPPv4N14GDALRasterBand10AdviseReadEiiiiii12GDALDataType12CSLConstList)
+    pub fn advise_read<T: Copy + GdalType>(
+        &self,
+        window: (isize, isize),
+        window_size: (usize, usize),
+        shape: Option<(usize, usize)>,
+    ) -> Result<()> {
+        let shape = shape.unwrap_or(window_size);
+        let rv = unsafe {
+            gdal_sys::GDALRasterAdviseRead(
+                self.c_rasterband,
+                window.0.try_into()?,
+                window.1.try_into()?,
+                window_size.0.try_into()?,
+                window_size.1.try_into()?,
+                shape.0.try_into()?,
+                shape.1.try_into()?,
+                T::gdal_ordinal(),
+                std::ptr::null_mut(),
+            )
+        };
+        if rv != CPLErr::CE_None {
+            return Err(_last_cpl_err(rv));
+        }
+        Ok(())
+    }
+
     /// Read a [`Buffer<T>`] from this band, where `T` implements [`GdalType`].
     ///
     /// # Arguments
@@ -503,6 +831,67 @@ impl<'a> RasterBand<'a> {
         Ok(Buffer::new(shape, data))
     }
 
+    /// Read a window of this band into an [`AnyBuffer`], using the band's own data type.
+    ///
+    /// This avoids having to match on [`band_type`](Self::band_type) and call
+    /// [`read_as`](Self::read_as) with the corresponding type parameter.
+    ///
+    /// # Arguments
+    /// * `window` - the window position from top left
+    /// * `window_size` - the window size (GDAL will interpolate data if `window_size` != `buffer_size`)
+    /// * `buffer_size` - the desired size of the buffer
+    /// * `e_resample_alg` - the resample algorithm used for the interpolation. Default: `NearestNeighbor`.
+    ///
+    /// # Errors
+    /// Returns [`GdalError::BadArgument`] for data types not supported by these bindings
+    /// (e.g. complex types, unless the `complex` feature is enabled).
+    pub fn read_generic(
+        &self,
+        window: (isize, isize),
+        window_size: (usize, usize),
+        buffer_size: (usize, usize),
+        e_resample_alg: Option<ResampleAlg>,
+    ) -> Result<AnyBuffer> {
+        macro_rules! read {
+            ($ty:ty) => {
+                self.read_as::<$ty>(window, window_size, buffer_size, e_resample_alg)?
+                    .into()
+            };
+        }
+
+        let buffer = match self.band_type() {
+            GdalDataType::UInt8 => read!(u8),
+            #[cfg(any(all(major_ge_3, minor_ge_7), major_ge_4))]
+            GdalDataType::Int8 => read!(i8),
+            GdalDataType::UInt16 => read!(u16),
+            GdalDataType::Int16 => read!(i16),
+            GdalDataType::UInt32 => read!(u32),
+            GdalDataType::Int32 => read!(i32),
+            #[cfg(all(major_ge_3, minor_ge_5))]
+            GdalDataType::UInt64 => read!(u64),
+            #[cfg(all(major_ge_3, minor_ge_5))]
+            GdalDataType::Int64 => read!(i64),
+            #[cfg(all(feature = "half", any(all(major_ge_3, minor_ge_11), major_ge_4)))]
+            GdalDataType::Float16 => read!(half::f16),
+            GdalDataType::Float32 => read!(f32),
+            GdalDataType::Float64 => read!(f64),
+            #[cfg(feature = "num-complex")]
+            GdalDataType::CInt16 => read!(Complex<i16>),
+            #[cfg(feature = "num-complex")]
+            GdalDataType::CInt32 => read!(Complex<i32>),
+            #[cfg(feature = "num-complex")]
+            GdalDataType::CFloat32 => read!(Complex<f32>),
+            #[cfg(feature = "num-complex")]
+            GdalDataType::CFloat64 => read!(Complex<f64>),
+            t => {
+                return Err(GdalError::BadArgument(format!(
+                    "unsupported band data type: {t}"
+                )))
+            }
+        };
+        Ok(buffer)
+    }
+
     /// Read the full band as a [`Buffer<T>`], where `T` implements [`GdalType`].
     pub fn read_band_as<T: Copy + GdalType>(&self) -> Result<Buffer<T>> {
         let size = self.size();
@@ -686,6 +1075,210 @@ impl<'a> RasterBand<'a> {
         Ok(())
     }
 
+    /// Write pixels from a slice into a window of this band, without copying them into a
+    /// [`Buffer`] first.
+    ///
+    /// # Arguments
+    /// * `window` - the window position from top left
+    /// * `window_size` - the window size (GDAL will interpolate data if window_size != size)
+    /// * `size` - the size of the data in `data`, as `(cols, rows)`
+    /// * `data` - the pixels to write
+    /// * `strides` - the distance between two consecutive pixels and two consecutive lines
+    ///   in `data`, in elements of `T`. Default: `(1, size.0)`, i.e. packed row-major data.
+    ///
+    /// The strides can be used to write one band of pixel-interleaved data, or a window of a
+    /// larger image, directly from the caller's memory.
+    ///
+    /// # Example
+    ///
+    /// ```rust, no_run
+    /// # fn main() -> gdal::errors::Result<()> {
+    /// use gdal::DriverManager;
+    /// let driver = DriverManager::get_driver_by_name("MEM")?;
+    /// let dataset = driver.create("", 2, 2, 3)?;
+    /// // RGB pixels, interleaved
+    /// let rgb = [255u8, 0, 0, 0, 255, 0, 0, 0, 255, 255, 255, 255];
+    /// for b in 0..3 {
+    ///     let mut band = dataset.rasterband(b + 1)?;
+    ///     band.write_from_slice((0, 0), (2, 2), (2, 2), &rgb[b..], Some((3, 6)))?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn write_from_slice<T: Copy + GdalType>(
+        &mut self,
+        window: (isize, isize),
+        window_size: (usize, usize),
+        size: (usize, usize),
+        data: &[T],
+        strides: Option<(usize, usize)>,
+    ) -> Result<()> {
+        let (pixel_stride, line_stride) = strides.unwrap_or((1, size.0));
+        let overflow = || GdalError::BadArgument("buffer strides overflow".into());
+        let required = if size.0 == 0 || size.1 == 0 {
+            0
+        } else {
+            (size.1 - 1)
+                .checked_mul(line_stride)
+                .and_then(|n| n.checked_add((size.0 - 1).checked_mul(pixel_stride)?))
+                .and_then(|n| n.checked_add(1))
+                .ok_or_else(overflow)?
+        };
+        if data.len() < required {
+            return Err(GdalError::BufferSizeMismatch(data.len(), size));
+        }
+
+        let elem = std::mem::size_of::<T>();
+        let pixel_space = pixel_stride.checked_mul(elem).ok_or_else(overflow)?;
+        let line_space = line_stride.checked_mul(elem).ok_or_else(overflow)?;
+        let rv = unsafe {
+            gdal_sys::GDALRasterIO(
+                self.c_rasterband,
+                GDALRWFlag::GF_Write,
+                window.0.try_into()?,
+                window.1.try_into()?,
+                window_size.0.try_into()?,
+                window_size.1.try_into()?,
+                data.as_ptr() as *mut c_void,
+                size.0.try_into()?,
+                size.1.try_into()?,
+                T::gdal_ordinal(),
+                pixel_space.try_into()?,
+                line_space.try_into()?,
+            )
+        };
+        if rv != CPLErr::CE_None {
+            return Err(_last_cpl_err(rv));
+        }
+        Ok(())
+    }
+
+    /// Read a block of this band in its compressed form, without decompressing it.
+    ///
+    /// The block is read in the first of the [compression formats](Dataset::compression_formats)
+    /// available for it, and `None` is returned if there are none, e.g. because the band isn't
+    /// compressed using a supported codec.
+    ///
+    /// # Arguments
+    /// * `block_index` - the block index, see [`RasterBand::read_block`]
+    ///
+    /// # Notes
+    /// For pixel-interleaved datasets, a compressed block usually holds the data of all bands,
+    /// and can only be read through [`Dataset::read_compressed_data`] with all bands.
+    #[cfg(any(all(major_ge_3, minor_ge_7), major_ge_4))]
+    pub fn read_compressed_block(
+        &self,
+        block_index: (usize, usize),
+    ) -> Result<Option<CompressedData>> {
+        let block_size = self.block_size();
+        let window = (block_index.0 * block_size.0, block_index.1 * block_size.1);
+        let window_size = self.actual_block_size(block_index.0, block_index.1)?;
+        let bands = [self.band_number()];
+
+        let formats = self
+            .dataset
+            .compression_formats(window, window_size, &bands)?;
+        match formats.first() {
+            Some(format) => self
+                .dataset
+                .read_compressed_data(format, window, window_size, &bands)
+                .map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// Compute a 16-bit checksum of the pixels in `window`, given as `(offset, size)`, or
+    /// of the whole band.
+    ///
+    /// The checksum is stable across platforms and GDAL versions, which makes it suitable for
+    /// comparing raster outputs in tests.
+    ///
+    /// Invalid windows, e.g. reaching out of the band, are only reported as errors on
+    /// GDAL >= 3.6. Older versions return a checksum anyway.
+    ///
+    /// See: [`GDALChecksumImage`](https://gdal.org/api/gdal_alg.html#_CPPv417GDALChecksumImage15GDALRasterBandHiiii)
+    pub fn checksum(&self, window: Option<((isize, isize), (usize, usize))>) -> Result<u16> {
+        let ((x_off, y_off), (cols, rows)) = window.unwrap_or(((0, 0), self.size()));
+        let checksum = unsafe {
+            gdal_sys::GDALChecksumImage(
+                self.c_rasterband,
+                x_off.try_into()?,
+                y_off.try_into()?,
+                cols.try_into()?,
+                rows.try_into()?,
+            )
+        };
+        // GDAL >= 3.6 returns -1 on errors, e.g. a window out of the band
+        if checksum < 0 {
+            return Err(_last_cpl_err(CPLErr::CE_Failure));
+        }
+        Ok(checksum as u16)
+    }
+
+    /// Find out whether the pixels in `window`, given as `(offset, size)`, or in the whole
+    /// band, hold data or are empty.
+    ///
+    /// Drivers with sparse storage, such as GeoTIFF with `SPARSE_OK=TRUE` or VRT mosaics, can
+    /// tell which areas were never written, without reading them.
+    ///
+    /// See: [`GDALGetDataCoverageStatus`](https://gdal.org/api/gdalrasterband_cpp.html#_CPPv4N14GDALRasterBand21GetDataCoverageStatusEiiiiiPd)
+    pub fn data_coverage_status(
+        &self,
+        window: Option<((isize, isize), (usize, usize))>,
+    ) -> Result<DataCoverageStatus> {
+        let ((x_off, y_off), (cols, rows)) = window.unwrap_or(((0, 0), self.size()));
+        let mut data_percent = 0.0;
+        let flags = unsafe {
+            gdal_sys::GDALGetDataCoverageStatus(
+                self.c_rasterband,
+                x_off.try_into()?,
+                y_off.try_into()?,
+                cols.try_into()?,
+                rows.try_into()?,
+                0,
+                &mut data_percent,
+            )
+        };
+        let status = DataCoverageStatus {
+            flags,
+            data_percent,
+        };
+        // unimplemented statuses report data, except after errors such as invalid windows
+        if status.is_unimplemented() && !status.has_data() {
+            return Err(_last_cpl_err(CPLErr::CE_Failure));
+        }
+        Ok(status)
+    }
+
+    /// Write the modified cached blocks of this band to disk.
+    ///
+    /// See: [`GDALFlushRasterCache`](https://gdal.org/api/raster_c_api.html#_CPPv420GDALFlushRasterCache15GDALRasterBandH)
+    pub fn flush_cache(&mut self) -> Result<()> {
+        let rv = unsafe { gdal_sys::GDALFlushRasterCache(self.c_rasterband) };
+        if rv != CPLErr::CE_None {
+            return Err(_last_cpl_err(rv));
+        }
+        Ok(())
+    }
+
+    /// Drop the cached blocks of this band, without writing the modified ones to disk
+    /// (GDAL >= 3.9).
+    ///
+    /// See: [`GDALDropRasterCache`](https://gdal.org/api/raster_c_api.html#_CPPv419GDALDropRasterCache15GDALRasterBandH)
+    #[cfg(any(all(major_ge_3, minor_ge_9), major_ge_4))]
+    pub fn drop_cache(&mut self) -> Result<()> {
+        let rv = unsafe { gdal_sys::GDALDropRasterCache(self.c_rasterband) };
+        if rv != CPLErr::CE_None {
+            return Err(_last_cpl_err(rv));
+        }
+        Ok(())
+    }
+
+    /// Returns the 1-based index of this band in its dataset, or 0 for e.g. mask bands.
+    pub fn band_number(&self) -> usize {
+        (unsafe { gdal_sys::GDALGetBandNumber(self.c_rasterband) }) as usize
+    }
+
     /// Returns the pixel datatype of this band.
     pub fn band_type(&self) -> GdalDataType {
         let ordinal = unsafe { gdal_sys::GDALGetRasterDataType(self.c_rasterband) };
@@ -707,17 +1300,30 @@ impl<'a> RasterBand<'a> {
     ///
     /// If `no_data` is `None`, any existing no-data value is deleted.
     pub fn set_no_data_value(&mut self, no_data: Option<f64>) -> Result<()> {
-        let rv = if let Some(no_data) = no_data {
-            unsafe { gdal_sys::GDALSetRasterNoDataValue(self.c_rasterband, no_data) }
-        } else {
-            unsafe { gdal_sys::GDALDeleteRasterNoDataValue(self.c_rasterband) }
+        let no_data = match no_data {
+            Some(no_data) => no_data,
+            None => return self.delete_no_data_value(),
         };
+        let rv = unsafe { gdal_sys::GDALSetRasterNoDataValue(self.c_rasterband, no_data) };
+        if rv != CPLErr::CE_None {
+            return Err(_last_cpl_err(rv));
+        }
+        Ok(())
+    }
 
+    /// Deletes the no-data value of this band, if any.
+    ///
+    /// This works for bands of any data type, and is equivalent to passing `None`
+    /// to [`set_no_data_value`](Self::set_no_data_value).
+    ///
+    /// # Notes
+    /// See also: [`GDALDeleteRasterNoDataValue`](https://gdal.org/api/raster_c_api.html#_CPPv427GDALDeleteRasterNoDataValue15GDALRasterBandH)
+    pub fn delete_no_data_value(&mut self) -> Result<()> {
+        let rv = unsafe { gdal_sys::GDALDeleteRasterNoDataValue(self.c_rasterband) };
         if rv != CPLErr::CE_None {
-            Err(_last_cpl_err(rv))
-        } else {
-            Ok(())
+            return Err(_last_cpl_err(rv));
         }
+        Ok(())
     }
 
     /// Fetch the no-data value for this band.
@@ -755,17 +1361,15 @@ impl<'a> RasterBand<'a> {
     /// [`GDALDeleteRasterNoDataValue`](https://gdal.org/api/raster_c_api.html#_CPPv427GDALDeleteRasterNoDataValue15GDALRasterBandH)
     #[cfg(all(major_ge_3, minor_ge_5))]
     pub fn set_no_data_value_u64(&mut self, no_data: Option<u64>) -> Result<()> {
-        let rv = if let Some(no_data) = no_data {
-            unsafe { gdal_sys::GDALSetRasterNoDataValueAsUInt64(self.c_rasterband, no_data) }
-        } else {
-            unsafe { gdal_sys::GDALDeleteRasterNoDataValue(self.c_rasterband) }
+        let no_data = match no_data {
+            Some(no_data) => no_data,
+            None => return self.delete_no_data_value(),
         };
-
+        let rv = unsafe { gdal_sys::GDALSetRasterNoDataValueAsUInt64(self.c_rasterband, no_data) };
         if rv != CPLErr::CE_None {
-            Err(_last_cpl_err(rv))
-        } else {
-            Ok(())
+            return Err(_last_cpl_err(rv));
         }
+        Ok(())
     }
 
     /// Fetch the no-data value for this band.
@@ -803,17 +1407,15 @@ impl<'a> RasterBand<'a> {
     /// [`GDALDeleteRasterNoDataValue`](https://gdal.org/api/raster_c_api.html#_CPPv427GDALDeleteRasterNoDataValue15GDALRasterBandH)
     #[cfg(all(major_ge_3, minor_ge_5))]
     pub fn set_no_data_value_i64(&mut self, no_data: Option<i64>) -> Result<()> {
-        let rv = if let Some(no_data) = no_data {
-            unsafe { gdal_sys::GDALSetRasterNoDataValueAsInt64(self.c_rasterband, no_data) }
-        } else {
-            unsafe { gdal_sys::GDALDeleteRasterNoDataValue(self.c_rasterband) }
+        let no_data = match no_data {
+            Some(no_data) => no_data,
+            None => return self.delete_no_data_value(),
         };
-
+        let rv = unsafe { gdal_sys::GDALSetRasterNoDataValueAsInt64(self.c_rasterband, no_data) };
         if rv != CPLErr::CE_None {
-            Err(_last_cpl_err(rv))
-        } else {
-            Ok(())
+            return Err(_last_cpl_err(rv));
         }
+        Ok(())
     }
 
     /// Fill this band with a constant value.
@@ -1033,6 +1635,57 @@ impl<'a> RasterBand<'a> {
         }
     }
 
+    /// Compute statistics of this band by scanning its pixels, and cache them on the band.
+    ///
+    /// Unlike [`get_statistics`](Self::get_statistics), previously computed statistics are ignored.
+    /// If `is_approx_ok` is `true`, statistics may be computed from overviews or a subset of the blocks.
+    ///
+    /// # Notes
+    /// See also: [`GDALComputeRasterStatistics`](https://gdal.org/api/raster_c_api.html#_CPPv427GDALComputeRasterStatistics15GDALRasterBandHiPdPdPdPd16GDALProgressFuncPv)
+    pub fn compute_statistics(&self, is_approx_ok: bool) -> Result<StatisticsAll> {
+        self._compute_statistics(is_approx_ok, None)
+    }
+
+    /// Compute statistics like [`compute_statistics`](Self::compute_statistics), stopping with
+    /// [`GdalError::Cancelled`] once `token` is cancelled.
+    pub fn compute_statistics_with_cancellation(
+        &self,
+        is_approx_ok: bool,
+        token: &CancellationToken,
+    ) -> Result<StatisticsAll> {
+        self._compute_statistics(is_approx_ok, Some(token))
+    }
+
+    fn _compute_statistics(
+        &self,
+        is_approx_ok: bool,
+        token: Option<&CancellationToken>,
+    ) -> Result<StatisticsAll> {
+        let mut statistics = StatisticsAll {
+            min: 0.,
+            max: 0.,
+            mean: 0.,
+            std_dev: 0.,
+        };
+        let (progress, progress_arg) = progress_args(token);
+        let rv = unsafe {
+            gdal_sys::GDALComputeRasterStatistics(
+                self.c_rasterband,
+                libc::c_int::from(is_approx_ok),
+                &mut statistics.min,
+                &mut statistics.max,
+                &mut statistics.mean,
+                &mut statistics.std_dev,
+                progress,
+                progress_arg,
+            )
+        };
+        if rv != CPLErr::CE_None {
+            return Err(cancelled_or(token, _last_cpl_err(rv)));
+        }
+        Ok(statistics)
+    }
+
     /// Compute the min/max values for a band.
     ///
     /// If `is_approx_ok` is `true`, then the band’s GetMinimum()/GetMaximum() will be trusted.
@@ -1266,7 +1919,7 @@ impl<'a> MajorObject for RasterBand<'a> {
 impl<'a> Metadata for RasterBand<'a> {}
 
 /// Represents a color interpretation of a RasterBand
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ColorInterpretation {
     /// Undefined
     Undefined,
@@ -1,14 +1,14 @@
 use std::convert::TryFrom;
 use std::ptr;
 
-use gdal_sys::{self, CPLErr};
+use gdal_sys::{self, CPLErr, OGRLayerH};
 use libc::c_void;
 
 use crate::cpl::CslStringList;
 use crate::dataset::Dataset;
 use crate::errors::*;
 use crate::utils::_last_cpl_err;
-use crate::vector::Geometry;
+use crate::vector::{Geometry, LayerAccess};
 
 #[derive(Copy, Clone, Debug)]
 pub enum BurnSource {
@@ -199,6 +199,98 @@ pub fn rasterize(
     Ok(())
 }
 
+/// Value burnt by [`rasterize_layers`] for the features of a layer.
+#[derive(Clone, Debug, PartialEq)]
+pub enum LayerBurnValue {
+    /// One value per layer, burnt into all the bands.
+    Values(Vec<f64>),
+    /// The value of this attribute field of each feature, burnt into all the bands.
+    Attribute(String),
+}
+
+/// Burn the features of layers into raster.
+///
+/// Rasterize the geometries of `layers` onto some `dataset` bands, like [`rasterize`].
+/// The layers are reprojected to the spatial reference of `dataset` if needed.
+///
+/// Bands are selected using indices supplied in `bands`. The burnt values are given by
+/// `burn`, either one per layer or from an attribute of the features.
+pub fn rasterize_layers<L: LayerAccess>(
+    dataset: &mut Dataset,
+    bands: &[usize],
+    layers: &[&L],
+    burn: &LayerBurnValue,
+    options: Option<RasterizeOptions>,
+) -> Result<()> {
+    if bands.is_empty() {
+        return Err(GdalError::BadArgument(
+            "`bands` must not be empty".to_string(),
+        ));
+    }
+    let raster_count = dataset.raster_count();
+    for band in bands {
+        let is_good = *band > 0 && *band <= raster_count;
+        if !is_good {
+            return Err(GdalError::BadArgument(format!(
+                "Band index {} is out of bounds",
+                *band
+            )));
+        }
+    }
+
+    let bands: Vec<i32> = bands.iter().map(|&band| band as i32).collect();
+    let mut c_options = CslStringList::try_from(options.unwrap_or_default())?;
+    let burn_values: Vec<f64> = match burn {
+        LayerBurnValue::Values(values) => {
+            if values.len() != layers.len() {
+                return Err(GdalError::BadArgument(format!(
+                    "Burn values length ({}) must match layers length ({})",
+                    values.len(),
+                    layers.len()
+                )));
+            }
+            values
+                .iter()
+                .flat_map(|burn| std::iter::repeat(burn).take(bands.len()))
+                .copied()
+                .collect()
+        }
+        LayerBurnValue::Attribute(field) => {
+            c_options.set_name_value("ATTRIBUTE", field)?;
+            Vec::new()
+        }
+    };
+    let layers: Vec<_> = layers
+        .iter()
+        .map(|layer| unsafe { layer.c_layer() })
+        .collect();
+
+    unsafe {
+        // As with `GDALRasterizeGeometries`, the lack of `const` is a mistake in the GDAL API.
+        let error = gdal_sys::GDALRasterizeLayers(
+            dataset.c_dataset(),
+            bands.len() as i32,
+            bands.as_ptr() as *mut i32,
+            layers.len() as i32,
+            layers.as_ptr() as *mut OGRLayerH,
+            None,
+            ptr::null_mut(),
+            if burn_values.is_empty() {
+                ptr::null_mut()
+            } else {
+                burn_values.as_ptr() as *mut f64
+            },
+            c_options.as_ptr(),
+            None,
+            ptr::null_mut(),
+        );
+        if error != CPLErr::CE_None {
+            return Err(_last_cpl_err(error));
+        }
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use std::convert::TryFrom;
@@ -3,8 +3,8 @@ use crate::errors::Result;
 use crate::metadata::Metadata;
 use crate::raster::rasterband::ResampleAlg;
 use crate::raster::{
-    ByteBuffer, ColorEntry, ColorInterpretation, ColorTable, GdalDataType, RasterCreationOptions,
-    StatisticsAll, StatisticsMinMax,
+    AnyBuffer, Buffer, ByteBuffer, ColorEntry, ColorInterpretation, ColorTable, GdalDataType,
+    RasterCreationOptions, StatisticsAll, StatisticsMinMax,
 };
 use crate::test_utils::{fixture, TempFixture};
 use crate::vsi::unlink_mem_file;
@@ -64,12 +64,69 @@ fn test_read_raster() {
     assert_eq!(buf.data(), vec!(7, 7, 7, 10, 8, 12));
 }
 
+#[test]
+fn test_advise_read() {
+    let dataset = Dataset::open(fixture("tinymarble.tif")).unwrap();
+    let rb = dataset.rasterband(1).unwrap();
+    rb.advise_read::<u8>((20, 30), (2, 3), None).unwrap();
+    rb.advise_read::<f64>((0, 0), rb.size(), Some((10, 5)))
+        .unwrap();
+    let rv = rb.read_as::<u8>((20, 30), (2, 3), (2, 3), None).unwrap();
+    assert_eq!(rv.data(), vec!(7, 7, 7, 10, 8, 12));
+}
+
+#[test]
+fn test_convert_buffer() {
+    use crate::raster::{convert_buffer, copy_words};
+
+    let src = Buffer::new((2, 2), vec![-3.7f32, 0.5, 40000., 12.2]);
+    let dst: Buffer<i16> = convert_buffer(&src);
+    assert_eq!(dst.shape(), (2, 2));
+    assert_eq!(dst.data(), [-4, 1, 32767, 12]);
+    let any = AnyBuffer::from(src);
+    assert_eq!(any.convert::<u8>().data(), [0, 1, 255, 12]);
+
+    // extract the second band of pixel-interleaved data
+    let rgb = [1u8, 2, 3, 4, 5, 6, 7, 8, 9];
+    let mut green = [0f64; 3];
+    copy_words(&rgb[1..], 3, &mut green, 1, 3);
+    assert_eq!(green, [2., 5., 8.]);
+}
+
 #[test]
 fn test_read_rasterbands() {
     let dataset = Dataset::open(fixture("tinymarble.tif")).unwrap();
     assert_eq!(dataset.rasterbands().count(), 3);
 }
 
+#[test]
+fn test_rasterband_index() {
+    let dataset = Dataset::open(fixture("tinymarble.tif")).unwrap();
+    assert!(dataset.rasterband(1).is_ok());
+    assert!(dataset.rasterband(3).is_ok());
+    assert!(matches!(
+        dataset.rasterband(0),
+        Err(crate::errors::GdalError::BadArgument(_))
+    ));
+    assert!(matches!(
+        dataset.rasterband(4),
+        Err(crate::errors::GdalError::BadArgument(_))
+    ));
+}
+
+#[test]
+fn test_rasterband_by_description() {
+    let driver = DriverManager::get_driver_by_name("MEM").unwrap();
+    let dataset = driver.create("", 2, 2, 3).unwrap();
+    for (i, name) in ["red", "nir", "nir"].iter().enumerate() {
+        let mut band = dataset.rasterband(i + 1).unwrap();
+        band.set_description(name).unwrap();
+    }
+    let band = dataset.rasterband_by_description("nir").unwrap().unwrap();
+    assert_eq!(band.band_number(), 2);
+    assert!(dataset.rasterband_by_description("blue").unwrap().is_none());
+}
+
 #[test]
 fn test_read_raster_with_default_resample() {
     let dataset = Dataset::open(fixture("tinymarble.tif")).unwrap();
@@ -125,6 +182,48 @@ fn test_write_raster() {
     assert_eq!(right.data()[0], 20u8);
 }
 
+#[test]
+fn test_write_from_slice() {
+    let driver = DriverManager::get_driver_by_name("MEM").unwrap();
+    let dataset = driver.create_with_band_type::<u16, _>("", 3, 2, 2).unwrap();
+
+    // two interleaved bands, in a 4 pixels wide image
+    let data: Vec<u16> = (0..16).collect();
+    for b in 0..2 {
+        let mut band = dataset.rasterband(b + 1).unwrap();
+        band.write_from_slice((0, 0), (3, 2), (3, 2), &data[b..], Some((2, 8)))
+            .unwrap();
+    }
+    let band = dataset.rasterband(1).unwrap();
+    assert_eq!(
+        band.read_band_as::<u16>().unwrap().data(),
+        [0, 2, 4, 8, 10, 12]
+    );
+    let band = dataset.rasterband(2).unwrap();
+    assert_eq!(
+        band.read_band_as::<u16>().unwrap().data(),
+        [1, 3, 5, 9, 11, 13]
+    );
+
+    let mut band = dataset.rasterband(1).unwrap();
+    band.write_from_slice((1, 1), (2, 1), (2, 1), &[7u16, 8], None)
+        .unwrap();
+    assert_eq!(
+        band.read_band_as::<u16>().unwrap().data(),
+        [0, 2, 4, 8, 7, 8]
+    );
+    assert!(band
+        .write_from_slice((0, 0), (3, 2), (3, 2), &data[..5], None)
+        .is_err());
+    assert!(band
+        .write_from_slice((0, 0), (3, 2), (3, 2), &data[4..], Some((2, 8)))
+        .is_err());
+    assert!(matches!(
+        band.write_from_slice((0, 0), (3, 2), (3, 2), &data, Some((1, usize::MAX))),
+        Err(crate::errors::GdalError::BadArgument(_))
+    ));
+}
+
 #[test]
 fn test_rename_remove_raster() {
     let dataset = Dataset::open(fixture("tinymarble.tif")).unwrap();
@@ -444,6 +543,20 @@ fn test_get_no_data_value() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_delete_no_data_value() -> Result<()> {
+    let driver = DriverManager::get_driver_by_name("MEM")?;
+    let ds = driver.create_with_band_type::<u8, _>("", 1, 1, 1)?;
+    let mut rasterband = ds.rasterband(1)?;
+    rasterband.set_no_data_value(Some(255.0))?;
+    assert_eq!(rasterband.no_data_value(), Some(255.0));
+    rasterband.delete_no_data_value()?;
+    assert_eq!(rasterband.no_data_value(), None);
+    // deleting a missing value isn't an error
+    rasterband.delete_no_data_value()?;
+    Ok(())
+}
+
 #[test]
 #[cfg(all(major_ge_3, minor_ge_5))]
 fn test_no_data_value_i64() -> Result<()> {
@@ -453,6 +566,8 @@ fn test_no_data_value_i64() -> Result<()> {
     assert_eq!(rasterband.no_data_value_i64(), None);
     rasterband.set_no_data_value_i64(Some(i64::MIN))?;
     assert_eq!(rasterband.no_data_value_i64(), Some(i64::MIN));
+    rasterband.delete_no_data_value()?;
+    assert_eq!(rasterband.no_data_value_i64(), None);
 
     Ok(())
 }
@@ -466,6 +581,48 @@ fn test_no_data_value_u64() -> Result<()> {
     assert_eq!(rasterband.no_data_value_u64(), None);
     rasterband.set_no_data_value_u64(Some(u64::MAX))?;
     assert_eq!(rasterband.no_data_value_u64(), Some(u64::MAX));
+    rasterband.set_no_data_value_u64(None)?;
+    assert_eq!(rasterband.no_data_value_u64(), None);
+
+    Ok(())
+}
+
+#[test]
+#[cfg(all(major_ge_3, minor_ge_5))]
+fn test_read_write_64_bit_values() -> Result<()> {
+    let driver = DriverManager::get_driver_by_name("MEM")?;
+
+    // none of these survive a round trip through f64
+    let ds = driver.create_with_band_type::<i64, _>("", 2, 1, 1)?;
+    let mut rasterband = ds.rasterband(1)?;
+    assert_eq!(rasterband.band_type(), GdalDataType::Int64);
+    let values = vec![i64::MIN + 1, i64::MAX - 1];
+    rasterband.write((0, 0), (2, 1), &mut Buffer::new((2, 1), values.clone()))?;
+    assert_eq!(rasterband.read_band_as::<i64>()?.data(), values);
+
+    let ds = driver.create_with_band_type::<u64, _>("", 2, 1, 1)?;
+    let mut rasterband = ds.rasterband(1)?;
+    assert_eq!(rasterband.band_type(), GdalDataType::UInt64);
+    let values = vec![u64::MAX - 1, (1 << 53) + 1];
+    rasterband.write((0, 0), (2, 1), &mut Buffer::new((2, 1), values.clone()))?;
+    assert_eq!(rasterband.read_band_as::<u64>()?.data(), values);
+
+    Ok(())
+}
+
+#[test]
+#[cfg(any(all(major_ge_3, minor_ge_7), major_ge_4))]
+fn test_read_write_int8() -> Result<()> {
+    let driver = DriverManager::get_driver_by_name("MEM")?;
+    let ds = driver.create_with_band_type::<i8, _>("", 3, 1, 1)?;
+    let mut rasterband = ds.rasterband(1)?;
+    assert_eq!(rasterband.band_type(), GdalDataType::Int8);
+    let values = vec![i8::MIN, 0, i8::MAX];
+    rasterband.write((0, 0), (3, 1), &mut Buffer::new((3, 1), values.clone()))?;
+    assert_eq!(rasterband.read_band_as::<i8>()?.data(), values);
+
+    rasterband.set_no_data_value(Some(-128.0))?;
+    assert_eq!(rasterband.no_data_value(), Some(-128.0));
 
     Ok(())
 }
@@ -570,6 +727,36 @@ fn test_read_overviews() {
     assert_eq!(overview_4.size(), (25, 13));
 }
 
+#[test]
+fn test_build_overviews_with_options() {
+    use crate::config::get_thread_local_config_option;
+    use crate::raster::{BuildOverviewsOptions, OverviewCompression, OverviewResampling};
+
+    let fixture = TempFixture::fixture("offset_scaled_tinymarble.tif");
+    // opened read-only, so that the overviews go to an external .ovr file
+    let mut dataset = Dataset::open(&fixture).unwrap();
+    let mut options = BuildOverviewsOptions::new();
+    options
+        .with_resampling(OverviewResampling::Average)
+        .with_compression(OverviewCompression::Deflate)
+        .with_predictor(2);
+    dataset
+        .build_overviews_with_options(&[2, 4], &[], &options)
+        .unwrap();
+    assert_eq!(dataset.rasterband(1).unwrap().overview_count().unwrap(), 2);
+    assert_eq!(
+        get_thread_local_config_option("COMPRESS_OVERVIEW", "unset").unwrap(),
+        "unset"
+    );
+    drop(dataset);
+
+    let ovr = Dataset::open(fixture.path().with_extension("tif.ovr")).unwrap();
+    assert_eq!(
+        ovr.metadata_item("COMPRESSION", "IMAGE_STRUCTURE"),
+        Some("DEFLATE".to_string())
+    );
+}
+
 #[test]
 fn test_fail_read_overviews() {
     let dataset = Dataset::open(fixture("offset_scaled_tinymarble.tif")).unwrap();
@@ -670,6 +857,62 @@ fn test_rasterize() {
     );
 }
 
+#[test]
+fn test_rasterize_layers() {
+    use crate::raster::{rasterize_layers, LayerBurnValue};
+    use crate::vector::{FieldValue, Geometry, LayerAccess, LayerOptions, OGRFieldType};
+
+    let driver = DriverManager::get_driver_by_name("Memory").unwrap();
+    let mut vector = driver.create_vector_only("").unwrap();
+    let mut layer = vector
+        .create_layer(LayerOptions {
+            name: "zones",
+            ty: gdal_sys::OGRwkbGeometryType::wkbPolygon,
+            ..Default::default()
+        })
+        .unwrap();
+    layer
+        .create_defn_fields(&[("zone", OGRFieldType::OFTInteger)])
+        .unwrap();
+    for (zone, wkt) in [
+        (3, "POLYGON ((0 0, 0 2, 2 2, 2 0, 0 0))"),
+        (7, "POLYGON ((2 2, 2 4, 4 4, 4 2, 2 2))"),
+    ] {
+        layer
+            .create_feature_fields(
+                Geometry::from_wkt(wkt).unwrap(),
+                &["zone"],
+                &[FieldValue::IntegerValue(zone)],
+            )
+            .unwrap();
+    }
+
+    let driver = DriverManager::get_driver_by_name("MEM").unwrap();
+    let mut dataset = driver.create("", 4, 4, 1).unwrap();
+    let burn = LayerBurnValue::Attribute("zone".to_string());
+    rasterize_layers(&mut dataset, &[1], &[&layer], &burn, None).unwrap();
+    let values = dataset.rasterband(1).unwrap().read_band_as::<u8>().unwrap();
+    assert_eq!(
+        values.data(),
+        [3, 3, 0, 0, 3, 3, 0, 0, 0, 0, 7, 7, 0, 0, 7, 7]
+    );
+
+    let burn = LayerBurnValue::Values(vec![1.]);
+    let options = super::RasterizeOptions {
+        merge_algorithm: super::MergeAlgorithm::Add,
+        ..Default::default()
+    };
+    rasterize_layers(&mut dataset, &[1], &[&layer], &burn, Some(options)).unwrap();
+    let values = dataset.rasterband(1).unwrap().read_band_as::<u8>().unwrap();
+    assert_eq!(
+        values.data(),
+        [4, 4, 0, 0, 4, 4, 0, 0, 0, 0, 8, 8, 0, 0, 8, 8]
+    );
+
+    let burn = LayerBurnValue::Values(vec![1., 2.]);
+    assert!(rasterize_layers(&mut dataset, &[1], &[&layer], &burn, None).is_err());
+}
+
 #[test]
 fn test_rasterband_unit() {
     let dataset = Dataset::open(fixture("tinymarble.tif")).unwrap();
@@ -808,6 +1051,157 @@ fn test_raster_stats() {
     );
 }
 
+#[test]
+fn test_cancellation() {
+    use crate::errors::GdalError;
+    use crate::progress::CancellationToken;
+    use crate::raster::reproject_with_cancellation;
+    use std::time::Duration;
+
+    let fixture = TempFixture::fixture("tinymarble.tif");
+    let mut dataset = Dataset::open(&fixture).unwrap();
+    let rb = dataset.rasterband(1).unwrap();
+
+    let token = CancellationToken::new();
+    let stats = rb
+        .compute_statistics_with_cancellation(false, &token)
+        .unwrap();
+    assert_eq!(stats, rb.compute_statistics(false).unwrap());
+    assert_eq!(stats.max, 255.0);
+
+    let _nolog = crate::test_utils::SuppressGDALErrorLog::new();
+    token.cancel();
+    assert!(matches!(
+        rb.compute_statistics_with_cancellation(false, &token),
+        Err(GdalError::Cancelled)
+    ));
+    assert!(matches!(
+        dataset.build_overviews_with_cancellation("NEAREST", &[2], &[], &token),
+        Err(GdalError::Cancelled)
+    ));
+
+    let driver = DriverManager::get_driver_by_name("MEM").unwrap();
+    let mut dst = driver
+        .create_with_band_type::<u8, _>("", 50, 25, 3)
+        .unwrap();
+    dst.set_spatial_ref(&dataset.spatial_ref().unwrap())
+        .unwrap();
+    let mut gt = dataset.geo_transform().unwrap();
+    gt[1] *= 2.;
+    gt[5] *= 2.;
+    dst.set_geo_transform(&gt).unwrap();
+    let expired = CancellationToken::with_timeout(Duration::ZERO);
+    assert!(matches!(
+        reproject_with_cancellation(&dataset, &dst, &expired),
+        Err(GdalError::Cancelled)
+    ));
+}
+
+#[test]
+fn test_warp_operation() {
+    use crate::raster::{
+        reproject_with_options, Transformer, WarpOperation, WarpOperationOptions, WarpResampleAlg,
+    };
+    use crate::spatial_ref::SpatialRef;
+
+    let driver = DriverManager::get_driver_by_name("MEM").unwrap();
+    let srs = SpatialRef::from_epsg(4326).unwrap();
+    let mut src = driver.create_with_band_type::<f64, _>("", 4, 4, 2).unwrap();
+    src.set_geo_transform(&[0., 1., 0., 4., 0., -1.]).unwrap();
+    src.set_spatial_ref(&srs).unwrap();
+    for band in 1..=2 {
+        let data = (0..16).map(|i| (band * i) as f64).collect();
+        src.rasterband(band)
+            .unwrap()
+            .write((0, 0), (4, 4), &mut Buffer::new((4, 4), data))
+            .unwrap();
+    }
+    let create_dst = || {
+        let mut dst = driver.create_with_band_type::<f64, _>("", 2, 2, 2).unwrap();
+        dst.set_geo_transform(&[0., 2., 0., 4., 0., -2.]).unwrap();
+        dst.set_spatial_ref(&srs).unwrap();
+        dst
+    };
+
+    let mut options = WarpOperationOptions::new();
+    options
+        .with_resampling(WarpResampleAlg::Average)
+        .with_memory_limit(1024 * 1024);
+    let dst = create_dst();
+    let operation = WarpOperation::new(&src, &dst, &options).unwrap();
+    operation.chunk_and_warp_image((0, 0), (2, 2)).unwrap();
+    drop(operation);
+    let averages = dst.rasterband(1).unwrap().read_band_as::<f64>().unwrap();
+    assert_eq!(averages.data(), [2.5, 4.5, 10.5, 12.5]);
+
+    // maps the destination pixel centers to the upper-left source pixel of each 2x2 block
+    struct Downsample;
+    impl Transformer for Downsample {
+        fn transform(
+            &self,
+            dst_to_src: bool,
+            x: &mut [f64],
+            y: &mut [f64],
+            _z: &mut [f64],
+            _success: &mut [bool],
+        ) -> bool {
+            for v in x.iter_mut().chain(y.iter_mut()) {
+                *v = if dst_to_src {
+                    2. * *v - 0.5
+                } else {
+                    (*v + 0.5) / 2.
+                };
+            }
+            true
+        }
+    }
+    let dst = create_dst();
+    let operation =
+        WarpOperation::with_transformer(&src, &dst, &WarpOperationOptions::new(), Downsample)
+            .unwrap();
+    operation.chunk_and_warp_multi((0, 0), (2, 2)).unwrap();
+    drop(operation);
+    let nearest = dst.rasterband(1).unwrap().read_band_as::<f64>().unwrap();
+    assert_eq!(nearest.data(), [0., 2., 8., 10.]);
+
+    let mut options = WarpOperationOptions::new();
+    options
+        .with_resampling(WarpResampleAlg::Max)
+        .with_band_mapping(&[(2, 1)])
+        .with_src_no_data_value(30.);
+    let dst = create_dst();
+    reproject_with_options(&src, &dst, &options).unwrap();
+    let maxima = dst.rasterband(1).unwrap().read_band_as::<f64>().unwrap();
+    assert_eq!(maxima.data(), [10., 14., 26., 28.]);
+}
+
+#[test]
+fn test_suggested_warp_output() {
+    use crate::raster::suggested_warp_output;
+    use crate::spatial_ref::SpatialRef;
+
+    let driver = DriverManager::get_driver_by_name("MEM").unwrap();
+    let mut src = driver
+        .create_with_band_type::<u8, _>("", 20, 10, 1)
+        .unwrap();
+    src.set_geo_transform(&[-10., 1., 0., 5., 0., -1.]).unwrap();
+    let wgs84 = SpatialRef::from_epsg(4326).unwrap();
+    src.set_spatial_ref(&wgs84).unwrap();
+
+    let (gt, size) = suggested_warp_output(&src, &wgs84).unwrap();
+    assert_eq!(size, (20, 10));
+    for (actual, expected) in gt.iter().zip([-10., 1., 0., 5., 0., -1.]) {
+        assert!((actual - expected).abs() < 1e-6, "{gt:?}");
+    }
+
+    let (gt, (cols, rows)) =
+        suggested_warp_output(&src, &SpatialRef::from_epsg(3857).unwrap()).unwrap();
+    // 10 degrees of longitude in web mercator
+    assert!((gt[0] + 1_113_194.9).abs() < 1.);
+    assert!((gt[0] + cols as f64 * gt[1] - 1_113_194.9).abs() < gt[1]);
+    assert!(rows > 0);
+}
+
 #[test]
 fn test_raster_get_histogram() {
     let fixture = TempFixture::fixture("tinymarble.tif");
@@ -888,3 +1282,343 @@ fn test_resample_str() {
         assert_eq!(parsed.unwrap(), e, "{stringed}");
     }
 }
+
+#[test]
+#[cfg(any(all(major_ge_3, minor_ge_7), major_ge_4))]
+fn test_read_compressed_block() {
+    let dataset = Dataset::open(fixture("tinymarble.tif")).unwrap();
+    let driver = DriverManager::get_driver_by_name("GTiff").unwrap();
+    let options = RasterCreationOptions::from_iter([
+        "TILED=YES",
+        "BLOCKXSIZE=16",
+        "BLOCKYSIZE=16",
+        "COMPRESS=JPEG",
+        "INTERLEAVE=BAND",
+    ]);
+    let mem_file_path = "/vsimem/test_read_compressed_block.tif";
+    let copy = dataset
+        .create_copy(&driver, mem_file_path, &options)
+        .unwrap();
+
+    let formats = copy.compression_formats((0, 0), (16, 16), &[1]).unwrap();
+    assert_eq!(formats.len(), 1);
+    assert!(formats[0].starts_with("JPEG"));
+    // Windows not aligned on a block can't be read in compressed form
+    assert!(copy
+        .compression_formats((1, 0), (16, 16), &[1])
+        .unwrap()
+        .is_empty());
+
+    let band = copy.rasterband(1).unwrap();
+    let block = band.read_compressed_block((1, 0)).unwrap().unwrap();
+    assert!(block.format.starts_with("JPEG"));
+    assert_eq!(&block.data[..2], &[0xFF, 0xD8]);
+
+    let uncompressed = dataset.rasterband(1).unwrap();
+    assert!(uncompressed
+        .read_compressed_block((0, 0))
+        .unwrap()
+        .is_none());
+
+    drop(copy);
+    unlink_mem_file(mem_file_path).unwrap();
+}
+
+#[test]
+#[cfg(feature = "ndarray")]
+fn test_dataset_read_as_array() {
+    use crate::raster::BandAxis;
+
+    let dataset = Dataset::open(fixture("tinymarble.tif")).unwrap();
+    let first = dataset
+        .read_as_array::<u8>((19, 5), (3, 4), (3, 4), &[], BandAxis::First, None)
+        .unwrap();
+    assert_eq!(first.shape(), [3, 4, 3]);
+    let band1 = arr2(&[
+        [226, 225, 157],
+        [215, 222, 225],
+        [213, 231, 229],
+        [171, 189, 192],
+    ]);
+    assert_eq!(first.index_axis(Axis(0), 0), band1);
+
+    let last = dataset
+        .read_as_array::<u8>((19, 5), (3, 4), (3, 4), &[2, 1], BandAxis::Last, None)
+        .unwrap();
+    assert_eq!(last.shape(), [4, 3, 2]);
+    assert_eq!(last.index_axis(Axis(2), 1), band1);
+    assert_eq!(last.index_axis(Axis(2), 0), first.index_axis(Axis(0), 1));
+
+    let band = dataset.rasterband(1).unwrap();
+    assert_eq!(
+        band.read_as_array::<u8>((19, 5), (3, 4), (3, 4), None)
+            .unwrap(),
+        band1
+    );
+}
+
+#[test]
+#[cfg(feature = "ndarray")]
+fn test_write_array() {
+    let driver = DriverManager::get_driver_by_name("MEM").unwrap();
+    let dataset = driver.create_with_band_type::<i32, _>("", 4, 3, 1).unwrap();
+    let mut band = dataset.rasterband(1).unwrap();
+
+    let data = Array2::from_shape_fn((3, 4), |(y, x)| (y * 4 + x) as i32);
+    band.write_array((0, 0), (4, 3), data.view()).unwrap();
+    assert_eq!(
+        band.read_as_array::<i32>((0, 0), (4, 3), (4, 3), None)
+            .unwrap(),
+        data
+    );
+
+    // non-contiguous view
+    let wide = Array2::from_shape_fn((3, 8), |(y, x)| (y * 8 + x) as i32);
+    let every_other = wide.slice(ndarray::s![.., ..;2]);
+    band.write_array((0, 0), (4, 3), every_other).unwrap();
+    assert_eq!(
+        band.read_as_array::<i32>((0, 0), (4, 3), (4, 3), None)
+            .unwrap(),
+        every_other
+    );
+
+    // negative strides
+    let flipped = data.slice(ndarray::s![..;-1, ..]);
+    band.write_array((0, 0), (4, 3), flipped).unwrap();
+    assert_eq!(
+        band.read_as_array::<i32>((0, 0), (4, 3), (4, 3), None)
+            .unwrap(),
+        flipped
+    );
+}
+
+#[test]
+#[cfg(feature = "image")]
+fn test_image_roundtrip() {
+    let dataset = Dataset::open(fixture("tinymarble.tif")).unwrap();
+    let image = dataset.to_image().unwrap().into_rgb8();
+    assert_eq!(image.dimensions(), (100, 50));
+    let green = dataset.rasterband(2).unwrap().read_band_as::<u8>().unwrap();
+    assert_eq!(image.get_pixel(7, 3)[1], green.data()[3 * 100 + 7]);
+
+    let copy = Dataset::from_image(&::image::DynamicImage::ImageRgb8(image.clone())).unwrap();
+    assert_eq!(copy.raster_count(), 3);
+    assert_eq!(
+        copy.rasterband(3).unwrap().color_interpretation(),
+        ColorInterpretation::BlueBand
+    );
+    assert_eq!(copy.to_image().unwrap().into_rgb8(), image);
+}
+
+#[test]
+#[cfg(feature = "image")]
+fn test_image_nodata_to_alpha() {
+    let driver = DriverManager::get_driver_by_name("MEM").unwrap();
+    let dataset = driver.create_with_band_type::<u16, _>("", 2, 1, 1).unwrap();
+    let mut band = dataset.rasterband(1).unwrap();
+    band.write((0, 0), (2, 1), &mut Buffer::new((2, 1), vec![0u16, 1000]))
+        .unwrap();
+    band.set_no_data_value(Some(0.0)).unwrap();
+
+    let image = dataset.to_image().unwrap();
+    let image = image.as_luma_alpha16().unwrap();
+    assert_eq!(image.get_pixel(0, 0).0, [0, 0]);
+    assert_eq!(image.get_pixel(1, 0).0, [1000, u16::MAX]);
+
+    let two_bands = driver.create("", 1, 1, 2).unwrap();
+    assert!(two_bands.to_image().is_err());
+}
+
+#[test]
+fn test_read_generic() {
+    let dataset = Dataset::open(fixture("tinymarble.tif")).unwrap();
+    let band = dataset.rasterband(1).unwrap();
+    let buffer = band.read_generic((0, 0), (10, 5), (10, 5), None).unwrap();
+    assert_eq!(buffer.data_type(), GdalDataType::UInt8);
+    assert_eq!(buffer.shape(), (10, 5));
+    assert_eq!(
+        buffer,
+        AnyBuffer::UInt8(band.read_as::<u8>((0, 0), (10, 5), (10, 5), None).unwrap())
+    );
+
+    let driver = DriverManager::get_driver_by_name("MEM").unwrap();
+    let dataset = driver.create_with_band_type::<f32, _>("", 3, 2, 1).unwrap();
+    let band = dataset.rasterband(1).unwrap();
+    let buffer = band.read_generic((0, 0), (3, 2), (3, 2), None).unwrap();
+    assert_eq!(buffer.data_type(), GdalDataType::Float32);
+    assert_eq!(buffer.len(), 6);
+    assert!(matches!(buffer, AnyBuffer::Float32(_)));
+}
+
+#[test]
+#[cfg(feature = "num-complex")]
+fn test_read_write_complex() -> Result<()> {
+    use num_complex::Complex;
+
+    let driver = DriverManager::get_driver_by_name("MEM")?;
+    let ds = driver.create_with_band_type::<Complex<f32>, _>("", 2, 1, 1)?;
+    let mut rasterband = ds.rasterband(1)?;
+    assert_eq!(rasterband.band_type(), GdalDataType::CFloat32);
+    let values = vec![Complex::new(1.5, -2.0), Complex::new(-0.25, 8.0)];
+    rasterband.write((0, 0), (2, 1), &mut Buffer::new((2, 1), values.clone()))?;
+    assert_eq!(rasterband.read_band_as::<Complex<f32>>()?.data(), values);
+
+    // GDAL converts between complex types
+    let widened = rasterband.read_band_as::<Complex<f64>>()?;
+    assert_eq!(widened.data()[1], Complex::new(-0.25, 8.0));
+    let buffer = rasterband.read_generic((0, 0), (2, 1), (2, 1), None)?;
+    assert_eq!(buffer, AnyBuffer::CFloat32(Buffer::new((2, 1), values)));
+
+    let ds = driver.create_with_band_type::<Complex<i16>, _>("", 1, 1, 1)?;
+    let mut rasterband = ds.rasterband(1)?;
+    assert_eq!(rasterband.band_type(), GdalDataType::CInt16);
+    let values = vec![Complex::new(i16::MIN, i16::MAX)];
+    rasterband.write((0, 0), (1, 1), &mut Buffer::new((1, 1), values.clone()))?;
+    assert_eq!(rasterband.read_band_as::<Complex<i16>>()?.data(), values);
+
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "half", any(all(major_ge_3, minor_ge_11), major_ge_4)))]
+fn test_read_write_float16() -> Result<()> {
+    use half::f16;
+
+    let driver = DriverManager::get_driver_by_name("MEM")?;
+    let ds = driver.create_with_band_type::<f16, _>("", 3, 1, 1)?;
+    let mut rasterband = ds.rasterband(1)?;
+    assert_eq!(rasterband.band_type(), GdalDataType::Float16);
+    let values = vec![f16::from_f32(0.5), f16::MAX, f16::NEG_INFINITY];
+    rasterband.write((0, 0), (3, 1), &mut Buffer::new((3, 1), values.clone()))?;
+    assert_eq!(rasterband.read_band_as::<f16>()?.data(), values);
+
+    // GDAL converts to wider types
+    let widened = rasterband.read_band_as::<f32>()?;
+    assert_eq!(widened.data(), [0.5, 65504.0, f32::NEG_INFINITY]);
+    let buffer = rasterband.read_generic((0, 0), (3, 1), (3, 1), None)?;
+    assert_eq!(buffer, AnyBuffer::Float16(Buffer::new((3, 1), values)));
+
+    Ok(())
+}
+
+#[test]
+fn test_data_coverage_status() -> Result<()> {
+    let driver = DriverManager::get_driver_by_name("MEM")?;
+    let dataset = driver.create_with_band_type::<u8, _>("", 32, 32, 1)?;
+    let status = dataset.rasterband(1)?.data_coverage_status(None)?;
+    assert!(status.is_unimplemented());
+    assert!(status.has_data());
+    assert_eq!(status.data_percent(), 100.0);
+
+    let path = "/vsimem/test_data_coverage_status.tif";
+    let driver = DriverManager::get_driver_by_name("GTiff")?;
+    let options = RasterCreationOptions::from_iter([
+        "TILED=YES",
+        "BLOCKXSIZE=16",
+        "BLOCKYSIZE=16",
+        "SPARSE_OK=TRUE",
+    ]);
+    let mut dataset =
+        driver.create_with_band_type_with_options::<u8, _>(path, 32, 32, 1, &options)?;
+    dataset
+        .rasterband(1)?
+        .write((0, 0), (16, 16), &mut Buffer::new((16, 16), vec![1u8; 256]))?;
+    dataset.flush_cache()?;
+
+    let band = dataset.rasterband(1)?;
+    let status = band.data_coverage_status(None)?;
+    assert!(!status.is_unimplemented());
+    assert!(status.has_data() && status.has_empty());
+    assert_eq!(status.data_percent(), 25.0);
+    assert!(!band
+        .data_coverage_status(Some(((0, 0), (16, 16))))?
+        .has_empty());
+    assert!(band
+        .data_coverage_status(Some(((16, 16), (16, 16))))?
+        .is_empty());
+
+    let _nolog = crate::test_utils::SuppressGDALErrorLog::new();
+    assert!(band.data_coverage_status(Some(((0, 0), (64, 1)))).is_err());
+
+    drop(dataset);
+    unlink_mem_file(path)?;
+    Ok(())
+}
+
+#[test]
+fn test_flush_cache() -> Result<()> {
+    let path = "/vsimem/test_flush_cache.tif";
+    let driver = DriverManager::get_driver_by_name("GTiff")?;
+    let dataset = driver.create_with_band_type::<u8, _>(path, 16, 16, 1)?;
+    let mut band = dataset.rasterband(1)?;
+    band.write((0, 0), (1, 1), &mut Buffer::new((1, 1), vec![1u8]))?;
+    band.flush_cache()?;
+    drop(dataset);
+
+    let dataset = Dataset::open(path)?;
+    let buf = dataset
+        .rasterband(1)?
+        .read_as::<u8>((0, 0), (2, 1), (2, 1), None)?;
+    assert_eq!(buf.data(), [1, 0]);
+    drop(dataset);
+    unlink_mem_file(path)?;
+    Ok(())
+}
+
+#[test]
+#[cfg(any(all(major_ge_3, minor_ge_9), major_ge_4))]
+fn test_drop_cache() -> Result<()> {
+    let path = "/vsimem/test_drop_cache.tif";
+    let driver = DriverManager::get_driver_by_name("GTiff")?;
+    let mut dataset = driver.create_with_band_type::<u8, _>(path, 16, 16, 1)?;
+    let mut band = dataset.rasterband(1)?;
+    band.write((0, 0), (1, 1), &mut Buffer::new((1, 1), vec![1u8]))?;
+    band.flush_cache()?;
+
+    // dropped writes are lost
+    band.write((1, 0), (1, 1), &mut Buffer::new((1, 1), vec![2u8]))?;
+    band.drop_cache()?;
+    dataset.drop_cache()?;
+    drop(dataset);
+
+    let dataset = Dataset::open(path)?;
+    let buf = dataset
+        .rasterband(1)?
+        .read_as::<u8>((0, 0), (2, 1), (2, 1), None)?;
+    assert_eq!(buf.data(), [1, 0]);
+    drop(dataset);
+    unlink_mem_file(path)?;
+    Ok(())
+}
+
+#[test]
+fn test_checksum() -> Result<()> {
+    let dataset = Dataset::open(fixture("tinymarble.tif"))?;
+    let band = dataset.rasterband(1)?;
+    let checksum = band.checksum(None)?;
+    assert_eq!(band.checksum(Some(((0, 0), band.size())))?, checksum);
+
+    // a window has the checksum of a copy of its pixels
+    let window = band.read_as::<u8>((10, 5), (20, 10), (20, 10), None)?;
+    let driver = DriverManager::get_driver_by_name("MEM")?;
+    let copy = driver.create_with_band_type::<u8, _>("", 20, 10, 1)?;
+    let mut copy_band = copy.rasterband(1)?;
+    copy_band.write((0, 0), (20, 10), &mut window.clone())?;
+    assert_eq!(
+        band.checksum(Some(((10, 5), (20, 10))))?,
+        copy_band.checksum(None)?
+    );
+    copy_band.fill(0., None)?;
+    assert_ne!(
+        copy_band.checksum(None)?,
+        band.checksum(Some(((10, 5), (20, 10))))?
+    );
+
+    #[cfg(any(all(major_ge_3, minor_ge_6), major_ge_4))]
+    {
+        let _nolog = crate::test_utils::SuppressGDALErrorLog::new();
+        assert!(band.checksum(Some(((0, 0), (10_000, 1)))).is_err());
+    }
+
+    Ok(())
+}
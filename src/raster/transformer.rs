@@ -0,0 +1,390 @@
+use std::ptr::{null_mut, NonNull};
+use std::sync::Mutex;
+
+use gdal_sys::{CPLErr, GDALTransformerFunc};
+use libc::{c_int, c_void};
+
+use crate::cpl::CslStringList;
+use crate::errors::*;
+use crate::gcp::{CGcps, Gcp};
+use crate::raster::Transformer;
+use crate::utils::{_last_cpl_err, _last_null_pointer_err};
+use crate::Dataset;
+
+/// A transformer implemented by GDAL, mapping between pixel/line and georeferenced coordinates.
+///
+/// It can be used on its own, such as to map the pixels of a dataset georeferenced by GCPs or
+/// RPCs, or passed to [`WarpOperation::with_transformer`](crate::raster::WarpOperation::with_transformer).
+///
+/// GDAL transformers are not thread-safe, so the calls through a shared transformer are
+/// serialized.
+///
+/// # Example
+///
+/// ```rust, no_run
+/// # fn main() -> gdal::errors::Result<()> {
+/// use gdal::cpl::CslStringList;
+/// use gdal::raster::GdalTransformer;
+/// use gdal::Dataset;
+///
+/// let dataset = Dataset::open("fixtures/gcp.tif")?;
+/// let mut options = CslStringList::new();
+/// options.set_name_value("METHOD", "GCP_TPS")?;
+/// let transformer = GdalTransformer::gen_img_proj(Some(&dataset), None, &options)?;
+///
+/// let (mut x, mut y) = ([10.5], [20.5]);
+/// transformer.transform_coords(false, &mut x, &mut y, &mut [])?;
+/// println!("pixel (10, 20) is centered on ({}, {})", x[0], y[0]);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct GdalTransformer {
+    func: GDALTransformerFunc,
+    arg: NonNull<c_void>,
+    lock: Mutex<()>,
+}
+
+// The transformer is only used while holding `lock`.
+unsafe impl Send for GdalTransformer {}
+unsafe impl Sync for GdalTransformer {}
+
+impl GdalTransformer {
+    fn from_c_transformer(
+        func: GDALTransformerFunc,
+        arg: *mut c_void,
+        method_name: &'static str,
+    ) -> Result<Self> {
+        match NonNull::new(arg) {
+            Some(arg) => Ok(Self {
+                func,
+                arg,
+                lock: Mutex::new(()),
+            }),
+            None => Err(_last_null_pointer_err(method_name)),
+        }
+    }
+
+    /// Create a transformer between the pixel/line coordinates of `src` and `dst`, going
+    /// through their georeferencing.
+    ///
+    /// Without `src` or `dst`, that side uses georeferenced coordinates instead.
+    /// `options` are `NAME=VALUE` pairs such as `SRC_SRS`, `DST_SRS` or `METHOD`, which selects
+    /// the geo-transform, the GCPs (`GCP_POLYNOMIAL` or `GCP_TPS`), the RPCs or the geolocation
+    /// arrays of a dataset.
+    ///
+    /// See: [`GDALCreateGenImgProjTransformer2`](https://gdal.org/api/gdal_alg.html#_CPPv432GDALCreateGenImgProjTransformer212GDALDatasetH12GDALDatasetHPPc)
+    pub fn gen_img_proj(
+        src: Option<&Dataset>,
+        dst: Option<&Dataset>,
+        options: &CslStringList,
+    ) -> Result<Self> {
+        let arg = unsafe {
+            gdal_sys::GDALCreateGenImgProjTransformer2(
+                src.map_or(null_mut(), |ds| ds.c_dataset()),
+                dst.map_or(null_mut(), |ds| ds.c_dataset()),
+                options.as_ptr() as *mut _,
+            )
+        };
+        Self::from_c_transformer(
+            Some(gdal_sys::GDALGenImgProjTransform),
+            arg,
+            "GDALCreateGenImgProjTransformer2",
+        )
+    }
+
+    /// Create a transformer from the pixel/line coordinates of `dataset` to longitudes and
+    /// latitudes, using its rational polynomial coefficients (the `RPC` metadata domain).
+    ///
+    /// `options` are `NAME=VALUE` pairs such as `RPC_HEIGHT` or `RPC_DEM`, passed to
+    /// [`gen_img_proj`](Self::gen_img_proj).
+    ///
+    /// See: [`GDALCreateRPCTransformer`](https://gdal.org/api/gdal_alg.html#_CPPv426GDALCreateRPCTransformerV2PK13GDALRPCInfoV2idPPc)
+    pub fn rpc(dataset: &Dataset, options: &CslStringList) -> Result<Self> {
+        let mut options = options.clone();
+        options.set_name_value("METHOD", "RPC")?;
+        Self::gen_img_proj(Some(dataset), None, &options)
+    }
+
+    /// Create a polynomial transformer from pixel/line to georeferenced coordinates, fitted to
+    /// `gcps` by least squares.
+    ///
+    /// `order` is 1 to 3, or 0 for the highest order supported by the number of GCPs.
+    /// With `reversed`, the transformer maps the other way.
+    ///
+    /// See: [`GDALCreateGCPTransformer`](https://gdal.org/api/gdal_alg.html#_CPPv424GDALCreateGCPTransformeriPK8GDAL_GCPii)
+    pub fn gcp_polynomial(gcps: &[Gcp], order: u32, reversed: bool) -> Result<Self> {
+        let c_gcps = CGcps::new(gcps)?;
+        let arg = unsafe {
+            gdal_sys::GDALCreateGCPTransformer(
+                c_gcps.len().try_into()?,
+                c_gcps.as_ptr(),
+                order.try_into()?,
+                c_int::from(reversed),
+            )
+        };
+        Self::from_c_transformer(
+            Some(gdal_sys::GDALGCPTransform),
+            arg,
+            "GDALCreateGCPTransformer",
+        )
+    }
+
+    /// Create a thin plate spline transformer from pixel/line to georeferenced coordinates,
+    /// going exactly through `gcps`.
+    ///
+    /// With `reversed`, the transformer maps the other way.
+    ///
+    /// See: [`GDALCreateTPSTransformer`](https://gdal.org/api/gdal_alg.html#_CPPv424GDALCreateTPSTransformeriPK8GDAL_GCPi)
+    pub fn gcp_tps(gcps: &[Gcp], reversed: bool) -> Result<Self> {
+        let c_gcps = CGcps::new(gcps)?;
+        let arg = unsafe {
+            gdal_sys::GDALCreateTPSTransformer(
+                c_gcps.len().try_into()?,
+                c_gcps.as_ptr(),
+                c_int::from(reversed),
+            )
+        };
+        Self::from_c_transformer(
+            Some(gdal_sys::GDALTPSTransform),
+            arg,
+            "GDALCreateTPSTransformer",
+        )
+    }
+
+    /// Wrap this transformer into one that transforms the ends and middle of each run of
+    /// points exactly, and interpolates linearly between them while the error stays below
+    /// `max_error`.
+    ///
+    /// This speeds up expensive transformers, typically with a `max_error` of 0.125 pixels.
+    ///
+    /// See: [`GDALCreateApproxTransformer`](https://gdal.org/api/gdal_alg.html#_CPPv427GDALCreateApproxTransformer19GDALTransformerFuncPvd)
+    pub fn approximate(self, max_error: f64) -> Result<Self> {
+        let arg = unsafe {
+            gdal_sys::GDALCreateApproxTransformer(self.func, self.arg.as_ptr(), max_error)
+        };
+        let approx = Self::from_c_transformer(
+            Some(gdal_sys::GDALApproxTransform),
+            arg,
+            "GDALCreateApproxTransformer",
+        )?;
+        unsafe { gdal_sys::GDALApproxTransformerOwnsSubtransformer(arg, 1) };
+        // now owned by `approx`
+        std::mem::forget(self);
+        Ok(approx)
+    }
+
+    /// Transform coordinates in place, from the source to the destination coordinates (such as
+    /// pixel/line to georeferenced coordinates), or the reverse if `dst_to_src` is `true`.
+    ///
+    /// `z` can be empty to ignore the heights. Fails if any of the points can't be transformed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slices have different lengths.
+    pub fn transform_coords(
+        &self,
+        dst_to_src: bool,
+        x: &mut [f64],
+        y: &mut [f64],
+        z: &mut [f64],
+    ) -> Result<()> {
+        let n = x.len();
+        assert_eq!(n, y.len(), "coordinate slices have different lengths");
+        let mut z_buf;
+        let z = if z.is_empty() {
+            z_buf = vec![0.0; n];
+            &mut z_buf[..]
+        } else {
+            assert_eq!(n, z.len(), "coordinate slices have different lengths");
+            z
+        };
+        let mut success = vec![false; n];
+        if !self.transform(dst_to_src, x, y, z, &mut success) || success.contains(&false) {
+            return Err(_last_cpl_err(CPLErr::CE_Failure));
+        }
+        Ok(())
+    }
+
+    /// Returns the GDAL transformer function and its argument, for use with other GDAL APIs.
+    ///
+    /// # Safety
+    /// The argument must only be used while `self` is alive, and not from several threads at once.
+    pub unsafe fn c_transformer(&self) -> (GDALTransformerFunc, *mut c_void) {
+        (self.func, self.arg.as_ptr())
+    }
+}
+
+impl Transformer for GdalTransformer {
+    fn transform(
+        &self,
+        dst_to_src: bool,
+        x: &mut [f64],
+        y: &mut [f64],
+        z: &mut [f64],
+        success: &mut [bool],
+    ) -> bool {
+        let len = x.len();
+        if y.len() != len || success.len() != len || !(z.is_empty() || z.len() == len) {
+            return false;
+        }
+        let (func, n) = match (self.func, c_int::try_from(len)) {
+            (Some(func), Ok(n)) => (func, n),
+            _ => return false,
+        };
+        let mut z_buf;
+        let z = if z.is_empty() {
+            z_buf = vec![0.0; len];
+            &mut z_buf[..]
+        } else {
+            z
+        };
+        let mut c_success = vec![0; len];
+        let _guard = self.lock.lock().unwrap_or_else(|e| e.into_inner());
+        let rv = unsafe {
+            func(
+                self.arg.as_ptr(),
+                c_int::from(dst_to_src),
+                n,
+                x.as_mut_ptr(),
+                y.as_mut_ptr(),
+                z.as_mut_ptr(),
+                c_success.as_mut_ptr(),
+            )
+        };
+        for (s, c) in success.iter_mut().zip(c_success) {
+            *s = c != 0;
+        }
+        rv != 0
+    }
+}
+
+impl Drop for GdalTransformer {
+    fn drop(&mut self) {
+        unsafe { gdal_sys::GDALDestroyTransformer(self.arg.as_ptr()) };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::raster::{Buffer, WarpOperation, WarpOperationOptions};
+    use crate::spatial_ref::SpatialRef;
+    use crate::test_utils::SuppressGDALErrorLog;
+    use crate::DriverManager;
+
+    const GEO_TRANSFORM: [f64; 6] = [10., 2., 0., 20., 0., -2.];
+
+    /// GCPs following `GEO_TRANSFORM`.
+    fn gcps() -> Vec<Gcp> {
+        [(0., 0.), (4., 0.), (0., 4.), (4., 4.), (2., 1.)]
+            .into_iter()
+            .enumerate()
+            .map(|(i, (pixel, line))| Gcp {
+                id: i.to_string(),
+                info: String::new(),
+                pixel,
+                line,
+                x: 10. + 2. * pixel,
+                y: 20. - 2. * line,
+                z: 0.,
+            })
+            .collect()
+    }
+
+    fn assert_maps_geo_transform(transformer: &GdalTransformer) {
+        let (mut x, mut y) = ([1., 3.], [1., 2.]);
+        transformer
+            .transform_coords(false, &mut x, &mut y, &mut [])
+            .unwrap();
+        for (v, expected) in x.iter().chain(&y).zip([12., 16., 18., 16.]) {
+            assert!((v - expected).abs() < 1e-6, "{v} != {expected}");
+        }
+        transformer
+            .transform_coords(true, &mut x, &mut y, &mut [])
+            .unwrap();
+        for (v, expected) in x.iter().chain(&y).zip([1., 3., 1., 2.]) {
+            assert!((v - expected).abs() < 1e-6, "{v} != {expected}");
+        }
+    }
+
+    #[test]
+    fn test_gen_img_proj() {
+        let driver = DriverManager::get_driver_by_name("MEM").unwrap();
+        let mut ds = driver.create("", 4, 4, 1).unwrap();
+        ds.set_geo_transform(&GEO_TRANSFORM).unwrap();
+        let transformer = GdalTransformer::gen_img_proj(Some(&ds), None, &CslStringList::new())
+            .unwrap()
+            .approximate(0.125)
+            .unwrap();
+        assert_maps_geo_transform(&transformer);
+    }
+
+    #[test]
+    fn test_gcp_transformers() {
+        assert_maps_geo_transform(&GdalTransformer::gcp_polynomial(&gcps(), 1, false).unwrap());
+        assert_maps_geo_transform(&GdalTransformer::gcp_tps(&gcps(), false).unwrap());
+
+        let reversed = GdalTransformer::gcp_polynomial(&gcps(), 0, true).unwrap();
+        let (mut x, mut y) = ([12.], [18.]);
+        reversed
+            .transform_coords(false, &mut x, &mut y, &mut [])
+            .unwrap();
+        assert!((x[0] - 1.).abs() < 1e-6 && (y[0] - 1.).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_transformer_errors() {
+        let _nolog = SuppressGDALErrorLog::new();
+        assert!(GdalTransformer::gcp_polynomial(&gcps()[..1], 1, false).is_err());
+
+        let driver = DriverManager::get_driver_by_name("MEM").unwrap();
+        let ds = driver.create("", 4, 4, 1).unwrap();
+        assert!(GdalTransformer::rpc(&ds, &CslStringList::new()).is_err());
+
+        let transformer = GdalTransformer::gcp_polynomial(&gcps(), 1, false).unwrap();
+        let (mut x, mut y, mut success) = ([1., 3.], [1., 2.], [false; 2]);
+        assert!(!transformer.transform(false, &mut [0.; 10], &mut [], &mut [], &mut []));
+        assert!(!transformer.transform(false, &mut x, &mut y, &mut [0.; 1], &mut success));
+        assert!(!transformer.transform(false, &mut x, &mut y, &mut [], &mut [false; 1]));
+        assert!(transformer.transform(false, &mut x, &mut y, &mut [], &mut success));
+        assert_eq!(success, [true; 2]);
+    }
+
+    #[test]
+    fn test_warp_with_gdal_transformer() {
+        let driver = DriverManager::get_driver_by_name("MEM").unwrap();
+        let srs = SpatialRef::from_epsg(4326).unwrap();
+        let mut src = driver.create_with_band_type::<f64, _>("", 4, 4, 1).unwrap();
+        src.set_geo_transform(&GEO_TRANSFORM).unwrap();
+        src.set_spatial_ref(&srs).unwrap();
+        let data = (0..16).map(f64::from).collect();
+        src.rasterband(1)
+            .unwrap()
+            .write((0, 0), (4, 4), &mut Buffer::new((4, 4), data))
+            .unwrap();
+        let create_dst = || {
+            let mut dst = driver.create_with_band_type::<f64, _>("", 2, 2, 1).unwrap();
+            dst.set_geo_transform(&[10., 4., 0., 20., 0., -4.]).unwrap();
+            dst.set_spatial_ref(&srs).unwrap();
+            dst
+        };
+
+        // the same as the default transformer
+        let options = WarpOperationOptions::new();
+        let expected = create_dst();
+        WarpOperation::new(&src, &expected, &options)
+            .unwrap()
+            .chunk_and_warp_image((0, 0), (2, 2))
+            .unwrap();
+        let dst = create_dst();
+        let transformer =
+            GdalTransformer::gen_img_proj(Some(&src), Some(&dst), &CslStringList::new()).unwrap();
+        WarpOperation::with_transformer(&src, &dst, &options, transformer)
+            .unwrap()
+            .chunk_and_warp_image((0, 0), (2, 2))
+            .unwrap();
+        let read = |ds: &Dataset| ds.rasterband(1).unwrap().read_band_as::<f64>().unwrap();
+        assert_eq!(read(&dst).data(), read(&expected).data());
+    }
+}
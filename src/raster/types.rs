@@ -1,13 +1,19 @@
 use crate::errors::{GdalError, Result};
 use crate::utils::_string;
 use gdal_sys::{
-    GDALAdjustValueToDataType, GDALDataType, GDALDataTypeIsConversionLossy, GDALDataTypeIsFloating,
-    GDALDataTypeIsInteger, GDALDataTypeIsSigned, GDALDataTypeUnion, GDALFindDataTypeForValue,
-    GDALGetDataTypeByName, GDALGetDataTypeName, GDALGetDataTypeSizeBits, GDALGetDataTypeSizeBytes,
+    GDALAdjustValueToDataType, GDALDataType, GDALDataTypeIsComplex, GDALDataTypeIsConversionLossy,
+    GDALDataTypeIsFloating, GDALDataTypeIsInteger, GDALDataTypeIsSigned, GDALDataTypeUnion,
+    GDALFindDataTypeForValue, GDALGetDataTypeByName, GDALGetDataTypeName, GDALGetDataTypeSizeBits,
+    GDALGetDataTypeSizeBytes,
 };
 use std::ffi::CString;
 use std::fmt::{Debug, Display, Formatter};
 
+#[cfg(all(feature = "half", any(all(major_ge_3, minor_ge_11), major_ge_4)))]
+use half::f16;
+#[cfg(feature = "num-complex")]
+use num_complex::Complex;
+
 /// Provides ergonomic access to functions describing [`GDALDataType`] ordinals.
 ///
 /// A [`GDALDataType`] indicates the primitive storage value of a cell/pixel in a [`RasterBand`][crate::raster::RasterBand].
@@ -46,10 +52,21 @@ pub enum GdalDataType {
     #[cfg(all(major_ge_3, minor_ge_5))]
     /// 64 bit signed integer  (GDAL >= 3.5)
     Int64 = GDALDataType::GDT_Int64,
+    /// Sixteen bit floating point (GDAL >= 3.11)
+    #[cfg(any(all(major_ge_3, minor_ge_11), major_ge_4))]
+    Float16 = GDALDataType::GDT_Float16,
     /// Thirty two bit floating point
     Float32 = GDALDataType::GDT_Float32,
     /// Sixty four bit floating point
     Float64 = GDALDataType::GDT_Float64,
+    /// Complex Int16
+    CInt16 = GDALDataType::GDT_CInt16,
+    /// Complex Int32
+    CInt32 = GDALDataType::GDT_CInt32,
+    /// Complex Float32
+    CFloat32 = GDALDataType::GDT_CFloat32,
+    /// Complex Float64
+    CFloat64 = GDALDataType::GDT_CFloat64,
 }
 
 impl GdalDataType {
@@ -134,6 +151,11 @@ impl GdalDataType {
         (unsafe { GDALDataTypeIsFloating(self.gdal_ordinal()) }) > 0
     }
 
+    /// Returns `true` if [`GDALDataType`] is complex.
+    pub fn is_complex(&self) -> bool {
+        (unsafe { GDALDataTypeIsComplex(self.gdal_ordinal()) }) > 0
+    }
+
     /// Returns `true` if [`GDALDataType`] supports negative values.
     pub fn is_signed(&self) -> bool {
         (unsafe { GDALDataTypeIsSigned(self.gdal_ordinal()) }) > 0
@@ -205,7 +227,7 @@ impl GdalDataType {
         r != 0
     }
 
-    /// Subset of the GDAL data types supported by Rust bindings.
+    /// Subset of the GDAL data types supported by Rust bindings, excluding the complex types.
     pub fn iter() -> impl Iterator<Item = GdalDataType> {
         use GdalDataType::*;
         [
@@ -220,6 +242,8 @@ impl GdalDataType {
             UInt64,
             #[cfg(all(major_ge_3, minor_ge_5))]
             Int64,
+            #[cfg(any(all(major_ge_3, minor_ge_11), major_ge_4))]
+            Float16,
             Float32,
             Float64,
         ]
@@ -279,11 +303,14 @@ impl TryFrom<u32> for GdalDataType {
             GDT_UInt64 => Ok(GdalDataType::UInt64),
             #[cfg(all(major_ge_3, minor_ge_5))]
             GDT_Int64 => Ok(GdalDataType::Int64),
+            #[cfg(any(all(major_ge_3, minor_ge_11), major_ge_4))]
+            GDT_Float16 => Ok(GdalDataType::Float16),
             GDT_Float32 => Ok(GdalDataType::Float32),
             GDT_Float64 => Ok(GdalDataType::Float64),
-            GDT_CInt16 | GDT_CInt32 | GDT_CFloat32 | GDT_CFloat64 => Err(GdalError::BadArgument(
-                "Complex data types are not available".into(),
-            )),
+            GDT_CInt16 => Ok(GdalDataType::CInt16),
+            GDT_CInt32 => Ok(GdalDataType::CInt32),
+            GDT_CFloat32 => Ok(GdalDataType::CFloat32),
+            GDT_CFloat64 => Ok(GdalDataType::CFloat64),
             o => Err(GdalError::BadArgument(format!(
                 "unknown GDALDataType ordinal `{o}`"
             ))),
@@ -399,6 +426,15 @@ impl GdalType for i64 {
     }
 }
 
+/// Provides evidence `f16` is a valid [`GDALDataType`].
+#[cfg(all(feature = "half", any(all(major_ge_3, minor_ge_11), major_ge_4)))]
+#[cfg_attr(docsrs, doc(cfg(feature = "float16")))]
+impl GdalType for f16 {
+    fn gdal_ordinal() -> GDALDataType::Type {
+        GDALDataType::GDT_Float16
+    }
+}
+
 /// Provides evidence `f32` is a valid [`GDALDataType`].
 impl GdalType for f32 {
     fn gdal_ordinal() -> GDALDataType::Type {
@@ -413,6 +449,42 @@ impl GdalType for f64 {
     }
 }
 
+/// Provides evidence `Complex<i16>` is a valid [`GDALDataType`].
+#[cfg(feature = "num-complex")]
+#[cfg_attr(docsrs, doc(cfg(feature = "complex")))]
+impl GdalType for Complex<i16> {
+    fn gdal_ordinal() -> GDALDataType::Type {
+        GDALDataType::GDT_CInt16
+    }
+}
+
+/// Provides evidence `Complex<i32>` is a valid [`GDALDataType`].
+#[cfg(feature = "num-complex")]
+#[cfg_attr(docsrs, doc(cfg(feature = "complex")))]
+impl GdalType for Complex<i32> {
+    fn gdal_ordinal() -> GDALDataType::Type {
+        GDALDataType::GDT_CInt32
+    }
+}
+
+/// Provides evidence `Complex<f32>` is a valid [`GDALDataType`].
+#[cfg(feature = "num-complex")]
+#[cfg_attr(docsrs, doc(cfg(feature = "complex")))]
+impl GdalType for Complex<f32> {
+    fn gdal_ordinal() -> GDALDataType::Type {
+        GDALDataType::GDT_CFloat32
+    }
+}
+
+/// Provides evidence `Complex<f64>` is a valid [`GDALDataType`].
+#[cfg(feature = "num-complex")]
+#[cfg_attr(docsrs, doc(cfg(feature = "complex")))]
+impl GdalType for Complex<f64> {
+    fn gdal_ordinal() -> GDALDataType::Type {
+        GDALDataType::GDT_CFloat64
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -448,6 +520,11 @@ mod tests {
                     assert!(!t.is_integer(), "{}", &name);
                     assert!(t.is_floating(), "{}", &name);
                 }
+                #[cfg(any(all(major_ge_3, minor_ge_11), major_ge_4))]
+                GDT_Float16 => {
+                    assert!(!t.is_integer(), "{}", &name);
+                    assert!(t.is_floating(), "{}", &name);
+                }
 
                 o => panic!("unknown type ordinal '{o}'"),
             }
@@ -470,13 +547,21 @@ mod tests {
                 GDT_Int64 => {
                     assert!(t.is_signed(), "{}", &name);
                 }
+                #[cfg(any(all(major_ge_3, minor_ge_11), major_ge_4))]
+                GDT_Float16 => {
+                    assert!(t.is_signed(), "{}", &name);
+                }
                 o => panic!("unknown type ordinal '{o}'"),
             }
         }
 
         for t in [GDT_CInt16, GDT_CInt32, GDT_CFloat32, GDT_CFloat64] {
-            assert!(TryInto::<GdalDataType>::try_into(t).is_err());
+            let t2: GdalDataType = t.try_into().unwrap();
+            assert_eq!(t2.gdal_ordinal(), t);
+            assert!(t2.is_complex(), "{t2}");
+            assert!(!GdalDataType::iter().any(|t| t == t2));
         }
+        assert!(!<f64>::datatype().is_complex());
     }
 
     #[test]
@@ -0,0 +1,271 @@
+use std::ffi::CString;
+use std::path::Path;
+
+use crate::errors::*;
+use crate::progress::{cancelled_or, progress_args, CancellationToken};
+use crate::raster::{RasterBand, RasterCreationOptions};
+use crate::utils::{_last_null_pointer_err, _path_to_c_string};
+use crate::Dataset;
+
+/// How [`viewshed`] computes the height of the cells between the observer and a target.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ViewshedCellMode {
+    /// From the diagonal neighbours.
+    Diagonal,
+    /// From the edge neighbours (the default).
+    #[default]
+    Edge,
+    /// The maximum of the diagonal and edge heights.
+    Max,
+    /// The minimum of the diagonal and edge heights.
+    Min,
+}
+
+impl ViewshedCellMode {
+    fn to_gdal(self) -> gdal_sys::GDALViewshedMode::Type {
+        match self {
+            ViewshedCellMode::Diagonal => gdal_sys::GDALViewshedMode::GVM_Diagonal,
+            ViewshedCellMode::Edge => gdal_sys::GDALViewshedMode::GVM_Edge,
+            ViewshedCellMode::Max => gdal_sys::GDALViewshedMode::GVM_Max,
+            ViewshedCellMode::Min => gdal_sys::GDALViewshedMode::GVM_Min,
+        }
+    }
+}
+
+/// Values written by [`viewshed`] to the output band.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ViewshedOutputMode {
+    /// The visibility of each cell, as the visible, invisible or out of range value
+    /// (the default).
+    #[default]
+    Visibility,
+    /// The minimum height of a target at each cell to be visible, above the DEM's datum.
+    MinTargetHeightFromDem,
+    /// The minimum height of a target at each cell to be visible, above the ground.
+    MinTargetHeightFromGround,
+}
+
+impl ViewshedOutputMode {
+    fn to_gdal(self) -> gdal_sys::GDALViewshedOutputType::Type {
+        match self {
+            ViewshedOutputMode::Visibility => gdal_sys::GDALViewshedOutputType::GVOT_NORMAL,
+            ViewshedOutputMode::MinTargetHeightFromDem => {
+                gdal_sys::GDALViewshedOutputType::GVOT_MIN_TARGET_HEIGHT_FROM_DEM
+            }
+            ViewshedOutputMode::MinTargetHeightFromGround => {
+                gdal_sys::GDALViewshedOutputType::GVOT_MIN_TARGET_HEIGHT_FROM_GROUND
+            }
+        }
+    }
+}
+
+/// Configuration options for [`viewshed`].
+///
+/// These map to the [`gdal_viewshed`] command line options, with the same defaults.
+///
+/// [`gdal_viewshed`]: https://gdal.org/programs/gdal_viewshed.html
+#[derive(Debug, Clone)]
+pub struct ViewshedOptions {
+    observer: (f64, f64),
+    observer_height: f64,
+    target_height: f64,
+    visible_value: f64,
+    invisible_value: f64,
+    out_of_range_value: f64,
+    no_data_value: f64,
+    curvature_coefficient: f64,
+    cell_mode: ViewshedCellMode,
+    max_distance: f64,
+    output_mode: ViewshedOutputMode,
+    output_format: String,
+    creation_options: RasterCreationOptions,
+    cancellation: Option<CancellationToken>,
+}
+
+impl ViewshedOptions {
+    /// Create options for an observer at `(x, y)`, in georeferenced coordinates, and at
+    /// `height` above the ground.
+    pub fn new(x: f64, y: f64, height: f64) -> Self {
+        Self {
+            observer: (x, y),
+            observer_height: height,
+            target_height: 0.,
+            visible_value: 255.,
+            invisible_value: 0.,
+            out_of_range_value: 0.,
+            no_data_value: -1.,
+            curvature_coefficient: 0.85714,
+            cell_mode: Default::default(),
+            max_distance: 0.,
+            output_mode: Default::default(),
+            output_format: "MEM".to_string(),
+            creation_options: Default::default(),
+            cancellation: None,
+        }
+    }
+
+    /// Set the height of the targets above the ground. Defaults to `0`.
+    pub fn with_target_height(&mut self, height: f64) -> &mut Self {
+        self.target_height = height;
+        self
+    }
+
+    /// Set the values of the visible, invisible and out of range cells.
+    /// Default to `255`, `0` and `0`.
+    pub fn with_values(&mut self, visible: f64, invisible: f64, out_of_range: f64) -> &mut Self {
+        self.visible_value = visible;
+        self.invisible_value = invisible;
+        self.out_of_range_value = out_of_range;
+        self
+    }
+
+    /// Set the nodata value of the output band. Defaults to `-1`, for none.
+    pub fn with_no_data_value(&mut self, no_data: f64) -> &mut Self {
+        self.no_data_value = no_data;
+        self
+    }
+
+    /// Set the coefficient of the curvature of the earth, corrected by the atmospheric
+    /// refraction. Defaults to `0.85714`, for visible light; use `1` to ignore the refraction,
+    /// or `0` to ignore the curvature.
+    pub fn with_curvature_coefficient(&mut self, coefficient: f64) -> &mut Self {
+        self.curvature_coefficient = coefficient;
+        self
+    }
+
+    /// Set how the heights of the cells are computed.
+    pub fn with_cell_mode(&mut self, mode: ViewshedCellMode) -> &mut Self {
+        self.cell_mode = mode;
+        self
+    }
+
+    /// Only compute the visibility up to `distance` from the observer, in georeferenced units.
+    ///
+    /// Defaults to `0`, for the whole raster.
+    pub fn with_max_distance(&mut self, distance: f64) -> &mut Self {
+        self.max_distance = distance;
+        self
+    }
+
+    /// Set what is written to the output band.
+    pub fn with_output_mode(&mut self, mode: ViewshedOutputMode) -> &mut Self {
+        self.output_mode = mode;
+        self
+    }
+
+    /// Specify the output format, as the short name of a driver. Defaults to `MEM`.
+    pub fn with_output_format(&mut self, format: &str) -> &mut Self {
+        self.output_format = format.to_owned();
+        self
+    }
+
+    /// Pass creation options to the output driver.
+    pub fn with_creation_options(&mut self, options: &RasterCreationOptions) -> &mut Self {
+        self.creation_options = options.clone();
+        self
+    }
+
+    /// Abort the computation with [`GdalError::Cancelled`] once `token` is cancelled.
+    pub fn with_cancellation(&mut self, token: &CancellationToken) -> &mut Self {
+        self.cancellation = Some(token.clone());
+        self
+    }
+}
+
+/// Compute the cells of the DEM `band` visible from an observer, into a new single band dataset.
+///
+/// With the default `MEM` output format, `dest` can be empty.
+///
+/// # Example
+///
+/// ```rust, no_run
+/// # fn main() -> gdal::errors::Result<()> {
+/// use gdal::raster::{viewshed, ViewshedOptions};
+/// use gdal::Dataset;
+///
+/// let dem = Dataset::open("fixtures/dem-hills.tiff")?;
+/// let mut options = ViewshedOptions::new(-1305000., 6130000., 30.);
+/// options
+///     .with_target_height(10.)
+///     .with_max_distance(5000.)
+///     .with_output_format("GTiff");
+/// let visible = viewshed(&dem.rasterband(1)?, "/tmp/viewshed.tif", &options)?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// See: [`GDALViewshedGenerate`](https://gdal.org/api/gdal_alg.html#_CPPv420GDALViewshedGenerate15GDALRasterBandHPKcPKc12CSLConstListddddddddd16GDALViewshedModed16GDALProgressFuncPv22GDALViewshedOutputType12CSLConstList)
+pub fn viewshed<P: AsRef<Path>>(
+    band: &RasterBand,
+    dest: P,
+    options: &ViewshedOptions,
+) -> Result<Dataset> {
+    let driver = CString::new(options.output_format.as_str())?;
+    let dest = _path_to_c_string(dest.as_ref())?;
+    let token = options.cancellation.as_ref();
+    let (progress, progress_arg) = progress_args(token);
+    let out_ds = unsafe {
+        gdal_sys::GDALViewshedGenerate(
+            band.c_rasterband(),
+            driver.as_ptr(),
+            dest.as_ptr(),
+            options.creation_options.as_ptr(),
+            options.observer.0,
+            options.observer.1,
+            options.observer_height,
+            options.target_height,
+            options.visible_value,
+            options.invisible_value,
+            options.out_of_range_value,
+            options.no_data_value,
+            options.curvature_coefficient,
+            options.cell_mode.to_gdal(),
+            options.max_distance,
+            progress,
+            progress_arg,
+            options.output_mode.to_gdal(),
+            std::ptr::null_mut(),
+        )
+    };
+    if out_ds.is_null() {
+        let err = _last_null_pointer_err("GDALViewshedGenerate");
+        return Err(cancelled_or(token, err));
+    }
+    Ok(unsafe { Dataset::from_c_dataset(out_ds) })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::raster::Buffer;
+    use crate::DriverManager;
+
+    #[test]
+    fn test_viewshed() {
+        // a flat 7x1 terrain, with a wall in the middle
+        let driver = DriverManager::get_driver_by_name("MEM").unwrap();
+        let mut dem = driver.create_with_band_type::<f32, _>("", 7, 1, 1).unwrap();
+        dem.set_geo_transform(&[0., 1., 0., 1., 0., -1.]).unwrap();
+        let heights = vec![0., 0., 0., 0., 10., 0., 0.];
+        dem.rasterband(1)
+            .unwrap()
+            .write((0, 0), (7, 1), &mut Buffer::new((7, 1), heights))
+            .unwrap();
+        let band = dem.rasterband(1).unwrap();
+
+        let mut options = ViewshedOptions::new(0.5, 0.5, 1.);
+        options.with_curvature_coefficient(0.);
+        let out = viewshed(&band, "", &options).unwrap();
+        assert_eq!(out.raster_size(), (7, 1));
+        let visible = out.rasterband(1).unwrap().read_band_as::<u8>().unwrap();
+        assert_eq!(visible.data(), [255, 255, 255, 255, 255, 0, 0]);
+
+        let token = CancellationToken::new();
+        token.cancel();
+        options.with_cancellation(&token);
+        assert!(matches!(
+            viewshed(&band, "", &options),
+            Err(GdalError::Cancelled)
+        ));
+    }
+}
@@ -0,0 +1,337 @@
+//! Memory-mapped views of rasters.
+
+use std::marker::PhantomData;
+use std::ops::Deref;
+use std::ptr::NonNull;
+
+use gdal_sys::{CPLVirtualMem, GDALRWFlag};
+use libc::c_int;
+
+use crate::dataset::Dataset;
+use crate::errors::*;
+use crate::raster::{GdalType, RasterBand};
+use crate::utils::_last_null_pointer_err;
+
+/// A memory-mapped view of a window of raster data, created by [`RasterBand::virtual_mem`]
+/// or [`Dataset::virtual_mem`].
+///
+/// Dereferences to a slice of the pixels, in row-major order, one band after the other.
+/// The pages of the slice are read through the GDAL block cache the first time they are
+/// accessed, so random access patterns only read the parts of the raster they touch.
+/// With [`RasterBand::virtual_mem_mut`] or [`Dataset::virtual_mem_mut`], the modified pages
+/// are written back when they are evicted from the cache, and when the view is dropped.
+///
+/// Virtual memory is only available on Linux. Errors while reading or writing the pages
+/// are not reported.
+///
+/// # Example
+///
+/// ```rust, no_run
+/// # fn main() -> gdal::errors::Result<()> {
+/// use gdal::Dataset;
+///
+/// let dataset = Dataset::open("fixtures/dem-hills.tiff")?;
+/// let band = dataset.rasterband(1)?;
+/// let dem = band.virtual_mem::<f32>((0, 0), band.size(), 16 * 1024 * 1024)?;
+/// let (cols, _) = dem.shape();
+/// let elevation = |x: usize, y: usize| dem[y * cols + x];
+/// println!("{}", elevation(100, 200));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct VirtualMem<'a, T: GdalType> {
+    c_mem: NonNull<CPLVirtualMem>,
+    shape: (usize, usize),
+    band_count: usize,
+    writable: bool,
+    _data: PhantomData<(&'a Dataset, *mut T)>,
+}
+
+impl<'a, T: Copy + GdalType> VirtualMem<'a, T> {
+    fn from_c_virtual_mem(
+        c_mem: *mut CPLVirtualMem,
+        shape: (usize, usize),
+        band_count: usize,
+        writable: bool,
+        method_name: &'static str,
+    ) -> Result<Self> {
+        match NonNull::new(c_mem) {
+            Some(c_mem) => Ok(Self {
+                c_mem,
+                shape,
+                band_count,
+                writable,
+                _data: PhantomData,
+            }),
+            None => Err(_last_null_pointer_err(method_name)),
+        }
+    }
+
+    /// Returns the size of the window, as `(cols, rows)`.
+    pub fn shape(&self) -> (usize, usize) {
+        self.shape
+    }
+
+    /// Returns the number of bands in the view.
+    pub fn band_count(&self) -> usize {
+        self.band_count
+    }
+
+    /// Returns a mutable slice of the pixels, if the view was created as writable.
+    pub fn as_mut_slice(&mut self) -> Result<&mut [T]> {
+        if !self.writable {
+            return Err(GdalError::BadArgument(
+                "the virtual memory view is read-only".to_string(),
+            ));
+        }
+        let len = self.shape.0 * self.shape.1 * self.band_count;
+        let addr = unsafe { gdal_sys::CPLVirtualMemGetAddr(self.c_mem.as_ptr()) };
+        Ok(unsafe { std::slice::from_raw_parts_mut(addr as *mut T, len) })
+    }
+}
+
+impl<T: Copy + GdalType> Deref for VirtualMem<'_, T> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        let addr = unsafe { gdal_sys::CPLVirtualMemGetAddr(self.c_mem.as_ptr()) };
+        let len = self.shape.0 * self.shape.1 * self.band_count;
+        unsafe { std::slice::from_raw_parts(addr as *const T, len) }
+    }
+}
+
+impl<T: GdalType> Drop for VirtualMem<'_, T> {
+    fn drop(&mut self) {
+        unsafe { gdal_sys::CPLVirtualMemFree(self.c_mem.as_ptr()) };
+    }
+}
+
+fn rw_flag(writable: bool) -> GDALRWFlag::Type {
+    if writable {
+        GDALRWFlag::GF_Write
+    } else {
+        GDALRWFlag::GF_Read
+    }
+}
+
+impl<'a> RasterBand<'a> {
+    /// Map the pixels of this band in `window`, of `window_size` pixels, into memory as a
+    /// read-only [`VirtualMem<T>`].
+    ///
+    /// Up to `cache_size` bytes of pages are kept in memory at once.
+    ///
+    /// See: [`GDALRasterBandGetVirtualMem`](https://gdal.org/api/raster_c_api.html#_CPPv427GDALRasterBandGetVirtualMem15GDALRasterBandH10GDALRWFlagiiiiii12GDALDataTypei7GIntBig6size_t6size_tiPPc)
+    pub fn virtual_mem<T: Copy + GdalType>(
+        &self,
+        window: (isize, isize),
+        window_size: (usize, usize),
+        cache_size: usize,
+    ) -> Result<VirtualMem<'a, T>> {
+        self._virtual_mem(window, window_size, cache_size, false)
+    }
+
+    /// Map the pixels of this band into memory like [`virtual_mem`](Self::virtual_mem), as a
+    /// writable view.
+    pub fn virtual_mem_mut<T: Copy + GdalType>(
+        &mut self,
+        window: (isize, isize),
+        window_size: (usize, usize),
+        cache_size: usize,
+    ) -> Result<VirtualMem<'a, T>> {
+        self._virtual_mem(window, window_size, cache_size, true)
+    }
+
+    fn _virtual_mem<T: Copy + GdalType>(
+        &self,
+        window: (isize, isize),
+        window_size: (usize, usize),
+        cache_size: usize,
+        writable: bool,
+    ) -> Result<VirtualMem<'a, T>> {
+        let elem = std::mem::size_of::<T>();
+        let c_mem = unsafe {
+            gdal_sys::GDALRasterBandGetVirtualMem(
+                self.c_rasterband(),
+                rw_flag(writable),
+                window.0.try_into()?,
+                window.1.try_into()?,
+                window_size.0.try_into()?,
+                window_size.1.try_into()?,
+                window_size.0.try_into()?,
+                window_size.1.try_into()?,
+                T::gdal_ordinal(),
+                elem.try_into()?,
+                (elem * window_size.0).try_into()?,
+                cache_size,
+                0,
+                0,
+                std::ptr::null_mut(),
+            )
+        };
+        VirtualMem::from_c_virtual_mem(
+            c_mem,
+            window_size,
+            1,
+            writable,
+            "GDALRasterBandGetVirtualMem",
+        )
+    }
+}
+
+impl Dataset {
+    /// Map the pixels of `bands` in `window`, of `window_size` pixels, into memory as a
+    /// read-only [`VirtualMem<T>`], one band after the other.
+    ///
+    /// `bands` are 1-based indexes, or empty for all bands. Up to `cache_size` bytes of pages
+    /// are kept in memory at once.
+    ///
+    /// See: [`GDALDatasetGetVirtualMem`](https://gdal.org/api/raster_c_api.html#_CPPv424GDALDatasetGetVirtualMem12GDALDatasetH10GDALRWFlagiiiiii12GDALDataTypeiPiiii6size_t6size_tiPPc)
+    pub fn virtual_mem<T: Copy + GdalType>(
+        &self,
+        window: (isize, isize),
+        window_size: (usize, usize),
+        bands: &[usize],
+        cache_size: usize,
+    ) -> Result<VirtualMem<'_, T>> {
+        self._virtual_mem(window, window_size, bands, cache_size, false)
+    }
+
+    /// Map the pixels of `bands` into memory like [`virtual_mem`](Self::virtual_mem), as a
+    /// writable view.
+    pub fn virtual_mem_mut<T: Copy + GdalType>(
+        &mut self,
+        window: (isize, isize),
+        window_size: (usize, usize),
+        bands: &[usize],
+        cache_size: usize,
+    ) -> Result<VirtualMem<'_, T>> {
+        self._virtual_mem(window, window_size, bands, cache_size, true)
+    }
+
+    fn _virtual_mem<T: Copy + GdalType>(
+        &self,
+        window: (isize, isize),
+        window_size: (usize, usize),
+        bands: &[usize],
+        cache_size: usize,
+        writable: bool,
+    ) -> Result<VirtualMem<'_, T>> {
+        let mut band_map = if bands.is_empty() {
+            (1..=self.raster_count())
+                .map(c_int::try_from)
+                .collect::<std::result::Result<Vec<_>, _>>()?
+        } else {
+            bands
+                .iter()
+                .map(|&b| c_int::try_from(b))
+                .collect::<std::result::Result<Vec<_>, _>>()?
+        };
+        let elem = std::mem::size_of::<T>();
+        let (cols, rows) = window_size;
+        let c_mem = unsafe {
+            gdal_sys::GDALDatasetGetVirtualMem(
+                self.c_dataset(),
+                rw_flag(writable),
+                window.0.try_into()?,
+                window.1.try_into()?,
+                cols.try_into()?,
+                rows.try_into()?,
+                cols.try_into()?,
+                rows.try_into()?,
+                T::gdal_ordinal(),
+                band_map.len().try_into()?,
+                band_map.as_mut_ptr(),
+                elem.try_into()?,
+                (elem * cols).try_into()?,
+                (elem * cols * rows).try_into()?,
+                cache_size,
+                0,
+                0,
+                std::ptr::null_mut(),
+            )
+        };
+        VirtualMem::from_c_virtual_mem(
+            c_mem,
+            window_size,
+            band_map.len(),
+            writable,
+            "GDALDatasetGetVirtualMem",
+        )
+    }
+}
+
+#[cfg(all(test, target_os = "linux"))]
+mod tests {
+    use super::*;
+    use crate::raster::Buffer;
+    use crate::test_utils::{fixture, SuppressGDALErrorLog};
+    use crate::DriverManager;
+
+    #[test]
+    fn test_band_virtual_mem() {
+        let dataset = Dataset::open(fixture("tinymarble.tif")).unwrap();
+        let band = dataset.rasterband(1).unwrap();
+        let expected = band.read_band_as::<u8>().unwrap();
+        let mem = band
+            .virtual_mem::<u8>((0, 0), band.size(), 1024 * 1024)
+            .unwrap();
+        assert_eq!(mem.shape(), band.size());
+        assert_eq!(&mem[..], expected.data());
+
+        let window = band.read_as::<u16>((20, 30), (2, 3), (2, 3), None).unwrap();
+        let mem = band
+            .virtual_mem::<u16>((20, 30), (2, 3), 1024 * 1024)
+            .unwrap();
+        assert_eq!(&mem[..], window.data());
+    }
+
+    #[test]
+    fn test_virtual_mem_mut() {
+        let driver = DriverManager::get_driver_by_name("MEM").unwrap();
+        let mut dataset = driver
+            .create_with_band_type::<f64, _>("", 64, 32, 2)
+            .unwrap();
+        {
+            let mut mem = dataset
+                .virtual_mem_mut::<f64>((0, 0), (64, 32), &[], 1024 * 1024)
+                .unwrap();
+            assert_eq!(mem.band_count(), 2);
+            let data = mem.as_mut_slice().unwrap();
+            data[0] = 1.;
+            data[64 * 32 + 5 * 64 + 3] = 2.;
+        }
+        let read = |band, window| {
+            dataset
+                .rasterband(band)
+                .unwrap()
+                .read_as::<f64>(window, (1, 1), (1, 1), None)
+                .unwrap()
+                .data()[0]
+        };
+        assert_eq!(read(1, (0, 0)), 1.);
+        assert_eq!(read(2, (3, 5)), 2.);
+
+        let mut band = dataset.rasterband(2).unwrap();
+        band.write((0, 0), (1, 1), &mut Buffer::new((1, 1), vec![3.]))
+            .unwrap();
+        let mut mem = band
+            .virtual_mem::<f64>((0, 0), (64, 32), 1024 * 1024)
+            .unwrap();
+        assert_eq!(mem[0], 3.);
+        assert!(mem.as_mut_slice().is_err());
+    }
+
+    #[test]
+    fn test_virtual_mem_errors() {
+        let _nolog = SuppressGDALErrorLog::new();
+        let dataset = Dataset::open(fixture("tinymarble.tif")).unwrap();
+        let band = dataset.rasterband(1).unwrap();
+        assert!(band
+            .virtual_mem::<u8>((0, 0), (1000, 1), 1024 * 1024)
+            .is_err());
+        assert!(dataset
+            .virtual_mem::<u8>((0, 0), (10, 10), &[4], 1024 * 1024)
+            .is_err());
+    }
+}
@@ -1,11 +1,32 @@
+use crate::cpl::CslStringList;
 use crate::dataset::Dataset;
-use crate::utils::_last_cpl_err;
-use gdal_sys::{self, CPLErr, GDALResampleAlg};
-use std::ptr::{null, null_mut};
+use crate::progress::{cancelled_or, progress_args, CancellationToken};
+use crate::raster::GdalDataType;
+use crate::spatial_ref::SpatialRef;
+use crate::utils::{_last_cpl_err, _last_null_pointer_err};
+use crate::GeoTransform;
+use gdal_sys::{self, CPLErr, GDALResampleAlg, GDALWarpOperationH, GDALWarpOptions};
+use libc::{c_int, c_void};
+use std::marker::PhantomData;
+use std::ptr::{null, null_mut, NonNull};
 
 use crate::errors::*;
 
 pub fn reproject(src: &Dataset, dst: &Dataset) -> Result<()> {
+    _reproject(src, dst, None)
+}
+
+/// Reproject like [`reproject`], stopping with [`GdalError::Cancelled`] once `token` is cancelled.
+pub fn reproject_with_cancellation(
+    src: &Dataset,
+    dst: &Dataset,
+    token: &CancellationToken,
+) -> Result<()> {
+    _reproject(src, dst, Some(token))
+}
+
+fn _reproject(src: &Dataset, dst: &Dataset, token: Option<&CancellationToken>) -> Result<()> {
+    let (progress, progress_arg) = progress_args(token);
     let rv = unsafe {
         gdal_sys::GDALReprojectImage(
             src.c_dataset(),
@@ -15,13 +36,582 @@ pub fn reproject(src: &Dataset, dst: &Dataset) -> Result<()> {
             GDALResampleAlg::GRA_Bilinear,
             0.0,
             0.0,
-            None,
+            progress,
+            progress_arg,
             null_mut(),
+        )
+    };
+    if rv != CPLErr::CE_None {
+        return Err(cancelled_or(token, _last_cpl_err(rv)));
+    }
+    Ok(())
+}
+
+/// Reproject `src` into `dst`, like [`reproject`], with the settings of `options`.
+///
+/// Wraps [`GDALReprojectImage`](https://gdal.org/api/gdalwarp_cpp.html#_CPPv418GDALReprojectImage12GDALDatasetHPKc12GDALDatasetHPKc15GDALResampleAlgddP16GDALProgressFuncPvP15GDALWarpOptions),
+/// which always uses the default transformer, so
+/// [`WarpOperationOptions::with_transformer_options`] is ignored.
+pub fn reproject_with_options(
+    src: &Dataset,
+    dst: &Dataset,
+    options: &WarpOperationOptions,
+) -> Result<()> {
+    let c_options = options.to_c_options(src, dst)?;
+    let token = options.cancellation.as_ref();
+    let (progress, progress_arg) = progress_args(token);
+    let rv = unsafe {
+        gdal_sys::GDALReprojectImage(
+            src.c_dataset(),
+            null(),
+            dst.c_dataset(),
+            null(),
+            options.resampling.to_gdal(),
+            options.memory_limit,
+            0.0,
+            progress,
+            progress_arg,
+            c_options.0.as_ptr(),
+        )
+    };
+    if rv != CPLErr::CE_None {
+        return Err(cancelled_or(token, _last_cpl_err(rv)));
+    }
+    Ok(())
+}
+
+/// Suggest the geo-transform and size, as `(cols, rows)`, of the output of warping `src` to `dst_srs`.
+///
+/// The output covers the whole source, with about the same number of pixels.
+/// This is how `gdalwarp` sizes its output when no resolution or size is given,
+/// and is useful to create the destination of [`reproject`] or [`WarpOperation`].
+///
+/// Wraps [`GDALSuggestedWarpOutput`](https://gdal.org/api/gdal_alg.html#_CPPv423GDALSuggestedWarpOutput12GDALDatasetH19GDALTransformerFuncPvPdPiPi).
+///
+/// # Example
+///
+/// ```rust, no_run
+/// # fn main() -> gdal::errors::Result<()> {
+/// use gdal::raster::suggested_warp_output;
+/// use gdal::spatial_ref::SpatialRef;
+/// use gdal::{Dataset, DriverManager};
+///
+/// let src = Dataset::open("fixtures/m_3607824_se_17_1_20160620_sub.tif")?;
+/// let dst_srs = SpatialRef::from_epsg(4326)?;
+/// let (gt, (cols, rows)) = suggested_warp_output(&src, &dst_srs)?;
+///
+/// let driver = DriverManager::get_driver_by_name("MEM")?;
+/// let mut dst = driver.create_with_band_type::<u8, _>("", cols, rows, src.raster_count())?;
+/// dst.set_geo_transform(&gt)?;
+/// dst.set_spatial_ref(&dst_srs)?;
+/// gdal::raster::reproject(&src, &dst)?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn suggested_warp_output(
+    src: &Dataset,
+    dst_srs: &SpatialRef,
+) -> Result<(GeoTransform, (usize, usize))> {
+    let mut transformer_options = CslStringList::new();
+    transformer_options.set_name_value("DST_SRS", &dst_srs.to_wkt()?)?;
+    let c_transformer = unsafe {
+        gdal_sys::GDALCreateGenImgProjTransformer2(
+            src.c_dataset(),
             null_mut(),
+            transformer_options.as_ptr() as *mut _,
         )
     };
+    if c_transformer.is_null() {
+        return Err(_last_null_pointer_err("GDALCreateGenImgProjTransformer2"));
+    }
+
+    let mut gt = GeoTransform::default();
+    let mut cols: c_int = 0;
+    let mut rows: c_int = 0;
+    let rv = unsafe {
+        let rv = gdal_sys::GDALSuggestedWarpOutput(
+            src.c_dataset(),
+            Some(gdal_sys::GDALGenImgProjTransform),
+            c_transformer,
+            gt.as_mut_ptr(),
+            &mut cols,
+            &mut rows,
+        );
+        gdal_sys::GDALDestroyGenImgProjTransformer(c_transformer);
+        rv
+    };
     if rv != CPLErr::CE_None {
         return Err(_last_cpl_err(rv));
     }
-    Ok(())
+    Ok((gt, (cols.try_into()?, rows.try_into()?)))
+}
+
+/// Resampling method used when warping.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WarpResampleAlg {
+    /// Nearest neighbour (the default)
+    #[default]
+    NearestNeighbour,
+    /// Bilinear (2x2 kernel)
+    Bilinear,
+    /// Cubic convolution approximation (4x4 kernel)
+    Cubic,
+    /// Cubic B-spline approximation (4x4 kernel)
+    CubicSpline,
+    /// Lanczos windowed sinc interpolation (6x6 kernel)
+    Lanczos,
+    /// Weighted average of all non-nodata contributing pixels
+    Average,
+    /// Root mean square of all non-nodata contributing pixels (GDAL >= 3.3)
+    #[cfg(all(major_ge_3, minor_ge_3))]
+    Rms,
+    /// Most frequent value of all contributing pixels
+    Mode,
+    /// Maximum value of all non-nodata contributing pixels
+    Max,
+    /// Minimum value of all non-nodata contributing pixels
+    Min,
+    /// Median value of all non-nodata contributing pixels
+    Median,
+    /// First quartile value of all non-nodata contributing pixels
+    Q1,
+    /// Third quartile value of all non-nodata contributing pixels
+    Q3,
+    /// Weighted sum of all non-nodata contributing pixels (GDAL >= 3.1)
+    #[cfg(all(major_ge_3, minor_ge_1))]
+    Sum,
+}
+
+impl WarpResampleAlg {
+    pub fn to_gdal(self) -> GDALResampleAlg::Type {
+        match self {
+            WarpResampleAlg::NearestNeighbour => GDALResampleAlg::GRA_NearestNeighbour,
+            WarpResampleAlg::Bilinear => GDALResampleAlg::GRA_Bilinear,
+            WarpResampleAlg::Cubic => GDALResampleAlg::GRA_Cubic,
+            WarpResampleAlg::CubicSpline => GDALResampleAlg::GRA_CubicSpline,
+            WarpResampleAlg::Lanczos => GDALResampleAlg::GRA_Lanczos,
+            WarpResampleAlg::Average => GDALResampleAlg::GRA_Average,
+            #[cfg(all(major_ge_3, minor_ge_3))]
+            WarpResampleAlg::Rms => GDALResampleAlg::GRA_RMS,
+            WarpResampleAlg::Mode => GDALResampleAlg::GRA_Mode,
+            WarpResampleAlg::Max => GDALResampleAlg::GRA_Max,
+            WarpResampleAlg::Min => GDALResampleAlg::GRA_Min,
+            WarpResampleAlg::Median => GDALResampleAlg::GRA_Med,
+            WarpResampleAlg::Q1 => GDALResampleAlg::GRA_Q1,
+            WarpResampleAlg::Q3 => GDALResampleAlg::GRA_Q3,
+            #[cfg(all(major_ge_3, minor_ge_1))]
+            WarpResampleAlg::Sum => GDALResampleAlg::GRA_Sum,
+        }
+    }
+
+    /// Name of the method, as used by the `-r` option of `gdalwarp`.
+    pub(crate) fn to_gdal_option(self) -> &'static str {
+        match self {
+            WarpResampleAlg::NearestNeighbour => "near",
+            WarpResampleAlg::Bilinear => "bilinear",
+            WarpResampleAlg::Cubic => "cubic",
+            WarpResampleAlg::CubicSpline => "cubicspline",
+            WarpResampleAlg::Lanczos => "lanczos",
+            WarpResampleAlg::Average => "average",
+            #[cfg(all(major_ge_3, minor_ge_3))]
+            WarpResampleAlg::Rms => "rms",
+            WarpResampleAlg::Mode => "mode",
+            WarpResampleAlg::Max => "max",
+            WarpResampleAlg::Min => "min",
+            WarpResampleAlg::Median => "med",
+            WarpResampleAlg::Q1 => "q1",
+            WarpResampleAlg::Q3 => "q3",
+            #[cfg(all(major_ge_3, minor_ge_1))]
+            WarpResampleAlg::Sum => "sum",
+        }
+    }
+}
+
+/// Maps coordinates between the destination and source pixel/line spaces of a [`WarpOperation`].
+///
+/// GDAL may call the transformer from several threads at once, hence the `Sync` bound.
+pub trait Transformer: Send + Sync {
+    /// Transform the points `(x[i], y[i], z[i])` in place, from destination to source
+    /// pixel/line coordinates if `dst_to_src` is `true`, and the reverse otherwise.
+    ///
+    /// `success[i]` must be set to `false` for points that can't be transformed.
+    /// Returns `false` if the transformation failed as a whole.
+    fn transform(
+        &self,
+        dst_to_src: bool,
+        x: &mut [f64],
+        y: &mut [f64],
+        z: &mut [f64],
+        success: &mut [bool],
+    ) -> bool;
+}
+
+unsafe extern "C" fn transform_trampoline(
+    arg: *mut c_void,
+    dst_to_src: c_int,
+    point_count: c_int,
+    x: *mut f64,
+    y: *mut f64,
+    z: *mut f64,
+    success: *mut c_int,
+) -> c_int {
+    if point_count <= 0 {
+        return 1;
+    }
+    let transformer = &*(arg as *const Box<dyn Transformer>);
+    let n = point_count as usize;
+    let x = std::slice::from_raw_parts_mut(x, n);
+    let y = std::slice::from_raw_parts_mut(y, n);
+    // GDAL doesn't always provide z values
+    let mut z_buf = Vec::new();
+    let z = if z.is_null() {
+        z_buf.resize(n, 0.0);
+        &mut z_buf[..]
+    } else {
+        std::slice::from_raw_parts_mut(z, n)
+    };
+    let mut ok = vec![true; n];
+    let rv = transformer.transform(dst_to_src != 0, x, y, z, &mut ok);
+    let success = std::slice::from_raw_parts_mut(success, n);
+    for (s, ok) in success.iter_mut().zip(ok) {
+        *s = c_int::from(ok);
+    }
+    c_int::from(rv)
+}
+
+/// Settings of a [`WarpOperation`], or of [`reproject_with_options`].
+///
+/// These map to the fields of [`GDALWarpOptions`](https://gdal.org/api/gdalwarp_cpp.html#_CPPv415GDALWarpOptions).
+#[derive(Debug, Clone, Default)]
+pub struct WarpOperationOptions {
+    resampling: WarpResampleAlg,
+    memory_limit: f64,
+    working_type: Option<GdalDataType>,
+    band_mapping: Vec<(usize, usize)>,
+    src_no_data: Option<f64>,
+    dst_no_data: Option<f64>,
+    src_alpha_band: Option<usize>,
+    dst_alpha_band: Option<usize>,
+    warp_options: CslStringList,
+    transformer_options: CslStringList,
+    cancellation: Option<CancellationToken>,
+}
+
+impl WarpOperationOptions {
+    /// Create the default settings: nearest neighbour resampling of all the bands.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Set the resampling method.
+    pub fn with_resampling(&mut self, alg: WarpResampleAlg) -> &mut Self {
+        self.resampling = alg;
+        self
+    }
+
+    /// Limit the memory used for the chunks of the operation, in bytes.
+    ///
+    /// Larger limits mean fewer, larger chunks. Defaults to 64 MB.
+    pub fn with_memory_limit(&mut self, bytes: usize) -> &mut Self {
+        self.memory_limit = bytes as f64;
+        self
+    }
+
+    /// Set the data type used for the computations, instead of the widest band type.
+    pub fn with_working_type(&mut self, data_type: GdalDataType) -> &mut Self {
+        self.working_type = Some(data_type);
+        self
+    }
+
+    /// Warp the (1-based) source bands into the destination bands, as `(src, dst)` pairs.
+    ///
+    /// Defaults to warping each source band into the destination band with the same index.
+    pub fn with_band_mapping(&mut self, bands: &[(usize, usize)]) -> &mut Self {
+        self.band_mapping = bands.to_vec();
+        self
+    }
+
+    /// Ignore the source pixels equal to `no_data`, in all bands.
+    pub fn with_src_no_data_value(&mut self, no_data: f64) -> &mut Self {
+        self.src_no_data = Some(no_data);
+        self
+    }
+
+    /// Set the destination pixels without source data to `no_data`, in all bands.
+    pub fn with_dst_no_data_value(&mut self, no_data: f64) -> &mut Self {
+        self.dst_no_data = Some(no_data);
+        self
+    }
+
+    /// Use the (1-based) source `band` as a transparency mask.
+    pub fn with_src_alpha_band(&mut self, band: usize) -> &mut Self {
+        self.src_alpha_band = Some(band);
+        self
+    }
+
+    /// Write the transparency of the output into the (1-based) destination `band`.
+    pub fn with_dst_alpha_band(&mut self, band: usize) -> &mut Self {
+        self.dst_alpha_band = Some(band);
+        self
+    }
+
+    /// Pass `NAME=VALUE` options to the warper, such as `NUM_THREADS`, `INIT_DEST` or `CUTLINE`.
+    ///
+    /// See [`GDALWarpOptions::papszWarpOptions`](https://gdal.org/api/gdalwarp_cpp.html#_CPPv4N15GDALWarpOptions16papszWarpOptionsE)
+    /// for the supported options.
+    pub fn with_warp_options(&mut self, options: &CslStringList) -> &mut Self {
+        self.warp_options = options.clone();
+        self
+    }
+
+    /// Pass `NAME=VALUE` options to the default transformer, such as `SRC_SRS`, `DST_SRS`,
+    /// `COORDINATE_OPERATION` or `METHOD`.
+    ///
+    /// See [`GDALCreateGenImgProjTransformer2`](https://gdal.org/api/gdal_alg.html#_CPPv432GDALCreateGenImgProjTransformer212GDALDatasetH12GDALDatasetHPPc)
+    /// for the supported options.
+    pub fn with_transformer_options(&mut self, options: &CslStringList) -> &mut Self {
+        self.transformer_options = options.clone();
+        self
+    }
+
+    /// Abort the operation with [`GdalError::Cancelled`] once `token` is cancelled.
+    pub fn with_cancellation(&mut self, token: &CancellationToken) -> &mut Self {
+        self.cancellation = Some(token.clone());
+        self
+    }
+
+    /// Build the GDAL warp options, without the transformer.
+    fn to_c_options(&self, src: &Dataset, dst: &Dataset) -> Result<CWarpOptions> {
+        let c_options = match NonNull::new(unsafe { gdal_sys::GDALCreateWarpOptions() }) {
+            Some(c_options) => CWarpOptions(c_options),
+            None => return Err(_last_null_pointer_err("GDALCreateWarpOptions")),
+        };
+        let c_opts = c_options.0.as_ptr();
+        unsafe {
+            (*c_opts).papszWarpOptions = self.warp_options.clone().into_ptr() as *mut _;
+            (*c_opts).dfWarpMemoryLimit = self.memory_limit;
+            (*c_opts).eResampleAlg = self.resampling.to_gdal();
+            if let Some(data_type) = self.working_type {
+                (*c_opts).eWorkingDataType = data_type.gdal_ordinal();
+            }
+            (*c_opts).hSrcDS = src.c_dataset();
+            (*c_opts).hDstDS = dst.c_dataset();
+
+            if self.band_mapping.is_empty() {
+                gdal_sys::GDALWarpInitDefaultBandMapping(c_opts, src.raster_count().try_into()?);
+            } else {
+                let n = self.band_mapping.len();
+                (*c_opts).nBandCount = n.try_into()?;
+                let size = n * std::mem::size_of::<c_int>();
+                (*c_opts).panSrcBands = gdal_sys::CPLMalloc(size) as *mut c_int;
+                (*c_opts).panDstBands = gdal_sys::CPLMalloc(size) as *mut c_int;
+                for (i, &(src_band, dst_band)) in self.band_mapping.iter().enumerate() {
+                    *(*c_opts).panSrcBands.add(i) = src_band.try_into()?;
+                    *(*c_opts).panDstBands.add(i) = dst_band.try_into()?;
+                }
+            }
+
+            if let Some(no_data) = self.src_no_data {
+                gdal_sys::GDALWarpInitSrcNoDataReal(c_opts, no_data);
+            }
+            if let Some(no_data) = self.dst_no_data {
+                gdal_sys::GDALWarpInitDstNoDataReal(c_opts, no_data);
+            }
+            if let Some(band) = self.src_alpha_band {
+                (*c_opts).nSrcAlphaBand = band.try_into()?;
+            }
+            if let Some(band) = self.dst_alpha_band {
+                (*c_opts).nDstAlphaBand = band.try_into()?;
+            }
+
+            let (progress, progress_arg) = progress_args(self.cancellation.as_ref());
+            (*c_opts).pfnProgress = progress;
+            (*c_opts).pProgressArg = progress_arg;
+        }
+        Ok(c_options)
+    }
+}
+
+/// Owned [`GDALWarpOptions`], which also frees the band and nodata arrays.
+struct CWarpOptions(NonNull<GDALWarpOptions>);
+
+impl Drop for CWarpOptions {
+    fn drop(&mut self) {
+        unsafe { gdal_sys::GDALDestroyWarpOptions(self.0.as_ptr()) };
+    }
+}
+
+enum TransformerArg<'a> {
+    GenImgProj(*mut c_void),
+    Custom(Box<Box<dyn Transformer + 'a>>),
+}
+
+/// A warp from a source into a destination dataset, which can be run in chunks.
+///
+/// This is a lower-level alternative to [`reproject`] and
+/// [`programs::raster::warp`](crate::programs::raster::warp), for custom transformers or
+/// control over the region and threading of the warp.
+/// The destination dataset must already have its final size, spatial reference and geo-transform.
+///
+/// Wraps [`GDALWarpOperation`](https://gdal.org/api/gdalwarp_cpp.html#_CPPv417GDALWarpOperation).
+///
+/// # Example
+///
+/// ```rust, no_run
+/// # fn main() -> gdal::errors::Result<()> {
+/// use gdal::{Dataset, DriverManager};
+/// use gdal::raster::{WarpOperation, WarpOperationOptions, WarpResampleAlg};
+///
+/// let src = Dataset::open("fixtures/tinymarble.tif")?;
+/// let driver = DriverManager::get_driver_by_name("MEM")?;
+/// let mut dst = driver.create_with_band_type::<u8, _>("", 50, 25, 3)?;
+/// let mut gt = src.geo_transform()?;
+/// gt[1] *= 2.;
+/// gt[5] *= 2.;
+/// dst.set_geo_transform(&gt)?;
+/// dst.set_spatial_ref(&src.spatial_ref()?)?;
+///
+/// let mut options = WarpOperationOptions::new();
+/// options
+///     .with_resampling(WarpResampleAlg::Average)
+///     .with_memory_limit(16 * 1024 * 1024);
+/// let operation = WarpOperation::new(&src, &dst, &options)?;
+/// operation.chunk_and_warp_multi((0, 0), dst.raster_size())?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct WarpOperation<'a> {
+    c_operation: GDALWarpOperationH,
+    transformer: TransformerArg<'a>,
+    cancellation: Option<CancellationToken>,
+    _datasets: PhantomData<&'a Dataset>,
+}
+
+impl<'a> WarpOperation<'a> {
+    /// Prepare a warp from `src` into `dst`, using the spatial references and geo-transforms
+    /// of both datasets to map their pixels.
+    pub fn new(src: &'a Dataset, dst: &'a Dataset, options: &WarpOperationOptions) -> Result<Self> {
+        let c_transformer = unsafe {
+            gdal_sys::GDALCreateGenImgProjTransformer2(
+                src.c_dataset(),
+                dst.c_dataset(),
+                options.transformer_options.as_ptr() as *mut _,
+            )
+        };
+        if c_transformer.is_null() {
+            return Err(_last_null_pointer_err("GDALCreateGenImgProjTransformer2"));
+        }
+        Self::create(src, dst, options, TransformerArg::GenImgProj(c_transformer))
+    }
+
+    /// Prepare a warp from `src` into `dst`, using `transformer` to map their pixels.
+    ///
+    /// The transformer options are ignored.
+    pub fn with_transformer<T: Transformer + 'a>(
+        src: &'a Dataset,
+        dst: &'a Dataset,
+        options: &WarpOperationOptions,
+        transformer: T,
+    ) -> Result<Self> {
+        let transformer: Box<dyn Transformer + 'a> = Box::new(transformer);
+        Self::create(
+            src,
+            dst,
+            options,
+            TransformerArg::Custom(Box::new(transformer)),
+        )
+    }
+
+    fn create(
+        src: &'a Dataset,
+        dst: &'a Dataset,
+        options: &WarpOperationOptions,
+        transformer: TransformerArg<'a>,
+    ) -> Result<Self> {
+        // own the transformer first, so that it's freed on errors
+        let mut operation = Self {
+            c_operation: null_mut(),
+            transformer,
+            cancellation: options.cancellation.clone(),
+            _datasets: PhantomData,
+        };
+
+        let c_options = options.to_c_options(src, dst)?;
+        let c_opts = c_options.0.as_ptr();
+        unsafe {
+            match &operation.transformer {
+                TransformerArg::GenImgProj(arg) => {
+                    (*c_opts).pfnTransformer = Some(gdal_sys::GDALGenImgProjTransform);
+                    (*c_opts).pTransformerArg = *arg;
+                }
+                TransformerArg::Custom(transformer) => {
+                    (*c_opts).pfnTransformer = Some(transform_trampoline);
+                    (*c_opts).pTransformerArg =
+                        &**transformer as *const Box<dyn Transformer + 'a> as *mut c_void;
+                }
+            }
+            // keep the token alive for as long as the operation
+            (*c_opts).pProgressArg = progress_args(operation.cancellation.as_ref()).1;
+        }
+
+        // the operation keeps a copy of the options
+        operation.c_operation = unsafe { gdal_sys::GDALCreateWarpOperation(c_opts) };
+        if operation.c_operation.is_null() {
+            return Err(_last_null_pointer_err("GDALCreateWarpOperation"));
+        }
+        Ok(operation)
+    }
+
+    /// Warp the destination window of `size` pixels starting at `offset`, chunk by chunk.
+    ///
+    /// Wraps `GDALWarpOperation::ChunkAndWarpImage`.
+    pub fn chunk_and_warp_image(&self, offset: (usize, usize), size: (usize, usize)) -> Result<()> {
+        let rv = unsafe {
+            gdal_sys::GDALChunkAndWarpImage(
+                self.c_operation,
+                offset.0.try_into()?,
+                offset.1.try_into()?,
+                size.0.try_into()?,
+                size.1.try_into()?,
+            )
+        };
+        self.check(rv)
+    }
+
+    /// Warp the destination window like [`chunk_and_warp_image`](Self::chunk_and_warp_image),
+    /// but read the source chunks on one thread while warping them on another.
+    ///
+    /// Wraps `GDALWarpOperation::ChunkAndWarpMulti`.
+    pub fn chunk_and_warp_multi(&self, offset: (usize, usize), size: (usize, usize)) -> Result<()> {
+        let rv = unsafe {
+            gdal_sys::GDALChunkAndWarpMulti(
+                self.c_operation,
+                offset.0.try_into()?,
+                offset.1.try_into()?,
+                size.0.try_into()?,
+                size.1.try_into()?,
+            )
+        };
+        self.check(rv)
+    }
+
+    fn check(&self, rv: CPLErr::Type) -> Result<()> {
+        if rv != CPLErr::CE_None {
+            return Err(cancelled_or(self.cancellation.as_ref(), _last_cpl_err(rv)));
+        }
+        Ok(())
+    }
+}
+
+impl Drop for WarpOperation<'_> {
+    fn drop(&mut self) {
+        unsafe {
+            if !self.c_operation.is_null() {
+                gdal_sys::GDALDestroyWarpOperation(self.c_operation);
+            }
+            if let TransformerArg::GenImgProj(arg) = self.transformer {
+                gdal_sys::GDALDestroyGenImgProjTransformer(arg);
+            }
+        }
+    }
 }
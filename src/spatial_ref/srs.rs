@@ -4,6 +4,7 @@ use std::ffi::{CStr, CString};
 use std::ptr::{self};
 use std::str::FromStr;
 
+use crate::cpl::CslStringList;
 use crate::errors::*;
 
 /// A OpenGIS Spatial Reference System definition.
@@ -589,6 +590,40 @@ impl SpatialRef {
         }
     }
 
+    /// Determine if two spatial references describe the same system, using the given
+    /// comparison options.
+    ///
+    /// The [`PartialEq`] implementation is equivalent to calling this with
+    /// [`SrsCompareOptions::default()`].
+    ///
+    /// # Example
+    ///
+    /// ```rust, no_run
+    /// # fn main() -> gdal::errors::Result<()> {
+    /// use gdal::spatial_ref::{SpatialRef, SrsCompareCriterion, SrsCompareOptions};
+    /// let wgs84 = SpatialRef::from_epsg(4326)?;
+    /// let crs84 = SpatialRef::from_definition("OGC:CRS84")?;
+    /// let options = SrsCompareOptions {
+    ///     criterion: SrsCompareCriterion::EquivalentExceptAxisOrderGeogcrs,
+    ///     ..Default::default()
+    /// };
+    /// assert!(wgs84.is_same_with_options(&crs84, &options));
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// See: [`OSRIsSameEx`](https://gdal.org/api/ogr_srs_api.html#_CPPv411OSRIsSameEx20OGRSpatialReferenceH20OGRSpatialReferenceHPPCKc)
+    pub fn is_same_with_options(&self, other: &SpatialRef, options: &SrsCompareOptions) -> bool {
+        let options = options.to_options_list();
+        unsafe {
+            gdal_sys::OSRIsSameEx(
+                self.0,
+                other.0,
+                options.as_ptr() as *const *const libc::c_char,
+            ) == 1
+        }
+    }
+
     /// Make a duplicate of the `GEOGCS` node of this [`SpatialRef`].
     ///
     /// Returns an error if the `GEOGCS` node is missing.
@@ -602,6 +637,104 @@ impl SpatialRef {
 
         Ok(SpatialRef(raw_ret))
     }
+
+    /// Get the geodetic CRS of this [`SpatialRef`], with its datum, ellipsoid and prime meridian.
+    ///
+    /// For projected spatial references, this describes the base geographic CRS.
+    /// Use [`geog_cs`](Self::geog_cs) to get it as a [`SpatialRef`] instead.
+    ///
+    /// Returns an error if the `GEOGCS` node is missing.
+    ///
+    /// # Example
+    ///
+    /// ```rust, no_run
+    /// # fn main() -> gdal::errors::Result<()> {
+    /// use gdal::spatial_ref::SpatialRef;
+    /// let utm = SpatialRef::from_epsg(32631)?;
+    /// let geodetic = utm.geodetic_crs()?;
+    /// assert_eq!(geodetic.name, "WGS 84");
+    /// assert_eq!(geodetic.datum.authority.as_deref(), Some("EPSG:6326"));
+    /// assert_eq!(geodetic.datum.ellipsoid.semi_major, 6378137.0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn geodetic_crs(&self) -> Result<GeodeticCrs> {
+        Ok(GeodeticCrs {
+            name: self.required_attr_value("GEOGCS")?,
+            authority: self.node_authority("GEOGCS"),
+            datum: self.datum()?,
+            prime_meridian: self.prime_meridian()?,
+        })
+    }
+
+    /// Get the datum of this [`SpatialRef`].
+    ///
+    /// Returns an error if the `DATUM` node is missing.
+    pub fn datum(&self) -> Result<Datum> {
+        Ok(Datum {
+            name: self.required_attr_value("DATUM")?,
+            authority: self.node_authority("DATUM"),
+            ellipsoid: self.ellipsoid()?,
+        })
+    }
+
+    /// Get the ellipsoid of this [`SpatialRef`].
+    ///
+    /// Returns an error if the `SPHEROID` node is missing.
+    ///
+    /// See: [`OSRGetSemiMajor`](https://gdal.org/api/ogr_srs_api.html#_CPPv415OSRGetSemiMajor20OGRSpatialReferenceHP6OGRErr),
+    /// [`OSRGetInvFlattening`](https://gdal.org/api/ogr_srs_api.html#_CPPv419OSRGetInvFlattening20OGRSpatialReferenceHP6OGRErr)
+    pub fn ellipsoid(&self) -> Result<Ellipsoid> {
+        let name = self.required_attr_value("SPHEROID")?;
+        let mut err_code = OGRErr::OGRERR_NONE;
+        let inv_flattening = unsafe { gdal_sys::OSRGetInvFlattening(self.0, &mut err_code) };
+        if err_code != OGRErr::OGRERR_NONE {
+            return Err(GdalError::OgrError {
+                err: err_code,
+                method_name: "OSRGetInvFlattening",
+            });
+        }
+        Ok(Ellipsoid {
+            name,
+            authority: self.node_authority("SPHEROID"),
+            semi_major: self.semi_major()?,
+            inv_flattening,
+        })
+    }
+
+    /// Get the prime meridian of this [`SpatialRef`].
+    ///
+    /// Returns an error if the `PRIMEM` node is missing.
+    ///
+    /// See: [`OSRGetPrimeMeridian`](https://gdal.org/api/ogr_srs_api.html#_CPPv419OSRGetPrimeMeridian20OGRSpatialReferenceHPPc)
+    pub fn prime_meridian(&self) -> Result<PrimeMeridian> {
+        let name = self.required_attr_value("PRIMEM")?;
+        let longitude = unsafe { gdal_sys::OSRGetPrimeMeridian(self.0, ptr::null_mut()) };
+        Ok(PrimeMeridian {
+            name,
+            authority: self.node_authority("PRIMEM"),
+            longitude,
+        })
+    }
+
+    /// Get the name of the `key` node, or an error if it is missing.
+    fn required_attr_value(&self, key: &str) -> Result<String> {
+        self.get_attr_value(key, 0)?.ok_or(GdalError::OgrError {
+            err: OGRErr::OGRERR_UNSUPPORTED_SRS,
+            method_name: "OSRGetAttrValue",
+        })
+    }
+
+    /// Get the authority of the `key` node, as `"name:code"`.
+    fn node_authority(&self, key: &str) -> Option<String> {
+        let c_key = CString::new(key).ok()?;
+        let c_name = unsafe { gdal_sys::OSRGetAuthorityName(self.0, c_key.as_ptr()) };
+        let c_code = unsafe { gdal_sys::OSRGetAuthorityCode(self.0, c_key.as_ptr()) };
+        if c_name.is_null() || c_code.is_null() {
+            return None;
+        }
+        Some(format!("{}:{}", _string(c_name), _string(c_code)))
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -616,6 +749,151 @@ pub struct AreaOfUse {
     pub name: String,
 }
 
+/// Geodetic CRS of a [`SpatialRef`].
+///
+/// See [`geodetic_crs`][SpatialRef::geodetic_crs].
+#[derive(Debug, Clone, PartialEq)]
+pub struct GeodeticCrs {
+    pub name: String,
+    /// Authority and code, e.g. `"EPSG:4326"`, if known.
+    pub authority: Option<String>,
+    pub datum: Datum,
+    pub prime_meridian: PrimeMeridian,
+}
+
+/// Geodetic datum of a [`SpatialRef`].
+///
+/// See [`datum`][SpatialRef::datum].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Datum {
+    pub name: String,
+    /// Authority and code, e.g. `"EPSG:6326"`, if known.
+    pub authority: Option<String>,
+    pub ellipsoid: Ellipsoid,
+}
+
+/// Ellipsoid of a [`SpatialRef`].
+///
+/// See [`ellipsoid`][SpatialRef::ellipsoid].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Ellipsoid {
+    pub name: String,
+    /// Authority and code, e.g. `"EPSG:7030"`, if known.
+    pub authority: Option<String>,
+    /// Semi-major axis, in meters.
+    pub semi_major: f64,
+    /// Inverse flattening, or `0` for a sphere.
+    pub inv_flattening: f64,
+}
+
+impl Ellipsoid {
+    /// Returns `true` if the ellipsoid is a sphere.
+    pub fn is_sphere(&self) -> bool {
+        self.inv_flattening == 0.0
+    }
+
+    /// Get the flattening, `(a - b) / a`.
+    pub fn flattening(&self) -> f64 {
+        if self.is_sphere() {
+            0.0
+        } else {
+            1.0 / self.inv_flattening
+        }
+    }
+
+    /// Get the semi-minor axis, in meters.
+    pub fn semi_minor(&self) -> f64 {
+        self.semi_major * (1.0 - self.flattening())
+    }
+}
+
+/// Prime meridian of a [`SpatialRef`].
+///
+/// See [`prime_meridian`][SpatialRef::prime_meridian].
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimeMeridian {
+    pub name: String,
+    /// Authority and code, e.g. `"EPSG:8901"`, if known.
+    pub authority: Option<String>,
+    /// Longitude relative to Greenwich, in degrees.
+    pub longitude: f64,
+}
+
+/// Criterion used by [`SpatialRef::is_same_with_options`] to decide whether two
+/// spatial references are the same.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum SrsCompareCriterion {
+    /// All properties, including names, must be identical.
+    Strict,
+    /// The spatial references must be equivalent, ignoring differences in names and aliases.
+    Equivalent,
+    /// Like [`Equivalent`][Self::Equivalent], but also ignores the axis order of geographic CRS.
+    EquivalentExceptAxisOrderGeogcrs,
+}
+
+impl SrsCompareCriterion {
+    fn to_gdal_option(self) -> &'static str {
+        match self {
+            SrsCompareCriterion::Strict => "STRICT",
+            SrsCompareCriterion::Equivalent => "EQUIVALENT",
+            SrsCompareCriterion::EquivalentExceptAxisOrderGeogcrs => {
+                "EQUIVALENT_EXCEPT_AXIS_ORDER_GEOGCRS"
+            }
+        }
+    }
+}
+
+/// Options for [`SpatialRef::is_same_with_options`].
+///
+/// The default values match those used by [`OSRIsSame`](https://gdal.org/api/ogr_srs_api.html#_CPPv49OSRIsSame20OGRSpatialReferenceH20OGRSpatialReferenceH).
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct SrsCompareOptions {
+    /// Ignore differences in the data axis to CRS axis mapping.
+    pub ignore_axis_mapping: bool,
+    /// Ignore differences in the coordinate epoch of dynamic CRS (GDAL >= 3.4).
+    pub ignore_coordinate_epoch: bool,
+    /// How strictly the spatial references themselves are compared.
+    pub criterion: SrsCompareCriterion,
+}
+
+impl Default for SrsCompareOptions {
+    fn default() -> Self {
+        Self {
+            ignore_axis_mapping: false,
+            ignore_coordinate_epoch: false,
+            criterion: SrsCompareCriterion::EquivalentExceptAxisOrderGeogcrs,
+        }
+    }
+}
+
+impl SrsCompareOptions {
+    fn to_options_list(self) -> CslStringList {
+        fn yes_no(v: bool) -> &'static str {
+            if v {
+                "YES"
+            } else {
+                "NO"
+            }
+        }
+
+        let mut opts = CslStringList::new();
+        // None of these keys or values can contain `=` or NUL, so these can't fail.
+        opts.set_name_value(
+            "IGNORE_DATA_AXIS_TO_SRS_AXIS_MAPPING",
+            yes_no(self.ignore_axis_mapping),
+        )
+        .expect("valid option");
+        opts.set_name_value(
+            "IGNORE_COORDINATE_EPOCH",
+            yes_no(self.ignore_coordinate_epoch),
+        )
+        .expect("valid option");
+        opts.set_name_value("CRITERION", self.criterion.to_gdal_option())
+            .expect("valid option");
+        opts
+    }
+}
+
 #[cfg(major_ge_3)]
 /// Data axis to CRS axis mapping strategy.
 ///
@@ -739,6 +1017,30 @@ mod tests {
         assert_eq!(spatial_ref5, spatial_ref4);
     }
 
+    #[test]
+    fn comparison_with_options() {
+        let mut wgs84 = SpatialRef::from_epsg(4326).unwrap();
+        let crs84 = SpatialRef::from_definition("OGC:CRS84").unwrap();
+
+        let strict = SrsCompareOptions {
+            criterion: SrsCompareCriterion::Strict,
+            ..Default::default()
+        };
+        assert!(wgs84.is_same_with_options(&wgs84.clone(), &strict));
+        assert!(!wgs84.is_same_with_options(&crs84, &strict));
+        assert!(wgs84.is_same_with_options(&crs84, &SrsCompareOptions::default()));
+
+        // differing axis mapping is only ignored on request
+        let other = wgs84.clone();
+        wgs84.set_axis_mapping_strategy(AxisMappingStrategy::TraditionalGisOrder);
+        assert!(!wgs84.is_same_with_options(&other, &SrsCompareOptions::default()));
+        let ignore_axis_mapping = SrsCompareOptions {
+            ignore_axis_mapping: true,
+            ..Default::default()
+        };
+        assert!(wgs84.is_same_with_options(&other, &ignore_axis_mapping));
+    }
+
     #[test]
     fn authority() {
         let spatial_ref = SpatialRef::from_epsg(4326).unwrap();
@@ -903,6 +1205,41 @@ mod tests {
         assert_almost_eq(semi_minor, 6_356_752.31);
     }
 
+    #[test]
+    fn geodetic_accessors() {
+        let spatial_ref = SpatialRef::from_epsg(32631).unwrap();
+        let geodetic = spatial_ref.geodetic_crs().unwrap();
+        assert_eq!(geodetic.name, "WGS 84");
+        assert_eq!(geodetic.authority.as_deref(), Some("EPSG:4326"));
+        assert_eq!(geodetic.datum, spatial_ref.datum().unwrap());
+        assert_eq!(geodetic.datum.name, "WGS_1984");
+        assert_eq!(geodetic.datum.authority.as_deref(), Some("EPSG:6326"));
+
+        let ellipsoid = spatial_ref.ellipsoid().unwrap();
+        assert_eq!(ellipsoid.name, "WGS 84");
+        assert_eq!(ellipsoid.authority.as_deref(), Some("EPSG:7030"));
+        assert_almost_eq(ellipsoid.semi_major, 6_378_137.0);
+        assert_almost_eq(ellipsoid.inv_flattening, 298.257223563);
+        assert_almost_eq(ellipsoid.semi_minor(), spatial_ref.semi_minor().unwrap());
+        assert!(!ellipsoid.is_sphere());
+
+        assert_eq!(geodetic.prime_meridian.name, "Greenwich");
+        assert_eq!(geodetic.prime_meridian.longitude, 0.0);
+
+        // NTF (Paris)
+        let spatial_ref = SpatialRef::from_epsg(4807).unwrap();
+        let prime_meridian = spatial_ref.prime_meridian().unwrap();
+        assert_eq!(prime_meridian.name, "Paris");
+        assert!((prime_meridian.longitude - 2.33722917).abs() < 1e-6);
+
+        let spatial_ref = SpatialRef::from_proj4("+proj=longlat +R=6371000 +no_defs").unwrap();
+        let ellipsoid = spatial_ref.ellipsoid().unwrap();
+        assert!(ellipsoid.is_sphere());
+        assert_eq!(ellipsoid.semi_minor(), 6_371_000.0);
+
+        assert!(SpatialRef::new().unwrap().datum().is_err());
+    }
+
     #[test]
     fn proj_params() {
         let spatial_ref = SpatialRef::from_proj4(
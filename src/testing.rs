@@ -0,0 +1,684 @@
+//! Helpers for testing code built on GDAL.
+//!
+//! [`snapshot`] and [`snapshot_layer`] render a dataset or a layer into a deterministic,
+//! line-oriented text, suitable for golden-file tests: metadata is sorted, numbers are rounded,
+//! and rasters are summarized by their checksums, so that unrelated changes in GDAL versions
+//! or platforms don't show up as differences.
+//!
+//! [`compare_datasets`] instead compares two rasters directly, reporting their differences in
+//! structure and pixels, e.g. to check the output of a pipeline against a reference file.
+//!
+//! # Example
+//!
+//! ```rust, no_run
+//! # fn main() -> gdal::errors::Result<()> {
+//! use gdal::testing::{snapshot, SnapshotOptions};
+//! use gdal::Dataset;
+//!
+//! let dataset = Dataset::open("fixtures/tinymarble.tif")?;
+//! let actual = snapshot(&dataset, &SnapshotOptions::new())?;
+//! let expected = std::fs::read_to_string("tests/snapshots/tinymarble.txt")?;
+//! assert_eq!(actual, expected);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt;
+
+use crate::errors::*;
+use crate::raster::GdalDataType;
+use crate::spatial_ref::SpatialRef;
+use crate::vector::{field_type_to_name, geometry_type_to_name, FieldValue, Geometry, LayerAccess};
+use crate::{Dataset, GeoTransform, Metadata};
+
+/// Options for [`snapshot`] and [`snapshot_layer`].
+#[derive(Debug, Clone)]
+pub struct SnapshotOptions {
+    precision: usize,
+    checksums: bool,
+    features: bool,
+}
+
+impl Default for SnapshotOptions {
+    fn default() -> Self {
+        Self {
+            precision: 6,
+            checksums: true,
+            features: true,
+        }
+    }
+}
+
+impl SnapshotOptions {
+    /// Create the default options: 6 decimals, with band checksums and features.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Round numbers, including geometry coordinates, to `decimals` decimals.
+    pub fn with_precision(&mut self, decimals: usize) -> &mut Self {
+        self.precision = decimals;
+        self
+    }
+
+    /// Leave out the band checksums, which reading every pixel of large rasters would make slow.
+    pub fn without_checksums(&mut self) -> &mut Self {
+        self.checksums = false;
+        self
+    }
+
+    /// Leave out the features of the layers, only keeping their schema and count.
+    pub fn without_features(&mut self) -> &mut Self {
+        self.features = false;
+        self
+    }
+}
+
+/// Render `dataset`, with its bands and layers, into a deterministic text.
+///
+/// See the [module documentation](self) for details.
+pub fn snapshot(dataset: &Dataset, options: &SnapshotOptions) -> Result<String> {
+    let mut out = Snapshot::new(options);
+    out.line(0, format!("driver: {}", dataset.driver().short_name()));
+
+    let band_count = dataset.raster_count();
+    if band_count > 0 {
+        let (cols, rows) = dataset.raster_size();
+        out.line(0, format!("size: {cols}x{rows}"));
+        if let Ok(srs) = dataset.spatial_ref() {
+            out.line(0, format!("srs: {}", srs_name(&srs)?));
+        }
+        if let Ok(gt) = dataset.geo_transform() {
+            out.line(0, format!("geo_transform: [{}]", out.numbers(&gt)));
+        }
+    }
+    out.metadata(0, dataset);
+
+    for index in 1..=band_count {
+        let band = dataset.rasterband(index)?;
+        out.line(0, format!("band {index}:"));
+        out.line(1, format!("type: {}", band.band_type().name()));
+        if let Some(no_data) = band.no_data_value() {
+            out.line(1, format!("no_data: {}", out.number(no_data)));
+        }
+        if let Some(scale) = band.scale() {
+            out.line(1, format!("scale: {}", out.number(scale)));
+        }
+        if let Some(offset) = band.offset() {
+            out.line(1, format!("offset: {}", out.number(offset)));
+        }
+        if options.checksums {
+            out.line(1, format!("checksum: {}", band.checksum(None)?));
+        }
+        out.metadata(1, &band);
+    }
+
+    for mut layer in dataset.layers() {
+        out.layer(&mut layer)?;
+    }
+
+    Ok(out.finish())
+}
+
+/// Render `layer`, with its schema and features, into a deterministic text.
+///
+/// Features are listed in reading order, which is deterministic for a given file and driver.
+/// See the [module documentation](self) for details.
+pub fn snapshot_layer<L: LayerAccess + Metadata>(
+    layer: &mut L,
+    options: &SnapshotOptions,
+) -> Result<String> {
+    let mut out = Snapshot::new(options);
+    out.layer(layer)?;
+    Ok(out.finish())
+}
+
+/// Options for [`compare_datasets`].
+#[derive(Debug, Clone, Default)]
+pub struct CompareOptions {
+    tolerance: f64,
+    geo_transform_tolerance: f64,
+    skip_pixels: bool,
+}
+
+impl CompareOptions {
+    /// Create the default options: exact comparison of the structure and pixels.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Consider the pixels differing by at most `tolerance` as equal.
+    pub fn with_tolerance(&mut self, tolerance: f64) -> &mut Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// Consider the geo-transform coefficients differing by at most `tolerance` as equal.
+    pub fn with_geo_transform_tolerance(&mut self, tolerance: f64) -> &mut Self {
+        self.geo_transform_tolerance = tolerance;
+        self
+    }
+
+    /// Only compare the structure of the datasets, not their pixels.
+    pub fn without_pixels(&mut self) -> &mut Self {
+        self.skip_pixels = true;
+        self
+    }
+}
+
+/// A difference between two datasets, found by [`compare_datasets`].
+///
+/// Each variant holds the values of the first dataset, `a`, and of the second one, `b`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Difference {
+    /// The rasters have different sizes, as `(cols, rows)`. Their pixels aren't compared.
+    RasterSize {
+        a: (usize, usize),
+        b: (usize, usize),
+    },
+    /// The datasets have different numbers of bands. Only the common bands are compared.
+    BandCount { a: usize, b: usize },
+    /// The geo-transforms differ, or only one dataset has one.
+    GeoTransform {
+        a: Option<GeoTransform>,
+        b: Option<GeoTransform>,
+    },
+    /// The spatial references differ, given by their authority code or WKT.
+    SpatialRef {
+        a: Option<String>,
+        b: Option<String>,
+    },
+    /// The data types of a band differ.
+    BandType {
+        band: usize,
+        a: GdalDataType,
+        b: GdalDataType,
+    },
+    /// The nodata values of a band differ.
+    NoDataValue {
+        band: usize,
+        a: Option<f64>,
+        b: Option<f64>,
+    },
+    /// `count` pixels of a band differ by more than the tolerance, by up to `max_difference`.
+    Pixels {
+        band: usize,
+        count: usize,
+        max_difference: f64,
+    },
+}
+
+impl fmt::Display for Difference {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Difference::RasterSize { a, b } => write!(f, "raster size: {a:?} != {b:?}"),
+            Difference::BandCount { a, b } => write!(f, "band count: {a} != {b}"),
+            Difference::GeoTransform { a, b } => write!(f, "geo-transform: {a:?} != {b:?}"),
+            Difference::SpatialRef { a, b } => write!(f, "spatial reference: {a:?} != {b:?}"),
+            Difference::BandType { band, a, b } => write!(f, "band {band} type: {a} != {b}"),
+            Difference::NoDataValue { band, a, b } => {
+                write!(f, "band {band} nodata value: {a:?} != {b:?}")
+            }
+            Difference::Pixels {
+                band,
+                count,
+                max_difference,
+            } => write!(
+                f,
+                "band {band}: {count} pixels differ, by up to {max_difference}"
+            ),
+        }
+    }
+}
+
+/// Compare the structure and pixels of the rasters `a` and `b`, returning their differences.
+///
+/// The result is empty when the datasets match, which makes for simple assertions.
+///
+/// # Example
+///
+/// ```rust, no_run
+/// # fn main() -> gdal::errors::Result<()> {
+/// use gdal::testing::{compare_datasets, CompareOptions};
+/// use gdal::Dataset;
+///
+/// let actual = Dataset::open("output.tif")?;
+/// let expected = Dataset::open("tests/reference/output.tif")?;
+/// let mut options = CompareOptions::new();
+/// options.with_tolerance(1e-6);
+/// let differences = compare_datasets(&actual, &expected, &options)?;
+/// assert!(differences.is_empty(), "{differences:#?}");
+/// # Ok(())
+/// # }
+/// ```
+pub fn compare_datasets(
+    a: &Dataset,
+    b: &Dataset,
+    options: &CompareOptions,
+) -> Result<Vec<Difference>> {
+    let mut differences = Vec::new();
+
+    let size = (a.raster_size(), b.raster_size());
+    if size.0 != size.1 {
+        differences.push(Difference::RasterSize {
+            a: size.0,
+            b: size.1,
+        });
+    }
+    let count = (a.raster_count(), b.raster_count());
+    if count.0 != count.1 {
+        differences.push(Difference::BandCount {
+            a: count.0,
+            b: count.1,
+        });
+    }
+
+    let gt = (a.geo_transform().ok(), b.geo_transform().ok());
+    let gt_equal = match gt {
+        (Some(gt_a), Some(gt_b)) => gt_a
+            .iter()
+            .zip(gt_b.iter())
+            .all(|(x, y)| (x - y).abs() <= options.geo_transform_tolerance),
+        (gt_a, gt_b) => gt_a == gt_b,
+    };
+    if !gt_equal {
+        differences.push(Difference::GeoTransform { a: gt.0, b: gt.1 });
+    }
+
+    let srs = (a.spatial_ref().ok(), b.spatial_ref().ok());
+    let srs_equal = match &srs {
+        (Some(srs_a), Some(srs_b)) => srs_a == srs_b,
+        (srs_a, srs_b) => srs_a.is_none() && srs_b.is_none(),
+    };
+    if !srs_equal {
+        differences.push(Difference::SpatialRef {
+            a: srs.0.as_ref().map(srs_name).transpose()?,
+            b: srs.1.as_ref().map(srs_name).transpose()?,
+        });
+    }
+
+    for index in 1..=count.0.min(count.1) {
+        let band_a = a.rasterband(index)?;
+        let band_b = b.rasterband(index)?;
+        let band_type = (band_a.band_type(), band_b.band_type());
+        if band_type.0 != band_type.1 {
+            differences.push(Difference::BandType {
+                band: index,
+                a: band_type.0,
+                b: band_type.1,
+            });
+        }
+        let no_data = (band_a.no_data_value(), band_b.no_data_value());
+        let no_data_equal = match no_data {
+            (Some(x), Some(y)) => x == y || (x.is_nan() && y.is_nan()),
+            (x, y) => x == y,
+        };
+        if !no_data_equal {
+            differences.push(Difference::NoDataValue {
+                band: index,
+                a: no_data.0,
+                b: no_data.1,
+            });
+        }
+
+        if options.skip_pixels || size.0 != size.1 {
+            continue;
+        }
+        let (cols, rows) = size.0;
+        let mut count = 0;
+        let mut max_difference = 0f64;
+        for row in 0..rows {
+            let window = (0, row as isize);
+            let row_a = band_a.read_as::<f64>(window, (cols, 1), (cols, 1), None)?;
+            let row_b = band_b.read_as::<f64>(window, (cols, 1), (cols, 1), None)?;
+            for (&x, &y) in row_a.data().iter().zip(row_b.data()) {
+                if x.is_nan() && y.is_nan() {
+                    continue;
+                }
+                let difference = (x - y).abs();
+                // NaN against a number is always a difference
+                if difference.is_nan() || difference > options.tolerance {
+                    count += 1;
+                    max_difference = max_difference.max(if difference.is_nan() {
+                        f64::INFINITY
+                    } else {
+                        difference
+                    });
+                }
+            }
+        }
+        if count > 0 {
+            differences.push(Difference::Pixels {
+                band: index,
+                count,
+                max_difference,
+            });
+        }
+    }
+
+    Ok(differences)
+}
+
+/// Get the authority code of `srs`, or its WKT if it has none.
+fn srs_name(srs: &SpatialRef) -> Result<String> {
+    srs.authority().or_else(|_| srs.to_wkt())
+}
+
+struct Snapshot<'a> {
+    options: &'a SnapshotOptions,
+    lines: Vec<String>,
+}
+
+impl<'a> Snapshot<'a> {
+    fn new(options: &'a SnapshotOptions) -> Self {
+        Self {
+            options,
+            lines: Vec::new(),
+        }
+    }
+
+    fn line(&mut self, indent: usize, line: String) {
+        self.lines.push(format!("{}{line}", "  ".repeat(indent)));
+    }
+
+    fn finish(self) -> String {
+        let mut out = self.lines.join("\n");
+        out.push('\n');
+        out
+    }
+
+    /// Format `value` rounded to the precision, without trailing zeros.
+    fn number(&self, value: f64) -> String {
+        if !value.is_finite() {
+            return value.to_string();
+        }
+        let rounded = format!("{value:.*}", self.options.precision);
+        let trimmed = if rounded.contains('.') {
+            rounded.trim_end_matches('0').trim_end_matches('.')
+        } else {
+            &rounded
+        };
+        match trimmed {
+            "-0" => "0".to_string(),
+            other => other.to_string(),
+        }
+    }
+
+    fn numbers(&self, values: &[f64]) -> String {
+        values
+            .iter()
+            .map(|&v| self.number(v))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    /// Write the metadata of `object`, sorted by domain and key.
+    fn metadata<M: Metadata>(&mut self, indent: usize, object: &M) {
+        let mut entries = object
+            .metadata()
+            .map(|entry| {
+                if entry.is_default_domain() {
+                    format!("{}={}", entry.key, entry.value)
+                } else {
+                    format!("{}/{}={}", entry.domain, entry.key, entry.value)
+                }
+            })
+            .collect::<Vec<_>>();
+        if entries.is_empty() {
+            return;
+        }
+        entries.sort();
+        self.line(indent, "metadata:".to_string());
+        for entry in entries {
+            self.line(indent + 1, entry);
+        }
+    }
+
+    /// Format `geometry` as WKT, with its coordinates rounded.
+    fn geometry(&self, geometry: &Geometry) -> Result<String> {
+        let wkt = geometry.wkt()?;
+        let mut out = String::with_capacity(wkt.len());
+        let mut number = String::new();
+        for c in wkt.chars().chain(std::iter::once(' ')) {
+            let is_number_char = c.is_ascii_digit()
+                || c == '.'
+                || c == '-'
+                || c == '+'
+                || ((c == 'e' || c == 'E') && !number.is_empty());
+            if is_number_char {
+                number.push(c);
+                continue;
+            }
+            if !number.is_empty() {
+                match number.parse::<f64>() {
+                    Ok(v) => out.push_str(&self.number(v)),
+                    Err(_) => out.push_str(&number),
+                }
+                number.clear();
+            }
+            out.push(c);
+        }
+        out.pop();
+        Ok(out)
+    }
+
+    fn field_value(&self, value: &FieldValue) -> String {
+        let list = |values: Vec<String>| format!("[{}]", values.join(", "));
+        match value {
+            FieldValue::IntegerValue(v) => v.to_string(),
+            FieldValue::IntegerListValue(v) => list(v.iter().map(i32::to_string).collect()),
+            FieldValue::Integer64Value(v) => v.to_string(),
+            FieldValue::Integer64ListValue(v) => list(v.iter().map(i64::to_string).collect()),
+            FieldValue::StringValue(v) => format!("{v:?}"),
+            FieldValue::StringListValue(v) => list(v.iter().map(|s| format!("{s:?}")).collect()),
+            FieldValue::RealValue(v) => self.number(*v),
+            FieldValue::RealListValue(v) => list(v.iter().map(|&v| self.number(v)).collect()),
+            FieldValue::DateValue(v) => v.to_string(),
+            FieldValue::DateTimeValue(v) => v.to_string(),
+            FieldValue::TimeValue(v) => v.to_string(),
+            FieldValue::BinaryValue(v) => v.iter().map(|b| format!("{b:02X}")).collect(),
+        }
+    }
+
+    fn layer<L: LayerAccess + Metadata>(&mut self, layer: &mut L) -> Result<()> {
+        self.line(0, format!("layer {:?}:", layer.name()));
+        if let Some(srs) = layer.spatial_ref() {
+            self.line(1, format!("srs: {}", srs_name(&srs)?));
+        }
+        self.line(1, format!("feature_count: {}", layer.feature_count()));
+        for field in layer.defn().fields() {
+            self.line(
+                1,
+                format!(
+                    "field {:?}: {}",
+                    field.name(),
+                    field_type_to_name(field.field_type())
+                ),
+            );
+        }
+        for field in layer.defn().geom_fields() {
+            self.line(
+                1,
+                format!(
+                    "geometry field {:?}: {}",
+                    field.name(),
+                    geometry_type_to_name(field.field_type())
+                ),
+            );
+        }
+        self.metadata(1, layer);
+
+        if self.options.features {
+            layer.reset_feature_reading();
+            let mut lines = Vec::new();
+            for feature in layer.features() {
+                let fid = feature
+                    .fid()
+                    .map_or("none".to_string(), |fid| fid.to_string());
+                lines.push((1, format!("feature {fid}:")));
+                for (name, value) in feature.fields() {
+                    let value = value.map_or("null".to_string(), |v| self.field_value(&v));
+                    lines.push((2, format!("{name:?} = {value}")));
+                }
+                if let Some(geometry) = feature.geometry() {
+                    lines.push((2, format!("geometry: {}", self.geometry(geometry)?)));
+                }
+            }
+            layer.reset_feature_reading();
+            for (indent, line) in lines {
+                self.line(indent, line);
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::raster::Buffer;
+    use crate::test_utils::fixture;
+    use crate::vector::LayerOptions;
+    use crate::DriverManager;
+
+    #[test]
+    fn test_snapshot_raster() {
+        let driver = DriverManager::get_driver_by_name("MEM").unwrap();
+        let mut ds = driver.create_with_band_type::<u8, _>("", 4, 2, 1).unwrap();
+        ds.set_geo_transform(&[10.123456789, 0.5, 0., 20., 0., -0.5])
+            .unwrap();
+        ds.set_spatial_ref(&SpatialRef::from_epsg(4326).unwrap())
+            .unwrap();
+        ds.set_metadata_item("b", "2", "").unwrap();
+        ds.set_metadata_item("a", "1", "").unwrap();
+        let mut band = ds.rasterband(1).unwrap();
+        band.write((0, 0), (4, 2), &mut Buffer::new((4, 2), (0..8).collect()))
+            .unwrap();
+        band.set_no_data_value(Some(255.)).unwrap();
+
+        let mut options = SnapshotOptions::new();
+        options.with_precision(3);
+        let snapshot = snapshot(&ds, &options).unwrap();
+        // MEM may add IMAGE_STRUCTURE metadata, depending on the GDAL version
+        assert!(snapshot.starts_with(
+            "driver: MEM
+size: 4x2
+srs: EPSG:4326
+geo_transform: [10.123, 0.5, 0, 20, 0, -0.5]
+metadata:
+"
+        ));
+        assert!(snapshot.contains("\n  a=1\n  b=2\n"));
+        assert!(snapshot.contains("\nband 1:\n  type: Byte\n  no_data: 255\n  checksum: "));
+        assert_eq!(snapshot, super::snapshot(&ds, &options).unwrap());
+    }
+
+    #[test]
+    fn test_compare_datasets() {
+        let driver = DriverManager::get_driver_by_name("MEM").unwrap();
+        let raster = |data: Vec<f32>| {
+            let mut ds = driver.create_with_band_type::<f32, _>("", 2, 2, 1).unwrap();
+            ds.set_geo_transform(&[0., 1., 0., 2., 0., -1.]).unwrap();
+            ds.rasterband(1)
+                .unwrap()
+                .write((0, 0), (2, 2), &mut Buffer::new((2, 2), data))
+                .unwrap();
+            ds
+        };
+        let a = raster(vec![1., 2., 3., f32::NAN]);
+        let mut b = raster(vec![1., 2., 3.5, f32::NAN]);
+        let options = CompareOptions::new();
+        assert_eq!(compare_datasets(&a, &a, &options).unwrap(), []);
+        assert_eq!(
+            compare_datasets(&a, &b, &options).unwrap(),
+            [Difference::Pixels {
+                band: 1,
+                count: 1,
+                max_difference: 0.5
+            }]
+        );
+
+        b.set_geo_transform(&[0., 1., 0., 2.001, 0., -1.]).unwrap();
+        b.set_spatial_ref(&SpatialRef::from_epsg(4326).unwrap())
+            .unwrap();
+        let mut options = CompareOptions::new();
+        options.with_tolerance(0.5);
+        let differences = compare_datasets(&a, &b, &options).unwrap();
+        assert_eq!(
+            differences,
+            [
+                Difference::GeoTransform {
+                    a: Some([0., 1., 0., 2., 0., -1.]),
+                    b: Some([0., 1., 0., 2.001, 0., -1.]),
+                },
+                Difference::SpatialRef {
+                    a: None,
+                    b: Some("EPSG:4326".to_string()),
+                },
+            ]
+        );
+        assert_eq!(
+            differences[1].to_string(),
+            "spatial reference: None != Some(\"EPSG:4326\")"
+        );
+
+        options.with_geo_transform_tolerance(0.01);
+        assert_eq!(compare_datasets(&a, &b, &options).unwrap().len(), 1);
+
+        let c = driver.create_with_band_type::<u8, _>("", 3, 2, 2).unwrap();
+        let mut options = CompareOptions::new();
+        options.without_pixels();
+        let differences = compare_datasets(&a, &c, &options).unwrap();
+        assert_eq!(
+            differences[..2],
+            [
+                Difference::RasterSize {
+                    a: (2, 2),
+                    b: (3, 2)
+                },
+                Difference::BandCount { a: 1, b: 2 },
+            ]
+        );
+        assert!(differences.contains(&Difference::BandType {
+            band: 1,
+            a: GdalDataType::Float32,
+            b: GdalDataType::UInt8,
+        }));
+    }
+
+    #[test]
+    fn test_snapshot_layer() {
+        let ds = crate::Dataset::open(fixture("roads.geojson")).unwrap();
+        let mut layer = ds.layer(0).unwrap();
+        let options = SnapshotOptions::new();
+        let first = snapshot_layer(&mut layer, &options).unwrap();
+        assert_eq!(first, snapshot_layer(&mut layer, &options).unwrap());
+        assert!(first.starts_with("layer \"roads\":\n"));
+        assert!(first.contains("\n  feature 236194095:\n"));
+        assert!(first.contains("    geometry: LINESTRING ("));
+
+        let driver = DriverManager::get_driver_by_name("Memory").unwrap();
+        let mut ds = driver.create_vector_only("").unwrap();
+        let mut layer = ds
+            .create_layer(LayerOptions {
+                name: "points",
+                ty: gdal_sys::OGRwkbGeometryType::wkbPoint,
+                ..Default::default()
+            })
+            .unwrap();
+        layer
+            .create_feature(Geometry::from_wkt("POINT (1.00000001 -0.0000001)").unwrap())
+            .unwrap();
+        let mut options = SnapshotOptions::new();
+        options.with_precision(4);
+        assert_eq!(
+            snapshot_layer(&mut layer, &options).unwrap(),
+            "layer \"points\":
+  feature_count: 1
+  geometry field \"\": Point
+  feature 0:
+    geometry: POINT (1 0)
+"
+        );
+    }
+}
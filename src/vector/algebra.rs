@@ -0,0 +1,223 @@
+//! Overlay of layers, see the layer algebra methods of [`LayerAccess`].
+
+use gdal_sys::{OGRErr, OGRLayerH};
+
+use crate::cpl::CslStringList;
+use crate::errors::*;
+use crate::progress::{cancelled_or, progress_args, CancellationToken};
+use crate::vector::LayerAccess;
+
+/// Options of the layer algebra methods of [`LayerAccess`], such as
+/// [`LayerAccess::intersection`].
+///
+/// By default, the operation stops at the first error and the result fields are prefixed
+/// neither with the input nor the method layer names.
+///
+/// # Example
+///
+/// ```rust, no_run
+/// use gdal::vector::{LayerAccess, LayerAlgebraOptions, LayerOptions};
+/// use gdal::Dataset;
+/// # fn main() -> gdal::errors::Result<()> {
+/// let parcels = Dataset::open("parcels.gpkg")?;
+/// let flood_zones = Dataset::open("flood_zones.gpkg")?;
+/// let mut output = gdal::DriverManager::get_driver_by_name("Memory")?.create_vector_only("")?;
+/// let mut result = output.create_layer(LayerOptions::default())?;
+///
+/// let mut options = LayerAlgebraOptions::new();
+/// options.with_skip_failures(true).with_method_prefix("zone_");
+/// parcels
+///     .layer(0)?
+///     .intersection(&mut flood_zones.layer(0)?, &mut result, &options)?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct LayerAlgebraOptions {
+    options: CslStringList,
+    cancellation: Option<CancellationToken>,
+}
+
+impl LayerAlgebraOptions {
+    /// Create an empty options set.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Skip the features that fail to be processed or written, instead of stopping.
+    pub fn with_skip_failures(&mut self, skip_failures: bool) -> &mut Self {
+        self.with_flag("SKIP_FAILURES", skip_failures)
+    }
+
+    /// Promote the result geometries to their multi-part types.
+    pub fn with_promote_to_multi(&mut self, promote_to_multi: bool) -> &mut Self {
+        self.with_flag("PROMOTE_TO_MULTI", promote_to_multi)
+    }
+
+    /// Keep the result geometries of a lower dimension than the input ones, e.g. the lines
+    /// where two polygons touch. They are dropped by default.
+    pub fn with_keep_lower_dimension_geometries(&mut self, keep: bool) -> &mut Self {
+        self.with_flag("KEEP_LOWER_DIMENSION_GEOMETRIES", keep)
+    }
+
+    /// Use prepared geometries to speed up the operation. This is the default.
+    pub fn with_prepared_geometries(&mut self, use_prepared: bool) -> &mut Self {
+        self.with_flag("USE_PREPARED_GEOMETRIES", use_prepared)
+    }
+
+    /// Test whether the features of the input layer are contained in those of the method
+    /// layer, to skip the intersection of such features.
+    pub fn with_pretest_containment(&mut self, pretest: bool) -> &mut Self {
+        self.with_flag("PRETEST_CONTAINMENT", pretest)
+    }
+
+    /// Prefix the names of the result fields coming from the input layer with `prefix`.
+    pub fn with_input_prefix(&mut self, prefix: &str) -> &mut Self {
+        self.with_option("INPUT_PREFIX", prefix)
+    }
+
+    /// Prefix the names of the result fields coming from the method layer with `prefix`.
+    pub fn with_method_prefix(&mut self, prefix: &str) -> &mut Self {
+        self.with_option("METHOD_PREFIX", prefix)
+    }
+
+    /// Set any other option, see the
+    /// [`OGRLayer::Intersection`](https://gdal.org/api/ogrlayer_cpp.html#_CPPv4N8OGRLayer12IntersectionEP8OGRLayerP8OGRLayerPPc16GDALProgressFuncPv)
+    /// documentation.
+    pub fn with_option(&mut self, name: &str, value: &str) -> &mut Self {
+        // Keys and values are always valid C strings here.
+        let _ = self.options.set_name_value(name, value);
+        self
+    }
+
+    /// Stop the operation when `token` is cancelled, returning [`GdalError::Cancelled`].
+    pub fn with_cancellation(&mut self, token: &CancellationToken) -> &mut Self {
+        self.cancellation = Some(token.clone());
+        self
+    }
+
+    fn with_flag(&mut self, name: &str, value: bool) -> &mut Self {
+        self.with_option(name, if value { "YES" } else { "NO" })
+    }
+}
+
+/// Signature shared by the `OGR_L_*` layer algebra functions.
+pub(crate) type LayerAlgebraFn = unsafe extern "C" fn(
+    OGRLayerH,
+    OGRLayerH,
+    OGRLayerH,
+    *mut *mut libc::c_char,
+    gdal_sys::GDALProgressFunc,
+    *mut libc::c_void,
+) -> OGRErr::Type;
+
+/// Run the layer algebra function `operation` on `input` and `method`, writing to `result`.
+pub(crate) fn layer_algebra<I: LayerAccess, M: LayerAccess, R: LayerAccess>(
+    operation: LayerAlgebraFn,
+    method_name: &'static str,
+    input: &mut I,
+    method: &mut M,
+    result: &mut R,
+    options: &LayerAlgebraOptions,
+) -> Result<()> {
+    let token = options.cancellation.as_ref();
+    let (progress, progress_arg) = progress_args(token);
+    let rv = unsafe {
+        operation(
+            input.c_layer(),
+            method.c_layer(),
+            result.c_layer(),
+            options.options.as_ptr() as *mut _,
+            progress,
+            progress_arg,
+        )
+    };
+    if rv != OGRErr::OGRERR_NONE {
+        let err = GdalError::OgrError {
+            err: rv,
+            method_name,
+        };
+        return Err(cancelled_or(token, err));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vector::{Geometry, Layer, LayerOptions};
+    use crate::{Dataset, DriverManager};
+
+    fn polygons<'a>(ds: &'a mut Dataset, name: &str, wkts: &[&str]) -> Layer<'a> {
+        let mut layer = ds
+            .create_layer(LayerOptions {
+                name,
+                ..Default::default()
+            })
+            .unwrap();
+        for wkt in wkts {
+            layer
+                .create_feature(Geometry::from_wkt(wkt).unwrap())
+                .unwrap();
+        }
+        layer
+    }
+
+    fn areas(layer: &mut Layer) -> Vec<f64> {
+        let mut areas = layer
+            .features()
+            .map(|f| f.geometry().unwrap().area())
+            .collect::<Vec<_>>();
+        areas.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        areas
+    }
+
+    #[test]
+    fn test_layer_algebra() {
+        let driver = DriverManager::get_driver_by_name("Memory").unwrap();
+        let mut input_ds = driver.create_vector_only("").unwrap();
+        let mut method_ds = driver.create_vector_only("").unwrap();
+        let mut result_ds = driver.create_vector_only("").unwrap();
+        let mut input = polygons(
+            &mut input_ds,
+            "input",
+            &["POLYGON ((0 0, 2 0, 2 2, 0 2, 0 0))"],
+        );
+        let mut method = polygons(
+            &mut method_ds,
+            "method",
+            &["POLYGON ((1 1, 3 1, 3 3, 1 3, 1 1))"],
+        );
+        let options = LayerAlgebraOptions::new();
+
+        let mut result = polygons(&mut result_ds, "intersection", &[]);
+        input
+            .intersection(&mut method, &mut result, &options)
+            .unwrap();
+        assert_eq!(areas(&mut result), [1.0]);
+
+        let mut result = polygons(&mut result_ds, "erase", &[]);
+        input.erase(&mut method, &mut result, &options).unwrap();
+        assert_eq!(areas(&mut result), [3.0]);
+
+        let mut result = polygons(&mut result_ds, "union", &[]);
+        input.union(&mut method, &mut result, &options).unwrap();
+        assert_eq!(areas(&mut result), [1.0, 3.0, 3.0]);
+
+        let mut result = polygons(&mut result_ds, "sym_difference", &[]);
+        input
+            .sym_difference(&mut method, &mut result, &options)
+            .unwrap();
+        assert_eq!(areas(&mut result), [3.0, 3.0]);
+
+        let token = CancellationToken::new();
+        token.cancel();
+        let mut result = polygons(&mut result_ds, "cancelled", &[]);
+        let mut options = LayerAlgebraOptions::new();
+        options.with_cancellation(&token);
+        assert!(matches!(
+            input.clip(&mut method, &mut result, &options),
+            Err(GdalError::Cancelled)
+        ));
+    }
+}
@@ -0,0 +1,162 @@
+//! [Arrow](https://arrow.apache.org/) integration for bulk feature I/O.
+
+use std::marker::PhantomData;
+
+use arrow::datatypes::SchemaRef;
+use arrow::ffi_stream::{ArrowArrayStreamReader, FFI_ArrowArrayStream};
+use arrow::record_batch::{RecordBatch, RecordBatchReader};
+#[cfg(any(major_ge_4, all(major_is_3, minor_ge_8)))]
+use arrow::{
+    array::{Array, StructArray},
+    datatypes::Field,
+    ffi::{FFI_ArrowArray, FFI_ArrowSchema},
+};
+
+use crate::errors::*;
+
+/// An iterator over the [`RecordBatch`]es of a layer, created by
+/// [`LayerAccess::read_arrow`](crate::vector::LayerAccess::read_arrow).
+///
+/// It borrows the layer, which can't be used until the reader is dropped. Like the layer, it
+/// can't be sent to another thread, as reading calls back into the dataset.
+pub struct ArrowStreamReader<'a> {
+    reader: ArrowArrayStreamReader,
+    // The raw pointer makes the reader `!Send`, as `Layer` is.
+    _layer: PhantomData<(&'a mut (), *mut ())>,
+}
+
+impl<'a> ArrowStreamReader<'a> {
+    pub(crate) fn new(stream: FFI_ArrowArrayStream) -> Result<Self> {
+        let reader = ArrowArrayStreamReader::try_new(stream)
+            .map_err(|e| GdalError::ArrowError(e.to_string()))?;
+        Ok(ArrowStreamReader {
+            reader,
+            _layer: PhantomData,
+        })
+    }
+
+    /// Returns the schema of the batches: one column per field, plus the FID and geometry
+    /// columns.
+    pub fn schema(&self) -> SchemaRef {
+        self.reader.schema()
+    }
+}
+
+impl<'a> Iterator for ArrowStreamReader<'a> {
+    type Item = Result<RecordBatch>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.reader
+            .next()
+            .map(|batch| batch.map_err(|e| GdalError::ArrowError(e.to_string())))
+    }
+}
+
+/// Export `batch` through the Arrow C data interface, as a struct array with one child per
+/// column.
+#[cfg(any(major_ge_4, all(major_is_3, minor_ge_8)))]
+pub(crate) fn batch_to_ffi(batch: &RecordBatch) -> Result<(FFI_ArrowArray, FFI_ArrowSchema)> {
+    let array = StructArray::from(batch.clone());
+    arrow::ffi::to_ffi(&array.to_data()).map_err(|e| GdalError::ArrowError(e.to_string()))
+}
+
+#[cfg(any(major_ge_4, all(major_is_3, minor_ge_8)))]
+pub(crate) fn field_to_ffi(field: &Field) -> Result<FFI_ArrowSchema> {
+    FFI_ArrowSchema::try_from(field).map_err(|e| GdalError::ArrowError(e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cpl::CslStringList;
+    use crate::test_utils::fixture;
+    use crate::vector::LayerAccess;
+    use crate::Dataset;
+    use arrow::array::{Array, BinaryArray};
+
+    #[test]
+    fn test_read_arrow() {
+        let ds = Dataset::open(fixture("roads.geojson")).unwrap();
+        let mut layer = ds.layer(0).unwrap();
+
+        let mut options = CslStringList::new();
+        options
+            .set_name_value("MAX_FEATURES_IN_BATCH", "5")
+            .unwrap();
+        let reader = layer.read_arrow(&options).unwrap();
+        let schema = reader.schema();
+        let (geom_column_index, _) = schema.column_with_name("wkb_geometry").unwrap();
+        assert!(schema.column_with_name("OGC_FID").is_some());
+        assert!(schema.column_with_name("highway").is_some());
+
+        let batches = reader.collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(batches.len(), 5);
+        assert_eq!(batches.iter().map(|b| b.num_rows()).sum::<usize>(), 21);
+
+        let geometries = batches[0]
+            .column(geom_column_index)
+            .as_any()
+            .downcast_ref::<BinaryArray>()
+            .unwrap();
+        assert_eq!(geometries.len(), 5);
+        assert!(!geometries.value(0).is_empty());
+
+        // the layer can be read again once the reader is dropped
+        assert_eq!(layer.features().count(), 21);
+    }
+
+    #[test]
+    #[cfg(any(major_ge_4, all(major_is_3, minor_ge_8)))]
+    fn test_write_arrow() {
+        use crate::vector::LayerOptions;
+        use crate::DriverManager;
+
+        let ds = Dataset::open(fixture("roads.geojson")).unwrap();
+        let mut layer = ds.layer(0).unwrap();
+        let mut options = CslStringList::new();
+        options.set_name_value("INCLUDE_FID", "NO").unwrap();
+        let reader = layer.read_arrow(&options).unwrap();
+        let schema = reader.schema();
+        let batches = reader.collect::<Result<Vec<_>, _>>().unwrap();
+
+        let driver = DriverManager::get_driver_by_name("GPKG").unwrap();
+        let path = "/vsimem/test_write_arrow.gpkg";
+        let mut output = driver.create_vector_only(path).unwrap();
+        let mut roads = output
+            .create_layer(LayerOptions {
+                name: "roads",
+                ty: gdal_sys::OGRwkbGeometryType::wkbLineString,
+                ..Default::default()
+            })
+            .unwrap();
+        for field in schema.fields() {
+            if field.name() != "wkb_geometry" {
+                roads
+                    .create_field_from_arrow(field, &CslStringList::new())
+                    .unwrap();
+            }
+        }
+
+        let mut options = CslStringList::new();
+        options
+            .set_name_value("GEOMETRY_NAME", "wkb_geometry")
+            .unwrap();
+        for batch in &batches {
+            roads.write_arrow(batch, &options).unwrap();
+        }
+
+        assert_eq!(roads.feature_count(), 21);
+        let feature = roads.features().next().unwrap();
+        assert_eq!(
+            feature
+                .field_as_string_by_name("highway")
+                .unwrap()
+                .as_deref(),
+            Some("footway")
+        );
+        assert!(feature.geometry().is_some());
+
+        drop(feature);
+        drop(output);
+        crate::vsi::unlink_mem_file(path).unwrap();
+    }
+}
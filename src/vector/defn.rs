@@ -2,9 +2,11 @@ use crate::spatial_ref::SpatialRef;
 use crate::utils::{_last_null_pointer_err, _string};
 use crate::vector::LayerAccess;
 use gdal_sys::{
-    self, OGRFeatureDefnH, OGRFieldDefnH, OGRFieldType, OGRGeomFieldDefnH, OGRwkbGeometryType,
+    self, OGRFeatureDefnH, OGRFieldDefnH, OGRFieldSubType, OGRFieldType, OGRGeomFieldDefnH,
+    OGRwkbGeometryType,
 };
 use libc::c_int;
+use std::ffi::CString;
 
 use crate::errors::*;
 
@@ -55,6 +57,36 @@ impl Defn {
         }
     }
 
+    /// Returns the index of the field called `name`.
+    ///
+    /// If the field is missing, returns [`GdalError::InvalidFieldName`].
+    pub fn field_index<S: AsRef<str>>(&self, name: S) -> Result<usize> {
+        let c_name = CString::new(name.as_ref())?;
+        let idx = unsafe { gdal_sys::OGR_FD_GetFieldIndex(self.c_defn, c_name.as_ptr()) };
+        if idx < 0 {
+            return Err(GdalError::InvalidFieldName {
+                field_name: name.as_ref().to_string(),
+                method_name: "field_index",
+            });
+        }
+        Ok(idx as usize)
+    }
+
+    /// Returns the index of the geometry field called `name`.
+    ///
+    /// If the field is missing, returns [`GdalError::InvalidFieldName`].
+    pub fn geom_field_index<S: AsRef<str>>(&self, name: S) -> Result<usize> {
+        let c_name = CString::new(name.as_ref())?;
+        let idx = unsafe { gdal_sys::OGR_FD_GetGeomFieldIndex(self.c_defn, c_name.as_ptr()) };
+        if idx < 0 {
+            return Err(GdalError::InvalidFieldName {
+                field_name: name.as_ref().to_string(),
+                method_name: "geom_field_index",
+            });
+        }
+        Ok(idx as usize)
+    }
+
     pub fn from_layer<L: LayerAccess>(lyr: &L) -> Defn {
         let c_defn = unsafe { gdal_sys::OGR_L_GetLayerDefn(lyr.c_layer()) };
         Defn { c_defn }
@@ -110,6 +142,52 @@ impl<'a> Field<'a> {
     pub fn precision(&'a self) -> i32 {
         unsafe { gdal_sys::OGR_Fld_GetPrecision(self.c_field_defn) }
     }
+
+    /// Get the sub-type of this field, e.g. [`OFSTBoolean`](OGRFieldSubType::OFSTBoolean) for
+    /// an [`OFTInteger`](OGRFieldType::OFTInteger) field holding booleans.
+    pub fn sub_type(&self) -> OGRFieldSubType::Type {
+        unsafe { gdal_sys::OGR_Fld_GetSubType(self.c_field_defn) }
+    }
+
+    /// Returns `true` if this field may be null.
+    pub fn is_nullable(&self) -> bool {
+        unsafe { gdal_sys::OGR_Fld_IsNullable(self.c_field_defn) != 0 }
+    }
+
+    /// Returns `true` if this field has a unique constraint.
+    #[cfg(any(all(major_is_3, minor_ge_2), major_ge_4))]
+    pub fn is_unique(&self) -> bool {
+        unsafe { gdal_sys::OGR_Fld_IsUnique(self.c_field_defn) != 0 }
+    }
+
+    /// Get the default value of this field, as an SQL expression, e.g. `'text'` for a string
+    /// literal or `CURRENT_TIMESTAMP`.
+    pub fn default_value(&self) -> Option<String> {
+        let rv = unsafe { gdal_sys::OGR_Fld_GetDefault(self.c_field_defn) };
+        if rv.is_null() {
+            return None;
+        }
+        Some(_string(rv))
+    }
+
+    /// Get the time zone flag of this [`OFTDateTime`](OGRFieldType::OFTDateTime) field.
+    ///
+    /// See [`FieldDefn::set_tz_flag`](crate::vector::FieldDefn::set_tz_flag) for its values.
+    #[cfg(any(all(major_ge_3, minor_ge_8), major_ge_4))]
+    pub fn tz_flag(&self) -> i32 {
+        unsafe { gdal_sys::OGR_Fld_GetTZFlag(self.c_field_defn) }
+    }
+
+    /// Get the name of the [field domain](crate::vector::FieldDomain) of this field, or an
+    /// empty string if it has none.
+    #[cfg(any(major_ge_4, all(major_is_3, minor_ge_3)))]
+    pub fn domain_name(&self) -> String {
+        _string(unsafe { gdal_sys::OGR_Fld_GetDomainName(self.c_field_defn) })
+    }
+
+    pub(crate) fn c_field_defn(&self) -> OGRFieldDefnH {
+        self.c_field_defn
+    }
 }
 
 pub struct GeomFieldIterator<'a> {
@@ -155,6 +233,11 @@ impl<'a> GeomField<'a> {
         unsafe { gdal_sys::OGR_GFld_GetType(self.c_field_defn) }
     }
 
+    /// Returns whether this geometry field may be null.
+    pub fn is_nullable(&self) -> bool {
+        unsafe { gdal_sys::OGR_GFld_IsNullable(self.c_field_defn) != 0 }
+    }
+
     pub fn spatial_ref(&'a self) -> Result<SpatialRef> {
         let c_obj = unsafe { gdal_sys::OGR_GFld_GetSpatialRef(self.c_field_defn) };
         if c_obj.is_null() {
@@ -1,3 +1,4 @@
+use crate::spatial_ref::{CoordTransform, SpatialRef};
 use crate::utils::{_last_null_pointer_err, _string, _string_array};
 use crate::vector::geometry::Geometry;
 use crate::vector::{Defn, LayerAccess, OwnedLayer};
@@ -5,9 +6,13 @@ use gdal_sys::{self, OGRErr, OGRFeatureH, OGRFieldType, OGRLayerH};
 use libc::{c_char, c_double, c_int, c_longlong};
 use std::convert::TryInto;
 use std::ffi::{CString, NulError};
+use std::marker::PhantomData;
+use std::ops::{Deref, DerefMut};
 use std::ptr;
 
-use chrono::{DateTime, Datelike, FixedOffset, LocalResult, NaiveDate, TimeZone, Timelike};
+use chrono::{
+    DateTime, Datelike, FixedOffset, LocalResult, NaiveDate, NaiveTime, TimeZone, Timelike,
+};
 
 use crate::errors::*;
 use std::slice;
@@ -15,7 +20,7 @@ use std::slice;
 /// OGR Feature
 #[derive(Debug)]
 pub struct Feature<'a> {
-    _defn: &'a Defn,
+    _defn: PhantomData<&'a Defn>,
     c_feature: OGRFeatureH,
     geometry: Vec<Geometry>,
 }
@@ -27,7 +32,7 @@ impl<'a> Feature<'a> {
             return Err(_last_null_pointer_err("OGR_F_Create"));
         };
         Ok(Feature {
-            _defn: defn,
+            _defn: PhantomData,
             c_feature,
             geometry: Feature::_lazy_feature_geometries(defn),
         })
@@ -39,7 +44,7 @@ impl<'a> Feature<'a> {
     /// This method operates on a raw C pointer
     pub unsafe fn from_c_feature(defn: &'a Defn, c_feature: OGRFeatureH) -> Feature {
         Feature {
-            _defn: defn,
+            _defn: PhantomData,
             c_feature,
             geometry: Feature::_lazy_feature_geometries(defn),
         }
@@ -61,6 +66,74 @@ impl<'a> Feature<'a> {
             .collect()
     }
 
+    /// Detach this feature from the layer or [`Defn`] it was created from.
+    ///
+    /// The feature is copied, with its own copy of the field definitions and spatial
+    /// references, so that later changes to the schema of the layer don't affect it.
+    ///
+    /// The returned [`OwnedFeature`] can outlive the layer and its dataset, and be sent to
+    /// other threads, e.g. with `layer.features().map(Feature::into_owned)`.
+    pub fn into_owned(self) -> Result<OwnedFeature> {
+        unsafe {
+            let c_src_defn = gdal_sys::OGR_F_GetDefnRef(self.c_feature);
+            let c_defn = gdal_sys::OGR_FD_Create(gdal_sys::OGR_FD_GetName(c_src_defn));
+            // A new definition has a default geometry field, replaced by those of the source.
+            gdal_sys::OGR_FD_DeleteGeomFieldDefn(c_defn, 0);
+            for i in 0..gdal_sys::OGR_FD_GetFieldCount(c_src_defn) {
+                gdal_sys::OGR_FD_AddFieldDefn(c_defn, gdal_sys::OGR_FD_GetFieldDefn(c_src_defn, i));
+            }
+            let geom_field_count = gdal_sys::OGR_FD_GetGeomFieldCount(c_src_defn);
+            for i in 0..geom_field_count {
+                gdal_sys::OGR_FD_AddGeomFieldDefn(
+                    c_defn,
+                    gdal_sys::OGR_FD_GetGeomFieldDefn(c_src_defn, i),
+                );
+                // The copied definition still references the spatial reference of the layer.
+                let c_field_defn = gdal_sys::OGR_FD_GetGeomFieldDefn(c_defn, i);
+                let c_srs = gdal_sys::OGR_GFld_GetSpatialRef(c_field_defn);
+                if !c_srs.is_null() {
+                    let c_srs = gdal_sys::OSRClone(c_srs);
+                    gdal_sys::OGR_GFld_SetSpatialRef(c_field_defn, c_srs);
+                    gdal_sys::OSRRelease(c_srs);
+                }
+            }
+
+            // The new feature holds the only reference to `c_defn`, released with it.
+            gdal_sys::OGR_FD_Reference(c_defn);
+            let c_feature = gdal_sys::OGR_F_Create(c_defn);
+            gdal_sys::OGR_FD_Release(c_defn);
+            if c_feature.is_null() {
+                return Err(_last_null_pointer_err("OGR_F_Create"));
+            }
+            // Both features have the same schema, so this copies every field.
+            gdal_sys::OGR_F_SetFrom(c_feature, self.c_feature, 1);
+            // Likewise, the copied geometries still reference the spatial references of the
+            // source geometries.
+            for i in 0..geom_field_count {
+                let c_geom = gdal_sys::OGR_F_GetGeomFieldRef(c_feature, i);
+                if c_geom.is_null() {
+                    continue;
+                }
+                let c_srs = gdal_sys::OGR_G_GetSpatialReference(c_geom);
+                if !c_srs.is_null() {
+                    let c_srs = gdal_sys::OSRClone(c_srs);
+                    gdal_sys::OGR_G_AssignSpatialReference(c_geom, c_srs);
+                    gdal_sys::OSRRelease(c_srs);
+                }
+            }
+
+            Ok(OwnedFeature {
+                feature: Feature {
+                    _defn: PhantomData,
+                    c_feature,
+                    geometry: (0..geom_field_count)
+                        .map(|_| Geometry::lazy_feature_geometry())
+                        .collect(),
+                },
+            })
+        }
+    }
+
     /// Returns the feature identifier, or `None` if none has been assigned.
     pub fn fid(&self) -> Option<u64> {
         let fid = unsafe { gdal_sys::OGR_F_GetFID(self.c_feature) };
@@ -71,6 +144,22 @@ impl<'a> Feature<'a> {
         }
     }
 
+    /// Set the feature identifier, or clear it with `None`.
+    ///
+    /// This is mostly useful before calling [`LayerAccess::set_feature`] or
+    /// `LayerAccess::upsert_feature` on a feature that wasn't read from the layer.
+    pub fn set_fid(&mut self, fid: Option<u64>) -> Result<()> {
+        let fid = fid.map_or(-1, |fid| fid as i64);
+        let rv = unsafe { gdal_sys::OGR_F_SetFID(self.c_feature, fid) };
+        if rv != OGRErr::OGRERR_NONE {
+            return Err(GdalError::OgrError {
+                err: rv,
+                method_name: "OGR_F_SetFID",
+            });
+        }
+        Ok(())
+    }
+
     /// Get the value of a named field. If the field exists, it returns a [`FieldValue`] wrapper,
     /// that you need to unpack to a base type (string, float, etc).
     ///
@@ -154,6 +243,12 @@ impl<'a> Feature<'a> {
             OGRFieldType::OFTDate => Ok(Some(FieldValue::DateValue(
                 self._field_as_datetime(field_id)?.date_naive(),
             ))),
+            OGRFieldType::OFTTime => Ok(Some(FieldValue::TimeValue(
+                self._field_as_datetime(field_id)?.time(),
+            ))),
+            OGRFieldType::OFTBinary => Ok(Some(FieldValue::BinaryValue(
+                self.field_bytes(field_id).unwrap_or_default().to_vec(),
+            ))),
             _ => Err(GdalError::UnhandledFieldType {
                 field_type,
                 method_name: "OGR_Fld_GetType",
@@ -165,7 +260,7 @@ impl<'a> Feature<'a> {
     ///
     /// If the field is missing, returns [`GdalError::InvalidFieldName`].
     ///
-    fn field_idx_from_name<S: AsRef<str>>(&self, field_name: S) -> Result<i32> {
+    pub(crate) fn field_idx_from_name<S: AsRef<str>>(&self, field_name: S) -> Result<i32> {
         let c_str_field_name = CString::new(field_name.as_ref())?;
         let field_id =
             unsafe { gdal_sys::OGR_F_GetFieldIndex(self.c_feature, c_str_field_name.as_ptr()) };
@@ -349,6 +444,56 @@ impl<'a> Feature<'a> {
         Ok(Some(value))
     }
 
+    /// Get the value of the specified field as a byte slice, borrowed from the feature.
+    ///
+    /// This is mostly useful for [`OFTBinary`](OGRFieldType::OFTBinary) fields,
+    /// since it avoids copying large blobs.
+    ///
+    /// If the field is missing, returns [`GdalError::InvalidFieldName`].
+    ///
+    /// Returns `Ok(None)` if the field is null or unset.
+    ///
+    pub fn field_as_bytes_by_name(&self, field_name: &str) -> Result<Option<&[u8]>> {
+        let field_idx = self.field_idx_from_name(field_name)?;
+        Ok(self.field_bytes(field_idx))
+    }
+
+    /// Get the value of the specified field as a byte slice, borrowed from the feature.
+    ///
+    /// This is mostly useful for [`OFTBinary`](OGRFieldType::OFTBinary) fields,
+    /// since it avoids copying large blobs.
+    ///
+    /// If the field is missing, returns [`GdalError::InvalidFieldIndex`].
+    ///
+    /// Returns `Ok(None)` if the field is null or unset.
+    ///
+    pub fn field_as_bytes(&self, field_idx: i32) -> Result<Option<&[u8]>> {
+        if field_idx >= self.field_count() {
+            return Err(GdalError::InvalidFieldIndex {
+                index: field_idx as usize,
+                method_name: "field_as_bytes",
+            });
+        }
+
+        Ok(self.field_bytes(field_idx))
+    }
+
+    fn field_bytes(&self, field_idx: i32) -> Option<&[u8]> {
+        if unsafe { gdal_sys::OGR_F_IsFieldSetAndNotNull(self.c_feature, field_idx) } == 0 {
+            return None;
+        }
+
+        let mut len: c_int = 0;
+        let ptr = unsafe { gdal_sys::OGR_F_GetFieldAsBinary(self.c_feature, field_idx, &mut len) };
+        if ptr.is_null() || len <= 0 {
+            return Some(&[]);
+        }
+
+        // The buffer is owned by the feature and stays valid until the field is modified,
+        // which requires a `&mut` borrow.
+        Some(unsafe { slice::from_raw_parts(ptr, len as usize) })
+    }
+
     /// Get the value of the specified field as a [`DateTime<FixedOffset>`].
     ///
     /// If the field is missing, returns [`GdalError::InvalidFieldName`].
@@ -399,11 +544,11 @@ impl<'a> Feature<'a> {
         let mut day: c_int = 0;
         let mut hour: c_int = 0;
         let mut minute: c_int = 0;
-        let mut second: c_int = 0;
+        let mut second: f32 = 0.;
         let mut tzflag: c_int = 0;
 
         let success = unsafe {
-            gdal_sys::OGR_F_GetFieldAsDateTime(
+            gdal_sys::OGR_F_GetFieldAsDateTimeEx(
                 self.c_feature,
                 field_id,
                 &mut year,
@@ -418,26 +563,34 @@ impl<'a> Feature<'a> {
         if success == 0 {
             return Err(GdalError::OgrError {
                 err: OGRErr::OGRERR_FAILURE,
-                method_name: "OGR_F_GetFieldAsDateTime",
+                method_name: "OGR_F_GetFieldAsDateTimeEx",
             });
         }
 
         // from https://github.com/OSGeo/gdal/blob/33a8a0edc764253b582e194d330eec3b83072863/gdal/ogr/ogrutils.cpp#L1309
-        let tzoffset_secs = if tzflag == 0 || tzflag == 100 {
+        // unknown (0), local (1) and mixed (2) time zones are read as UTC
+        let tzoffset_secs = if tzflag <= 2 || tzflag == 100 {
             0
         } else {
             (tzflag - 100) * 15 * 60
         };
-        let rv = FixedOffset::east_opt(tzoffset_secs)
-            .ok_or_else(|| GdalError::DateError(tzoffset_secs.to_string()))?
-            .with_ymd_and_hms(
-                year,
-                month as u32,
-                day as u32,
-                hour as u32,
-                minute as u32,
-                second as u32,
-            );
+        // OFTTime values have no date
+        let (year, month, day) = if year == 0 && month == 0 && day == 0 {
+            (1970, 1, 1)
+        } else {
+            (year, month, day)
+        };
+        let whole_seconds = second.trunc();
+        let millis = ((second - whole_seconds) * 1000.).round() as u32;
+        let naive = NaiveDate::from_ymd_opt(year, month as u32, day as u32).and_then(|date| {
+            date.and_hms_milli_opt(hour as u32, minute as u32, whole_seconds as u32, millis)
+        });
+        let rv = match naive {
+            Some(naive) => FixedOffset::east_opt(tzoffset_secs)
+                .ok_or_else(|| GdalError::DateError(tzoffset_secs.to_string()))?
+                .from_local_datetime(&naive),
+            None => LocalResult::None,
+        };
         match rv {
             LocalResult::None => Err(
                 GdalError::DateError(format!("Unable to reconstruct valid date from fields: {year}, {month}, {day}, {hour}, {minute}, {second}"))
@@ -463,6 +616,45 @@ impl<'a> Feature<'a> {
         }
     }
 
+    /// Serialize the feature's geometry as WKT, after transforming it into `spatial_ref`.
+    ///
+    /// If the geometry has no spatial reference, the one of the feature's geometry field is used.
+    ///
+    /// Returns `Ok(None)` if the feature has no geometry.
+    ///
+    /// # Example
+    ///
+    /// ```rust, no_run
+    /// # fn main() -> gdal::errors::Result<()> {
+    /// use gdal::Dataset;
+    /// use gdal::spatial_ref::SpatialRef;
+    /// use gdal::vector::LayerAccess;
+    /// let dataset = Dataset::open("fixtures/roads.geojson")?;
+    /// let mut layer = dataset.layer(0)?;
+    /// let web_mercator = SpatialRef::from_epsg(3857)?;
+    /// for feature in layer.features() {
+    ///     println!("{:?}", feature.geometry_wkt_in(&web_mercator)?);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn geometry_wkt_in(&self, spatial_ref: &SpatialRef) -> Result<Option<String>> {
+        let c_geom = unsafe { gdal_sys::OGR_F_GetGeometryRef(self.c_feature) };
+        if c_geom.is_null() {
+            return Ok(None);
+        }
+        let mut geometry = unsafe { Geometry::with_c_geometry(c_geom, false) }.clone();
+        if geometry.spatial_ref().is_none() {
+            let c_field_defn = unsafe { gdal_sys::OGR_F_GetGeomFieldDefnRef(self.c_feature, 0) };
+            let c_srs = unsafe { gdal_sys::OGR_GFld_GetSpatialRef(c_field_defn) };
+            if !c_srs.is_null() {
+                geometry.set_spatial_ref(unsafe { SpatialRef::from_c_obj(c_srs) }?);
+            }
+        }
+        geometry.transform_to_inplace(spatial_ref)?;
+        geometry.wkt().map(Some)
+    }
+
     pub fn geometry_by_name(&self, field_name: &str) -> Result<&Geometry> {
         let c_str_field_name = CString::new(field_name)?;
         let idx =
@@ -513,6 +705,11 @@ impl<'a> Feature<'a> {
     }
 
     pub fn set_field_string_list(&mut self, field_name: &str, value: &[&str]) -> Result<()> {
+        let idx = self.field_idx_from_name(field_name)?;
+        self._set_field_string_list(idx, value)
+    }
+
+    fn _set_field_string_list(&mut self, idx: c_int, value: &[&str]) -> Result<()> {
         let c_strings = value
             .iter()
             .map(|&value| CString::new(value))
@@ -525,11 +722,23 @@ impl<'a> Feature<'a> {
         // OGR_F_SetFieldStringList takes a CSLConstList, which is defined as *mut *mut c_char in
         // gdal-sys despite being constant.
         let c_value = c_str_ptrs.as_ptr() as *mut *mut c_char;
-        let idx = self.field_idx_from_name(field_name)?;
         unsafe { gdal_sys::OGR_F_SetFieldStringList(self.c_feature, idx, c_value) };
         Ok(())
     }
 
+    pub fn set_field_binary(&mut self, field_name: &str, value: &[u8]) -> Result<()> {
+        let idx = self.field_idx_from_name(field_name)?;
+        unsafe {
+            gdal_sys::OGR_F_SetFieldBinary(
+                self.c_feature,
+                idx,
+                value.len().try_into()?,
+                value.as_ptr() as *const libc::c_void,
+            )
+        };
+        Ok(())
+    }
+
     pub fn set_field_double(&mut self, field_name: &str, value: f64) -> Result<()> {
         let idx = self.field_idx_from_name(field_name)?;
         unsafe { gdal_sys::OGR_F_SetFieldDouble(self.c_feature, idx, value as c_double) };
@@ -593,21 +802,21 @@ impl<'a> Feature<'a> {
         value: DateTime<FixedOffset>,
     ) -> Result<()> {
         let idx = self.field_idx_from_name(field_name)?;
+        self._set_field_datetime(idx, value);
+        Ok(())
+    }
 
+    fn _set_field_datetime(&mut self, idx: c_int, value: DateTime<FixedOffset>) {
         let year = value.year() as c_int;
         let month = value.month() as c_int;
         let day = value.day() as c_int;
         let hour = value.hour() as c_int;
         let minute = value.minute() as c_int;
-        let second = value.second() as c_int;
-        let tzflag: c_int = if value.offset().local_minus_utc() == 0 {
-            0
-        } else {
-            100 + (value.offset().local_minus_utc() / (15 * 60))
-        };
+        let second = value.second() as f32 + value.timestamp_subsec_millis() as f32 / 1000.;
+        let tzflag: c_int = 100 + (value.offset().local_minus_utc() / (15 * 60));
 
         unsafe {
-            gdal_sys::OGR_F_SetFieldDateTime(
+            gdal_sys::OGR_F_SetFieldDateTimeEx(
                 self.c_feature,
                 idx,
                 year,
@@ -619,24 +828,95 @@ impl<'a> Feature<'a> {
                 tzflag,
             )
         };
+    }
+
+    /// Set the value of an [`OFTTime`](OGRFieldType::OFTTime) field, with a millisecond
+    /// precision.
+    pub fn set_field_time(&mut self, field_name: &str, value: NaiveTime) -> Result<()> {
+        let idx = self.field_idx_from_name(field_name)?;
+        self._set_field_time(idx, value);
         Ok(())
     }
 
+    fn _set_field_time(&mut self, idx: c_int, value: NaiveTime) {
+        let second = value.second() as f32 + (value.nanosecond() / 1_000_000) as f32 / 1000.;
+        unsafe {
+            gdal_sys::OGR_F_SetFieldDateTimeEx(
+                self.c_feature,
+                idx,
+                0,
+                0,
+                0,
+                value.hour() as c_int,
+                value.minute() as c_int,
+                second,
+                0,
+            )
+        };
+    }
+
     pub fn set_field(&mut self, field_name: &str, value: &FieldValue) -> Result<()> {
+        let idx = self.field_idx_from_name(field_name)?;
+        self.set_field_by_index(idx, value)
+    }
+
+    /// Set the value of the field at index `field_idx`, saving the lookup of its name when
+    /// setting many features.
+    ///
+    /// If the field is missing, returns [`GdalError::InvalidFieldIndex`].
+    pub fn set_field_by_index(&mut self, field_idx: i32, value: &FieldValue) -> Result<()> {
+        if field_idx < 0 || field_idx >= self.field_count() {
+            return Err(GdalError::InvalidFieldIndex {
+                index: field_idx as usize,
+                method_name: "set_field_by_index",
+            });
+        }
+
+        let c_feature = self.c_feature;
         match value {
-            FieldValue::IntegerValue(value) => self.set_field_integer(field_name, *value),
-            FieldValue::IntegerListValue(value) => self.set_field_integer_list(field_name, value),
-            FieldValue::Integer64Value(value) => self.set_field_integer64(field_name, *value),
-            FieldValue::Integer64ListValue(value) => {
-                self.set_field_integer64_list(field_name, value)
+            FieldValue::IntegerValue(value) => unsafe {
+                gdal_sys::OGR_F_SetFieldInteger(c_feature, field_idx, *value as c_int)
+            },
+            FieldValue::IntegerListValue(value) => unsafe {
+                gdal_sys::OGR_F_SetFieldIntegerList(
+                    c_feature,
+                    field_idx,
+                    value.len() as c_int,
+                    value.as_ptr(),
+                )
+            },
+            FieldValue::Integer64Value(value) => unsafe {
+                gdal_sys::OGR_F_SetFieldInteger64(c_feature, field_idx, *value as c_longlong)
+            },
+            FieldValue::Integer64ListValue(value) => unsafe {
+                gdal_sys::OGR_F_SetFieldInteger64List(
+                    c_feature,
+                    field_idx,
+                    value.len() as c_int,
+                    value.as_ptr(),
+                )
+            },
+            FieldValue::StringValue(ref value) => {
+                let c_str_value = CString::new(value.as_str())?;
+                unsafe {
+                    gdal_sys::OGR_F_SetFieldString(c_feature, field_idx, c_str_value.as_ptr())
+                }
             }
-            FieldValue::StringValue(ref value) => self.set_field_string(field_name, value.as_str()),
             FieldValue::StringListValue(ref value) => {
                 let strs = value.iter().map(String::as_str).collect::<Vec<&str>>();
-                self.set_field_string_list(field_name, &strs)
+                self._set_field_string_list(field_idx, &strs)?;
             }
-            FieldValue::RealValue(value) => self.set_field_double(field_name, *value),
-            FieldValue::RealListValue(value) => self.set_field_double_list(field_name, value),
+            FieldValue::RealValue(value) => unsafe {
+                gdal_sys::OGR_F_SetFieldDouble(c_feature, field_idx, *value as c_double)
+            },
+            FieldValue::RealListValue(value) => unsafe {
+                gdal_sys::OGR_F_SetFieldDoubleList(
+                    c_feature,
+                    field_idx,
+                    value.len() as c_int,
+                    value.as_ptr(),
+                )
+            },
             FieldValue::DateValue(value) => {
                 let dv = value
                     .and_hms_opt(0, 0, 0)
@@ -646,10 +926,20 @@ impl<'a> Feature<'a> {
                     FixedOffset::east_opt(0)
                         .ok_or_else(|| GdalError::DateError("utc offset".into()))?,
                 );
-                self.set_field_datetime(field_name, dt)
+                self._set_field_datetime(field_idx, dt)
             }
-            FieldValue::DateTimeValue(value) => self.set_field_datetime(field_name, *value),
+            FieldValue::DateTimeValue(value) => self._set_field_datetime(field_idx, *value),
+            FieldValue::TimeValue(value) => self._set_field_time(field_idx, *value),
+            FieldValue::BinaryValue(value) => unsafe {
+                gdal_sys::OGR_F_SetFieldBinary(
+                    c_feature,
+                    field_idx,
+                    value.len().try_into()?,
+                    value.as_ptr() as *const libc::c_void,
+                )
+            },
         }
+        Ok(())
     }
 
     /// Clear a field, marking it as null.
@@ -674,6 +964,30 @@ impl<'a> Feature<'a> {
         Ok(())
     }
 
+    /// Get the [OGR style string] of this feature, describing how it is drawn, if it has one.
+    ///
+    /// It can be parsed into a [`StyleString`](crate::vector::StyleString).
+    ///
+    /// [OGR style string]: https://gdal.org/user/ogr_feature_style.html
+    pub fn style_string(&self) -> Option<String> {
+        let c_style = unsafe { gdal_sys::OGR_F_GetStyleString(self.c_feature) };
+        if c_style.is_null() {
+            None
+        } else {
+            Some(_string(c_style))
+        }
+    }
+
+    /// Set the [OGR style string] of this feature, e.g. from the `to_string()` of a
+    /// [`StyleString`](crate::vector::StyleString).
+    ///
+    /// [OGR style string]: https://gdal.org/user/ogr_feature_style.html
+    pub fn set_style_string(&mut self, style: &str) -> Result<()> {
+        let c_style = CString::new(style)?;
+        unsafe { gdal_sys::OGR_F_SetStyleString(self.c_feature, c_style.as_ptr()) };
+        Ok(())
+    }
+
     pub fn set_geometry(&mut self, geom: Geometry) -> Result<()> {
         let rv = unsafe { gdal_sys::OGR_F_SetGeometry(self.c_feature, geom.c_geometry()) };
         if rv != OGRErr::OGRERR_NONE {
@@ -686,6 +1000,45 @@ impl<'a> Feature<'a> {
         Ok(())
     }
 
+    /// Set the geometry of the geometry field called `field_name`.
+    ///
+    /// If the field is missing, returns [`GdalError::InvalidFieldName`].
+    pub fn set_geometry_by_name(&mut self, field_name: &str, geom: Geometry) -> Result<()> {
+        let c_str_field_name = CString::new(field_name)?;
+        let idx =
+            unsafe { gdal_sys::OGR_F_GetGeomFieldIndex(self.c_feature, c_str_field_name.as_ptr()) };
+        if idx == -1 {
+            Err(GdalError::InvalidFieldName {
+                field_name: field_name.to_string(),
+                method_name: "set_geometry_by_name",
+            })
+        } else {
+            self.set_geometry_by_index(idx as usize, geom)
+        }
+    }
+
+    /// Set the geometry of the geometry field at index `idx`.
+    ///
+    /// If the field is missing, returns [`GdalError::InvalidFieldIndex`].
+    pub fn set_geometry_by_index(&mut self, idx: usize, geom: Geometry) -> Result<()> {
+        if idx >= self.geometry.len() {
+            return Err(GdalError::InvalidFieldIndex {
+                index: idx,
+                method_name: "set_geometry_by_index",
+            });
+        }
+        let rv =
+            unsafe { gdal_sys::OGR_F_SetGeomField(self.c_feature, idx as i32, geom.c_geometry()) };
+        if rv != OGRErr::OGRERR_NONE {
+            return Err(GdalError::OgrError {
+                err: rv,
+                method_name: "OGR_F_SetGeomField",
+            });
+        }
+        self.geometry[idx] = geom;
+        Ok(())
+    }
+
     pub fn field_count(&self) -> i32 {
         unsafe { gdal_sys::OGR_F_GetFieldCount(self.c_feature) }
     }
@@ -754,6 +1107,32 @@ impl<'a> Drop for Feature<'a> {
     }
 }
 
+/// A [`Feature`] detached from its layer, created by [`Feature::into_owned`].
+///
+/// It derefs to a [`Feature`], to read and modify it.
+#[derive(Debug)]
+pub struct OwnedFeature {
+    feature: Feature<'static>,
+}
+
+/// The feature, its definition and their spatial references are copies, which aren't shared
+/// with the layer or with other features.
+unsafe impl Send for OwnedFeature {}
+
+impl Deref for OwnedFeature {
+    type Target = Feature<'static>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.feature
+    }
+}
+
+impl DerefMut for OwnedFeature {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.feature
+    }
+}
+
 pub struct FeatureIterator<'a> {
     defn: &'a Defn,
     c_layer: OGRLayerH,
@@ -791,6 +1170,98 @@ impl<'a> FeatureIterator<'a> {
             defn,
         }
     }
+
+    /// Adjust the size hint for an iterator whose layer was positioned past its first `skipped` features.
+    pub(crate) fn _skipping(mut self, skipped: usize) -> Self {
+        self.size_hint = self.size_hint.map(|s| s.saturating_sub(skipped));
+        self
+    }
+}
+
+/// Iterator over the features of a layer, with their geometries transformed into another
+/// spatial reference, created by [`LayerAccess::features_in`].
+pub struct TransformedFeatureIterator<'a> {
+    features: FeatureIterator<'a>,
+    transform: CoordTransform,
+}
+
+impl<'a> TransformedFeatureIterator<'a> {
+    pub(crate) fn new(features: FeatureIterator<'a>, transform: CoordTransform) -> Self {
+        TransformedFeatureIterator {
+            features,
+            transform,
+        }
+    }
+}
+
+impl<'a> Iterator for TransformedFeatureIterator<'a> {
+    type Item = Result<Feature<'a>>;
+
+    /// Returns the next feature, or an error if some of the points of its geometry couldn't
+    /// be transformed. Iteration can go on after an error.
+    fn next(&mut self) -> Option<Self::Item> {
+        let feature = self.features.next()?;
+        let c_geom = unsafe { gdal_sys::OGR_F_GetGeometryRef(feature.c_feature) };
+        if !c_geom.is_null() {
+            let rv = unsafe { gdal_sys::OGR_G_Transform(c_geom, self.transform.to_c_hct()) };
+            if rv != OGRErr::OGRERR_NONE {
+                return Some(Err(GdalError::OgrError {
+                    err: rv,
+                    method_name: "OGR_G_Transform",
+                }));
+            }
+        }
+        Some(Ok(feature))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.features.size_hint()
+    }
+}
+
+/// Iterator over the features of a layer intersecting a geometry, created by
+/// [`LayerAccess::features_intersecting`].
+///
+/// The spatial filter of the layer is cleared when the iterator is dropped.
+pub struct IntersectingFeatureIterator<'a> {
+    features: FeatureIterator<'a>,
+    geometry: &'a Geometry,
+    c_layer: OGRLayerH,
+}
+
+impl<'a> IntersectingFeatureIterator<'a> {
+    pub(crate) fn new(
+        features: FeatureIterator<'a>,
+        geometry: &'a Geometry,
+        c_layer: OGRLayerH,
+    ) -> Self {
+        IntersectingFeatureIterator {
+            features,
+            geometry,
+            c_layer,
+        }
+    }
+}
+
+impl<'a> Iterator for IntersectingFeatureIterator<'a> {
+    type Item = Feature<'a>;
+
+    fn next(&mut self) -> Option<Feature<'a>> {
+        let geometry = self.geometry;
+        self.features
+            .by_ref()
+            .find(|feature| feature.geometry().map_or(false, |g| g.intersects(geometry)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.features.size_hint().1)
+    }
+}
+
+impl<'a> Drop for IntersectingFeatureIterator<'a> {
+    fn drop(&mut self) {
+        unsafe { gdal_sys::OGR_L_SetSpatialFilter(self.c_layer, ptr::null_mut()) };
+    }
 }
 
 pub struct OwnedFeatureIterator {
@@ -828,6 +1299,51 @@ where
     }
 }
 
+/// Iterator over the features of an [`OwnedLayer`], as [`OwnedFeature`]s, created by
+/// [`OwnedLayer::into_features`].
+///
+/// Unlike [`OwnedFeatureIterator`], it is a regular [`Iterator`], which can be sent to
+/// other threads along with its features. Copying a feature fails only if it can't be
+/// allocated.
+#[derive(Debug)]
+pub struct IntoFeatureIterator {
+    layer: OwnedLayer,
+    size_hint: Option<usize>,
+}
+
+impl IntoFeatureIterator {
+    pub(crate) fn _with_layer(layer: OwnedLayer) -> Self {
+        let size_hint = layer.try_feature_count().and_then(|s| s.try_into().ok());
+        Self { layer, size_hint }
+    }
+
+    /// Returns the layer and consumes this iterator.
+    pub fn into_layer(self) -> OwnedLayer {
+        self.layer
+    }
+}
+
+impl Iterator for IntoFeatureIterator {
+    type Item = Result<OwnedFeature>;
+
+    fn next(&mut self) -> Option<Result<OwnedFeature>> {
+        let c_feature = unsafe { gdal_sys::OGR_L_GetNextFeature(self.layer.c_layer()) };
+        if c_feature.is_null() {
+            None
+        } else {
+            let feature = unsafe { Feature::from_c_feature(self.layer.defn(), c_feature) };
+            Some(feature.into_owned())
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.size_hint {
+            Some(size) => (size, Some(size)),
+            None => (0, None),
+        }
+    }
+}
+
 impl OwnedFeatureIterator {
     pub(crate) fn _with_layer(layer: OwnedLayer) -> Self {
         let size_hint = layer.try_feature_count().and_then(|s| s.try_into().ok());
@@ -863,6 +1379,8 @@ pub enum FieldValue {
     RealListValue(Vec<f64>),
     DateValue(NaiveDate),
     DateTimeValue(DateTime<FixedOffset>),
+    TimeValue(NaiveTime),
+    BinaryValue(Vec<u8>),
 }
 
 impl FieldValue {
@@ -917,6 +1435,23 @@ impl FieldValue {
         }
     }
 
+    /// Interpret the value as `NaiveTime`. Returns `None` if the value is something else.
+    pub fn into_time(self) -> Option<NaiveTime> {
+        match self {
+            FieldValue::TimeValue(rv) => Some(rv),
+            FieldValue::DateTimeValue(rv) => Some(rv.time()),
+            _ => None,
+        }
+    }
+
+    /// Interpret the value as bytes. Returns `None` if the value is something else.
+    pub fn into_bytes(self) -> Option<Vec<u8>> {
+        match self {
+            FieldValue::BinaryValue(rv) => Some(rv),
+            _ => None,
+        }
+    }
+
     pub fn ogr_field_type(&self) -> OGRFieldType::Type {
         match self {
             FieldValue::IntegerValue(_) => OGRFieldType::OFTInteger,
@@ -929,10 +1464,49 @@ impl FieldValue {
             FieldValue::RealListValue(_) => OGRFieldType::OFTRealList,
             FieldValue::DateValue(_) => OGRFieldType::OFTDate,
             FieldValue::DateTimeValue(_) => OGRFieldType::OFTDateTime,
+            FieldValue::TimeValue(_) => OGRFieldType::OFTTime,
+            FieldValue::BinaryValue(_) => OGRFieldType::OFTBinary,
         }
     }
 }
 
+/// Format `date` as an ISO 8601 date, without chrono's `alloc` feature.
+#[cfg(any(feature = "serde", feature = "geozero"))]
+pub(crate) fn format_iso_date(date: &NaiveDate) -> String {
+    format!("{:04}-{:02}-{:02}", date.year(), date.month(), date.day())
+}
+
+/// Format `time` as an ISO 8601 time, with milliseconds if any.
+#[cfg(any(feature = "serde", feature = "geozero"))]
+pub(crate) fn format_iso_time(time: &NaiveTime) -> String {
+    let millis = time.nanosecond() / 1_000_000;
+    let mut out = format!(
+        "{:02}:{:02}:{:02}",
+        time.hour(),
+        time.minute(),
+        time.second()
+    );
+    if millis != 0 {
+        out.push_str(&format!(".{millis:03}"));
+    }
+    out
+}
+
+/// Format `date_time` as an RFC 3339 date and time, as [`DateTime::to_rfc3339`] does.
+#[cfg(any(feature = "serde", feature = "geozero"))]
+pub(crate) fn format_rfc3339(date_time: &DateTime<FixedOffset>) -> String {
+    let offset = date_time.offset().local_minus_utc();
+    let sign = if offset < 0 { '-' } else { '+' };
+    let offset = offset.abs() / 60;
+    format!(
+        "{}T{}{sign}{:02}:{:02}",
+        format_iso_date(&date_time.date_naive()),
+        format_iso_time(&date_time.time()),
+        offset / 60,
+        offset % 60
+    )
+}
+
 pub fn field_type_to_name(ty: OGRFieldType::Type) -> String {
     let rv = unsafe { gdal_sys::OGR_GetFieldTypeName(ty) };
     _string(rv)
@@ -944,6 +1518,118 @@ mod tests {
     use crate::test_utils::fixture;
     use crate::Dataset;
 
+    #[test]
+    fn test_owned_features() {
+        let features = {
+            let ds = Dataset::open(fixture("roads.geojson")).unwrap();
+            let mut layer = ds.layer(0).unwrap();
+            layer
+                .features()
+                .map(Feature::into_owned)
+                .collect::<Result<Vec<_>>>()
+                .unwrap()
+        };
+        let highways = std::thread::spawn(move || {
+            features
+                .iter()
+                .map(|f| f.field_as_string_by_name("highway").unwrap().unwrap())
+                .collect::<Vec<_>>()
+        })
+        .join()
+        .unwrap();
+        assert_eq!(highways.len(), 21);
+        assert_eq!(highways[0], "footway");
+
+        let layer = Dataset::open(fixture("roads.geojson"))
+            .unwrap()
+            .into_layer(0)
+            .unwrap();
+        let (sender, receiver) = std::sync::mpsc::channel();
+        std::thread::spawn(move || {
+            for feature in layer.into_features() {
+                sender.send(feature.unwrap()).unwrap();
+            }
+        });
+        let mut features = receiver.iter().collect::<Vec<_>>();
+        assert_eq!(features.len(), 21);
+        features[0].set_field_integer("sort_key", 1).unwrap();
+        assert_eq!(
+            features[0].field_as_integer_by_name("sort_key").unwrap(),
+            Some(1)
+        );
+        assert!(features[0].geometry().is_some());
+    }
+
+    #[test]
+    fn test_owned_feature_schema() {
+        use crate::vector::{FieldDefn, LayerOptions};
+        use crate::DriverManager;
+
+        let driver = DriverManager::get_driver_by_name("Memory").unwrap();
+        let mut ds = driver.create_vector_only("").unwrap();
+        let mut layer = ds.create_layer(LayerOptions::default()).unwrap();
+        for name in ["a", "b"] {
+            FieldDefn::new(name, OGRFieldType::OFTInteger)
+                .unwrap()
+                .add_to_layer(&layer)
+                .unwrap();
+        }
+        let mut feature = Feature::new(layer.defn()).unwrap();
+        feature.set_field_integer("a", 1).unwrap();
+        feature.set_field_integer("b", 2).unwrap();
+        feature.set_fid(Some(7)).unwrap();
+        let owned = feature.into_owned().unwrap();
+
+        layer.delete_field(0).unwrap();
+        assert_eq!(owned.fid(), Some(7));
+        assert_eq!(owned.field_count(), 2);
+        assert_eq!(owned.field_as_integer_by_name("a").unwrap(), Some(1));
+        assert_eq!(owned.field_as_integer_by_name("b").unwrap(), Some(2));
+    }
+
+    #[test]
+    fn test_owned_feature_spatial_ref() {
+        use crate::spatial_ref::SpatialRef;
+        use crate::vector::{Geometry, LayerOptions};
+        use crate::DriverManager;
+
+        let driver = DriverManager::get_driver_by_name("Memory").unwrap();
+        let mut ds = driver.create_vector_only("").unwrap();
+        let srs = SpatialRef::from_epsg(4326).unwrap();
+        let layer = ds
+            .create_layer(LayerOptions {
+                srs: Some(&srs),
+                ..Default::default()
+            })
+            .unwrap();
+        let c_srs = unsafe { gdal_sys::OGR_L_GetSpatialRef(layer.c_layer()) };
+        let mut geometry = Geometry::from_wkt("POINT (1 2)").unwrap();
+        geometry.set_spatial_ref(srs.clone());
+        let c_geom_srs = unsafe { gdal_sys::OGR_G_GetSpatialReference(geometry.c_geometry()) };
+        let mut feature = Feature::new(layer.defn()).unwrap();
+        feature.set_geometry(geometry).unwrap();
+
+        let owned = feature.into_owned().unwrap();
+        let c_defn = unsafe { gdal_sys::OGR_F_GetDefnRef(owned.c_feature()) };
+        let c_field_srs = unsafe {
+            gdal_sys::OGR_GFld_GetSpatialRef(gdal_sys::OGR_FD_GetGeomFieldDefn(c_defn, 0))
+        };
+        let c_geom = unsafe { gdal_sys::OGR_F_GetGeometryRef(owned.c_feature()) };
+        let c_owned_geom_srs = unsafe { gdal_sys::OGR_G_GetSpatialReference(c_geom) };
+        assert!(!c_field_srs.is_null() && c_field_srs != c_srs);
+        assert!(!c_owned_geom_srs.is_null() && c_owned_geom_srs != c_geom_srs);
+        assert_eq!(
+            owned
+                .geometry()
+                .unwrap()
+                .spatial_ref()
+                .unwrap()
+                .auth_code()
+                .unwrap(),
+            4326
+        );
+    }
+
     #[test]
     fn test_field_type_to_name() {
         assert_eq!(field_type_to_name(OGRFieldType::OFTReal), "Real");
@@ -961,6 +1647,107 @@ mod tests {
         assert!(feature.field("highway").unwrap().is_none());
     }
 
+    #[test]
+    fn test_geometry_wkt_in() {
+        let ds = Dataset::open(fixture("roads.geojson")).unwrap();
+        let mut layer = ds.layer(0).unwrap();
+        let feature = layer.features().next().unwrap();
+        let web_mercator = SpatialRef::from_epsg(3857).unwrap();
+
+        let expected = feature
+            .geometry()
+            .unwrap()
+            .transform_to(&web_mercator)
+            .unwrap()
+            .wkt()
+            .unwrap();
+        assert_eq!(
+            feature.geometry_wkt_in(&web_mercator).unwrap(),
+            Some(expected)
+        );
+
+        drop(feature);
+
+        let empty = Feature::new(layer.defn()).unwrap();
+        assert_eq!(empty.geometry_wkt_in(&web_mercator).unwrap(), None);
+    }
+
+    #[test]
+    fn test_field_as_bytes() {
+        let driver = crate::DriverManager::get_driver_by_name("Memory").unwrap();
+        let mut ds = driver.create_vector_only("").unwrap();
+        let layer = ds.create_layer(Default::default()).unwrap();
+        layer
+            .create_defn_fields(&[("blob", OGRFieldType::OFTBinary)])
+            .unwrap();
+
+        let mut feature = Feature::new(layer.defn()).unwrap();
+        assert_eq!(feature.field_as_bytes(0).unwrap(), None);
+        assert!(feature.field_as_bytes(1).is_err());
+
+        let blob = [0u8, 1, 2, 255, 0];
+        feature.set_field_binary("blob", &blob).unwrap();
+        assert_eq!(feature.field_as_bytes(0).unwrap(), Some(&blob[..]));
+        assert_eq!(
+            feature.field_as_bytes_by_name("blob").unwrap(),
+            Some(&blob[..])
+        );
+    }
+
+    #[test]
+    fn test_field_value_round_trip() {
+        use chrono::NaiveTime;
+
+        let driver = crate::DriverManager::get_driver_by_name("Memory").unwrap();
+        let mut ds = driver.create_vector_only("").unwrap();
+        let layer = ds.create_layer(Default::default()).unwrap();
+        layer
+            .create_defn_fields(&[
+                ("blob", OGRFieldType::OFTBinary),
+                ("time", OGRFieldType::OFTTime),
+                ("date", OGRFieldType::OFTDate),
+                ("utc", OGRFieldType::OFTDateTime),
+                ("local", OGRFieldType::OFTDateTime),
+            ])
+            .unwrap();
+
+        let values = [
+            ("blob", FieldValue::BinaryValue(vec![0, 1, 2, 255])),
+            (
+                "time",
+                FieldValue::TimeValue(NaiveTime::from_hms_milli_opt(23, 59, 58, 250).unwrap()),
+            ),
+            (
+                "date",
+                FieldValue::DateValue(NaiveDate::from_ymd_opt(2024, 2, 29).unwrap()),
+            ),
+            (
+                "utc",
+                FieldValue::DateTimeValue(
+                    DateTime::parse_from_rfc3339("2024-02-29T12:34:56.789Z").unwrap(),
+                ),
+            ),
+            (
+                "local",
+                FieldValue::DateTimeValue(
+                    DateTime::parse_from_rfc3339("2024-02-29T12:34:56+05:30").unwrap(),
+                ),
+            ),
+        ];
+
+        let mut feature = Feature::new(layer.defn()).unwrap();
+        for (name, value) in &values {
+            feature.set_field(name, value).unwrap();
+        }
+        for (name, value) in &values {
+            assert_eq!(feature.field(name).unwrap().as_ref(), Some(value));
+        }
+        assert_eq!(
+            feature.field_as_string_by_name("utc").unwrap().unwrap(),
+            "2024/02/29 12:34:56.789+00"
+        );
+    }
+
     #[test]
     fn test_field_unset() {
         let ds = Dataset::open(fixture("roads.geojson")).unwrap();
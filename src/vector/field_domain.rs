@@ -0,0 +1,595 @@
+use std::ffi::CString;
+use std::mem::ManuallyDrop;
+use std::ptr::{null, null_mut};
+
+use gdal_sys::{
+    CPLErr, OGRCodedValue, OGRField, OGRFieldDomainH, OGRFieldDomainMergePolicy,
+    OGRFieldDomainSplitPolicy, OGRFieldDomainType, OGRFieldSubType, OGRFieldType,
+};
+
+use crate::errors::*;
+use crate::utils::{_last_cpl_err, _last_null_pointer_err, _string};
+use crate::vector::FieldValue;
+use crate::Dataset;
+
+/// A field domain, restricting the values a field can take, e.g. to a list of codes or
+/// a range of numbers.
+///
+/// Field domains are stored at the dataset level, with
+/// [`Dataset::add_field_domain`], and are attached to fields by name, with
+/// [`FieldDefn::set_domain_name`](crate::vector::FieldDefn::set_domain_name).
+///
+/// # Example
+///
+/// ```rust, no_run
+/// use gdal::vector::{FieldDefn, FieldDomain, LayerAccess, LayerOptions, OGRFieldSubType, OGRFieldType};
+/// use gdal::DriverManager;
+/// # fn main() -> gdal::errors::Result<()> {
+/// let driver = DriverManager::get_driver_by_name("GPKG")?;
+/// let mut dataset = driver.create_vector_only("/vsimem/roads.gpkg")?;
+/// let domain = FieldDomain::new_coded(
+///     "surface",
+///     "Road surface",
+///     OGRFieldType::OFTInteger,
+///     OGRFieldSubType::OFSTNone,
+///     &[("1", Some("asphalt")), ("2", Some("gravel"))],
+/// )?;
+/// dataset.add_field_domain(&domain)?;
+///
+/// let layer = dataset.create_layer(LayerOptions {
+///     name: "roads",
+///     ..Default::default()
+/// })?;
+/// let field = FieldDefn::new("surface", OGRFieldType::OFTInteger)?;
+/// field.set_domain_name("surface")?;
+/// field.add_to_layer(&layer)?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct FieldDomain {
+    c_field_domain: OGRFieldDomainH,
+}
+
+impl Drop for FieldDomain {
+    fn drop(&mut self) {
+        unsafe { gdal_sys::OGR_FldDomain_Destroy(self.c_field_domain) };
+    }
+}
+
+/// A bound of a range [`FieldDomain`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct RangeBound {
+    /// The value of the bound, an [`IntegerValue`](FieldValue::IntegerValue),
+    /// [`Integer64Value`](FieldValue::Integer64Value) or [`RealValue`](FieldValue::RealValue).
+    pub value: FieldValue,
+    /// Whether the bound is part of the range.
+    pub inclusive: bool,
+}
+
+impl FieldDomain {
+    /// Create a coded field domain, whose values are restricted to `values`, a list of codes
+    /// with an optional description.
+    pub fn new_coded(
+        name: &str,
+        description: &str,
+        field_type: OGRFieldType::Type,
+        field_sub_type: OGRFieldSubType::Type,
+        values: &[(&str, Option<&str>)],
+    ) -> Result<FieldDomain> {
+        let c_name = CString::new(name)?;
+        let c_description = CString::new(description)?;
+        let c_values = values
+            .iter()
+            .map(|(code, value)| Ok((CString::new(*code)?, value.map(CString::new).transpose()?)))
+            .collect::<Result<Vec<_>>>()?;
+        let mut enumeration = c_values
+            .iter()
+            .map(|(code, value)| OGRCodedValue {
+                pszCode: code.as_ptr() as *mut _,
+                pszValue: value.as_ref().map_or(null_mut(), |v| v.as_ptr() as *mut _),
+            })
+            .collect::<Vec<_>>();
+        enumeration.push(OGRCodedValue {
+            pszCode: null_mut(),
+            pszValue: null_mut(),
+        });
+        let c_field_domain = unsafe {
+            gdal_sys::OGR_CodedFldDomain_Create(
+                c_name.as_ptr(),
+                c_description.as_ptr(),
+                field_type,
+                field_sub_type,
+                enumeration.as_ptr(),
+            )
+        };
+        if c_field_domain.is_null() {
+            return Err(_last_null_pointer_err("OGR_CodedFldDomain_Create"));
+        }
+        Ok(FieldDomain { c_field_domain })
+    }
+
+    /// Create a range field domain, whose values are restricted to be between `min` and
+    /// `max`. A missing bound leaves the range open on that side.
+    ///
+    /// `field_type` must be [`OFTInteger`](OGRFieldType::OFTInteger),
+    /// [`OFTInteger64`](OGRFieldType::OFTInteger64) or [`OFTReal`](OGRFieldType::OFTReal),
+    /// and the bounds must be values of that type.
+    pub fn new_range(
+        name: &str,
+        description: &str,
+        field_type: OGRFieldType::Type,
+        field_sub_type: OGRFieldSubType::Type,
+        min: Option<RangeBound>,
+        max: Option<RangeBound>,
+    ) -> Result<FieldDomain> {
+        let c_name = CString::new(name)?;
+        let c_description = CString::new(description)?;
+        let c_min = min
+            .as_ref()
+            .map(|b| to_raw_field(&b.value, field_type))
+            .transpose()?;
+        let c_max = max
+            .as_ref()
+            .map(|b| to_raw_field(&b.value, field_type))
+            .transpose()?;
+        let c_field_domain = unsafe {
+            gdal_sys::OGR_RangeFldDomain_Create(
+                c_name.as_ptr(),
+                c_description.as_ptr(),
+                field_type,
+                field_sub_type,
+                c_min.as_ref().map_or(null(), |f| f as *const _),
+                min.map_or(false, |b| b.inclusive),
+                c_max.as_ref().map_or(null(), |f| f as *const _),
+                max.map_or(false, |b| b.inclusive),
+            )
+        };
+        if c_field_domain.is_null() {
+            return Err(_last_null_pointer_err("OGR_RangeFldDomain_Create"));
+        }
+        Ok(FieldDomain { c_field_domain })
+    }
+
+    /// Create a glob field domain, whose values must match the glob pattern `glob`, e.g.
+    /// `"[A-Z]*"`.
+    pub fn new_glob(
+        name: &str,
+        description: &str,
+        field_type: OGRFieldType::Type,
+        field_sub_type: OGRFieldSubType::Type,
+        glob: &str,
+    ) -> Result<FieldDomain> {
+        let c_name = CString::new(name)?;
+        let c_description = CString::new(description)?;
+        let c_glob = CString::new(glob)?;
+        let c_field_domain = unsafe {
+            gdal_sys::OGR_GlobFldDomain_Create(
+                c_name.as_ptr(),
+                c_description.as_ptr(),
+                field_type,
+                field_sub_type,
+                c_glob.as_ptr(),
+            )
+        };
+        if c_field_domain.is_null() {
+            return Err(_last_null_pointer_err("OGR_GlobFldDomain_Create"));
+        }
+        Ok(FieldDomain { c_field_domain })
+    }
+
+    /// Creates a new FieldDomain by wrapping a C pointer
+    ///
+    /// # Safety
+    /// This method operates on a raw C pointer, which is destroyed when the returned value
+    /// is dropped
+    pub unsafe fn from_c_field_domain(c_field_domain: OGRFieldDomainH) -> FieldDomain {
+        FieldDomain { c_field_domain }
+    }
+
+    /// Returns the wrapped C pointer
+    ///
+    /// # Safety
+    /// This method returns a raw C pointer
+    pub unsafe fn c_field_domain(&self) -> OGRFieldDomainH {
+        self.c_field_domain
+    }
+
+    /// Get the name of this field domain.
+    pub fn name(&self) -> String {
+        _string(unsafe { gdal_sys::OGR_FldDomain_GetName(self.c_field_domain) })
+    }
+
+    /// Get the description of this field domain.
+    pub fn description(&self) -> String {
+        _string(unsafe { gdal_sys::OGR_FldDomain_GetDescription(self.c_field_domain) })
+    }
+
+    /// Get the type of this field domain: coded, range or glob.
+    pub fn domain_type(&self) -> OGRFieldDomainType::Type {
+        unsafe { gdal_sys::OGR_FldDomain_GetDomainType(self.c_field_domain) }
+    }
+
+    /// Get the type of the fields this domain applies to.
+    pub fn field_type(&self) -> OGRFieldType::Type {
+        unsafe { gdal_sys::OGR_FldDomain_GetFieldType(self.c_field_domain) }
+    }
+
+    /// Get the sub-type of the fields this domain applies to.
+    pub fn field_sub_type(&self) -> OGRFieldSubType::Type {
+        unsafe { gdal_sys::OGR_FldDomain_GetFieldSubType(self.c_field_domain) }
+    }
+
+    /// Get how values are computed when a feature is split.
+    pub fn split_policy(&self) -> OGRFieldDomainSplitPolicy::Type {
+        unsafe { gdal_sys::OGR_FldDomain_GetSplitPolicy(self.c_field_domain) }
+    }
+
+    /// Set how values are computed when a feature is split.
+    pub fn set_split_policy(&mut self, policy: OGRFieldDomainSplitPolicy::Type) {
+        unsafe { gdal_sys::OGR_FldDomain_SetSplitPolicy(self.c_field_domain, policy) };
+    }
+
+    /// Get how values are computed when features are merged.
+    pub fn merge_policy(&self) -> OGRFieldDomainMergePolicy::Type {
+        unsafe { gdal_sys::OGR_FldDomain_GetMergePolicy(self.c_field_domain) }
+    }
+
+    /// Set how values are computed when features are merged.
+    pub fn set_merge_policy(&mut self, policy: OGRFieldDomainMergePolicy::Type) {
+        unsafe { gdal_sys::OGR_FldDomain_SetMergePolicy(self.c_field_domain, policy) };
+    }
+
+    /// Get the codes of a coded field domain, with their optional description.
+    ///
+    /// Returns `None` for other field domains.
+    pub fn coded_values(&self) -> Option<Vec<(String, Option<String>)>> {
+        if self.domain_type() != OGRFieldDomainType::OFDT_CODED {
+            return None;
+        }
+        let mut c_value =
+            unsafe { gdal_sys::OGR_CodedFldDomain_GetEnumeration(self.c_field_domain) };
+        let mut values = Vec::new();
+        while !c_value.is_null() && !unsafe { *c_value }.pszCode.is_null() {
+            let OGRCodedValue { pszCode, pszValue } = unsafe { *c_value };
+            let value = (!pszValue.is_null()).then(|| _string(pszValue));
+            values.push((_string(pszCode), value));
+            c_value = unsafe { c_value.add(1) };
+        }
+        Some(values)
+    }
+
+    /// Get the lower bound of a range field domain, or `None` if it has no lower bound or
+    /// isn't a range field domain.
+    pub fn range_min(&self) -> Option<RangeBound> {
+        if self.domain_type() != OGRFieldDomainType::OFDT_RANGE {
+            return None;
+        }
+        let mut inclusive = false;
+        let c_field =
+            unsafe { gdal_sys::OGR_RangeFldDomain_GetMin(self.c_field_domain, &mut inclusive) };
+        self.range_bound(c_field, inclusive)
+    }
+
+    /// Get the upper bound of a range field domain, or `None` if it has no upper bound or
+    /// isn't a range field domain.
+    pub fn range_max(&self) -> Option<RangeBound> {
+        if self.domain_type() != OGRFieldDomainType::OFDT_RANGE {
+            return None;
+        }
+        let mut inclusive = false;
+        let c_field =
+            unsafe { gdal_sys::OGR_RangeFldDomain_GetMax(self.c_field_domain, &mut inclusive) };
+        self.range_bound(c_field, inclusive)
+    }
+
+    /// Get the pattern of a glob field domain.
+    ///
+    /// Returns `None` for other field domains.
+    pub fn glob(&self) -> Option<String> {
+        if self.domain_type() != OGRFieldDomainType::OFDT_GLOB {
+            return None;
+        }
+        Some(_string(unsafe {
+            gdal_sys::OGR_GlobFldDomain_GetGlob(self.c_field_domain)
+        }))
+    }
+
+    fn range_bound(&self, c_field: *const OGRField, inclusive: bool) -> Option<RangeBound> {
+        if c_field.is_null() || unsafe { gdal_sys::OGR_RawField_IsUnset(c_field) } != 0 {
+            return None;
+        }
+        let value = unsafe {
+            match self.field_type() {
+                OGRFieldType::OFTInteger => FieldValue::IntegerValue((*c_field).Integer),
+                OGRFieldType::OFTInteger64 => FieldValue::Integer64Value((*c_field).Integer64),
+                OGRFieldType::OFTReal => FieldValue::RealValue((*c_field).Real),
+                _ => return None,
+            }
+        };
+        Some(RangeBound { value, inclusive })
+    }
+}
+
+fn to_raw_field(value: &FieldValue, field_type: OGRFieldType::Type) -> Result<OGRField> {
+    match (value, field_type) {
+        (FieldValue::IntegerValue(value), OGRFieldType::OFTInteger) => {
+            Ok(OGRField { Integer: *value })
+        }
+        (FieldValue::Integer64Value(value), OGRFieldType::OFTInteger64) => {
+            Ok(OGRField { Integer64: *value })
+        }
+        (FieldValue::RealValue(value), OGRFieldType::OFTReal) => Ok(OGRField { Real: *value }),
+        _ => Err(GdalError::BadArgument(format!(
+            "unsupported range bound {value:?} for field type {field_type}"
+        ))),
+    }
+}
+
+/// Converts the failure reason returned by the field domain functions of `GDALDataset`.
+fn failure_reason_err(c_failure_reason: *mut libc::c_char) -> GdalError {
+    if c_failure_reason.is_null() {
+        return _last_cpl_err(CPLErr::CE_Failure);
+    }
+    let msg = _string(c_failure_reason);
+    unsafe { gdal_sys::VSIFree(c_failure_reason.cast()) };
+    GdalError::CplError {
+        class: CPLErr::CE_Failure,
+        // CPLE_AppDefined
+        number: 1,
+        msg,
+    }
+}
+
+/// Field domain methods for [Dataset].
+impl Dataset {
+    /// Add a field domain to this dataset.
+    ///
+    /// Not all drivers support this, see
+    /// [`DatasetCaps::ODsCAddFieldDomain`](crate::vector::DatasetCaps::ODsCAddFieldDomain).
+    ///
+    /// See: [GDALDatasetAddFieldDomain](https://gdal.org/api/raster_c_api.html#_CPPv425GDALDatasetAddFieldDomain12GDALDatasetH15OGRFieldDomainHPPc)
+    pub fn add_field_domain(&mut self, field_domain: &FieldDomain) -> Result<()> {
+        let mut c_failure_reason = null_mut();
+        let rv = unsafe {
+            gdal_sys::GDALDatasetAddFieldDomain(
+                self.c_dataset(),
+                field_domain.c_field_domain,
+                &mut c_failure_reason,
+            )
+        };
+        if !rv {
+            return Err(failure_reason_err(c_failure_reason));
+        }
+        Ok(())
+    }
+
+    /// Get a copy of the field domain called `name`, or `None` if there is none.
+    ///
+    /// The bounds of range field domains are only copied for integer and real fields.
+    ///
+    /// See: [GDALDatasetGetFieldDomain](https://gdal.org/api/raster_c_api.html#_CPPv425GDALDatasetGetFieldDomain12GDALDatasetHPKc)
+    pub fn field_domain(&self, name: &str) -> Result<Option<FieldDomain>> {
+        let c_name = CString::new(name)?;
+        let c_field_domain =
+            unsafe { gdal_sys::GDALDatasetGetFieldDomain(self.c_dataset(), c_name.as_ptr()) };
+        if c_field_domain.is_null() {
+            return Ok(None);
+        }
+        unsafe { clone_field_domain(c_field_domain) }.map(Some)
+    }
+
+    /// Get the names of the field domains of this dataset.
+    ///
+    /// See: [GDALDatasetGetFieldDomainNames](https://gdal.org/api/raster_c_api.html#_CPPv430GDALDatasetGetFieldDomainNames12GDALDatasetH12CSLConstList)
+    #[cfg(any(major_ge_4, all(major_is_3, minor_ge_5)))]
+    pub fn field_domain_names(&self) -> Vec<String> {
+        let c_names =
+            unsafe { gdal_sys::GDALDatasetGetFieldDomainNames(self.c_dataset(), null_mut()) };
+        if c_names.is_null() {
+            return Vec::new();
+        }
+        let names = crate::utils::_string_array(c_names);
+        unsafe { gdal_sys::CSLDestroy(c_names) };
+        names
+    }
+
+    /// Replace the field domain of this dataset with the same name as `field_domain`.
+    ///
+    /// See: [GDALDatasetUpdateFieldDomain](https://gdal.org/api/raster_c_api.html#_CPPv428GDALDatasetUpdateFieldDomain12GDALDatasetH15OGRFieldDomainHPPc)
+    #[cfg(any(major_ge_4, all(major_is_3, minor_ge_5)))]
+    pub fn update_field_domain(&mut self, field_domain: &FieldDomain) -> Result<()> {
+        let mut c_failure_reason = null_mut();
+        let rv = unsafe {
+            gdal_sys::GDALDatasetUpdateFieldDomain(
+                self.c_dataset(),
+                field_domain.c_field_domain,
+                &mut c_failure_reason,
+            )
+        };
+        if !rv {
+            return Err(failure_reason_err(c_failure_reason));
+        }
+        Ok(())
+    }
+
+    /// Delete the field domain called `name` from this dataset.
+    ///
+    /// See: [GDALDatasetDeleteFieldDomain](https://gdal.org/api/raster_c_api.html#_CPPv428GDALDatasetDeleteFieldDomain12GDALDatasetHPKcPPc)
+    #[cfg(any(major_ge_4, all(major_is_3, minor_ge_5)))]
+    pub fn delete_field_domain(&mut self, name: &str) -> Result<()> {
+        let c_name = CString::new(name)?;
+        let mut c_failure_reason = null_mut();
+        let rv = unsafe {
+            gdal_sys::GDALDatasetDeleteFieldDomain(
+                self.c_dataset(),
+                c_name.as_ptr(),
+                &mut c_failure_reason,
+            )
+        };
+        if !rv {
+            return Err(failure_reason_err(c_failure_reason));
+        }
+        Ok(())
+    }
+}
+
+/// Copies a field domain owned by GDAL, as there is no `OGR_FldDomain_Clone`.
+unsafe fn clone_field_domain(c_field_domain: OGRFieldDomainH) -> Result<FieldDomain> {
+    // `domain` doesn't own the C object, so it must not be dropped.
+    let domain = ManuallyDrop::new(FieldDomain { c_field_domain });
+    let copy = match domain.domain_type() {
+        OGRFieldDomainType::OFDT_CODED => {
+            let values = domain.coded_values().unwrap_or_default();
+            let values = values
+                .iter()
+                .map(|(code, value)| (code.as_str(), value.as_deref()))
+                .collect::<Vec<_>>();
+            FieldDomain::new_coded(
+                &domain.name(),
+                &domain.description(),
+                domain.field_type(),
+                domain.field_sub_type(),
+                &values,
+            )
+        }
+        OGRFieldDomainType::OFDT_RANGE => FieldDomain::new_range(
+            &domain.name(),
+            &domain.description(),
+            domain.field_type(),
+            domain.field_sub_type(),
+            domain.range_min(),
+            domain.range_max(),
+        ),
+        _ => FieldDomain::new_glob(
+            &domain.name(),
+            &domain.description(),
+            domain.field_type(),
+            domain.field_sub_type(),
+            &domain.glob().unwrap_or_default(),
+        ),
+    };
+    let mut copy = copy?;
+    copy.set_split_policy(domain.split_policy());
+    copy.set_merge_policy(domain.merge_policy());
+    Ok(copy)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vector::{FieldDefn, LayerAccess, LayerOptions};
+    use crate::DriverManager;
+
+    #[test]
+    fn test_field_domains() {
+        let driver = DriverManager::get_driver_by_name("GPKG").unwrap();
+        let mut ds = driver
+            .create_vector_only("/vsimem/test_field_domains.gpkg")
+            .unwrap();
+
+        let coded = FieldDomain::new_coded(
+            "surface",
+            "Road surface",
+            OGRFieldType::OFTInteger,
+            OGRFieldSubType::OFSTNone,
+            &[("1", Some("asphalt")), ("2", None)],
+        )
+        .unwrap();
+        assert_eq!(coded.domain_type(), OGRFieldDomainType::OFDT_CODED);
+        assert!(coded.range_min().is_none());
+        ds.add_field_domain(&coded).unwrap();
+        assert!(ds.add_field_domain(&coded).is_err());
+
+        let range = FieldDomain::new_range(
+            "speed",
+            "Speed limit",
+            OGRFieldType::OFTReal,
+            OGRFieldSubType::OFSTNone,
+            Some(RangeBound {
+                value: FieldValue::RealValue(0.0),
+                inclusive: true,
+            }),
+            Some(RangeBound {
+                value: FieldValue::RealValue(130.0),
+                inclusive: false,
+            }),
+        )
+        .unwrap();
+        ds.add_field_domain(&range).unwrap();
+        assert!(FieldDomain::new_range(
+            "bad",
+            "",
+            OGRFieldType::OFTReal,
+            OGRFieldSubType::OFSTNone,
+            Some(RangeBound {
+                value: FieldValue::StringValue("0".to_string()),
+                inclusive: true,
+            }),
+            None,
+        )
+        .is_err());
+
+        let glob = FieldDomain::new_glob(
+            "code",
+            "",
+            OGRFieldType::OFTString,
+            OGRFieldSubType::OFSTNone,
+            "[A-Z]*",
+        )
+        .unwrap();
+        ds.add_field_domain(&glob).unwrap();
+
+        let coded = ds.field_domain("surface").unwrap().unwrap();
+        assert_eq!(coded.name(), "surface");
+        assert_eq!(coded.description(), "Road surface");
+        assert_eq!(coded.field_type(), OGRFieldType::OFTInteger);
+        assert_eq!(
+            coded.coded_values().unwrap(),
+            [
+                ("1".to_string(), Some("asphalt".to_string())),
+                ("2".to_string(), None)
+            ]
+        );
+        let range = ds.field_domain("speed").unwrap().unwrap();
+        assert_eq!(
+            range.range_min(),
+            Some(RangeBound {
+                value: FieldValue::RealValue(0.0),
+                inclusive: true,
+            })
+        );
+        assert_eq!(
+            range.range_max(),
+            Some(RangeBound {
+                value: FieldValue::RealValue(130.0),
+                inclusive: false,
+            })
+        );
+        let glob = ds.field_domain("code").unwrap().unwrap();
+        assert_eq!(glob.glob().unwrap(), "[A-Z]*");
+        assert!(ds.field_domain("missing").unwrap().is_none());
+
+        let layer = ds
+            .create_layer(LayerOptions {
+                name: "roads",
+                ..Default::default()
+            })
+            .unwrap();
+        let field = FieldDefn::new("surface", OGRFieldType::OFTInteger).unwrap();
+        field.set_domain_name("surface").unwrap();
+        field.add_to_layer(&layer).unwrap();
+        let field = layer.defn().fields().next().unwrap();
+        assert_eq!(field.domain_name(), "surface");
+
+        #[cfg(any(major_ge_4, all(major_is_3, minor_ge_5)))]
+        {
+            let mut names = ds.field_domain_names();
+            names.sort();
+            assert_eq!(names, ["code", "speed", "surface"]);
+            ds.delete_field_domain("code").unwrap();
+            assert!(ds.field_domain("code").unwrap().is_none());
+            assert!(ds.delete_field_domain("code").is_err());
+        }
+    }
+}
@@ -0,0 +1,291 @@
+//! Conversion of [`Feature`]s to and from GeoJSON features, as [`serde_json::Value`]s.
+
+use gdal_sys::OGRFieldType;
+use serde::ser::{Error as _, SerializeMap};
+use serde::{Serialize, Serializer};
+use serde_json::{Map, Value};
+
+use crate::errors::*;
+use crate::vector::feature::{format_iso_date, format_iso_time, format_rfc3339};
+use crate::vector::{Defn, Feature, FieldValue, Geometry};
+
+impl Serialize for FieldValue {
+    /// Serialize the value as a JSON value: numbers, strings and arrays of them. Dates and
+    /// times are written as ISO 8601 strings and binary values as hexadecimal strings.
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        match self {
+            FieldValue::IntegerValue(v) => v.serialize(serializer),
+            FieldValue::IntegerListValue(v) => v.serialize(serializer),
+            FieldValue::Integer64Value(v) => v.serialize(serializer),
+            FieldValue::Integer64ListValue(v) => v.serialize(serializer),
+            FieldValue::StringValue(v) => v.serialize(serializer),
+            FieldValue::StringListValue(v) => v.serialize(serializer),
+            FieldValue::RealValue(v) => v.serialize(serializer),
+            FieldValue::RealListValue(v) => v.serialize(serializer),
+            FieldValue::DateValue(v) => format_iso_date(v).serialize(serializer),
+            FieldValue::DateTimeValue(v) => format_rfc3339(v).serialize(serializer),
+            FieldValue::TimeValue(v) => format_iso_time(v).serialize(serializer),
+            FieldValue::BinaryValue(v) => {
+                let hex: String = v.iter().map(|b| format!("{b:02X}")).collect();
+                hex.serialize(serializer)
+            }
+        }
+    }
+}
+
+impl<'a> Serialize for Feature<'a> {
+    /// Serialize the feature as a GeoJSON feature, with its FID as `id`, its fields as
+    /// `properties` and its first geometry as `geometry`.
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        let geometry = match self.geometry() {
+            Some(geometry) => {
+                let json = geometry.json().map_err(S::Error::custom)?;
+                serde_json::from_str(&json).map_err(S::Error::custom)?
+            }
+            None => Value::Null,
+        };
+        let properties = self
+            .fields()
+            .map(|(name, value)| Ok((name, serde_json::to_value(value)?)))
+            .collect::<serde_json::Result<Map<_, _>>>()
+            .map_err(S::Error::custom)?;
+
+        let fid = self.fid();
+        let mut map = serializer.serialize_map(Some(3 + usize::from(fid.is_some())))?;
+        map.serialize_entry("type", "Feature")?;
+        if let Some(fid) = fid {
+            map.serialize_entry("id", &fid)?;
+        }
+        map.serialize_entry("properties", &properties)?;
+        map.serialize_entry("geometry", &geometry)?;
+        map.end()
+    }
+}
+
+/// # GeoJSON
+impl<'a> Feature<'a> {
+    /// Convert this feature to a GeoJSON feature, see its [`Serialize`] implementation.
+    pub fn to_geojson(&self) -> Result<Value> {
+        serde_json::to_value(self).map_err(|e| GdalError::JsonError(e.to_string()))
+    }
+
+    /// Create a feature of the schema `defn` from the GeoJSON feature `value`.
+    ///
+    /// The `properties` are set to the fields of the same names, converting them to the
+    /// field types; strings are parsed by GDAL, e.g. for dates. The `geometry` is set to the
+    /// first geometry field and a numeric `id` to the FID.
+    ///
+    /// Returns [`GdalError::InvalidFieldName`] for the properties missing from `defn`.
+    ///
+    /// # Example
+    ///
+    /// ```rust, no_run
+    /// # fn main() -> gdal::errors::Result<()> {
+    /// use gdal::vector::{Feature, LayerAccess};
+    /// use gdal::Dataset;
+    ///
+    /// let dataset = Dataset::open("fixtures/roads.geojson")?;
+    /// let mut layer = dataset.layer(0)?;
+    /// let value = layer.features().next().unwrap().to_geojson()?;
+    /// let copy = Feature::from_geojson(layer.defn(), &value)?;
+    /// assert_eq!(copy.to_geojson()?, value);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_geojson(defn: &'a Defn, value: &Value) -> Result<Feature<'a>> {
+        let value = value
+            .as_object()
+            .ok_or_else(|| GdalError::JsonError("a GeoJSON feature must be an object".into()))?;
+        let mut feature = Feature::new(defn)?;
+
+        if let Some(properties) = value.get("properties").and_then(Value::as_object) {
+            for (name, property) in properties {
+                let idx = defn.field_index(name)?;
+                let field_type = defn
+                    .fields()
+                    .nth(idx)
+                    .map_or(OGRFieldType::OFTString, |field| field.field_type());
+                feature.set_property(name, field_type, property)?;
+            }
+        }
+
+        match value.get("geometry") {
+            None | Some(Value::Null) => {}
+            Some(geometry) => {
+                feature.set_geometry(Geometry::from_geojson(&geometry.to_string())?)?
+            }
+        }
+
+        if let Some(fid) = value.get("id").and_then(Value::as_u64) {
+            feature.set_fid(Some(fid))?;
+        }
+
+        Ok(feature)
+    }
+
+    /// Set the field `name`, of type `field_type`, to the JSON value `property`.
+    fn set_property(
+        &mut self,
+        name: &str,
+        field_type: OGRFieldType::Type,
+        property: &Value,
+    ) -> Result<()> {
+        let invalid =
+            || GdalError::JsonError(format!("invalid value for the field '{name}': {property}"));
+        match property {
+            Value::Null => self.set_field_null(name),
+            Value::Bool(b) => self.set_field_integer(name, i32::from(*b)),
+            Value::Number(n) => match (field_type, n.as_i64()) {
+                (OGRFieldType::OFTInteger, Some(v)) => self.set_field_integer(name, v.try_into()?),
+                (OGRFieldType::OFTInteger64, Some(v)) => self.set_field_integer64(name, v),
+                _ => self.set_field_double(name, n.as_f64().ok_or_else(invalid)?),
+            },
+            Value::String(s) => self.set_field_string(name, s),
+            Value::Array(values) => match field_type {
+                OGRFieldType::OFTIntegerList => {
+                    let values = values
+                        .iter()
+                        .map(|v| Ok(v.as_i64().ok_or_else(invalid)?.try_into()?))
+                        .collect::<Result<Vec<i32>>>()?;
+                    self.set_field_integer_list(name, &values)
+                }
+                OGRFieldType::OFTInteger64List => {
+                    let values = values
+                        .iter()
+                        .map(|v| v.as_i64().ok_or_else(invalid))
+                        .collect::<Result<Vec<_>>>()?;
+                    self.set_field_integer64_list(name, &values)
+                }
+                OGRFieldType::OFTRealList => {
+                    let values = values
+                        .iter()
+                        .map(|v| v.as_f64().ok_or_else(invalid))
+                        .collect::<Result<Vec<_>>>()?;
+                    self.set_field_double_list(name, &values)
+                }
+                OGRFieldType::OFTStringList => {
+                    let values = values
+                        .iter()
+                        .map(|v| match v {
+                            Value::String(s) => s.clone(),
+                            v => v.to_string(),
+                        })
+                        .collect::<Vec<_>>();
+                    let values = values.iter().map(String::as_str).collect::<Vec<_>>();
+                    self.set_field_string_list(name, &values)
+                }
+                _ => self.set_field_string(name, &property.to_string()),
+            },
+            Value::Object(_) => self.set_field_string(name, &property.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::test_utils::fixture;
+    use crate::vector::{FieldDefn, LayerAccess, LayerOptions};
+    use crate::{Dataset, DriverManager};
+
+    #[test]
+    fn test_feature_to_geojson() {
+        let ds = Dataset::open(fixture("roads.geojson")).unwrap();
+        let mut layer = ds.layer(0).unwrap();
+        let value = {
+            let feature = layer.features().next().unwrap();
+            let value = feature.to_geojson().unwrap();
+            assert_eq!(value["type"], "Feature");
+            assert_eq!(value["id"], 236194095);
+            assert_eq!(value["properties"]["highway"], "footway");
+            assert_eq!(value["properties"]["sort_key"], -9.0);
+            assert_eq!(value["properties"]["railway"], Value::Null);
+            assert_eq!(value["geometry"]["type"], "LineString");
+            assert_eq!(
+                value["geometry"]["coordinates"][0],
+                json!([26.1019276, 44.4302748])
+            );
+            assert_eq!(serde_json::to_value(&feature).unwrap(), value);
+            value
+        };
+
+        let copy = Feature::from_geojson(layer.defn(), &value).unwrap();
+        assert_eq!(copy.to_geojson().unwrap(), value);
+    }
+
+    #[test]
+    fn test_field_value_to_json() {
+        use chrono::{FixedOffset, NaiveDate, NaiveTime, TimeZone};
+
+        let date = NaiveDate::from_ymd_opt(2024, 2, 29).unwrap();
+        let time = NaiveTime::from_hms_milli_opt(8, 5, 3, 250).unwrap();
+        let date_time = FixedOffset::west_opt(5400)
+            .unwrap()
+            .from_local_datetime(&date.and_hms_opt(23, 59, 1).unwrap())
+            .unwrap();
+        let to_json = |value| serde_json::to_value(value).unwrap();
+        assert_eq!(to_json(FieldValue::DateValue(date)), "2024-02-29");
+        assert_eq!(to_json(FieldValue::TimeValue(time)), "08:05:03.250");
+        assert_eq!(
+            to_json(FieldValue::DateTimeValue(date_time)),
+            "2024-02-29T23:59:01-01:30"
+        );
+    }
+
+    #[test]
+    fn test_feature_from_geojson() {
+        let driver = DriverManager::get_driver_by_name("Memory").unwrap();
+        let mut ds = driver.create_vector_only("").unwrap();
+        let layer = ds.create_layer(LayerOptions::default()).unwrap();
+        for (name, field_type) in [
+            ("count", OGRFieldType::OFTInteger),
+            ("ratio", OGRFieldType::OFTReal),
+            ("day", OGRFieldType::OFTDate),
+            ("tags", OGRFieldType::OFTStringList),
+        ] {
+            FieldDefn::new(name, field_type)
+                .unwrap()
+                .add_to_layer(&layer)
+                .unwrap();
+        }
+
+        let value = json!({
+            "type": "Feature",
+            "properties": {
+                "count": 3,
+                "ratio": 0.5,
+                "day": "2024-02-29",
+                "tags": ["a", "b"],
+            },
+            "geometry": { "type": "Point", "coordinates": [1.0, 2.0] },
+        });
+        let feature = Feature::from_geojson(layer.defn(), &value).unwrap();
+        assert_eq!(feature.fid(), None);
+        assert_eq!(
+            feature.field("count").unwrap(),
+            Some(FieldValue::IntegerValue(3))
+        );
+        assert_eq!(
+            feature.field("ratio").unwrap(),
+            Some(FieldValue::RealValue(0.5))
+        );
+        assert_eq!(
+            feature.to_geojson().unwrap()["properties"]["day"],
+            "2024-02-29"
+        );
+        assert_eq!(
+            feature.field("tags").unwrap(),
+            Some(FieldValue::StringListValue(vec!["a".into(), "b".into()]))
+        );
+        assert_eq!(feature.geometry().unwrap().get_point(0), (1.0, 2.0, 0.0));
+
+        let missing = json!({ "type": "Feature", "properties": { "name": "x" } });
+        assert!(matches!(
+            Feature::from_geojson(layer.defn(), &missing),
+            Err(GdalError::InvalidFieldName { .. })
+        ));
+        assert!(Feature::from_geojson(layer.defn(), &json!([1, 2])).is_err());
+    }
+}
@@ -3,14 +3,15 @@ use std::fmt::{self, Debug, Formatter};
 use std::marker::PhantomData;
 use std::mem::MaybeUninit;
 use std::ops::{Deref, DerefMut};
+use std::ptr::null_mut;
 
-use libc::{c_double, c_int};
+use libc::{c_double, c_int, c_void};
 
-use gdal_sys::{self, OGRErr, OGRGeometryH, OGRwkbGeometryType};
+use gdal_sys::{self, CPLErr, OGRErr, OGRGeometryH, OGRwkbGeometryType};
 
 use crate::errors::*;
 use crate::spatial_ref::SpatialRef;
-use crate::utils::{_last_null_pointer_err, _string};
+use crate::utils::{_last_cpl_err, _last_null_pointer_err, _string};
 use crate::vector::{Envelope, Envelope3D};
 
 /// OGR Geometry
@@ -150,9 +151,232 @@ impl Geometry {
         (x, y, z)
     }
 
+    /// Get the X, Y, Z and M coordinates of a point of a line string or a point geometry.
+    ///
+    /// Z and M are `0` when the geometry doesn't have them.
+    ///
+    /// See: [`OGR_G_GetPointZM`](https://gdal.org/api/vector_c_api.html#_CPPv416OGR_G_GetPointZM12OGRGeometryHiPdPdPdPd)
+    pub fn get_point_zm(&self, index: usize) -> (f64, f64, f64, f64) {
+        let mut x: c_double = 0.;
+        let mut y: c_double = 0.;
+        let mut z: c_double = 0.;
+        let mut m: c_double = 0.;
+        unsafe {
+            gdal_sys::OGR_G_GetPointZM(
+                self.c_geometry(),
+                index as c_int,
+                &mut x,
+                &mut y,
+                &mut z,
+                &mut m,
+            )
+        };
+        (x, y, z, m)
+    }
+
+    /// Set the X, Y, Z and M coordinates of a point of a line string or a point geometry,
+    /// making the geometry 3D and measured.
+    ///
+    /// See: [`OGR_G_SetPointZM`](https://gdal.org/api/vector_c_api.html#_CPPv416OGR_G_SetPointZM12OGRGeometryHidddd)
+    pub fn set_point_zm(&mut self, i: usize, point: (f64, f64, f64, f64)) {
+        let (x, y, z, m) = point;
+        unsafe { gdal_sys::OGR_G_SetPointZM(self.c_geometry(), i as c_int, x, y, z, m) };
+    }
+
+    /// Add a point with X, Y, Z and M coordinates to a line string, making it 3D and measured.
+    ///
+    /// See: [`OGR_G_AddPointZM`](https://gdal.org/api/vector_c_api.html#_CPPv416OGR_G_AddPointZM12OGRGeometryHdddd)
+    pub fn add_point_zm(&mut self, point: (f64, f64, f64, f64)) {
+        let (x, y, z, m) = point;
+        unsafe { gdal_sys::OGR_G_AddPointZM(self.c_geometry(), x, y, z, m) };
+    }
+
+    /// Get the X, Y, Z and M coordinates of all the points of a line string or a point
+    /// geometry.
+    pub fn get_point_vec_zm(&self) -> Vec<(f64, f64, f64, f64)> {
+        (0..self.point_count())
+            .map(|i| self.get_point_zm(i))
+            .collect()
+    }
+
+    /// Read the coordinates of all the points of a line string or a point geometry into
+    /// `buffer`, with a single call to GDAL.
+    ///
+    /// The buffers are resized to the number of points, reusing their allocations. The Z and M
+    /// buffers are left empty when the geometry doesn't have these coordinates.
+    ///
+    /// See: [`OGR_G_GetPointsZM`](https://gdal.org/api/vector_c_api.html#_CPPv417OGR_G_GetPointsZM12OGRGeometryHPviPviPviPvi)
+    pub fn read_points(&self, buffer: &mut PointBuffer) {
+        let count = self.point_count();
+        let (is_3d, is_measured) = (self.is_3d(), self.is_measured());
+        buffer.x.resize(count, 0.);
+        buffer.y.resize(count, 0.);
+        buffer.z.resize(if is_3d { count } else { 0 }, 0.);
+        buffer.m.resize(if is_measured { count } else { 0 }, 0.);
+        if count == 0 {
+            return;
+        }
+        let stride = std::mem::size_of::<f64>() as c_int;
+        let ptr = |values: &mut Vec<f64>| {
+            if values.is_empty() {
+                null_mut()
+            } else {
+                values.as_mut_ptr() as *mut c_void
+            }
+        };
+        unsafe {
+            gdal_sys::OGR_G_GetPointsZM(
+                self.c_geometry(),
+                ptr(&mut buffer.x),
+                stride,
+                ptr(&mut buffer.y),
+                stride,
+                ptr(&mut buffer.z),
+                stride,
+                ptr(&mut buffer.m),
+                stride,
+            )
+        };
+    }
+
+    /// Replace the points of a line string or a point geometry by the ones in `buffer`, with a
+    /// single call to GDAL.
+    ///
+    /// The geometry becomes 3D or measured depending on whether the Z and M buffers are empty.
+    ///
+    /// # Errors
+    /// The buffers must have the same length, or be empty for Z and M.
+    ///
+    /// See: [`OGR_G_SetPointsZM`](https://gdal.org/api/vector_c_api.html#_CPPv417OGR_G_SetPointsZM12OGRGeometryHiPKviPKviPKviPKvi)
+    pub fn write_points(&mut self, buffer: &PointBuffer) -> Result<()> {
+        let count = buffer.x.len();
+        if buffer.y.len() != count
+            || !(buffer.z.is_empty() || buffer.z.len() == count)
+            || !(buffer.m.is_empty() || buffer.m.len() == count)
+        {
+            return Err(GdalError::BadArgument(
+                "the point buffers must have the same length".to_string(),
+            ));
+        }
+        let stride = std::mem::size_of::<f64>() as c_int;
+        let ptr = |values: &Vec<f64>| {
+            if values.is_empty() {
+                std::ptr::null()
+            } else {
+                values.as_ptr() as *const c_void
+            }
+        };
+        unsafe {
+            gdal_sys::OGR_G_SetPointsZM(
+                self.c_geometry(),
+                count as c_int,
+                ptr(&buffer.x),
+                stride,
+                ptr(&buffer.y),
+                stride,
+                ptr(&buffer.z),
+                stride,
+                ptr(&buffer.m),
+                stride,
+            )
+        };
+        Ok(())
+    }
+
+    /// Returns an iterator over the `(x, y, z, m)` coordinates of the points of a line string
+    /// or a point geometry.
+    ///
+    /// The coordinates are read with a single call to GDAL, see
+    /// [`read_points`](Self::read_points) to also reuse the buffers across geometries.
+    pub fn points(&self) -> PointIterator {
+        let mut buffer = PointBuffer::default();
+        self.read_points(&mut buffer);
+        PointIterator { buffer, index: 0 }
+    }
+
+    /// Call `f` on the `(x, y, z, m)` coordinates of every vertex of the geometry, including
+    /// those of its rings and sub-geometries, and write back the modified values.
+    ///
+    /// Each line string or point is read and written with a single call to GDAL, reusing the
+    /// same buffers. Z and M are `0` when the geometry doesn't have them, and changes to them
+    /// are then ignored.
+    ///
+    /// # Example
+    ///
+    /// ```rust, no_run
+    /// # fn main() -> gdal::errors::Result<()> {
+    /// use gdal::vector::Geometry;
+    /// let mut polygon = Geometry::from_wkt("POLYGON ((0 0,1 0,1 1,0 0))")?;
+    /// polygon.for_each_point_mut(|x, y, _, _| {
+    ///     *x += 10.;
+    ///     *y *= 2.;
+    /// })?;
+    /// assert_eq!(polygon.wkt()?, "POLYGON ((10 0,11 0,11 2,10 0))");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn for_each_point_mut<F>(&mut self, mut f: F) -> Result<()>
+    where
+        F: FnMut(&mut f64, &mut f64, &mut f64, &mut f64),
+    {
+        let mut buffer = PointBuffer::default();
+        self.for_each_point_mut_with(&mut buffer, &mut f)
+    }
+
+    fn for_each_point_mut_with<F>(&mut self, buffer: &mut PointBuffer, f: &mut F) -> Result<()>
+    where
+        F: FnMut(&mut f64, &mut f64, &mut f64, &mut f64),
+    {
+        let count = self.geometry_count();
+        if count > 0 {
+            for i in 0..count {
+                self.get_geometry(i).for_each_point_mut_with(buffer, f)?;
+            }
+            return Ok(());
+        }
+        if self.point_count() == 0 {
+            return Ok(());
+        }
+        self.read_points(buffer);
+        let (mut z, mut m) = (0., 0.);
+        for i in 0..buffer.x.len() {
+            f(
+                &mut buffer.x[i],
+                &mut buffer.y[i],
+                buffer.z.get_mut(i).unwrap_or(&mut z),
+                buffer.m.get_mut(i).unwrap_or(&mut m),
+            );
+        }
+        self.write_points(buffer)
+    }
+
     pub fn get_point_vec(&self) -> Vec<(f64, f64, f64)> {
-        let length = unsafe { gdal_sys::OGR_G_GetPointCount(self.c_geometry()) };
-        (0..length).map(|i| self.get_point(i)).collect()
+        let mut points = Vec::new();
+        self.get_points(&mut points);
+        points
+    }
+
+    /// Get the coordinates of all the points of a line string or a point geometry into
+    /// `points`, replacing its contents.
+    ///
+    /// Unlike [`get_point_vec`](Self::get_point_vec), this doesn't allocate if `points` has
+    /// enough capacity, so the same buffer can be reused across geometries.
+    pub fn get_points(&self, points: &mut Vec<(f64, f64, f64)>) {
+        let length = self.point_count();
+        points.clear();
+        points.reserve(length);
+        points.extend((0..length).map(|i| self.get_point(i as i32)));
+    }
+
+    /// Set the number of points of a line string, adding points at `(0, 0, 0)` or truncating
+    /// the existing ones as needed.
+    ///
+    /// Call this before [`set_point`](Self::set_point) to fill a line string without growing it
+    /// one point at a time.
+    ///
+    /// See: [`OGR_G_SetPointCount`](https://gdal.org/api/vector_c_api.html#_CPPv419OGR_G_SetPointCount12OGRGeometryHi)
+    pub fn set_point_count(&mut self, count: usize) {
+        unsafe { gdal_sys::OGR_G_SetPointCount(self.c_geometry(), count as c_int) };
     }
 
     /// Get the geometry type ordinal
@@ -233,7 +457,29 @@ impl Geometry {
         Ok(())
     }
 
-    /// Compute geometry area in units of the spatial reference system in use.
+    /// Remove the sub-geometry at `index` from a polygon, multi-geometry or geometry
+    /// collection, shifting the following ones down.
+    ///
+    /// See: [`OGR_G_RemoveGeometry`](https://gdal.org/api/vector_c_api.html#_CPPv420OGR_G_RemoveGeometry12OGRGeometryHii)
+    pub fn remove_geometry(&mut self, index: usize) -> Result<()> {
+        let rv = unsafe { gdal_sys::OGR_G_RemoveGeometry(self.c_geometry(), index as c_int, 1) };
+        if rv != OGRErr::OGRERR_NONE {
+            return Err(GdalError::OgrError {
+                err: rv,
+                method_name: "OGR_G_RemoveGeometry",
+            });
+        }
+        Ok(())
+    }
+
+    /// Remove all the points or sub-geometries of the geometry, leaving it empty.
+    ///
+    /// See: [`OGR_G_Empty`](https://gdal.org/api/vector_c_api.html#_CPPv411OGR_G_Empty12OGRGeometryH)
+    pub fn clear(&mut self) {
+        unsafe { gdal_sys::OGR_G_Empty(self.c_geometry()) };
+    }
+
+    /// Compute geometry length in units of the spatial reference system in use.
     ///
     /// Supported for `Curve` (including `LineString` and `CircularString`) and `MultiCurve`.
     /// Returns zero for all other geometry types.
@@ -253,6 +499,62 @@ impl Geometry {
         unsafe { gdal_sys::OGR_G_Area(self.c_geometry()) }
     }
 
+    /// Compute the geodesic area of the geometry in square meters, on the ellipsoid of its
+    /// spatial reference, which must be set.
+    ///
+    /// Unlike [`area`](Self::area), the result is correct for geographic coordinates, without
+    /// reprojecting to an equal-area projection first.
+    ///
+    /// Supported for `LinearRing`, `Polygon`, `MultiPolygon` and their curved variants.
+    /// Returns zero for all other geometry types.
+    ///
+    /// See: [`OGR_G_GeodesicArea`](https://gdal.org/api/vector_c_api.html#_CPPv418OGR_G_GeodesicArea12OGRGeometryH)
+    #[cfg(any(major_ge_4, all(major_is_3, minor_ge_9)))]
+    pub fn geodesic_area(&self) -> Result<f64> {
+        let area = unsafe { gdal_sys::OGR_G_GeodesicArea(self.c_geometry()) };
+        if area < 0.0 {
+            return Err(_last_cpl_err(CPLErr::CE_Failure));
+        }
+        Ok(area)
+    }
+
+    /// Compute the geodesic length of the geometry in meters, on the ellipsoid of its
+    /// spatial reference, which must be set.
+    ///
+    /// Unlike [`length`](Self::length), the result is correct for geographic coordinates,
+    /// without reprojecting first.
+    ///
+    /// Supported for `Curve`, `MultiCurve` and the boundaries of surfaces.
+    /// Returns zero for all other geometry types.
+    ///
+    /// See: [`OGR_G_GeodesicLength`](https://gdal.org/api/vector_c_api.html#_CPPv420OGR_G_GeodesicLength12OGRGeometryH)
+    #[cfg(any(major_ge_4, all(major_is_3, minor_ge_10)))]
+    pub fn geodesic_length(&self) -> Result<f64> {
+        let length = unsafe { gdal_sys::OGR_G_GeodesicLength(self.c_geometry()) };
+        if length < 0.0 {
+            return Err(_last_cpl_err(CPLErr::CE_Failure));
+        }
+        Ok(length)
+    }
+
+    /// Compute the minimum distance between `self` and `other`, in units of the spatial
+    /// reference system in use.
+    ///
+    /// # Notes
+    /// This function requires the GEOS library.
+    /// Check with [`VersionInfo::has_geos`][has_geos].
+    ///
+    /// See: [`OGR_G_Distance`](https://gdal.org/api/vector_c_api.html#_CPPv414OGR_G_Distance12OGRGeometryH12OGRGeometryH)
+    ///
+    /// [has_geos]: crate::version::VersionInfo::has_geos
+    pub fn distance(&self, other: &Self) -> Result<f64> {
+        let distance = unsafe { gdal_sys::OGR_G_Distance(self.c_geometry(), other.c_geometry()) };
+        if distance < 0.0 {
+            return Err(_last_cpl_err(CPLErr::CE_Failure));
+        }
+        Ok(distance)
+    }
+
     /// Computes and returns the axis-aligned 2D bounding envelope for this geometry.
     ///
     /// See: [`OGR_G_GetEnvelope`](https://gdal.org/api/vector_c_api.html#_CPPv417OGR_G_GetEnvelope12OGRGeometryHP11OGREnvelope)
@@ -282,6 +584,81 @@ impl Geometry {
         unsafe { gdal_sys::OGR_G_FlattenTo2D(self.c_geometry()) };
     }
 
+    /// Returns `true` if the geometry has Z coordinates.
+    ///
+    /// See: [`OGR_G_Is3D`](https://gdal.org/api/vector_c_api.html#_CPPv410OGR_G_Is3D12OGRGeometryH)
+    pub fn is_3d(&self) -> bool {
+        unsafe { gdal_sys::OGR_G_Is3D(self.c_geometry()) != 0 }
+    }
+
+    /// Returns `true` if the geometry has M coordinates.
+    ///
+    /// See: [`OGR_G_IsMeasured`](https://gdal.org/api/vector_c_api.html#_CPPv416OGR_G_IsMeasured12OGRGeometryH)
+    pub fn is_measured(&self) -> bool {
+        unsafe { gdal_sys::OGR_G_IsMeasured(self.c_geometry()) != 0 }
+    }
+
+    /// Add or remove the Z coordinates of the geometry and its sub-geometries.
+    ///
+    /// New Z coordinates are set to `0`.
+    ///
+    /// See: [`OGR_G_Set3D`](https://gdal.org/api/vector_c_api.html#_CPPv411OGR_G_Set3D12OGRGeometryHi)
+    pub fn set_3d(&mut self, is_3d: bool) {
+        unsafe { gdal_sys::OGR_G_Set3D(self.c_geometry(), c_int::from(is_3d)) };
+    }
+
+    /// Add or remove the M coordinates of the geometry and its sub-geometries.
+    ///
+    /// New M coordinates are set to `0`.
+    ///
+    /// See: [`OGR_G_SetMeasured`](https://gdal.org/api/vector_c_api.html#_CPPv417OGR_G_SetMeasured12OGRGeometryHi)
+    pub fn set_measured(&mut self, is_measured: bool) {
+        unsafe { gdal_sys::OGR_G_SetMeasured(self.c_geometry(), c_int::from(is_measured)) };
+    }
+
+    /// Returns `true` if the geometry is or contains a curve, such as a `CircularString`,
+    /// `CompoundCurve` or `CurvePolygon`.
+    ///
+    /// If `look_for_non_linear` is `true`, a curve type that only holds straight segments,
+    /// such as a `CompoundCurve` of line strings, doesn't count.
+    ///
+    /// See: [`OGR_G_HasCurveGeometry`](https://gdal.org/api/vector_c_api.html#_CPPv422OGR_G_HasCurveGeometry12OGRGeometryHi)
+    pub fn has_curve_geometry(&self, look_for_non_linear: bool) -> bool {
+        unsafe {
+            gdal_sys::OGR_G_HasCurveGeometry(self.c_geometry(), c_int::from(look_for_non_linear))
+                != 0
+        }
+    }
+
+    /// Return a linear approximation of the geometry, where the curves are replaced by line
+    /// strings, e.g. a `CurvePolygon` by a `Polygon`.
+    ///
+    /// `max_angle_step` is the largest step in degrees along the arcs, or `0` for the
+    /// default of 4 degrees.
+    ///
+    /// See: [`OGR_G_GetLinearGeometry`](https://gdal.org/api/vector_c_api.html#_CPPv423OGR_G_GetLinearGeometry12OGRGeometryHdPPc)
+    pub fn get_linear_geometry(&self, max_angle_step: f64) -> Result<Geometry> {
+        let c_geom = unsafe {
+            gdal_sys::OGR_G_GetLinearGeometry(self.c_geometry(), max_angle_step, null_mut())
+        };
+        if c_geom.is_null() {
+            return Err(_last_null_pointer_err("OGR_G_GetLinearGeometry"));
+        }
+        Ok(unsafe { Geometry::with_c_geometry(c_geom, true) })
+    }
+
+    /// Return the geometry with the arcs that were linearized, for instance by
+    /// [`get_linear_geometry`](Self::get_linear_geometry), turned back into curves.
+    ///
+    /// See: [`OGR_G_GetCurveGeometry`](https://gdal.org/api/vector_c_api.html#_CPPv422OGR_G_GetCurveGeometry12OGRGeometryHPPc)
+    pub fn get_curve_geometry(&self) -> Result<Geometry> {
+        let c_geom = unsafe { gdal_sys::OGR_G_GetCurveGeometry(self.c_geometry(), null_mut()) };
+        if c_geom.is_null() {
+            return Err(_last_null_pointer_err("OGR_G_GetCurveGeometry"));
+        }
+        Ok(unsafe { Geometry::with_c_geometry(c_geom, true) })
+    }
+
     /// Get the spatial reference system for this geometry.
     ///
     /// Returns `Some(SpatialRef)`, or `None` if one isn't defined.
@@ -362,6 +739,102 @@ pub fn geometry_type_to_name(ty: OGRwkbGeometryType::Type) -> String {
     _string(rv)
 }
 
+/// Set whether the drivers may return curve geometries, such as `CircularString` or
+/// `CurvePolygon`, when reading layers. This is enabled by default.
+///
+/// When disabled, curves are read as their linear approximations, for code that can only
+/// handle the simple feature geometry types. This setting applies to the whole process.
+///
+/// See: [`OGRSetNonLinearGeometriesEnabledFlag`](https://gdal.org/api/vector_c_api.html#_CPPv436OGRSetNonLinearGeometriesEnabledFlagi)
+pub fn set_non_linear_geometries_enabled(enabled: bool) {
+    unsafe { gdal_sys::OGRSetNonLinearGeometriesEnabledFlag(c_int::from(enabled)) };
+}
+
+/// Returns whether the drivers may return curve geometries, see
+/// [`set_non_linear_geometries_enabled`].
+pub fn non_linear_geometries_enabled() -> bool {
+    unsafe { gdal_sys::OGRGetNonLinearGeometriesEnabledFlag() != 0 }
+}
+
+/// Returns the 2D version of a geometry type, e.g. `wkbPoint` for `wkbPointZM`.
+///
+/// See: [`OGR_GT_Flatten`](https://gdal.org/api/vector_c_api.html#_CPPv414OGR_GT_Flatten18OGRwkbGeometryType)
+pub fn geometry_type_flatten(ty: OGRwkbGeometryType::Type) -> OGRwkbGeometryType::Type {
+    unsafe { gdal_sys::OGR_GT_Flatten(ty) }
+}
+
+/// Returns `true` if geometries of this type have Z coordinates.
+///
+/// See: [`OGR_GT_HasZ`](https://gdal.org/api/vector_c_api.html#_CPPv411OGR_GT_HasZ18OGRwkbGeometryType)
+pub fn geometry_type_has_z(ty: OGRwkbGeometryType::Type) -> bool {
+    unsafe { gdal_sys::OGR_GT_HasZ(ty) != 0 }
+}
+
+/// Returns `true` if geometries of this type have M coordinates.
+///
+/// See: [`OGR_GT_HasM`](https://gdal.org/api/vector_c_api.html#_CPPv411OGR_GT_HasM18OGRwkbGeometryType)
+pub fn geometry_type_has_m(ty: OGRwkbGeometryType::Type) -> bool {
+    unsafe { gdal_sys::OGR_GT_HasM(ty) != 0 }
+}
+
+/// Reusable coordinate buffers for [`Geometry::read_points`] and [`Geometry::write_points`],
+/// with one vector per dimension.
+///
+/// `z` and `m` are empty for geometries without these coordinates.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PointBuffer {
+    pub x: Vec<f64>,
+    pub y: Vec<f64>,
+    pub z: Vec<f64>,
+    pub m: Vec<f64>,
+}
+
+impl PointBuffer {
+    /// Returns the number of points.
+    pub fn len(&self) -> usize {
+        self.x.len()
+    }
+
+    /// Returns `true` if there are no points.
+    pub fn is_empty(&self) -> bool {
+        self.x.is_empty()
+    }
+}
+
+/// Iterator over the `(x, y, z, m)` coordinates of the points of a geometry, created by
+/// [`Geometry::points`].
+///
+/// Z and M are `0` when the geometry doesn't have them.
+pub struct PointIterator {
+    buffer: PointBuffer,
+    index: usize,
+}
+
+impl Iterator for PointIterator {
+    type Item = (f64, f64, f64, f64);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let i = self.index;
+        if i >= self.buffer.len() {
+            return None;
+        }
+        self.index += 1;
+        Some((
+            self.buffer.x[i],
+            self.buffer.y[i],
+            self.buffer.z.get(i).copied().unwrap_or(0.),
+            self.buffer.m.get(i).copied().unwrap_or(0.),
+        ))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.buffer.len() - self.index;
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for PointIterator {}
+
 /// Reference to owned geometry
 pub struct GeometryRef<'a> {
     geom: Geometry,
@@ -394,7 +867,8 @@ mod tests {
     use crate::spatial_ref::SpatialRef;
     use crate::test_utils::SuppressGDALErrorLog;
     use gdal_sys::OGRwkbGeometryType::{
-        wkbLineString, wkbLinearRing, wkbMultiPoint, wkbMultiPolygon, wkbPoint, wkbPolygon,
+        wkbLineString, wkbLineString25D, wkbLineStringZM, wkbLinearRing, wkbMultiPoint,
+        wkbMultiPolygon, wkbPoint, wkbPolygon,
     };
 
     #[test]
@@ -418,6 +892,16 @@ mod tests {
         assert_eq!(geom.length() as i32, 15);
     }
 
+    #[test]
+    #[allow(clippy::float_cmp)]
+    pub fn test_distance() {
+        let point = Geometry::from_wkt("POINT (0 0)").unwrap();
+        let line = Geometry::from_wkt("LINESTRING (3 -1, 3 4)").unwrap();
+        assert_eq!(point.distance(&line).unwrap(), 3.0);
+        assert_eq!(line.distance(&point).unwrap(), 3.0);
+        assert_eq!(point.distance(&point).unwrap(), 0.0);
+    }
+
     #[test]
     #[allow(clippy::float_cmp)]
     pub fn test_area() {
@@ -433,6 +917,37 @@ mod tests {
         assert_eq!(geom.area().floor(), 25.0);
     }
 
+    #[test]
+    #[cfg(any(major_ge_4, all(major_is_3, minor_ge_9)))]
+    pub fn test_geodesic_area() {
+        let wkt = "POLYGON ((0 0, 0 1, 1 1, 1 0, 0 0))";
+        let mut geom = Geometry::from_wkt(wkt).unwrap();
+        {
+            let _nolog = SuppressGDALErrorLog::new();
+            assert!(geom.geodesic_area().is_err());
+        }
+
+        let mut srs = SpatialRef::from_epsg(4326).unwrap();
+        srs.set_axis_mapping_strategy(crate::spatial_ref::AxisMappingStrategy::TraditionalGisOrder);
+        geom.set_spatial_ref(srs);
+        // A 1° by 1° square at the equator is about 12,309 km².
+        let area = geom.geodesic_area().unwrap();
+        assert!((area / 1e6 - 12_308.8).abs() < 1.0, "{area}");
+        assert_eq!(geom.area(), 1.0);
+    }
+
+    #[test]
+    #[cfg(any(major_ge_4, all(major_is_3, minor_ge_10)))]
+    pub fn test_geodesic_length() {
+        let mut geom = Geometry::from_wkt("LINESTRING (0 0, 1 0)").unwrap();
+        let mut srs = SpatialRef::from_epsg(4326).unwrap();
+        srs.set_axis_mapping_strategy(crate::spatial_ref::AxisMappingStrategy::TraditionalGisOrder);
+        geom.set_spatial_ref(srs);
+        // One degree of longitude at the equator is about 111.32 km.
+        let length = geom.geodesic_length().unwrap();
+        assert!((length - 111_319.5).abs() < 1.0, "{length}");
+    }
+
     #[test]
     pub fn test_is_empty() {
         let geom = Geometry::empty(wkbMultiPolygon).unwrap();
@@ -541,6 +1056,121 @@ mod tests {
         assert!(!points.is_empty());
     }
 
+    #[test]
+    pub fn test_geometry_zm() {
+        let mut line = Geometry::empty(wkbLineString).unwrap();
+        line.add_point_2d((0., 0.));
+        assert!(!line.is_3d() && !line.is_measured());
+
+        line.add_point_zm((1., 2., 3., 4.));
+        assert!(line.is_3d() && line.is_measured());
+        assert_eq!(line.geometry_type(), wkbLineStringZM);
+        assert_eq!(line.get_point_zm(1), (1., 2., 3., 4.));
+        line.set_point_zm(0, (5., 6., 7., 8.));
+        assert_eq!(
+            line.get_point_vec_zm(),
+            vec![(5., 6., 7., 8.), (1., 2., 3., 4.)]
+        );
+        assert_eq!(line.wkt().unwrap(), "LINESTRING ZM (5 6 7 8,1 2 3 4)");
+
+        line.set_measured(false);
+        assert_eq!(line.geometry_type(), wkbLineString25D);
+        assert_eq!(line.get_point_zm(0), (5., 6., 7., 0.));
+        line.set_3d(false);
+        assert_eq!(line.geometry_type(), wkbLineString);
+        line.set_measured(true);
+        assert_eq!(line.wkt().unwrap(), "LINESTRING M (5 6 0,1 2 0)");
+
+        assert_eq!(geometry_type_flatten(wkbLineStringZM), wkbLineString);
+        assert!(geometry_type_has_z(wkbLineString25D));
+        assert!(!geometry_type_has_m(wkbLineString25D));
+        assert!(geometry_type_has_m(wkbLineStringZM));
+    }
+
+    #[test]
+    pub fn test_points() {
+        let line = Geometry::from_wkt("LINESTRING Z (0 1 2,3 4 5,6 7 8)").unwrap();
+        let points: Vec<_> = line.points().collect();
+        assert_eq!(
+            points,
+            vec![(0., 1., 2., 0.), (3., 4., 5., 0.), (6., 7., 8., 0.)]
+        );
+        assert_eq!(line.points().len(), 3);
+
+        let mut buffer = PointBuffer::default();
+        line.read_points(&mut buffer);
+        assert_eq!(buffer.x, [0., 3., 6.]);
+        assert_eq!(buffer.z, [2., 5., 8.]);
+        assert!(buffer.m.is_empty());
+
+        let point = Geometry::from_wkt("POINT (1 2)").unwrap();
+        point.read_points(&mut buffer);
+        assert_eq!(buffer.len(), 1);
+        assert!(buffer.z.is_empty());
+
+        let mut line = Geometry::empty(wkbLineString).unwrap();
+        let buffer = PointBuffer {
+            x: vec![0., 1.],
+            y: vec![2., 3.],
+            z: vec![],
+            m: vec![4., 5.],
+        };
+        line.write_points(&buffer).unwrap();
+        assert_eq!(line.wkt().unwrap(), "LINESTRING M (0 2 4,1 3 5)");
+        let invalid = PointBuffer {
+            x: vec![0., 1.],
+            y: vec![2.],
+            ..Default::default()
+        };
+        assert!(line.write_points(&invalid).is_err());
+    }
+
+    #[test]
+    pub fn test_for_each_point_mut() {
+        let mut geom = Geometry::from_wkt(
+            "MULTIPOLYGON Z (((0 0 1,1 0 1,1 1 1,0 0 1)),((2 2 1,3 2 1,3 3 1,2 2 1)))",
+        )
+        .unwrap();
+        geom.for_each_point_mut(|x, _, z, m| {
+            *x *= 2.;
+            *z += 1.;
+            *m = 5.;
+        })
+        .unwrap();
+        assert_eq!(
+            geom.wkt().unwrap(),
+            "MULTIPOLYGON Z (((0 0 2,2 0 2,2 1 2,0 0 2)),((4 2 2,6 2 2,6 3 2,4 2 2)))"
+        );
+    }
+
+    #[test]
+    pub fn test_curve_geometry() {
+        let arc = Geometry::from_wkt("CIRCULARSTRING (0 0,1 1,2 0)").unwrap();
+        assert!(arc.has_curve_geometry(true));
+
+        let linear = arc.get_linear_geometry(10.).unwrap();
+        assert_eq!(linear.geometry_type(), wkbLineString);
+        assert!(!linear.has_curve_geometry(false));
+        assert!(linear.point_count() > 3);
+        assert_eq!(linear.get_point(0), (0., 0., 0.));
+
+        let curve = linear.get_curve_geometry().unwrap();
+        assert!(curve.has_curve_geometry(true));
+
+        let compound = Geometry::from_wkt("COMPOUNDCURVE ((0 0,1 0),(1 0,1 1))").unwrap();
+        assert!(compound.has_curve_geometry(false));
+        assert!(!compound.has_curve_geometry(true));
+        assert_eq!(
+            compound.get_linear_geometry(0.).unwrap().wkt().unwrap(),
+            "LINESTRING (0 0,1 0,1 1)"
+        );
+
+        assert!(non_linear_geometries_enabled());
+        set_non_linear_geometries_enabled(false);
+        assert!(!non_linear_geometries_enabled());
+        set_non_linear_geometries_enabled(true);
+    }
+
     #[test]
     pub fn test_geometry_type_to_name() {
         assert_eq!(geometry_type_to_name(wkbLineString), "Line String");
@@ -563,4 +1193,38 @@ mod tests {
             polygon.wkt().unwrap()
         );
     }
+
+    #[test]
+    pub fn test_geometry_edit() {
+        let mut ring = Geometry::empty(wkbLinearRing).unwrap();
+        ring.set_point_count(4);
+        for (i, (x, y)) in [(0., 0.), (1., 0.), (1., 1.), (0., 0.)]
+            .into_iter()
+            .enumerate()
+        {
+            ring.set_point_2d(i, (x, y));
+        }
+        let mut points = Vec::with_capacity(8);
+        ring.get_points(&mut points);
+        assert_eq!(points.len(), 4);
+        assert_eq!(points[2], (1., 1., 0.));
+        assert_eq!(points, ring.get_point_vec());
+
+        let mut hole = Geometry::empty(wkbLinearRing).unwrap();
+        hole.add_point_2d((0.5, 0.2));
+        hole.add_point_2d((0.8, 0.2));
+        hole.add_point_2d((0.8, 0.5));
+        hole.add_point_2d((0.5, 0.2));
+        let mut polygon = Geometry::empty(wkbPolygon).unwrap();
+        polygon.add_geometry(ring).unwrap();
+        polygon.add_geometry(hole).unwrap();
+        assert_eq!(polygon.geometry_count(), 2);
+
+        polygon.remove_geometry(1).unwrap();
+        assert_eq!(polygon.wkt().unwrap(), "POLYGON ((0 0,1 0,1 1,0 0))");
+        assert!(polygon.remove_geometry(1).is_err());
+
+        polygon.clear();
+        assert!(polygon.is_empty());
+    }
 }
@@ -0,0 +1,358 @@
+//! [geozero](https://docs.rs/geozero) integration, for streaming geometries and features into
+//! any geozero processor (GeoJSON, MVT, PostGIS EWKB, FlatGeobuf, ...) without converting
+//! them to intermediate structures first.
+
+use gdal_sys::OGRwkbGeometryType;
+use geozero::error::{GeozeroError, Result};
+use geozero::{
+    ColumnValue, CoordDimensions, FeatureProcessor, GeomProcessor, GeozeroDatasource,
+    GeozeroGeometry,
+};
+
+use crate::vector::feature::{format_iso_date, format_iso_time, format_rfc3339};
+use crate::vector::{FieldValue, Geometry, Layer, LayerAccess, OwnedLayer};
+
+impl GeozeroGeometry for Geometry {
+    fn process_geom<P: GeomProcessor>(&self, processor: &mut P) -> Result<()> {
+        process_geom_n(self, 0, processor)
+    }
+
+    fn dims(&self) -> CoordDimensions {
+        CoordDimensions {
+            z: self.is_3d(),
+            m: self.is_measured(),
+            ..CoordDimensions::xy()
+        }
+    }
+
+    /// Returns the EPSG code of the geometry's spatial reference, if it has one.
+    fn srid(&self) -> Option<i32> {
+        let srs = self.spatial_ref()?;
+        match srs.auth_name() {
+            Ok(name) if name == "EPSG" => srs.auth_code().ok(),
+            _ => None,
+        }
+    }
+}
+
+fn process_geom_n<P: GeomProcessor>(geom: &Geometry, idx: usize, processor: &mut P) -> Result<()> {
+    let geometry_type = unsafe { gdal_sys::OGR_GT_Flatten(geom.geometry_type()) };
+    let sub_geometry = |n: usize| unsafe { geom.get_unowned_geometry(n) };
+    match geometry_type {
+        OGRwkbGeometryType::wkbPoint => {
+            if geom.is_empty() {
+                return processor.empty_point(idx);
+            }
+            processor.point_begin(idx)?;
+            process_coord(geom, 0, 0, processor)?;
+            processor.point_end(idx)
+        }
+        OGRwkbGeometryType::wkbMultiPoint => {
+            let count = geom.geometry_count();
+            processor.multipoint_begin(count, idx)?;
+            for n in 0..count {
+                process_coord(&sub_geometry(n), 0, n, processor)?;
+            }
+            processor.multipoint_end(idx)
+        }
+        OGRwkbGeometryType::wkbLineString | OGRwkbGeometryType::wkbLinearRing => {
+            process_linestring(geom, true, idx, processor)
+        }
+        OGRwkbGeometryType::wkbMultiLineString => {
+            let count = geom.geometry_count();
+            processor.multilinestring_begin(count, idx)?;
+            for n in 0..count {
+                process_linestring(&sub_geometry(n), false, n, processor)?;
+            }
+            processor.multilinestring_end(idx)
+        }
+        OGRwkbGeometryType::wkbPolygon => process_polygon(geom, true, idx, processor),
+        OGRwkbGeometryType::wkbMultiPolygon => {
+            let count = geom.geometry_count();
+            processor.multipolygon_begin(count, idx)?;
+            for n in 0..count {
+                process_polygon(&sub_geometry(n), false, n, processor)?;
+            }
+            processor.multipolygon_end(idx)
+        }
+        OGRwkbGeometryType::wkbGeometryCollection => {
+            let count = geom.geometry_count();
+            processor.geometrycollection_begin(count, idx)?;
+            for n in 0..count {
+                process_geom_n(&sub_geometry(n), n, processor)?;
+            }
+            processor.geometrycollection_end(idx)
+        }
+        _ => Err(GeozeroError::GeometryFormat),
+    }
+}
+
+/// Pass the `point`-th vertex of `geom` to the processor, with Z and M values if it asks
+/// for them.
+fn process_coord<P: GeomProcessor>(
+    geom: &Geometry,
+    point: usize,
+    idx: usize,
+    processor: &mut P,
+) -> Result<()> {
+    let dims = processor.dimensions();
+    if !processor.multi_dim() {
+        let (x, y, _) = geom.get_point(point as i32);
+        return processor.xy(x, y, idx);
+    }
+    let (mut x, mut y, mut z, mut m) = (0., 0., 0., 0.);
+    unsafe {
+        gdal_sys::OGR_G_GetPointZM(
+            geom.c_geometry(),
+            point as libc::c_int,
+            &mut x,
+            &mut y,
+            &mut z,
+            &mut m,
+        )
+    };
+    let coord_dims = GeozeroGeometry::dims(geom);
+    processor.coordinate(
+        x,
+        y,
+        (dims.z && coord_dims.z).then(|| z),
+        (dims.m && coord_dims.m).then(|| m),
+        None,
+        None,
+        idx,
+    )
+}
+
+fn process_linestring<P: GeomProcessor>(
+    geom: &Geometry,
+    tagged: bool,
+    idx: usize,
+    processor: &mut P,
+) -> Result<()> {
+    let count = geom.point_count();
+    processor.linestring_begin(tagged, count, idx)?;
+    for point in 0..count {
+        process_coord(geom, point, point, processor)?;
+    }
+    processor.linestring_end(tagged, idx)
+}
+
+fn process_polygon<P: GeomProcessor>(
+    geom: &Geometry,
+    tagged: bool,
+    idx: usize,
+    processor: &mut P,
+) -> Result<()> {
+    let count = geom.geometry_count();
+    processor.polygon_begin(tagged, count, idx)?;
+    for n in 0..count {
+        let ring = unsafe { geom.get_unowned_geometry(n) };
+        process_linestring(&ring, false, n, processor)?;
+    }
+    processor.polygon_end(tagged, idx)
+}
+
+/// Pass the features of `layer` to the processor, as a dataset named after the layer.
+///
+/// Null and unset fields are skipped, date and time fields are passed as ISO 8601 strings
+/// and list fields as JSON arrays.
+fn process_layer<L: LayerAccess, P: FeatureProcessor>(
+    layer: &mut L,
+    processor: &mut P,
+) -> Result<()> {
+    processor.dataset_begin(Some(&layer.name()))?;
+    for (feature_idx, feature) in layer.features().enumerate() {
+        let feature_idx = feature_idx as u64;
+        processor.feature_begin(feature_idx)?;
+
+        processor.properties_begin()?;
+        let mut property_idx = 0;
+        for (name, value) in feature.fields() {
+            let value = match value {
+                Some(value) => value,
+                None => continue,
+            };
+            if process_property(property_idx, &name, &value, processor)? {
+                break;
+            }
+            property_idx += 1;
+        }
+        processor.properties_end()?;
+
+        processor.geometry_begin()?;
+        if let Some(geometry) = feature.geometry() {
+            process_geom_n(geometry, 0, processor)?;
+        }
+        processor.geometry_end()?;
+
+        processor.feature_end(feature_idx)?;
+    }
+    processor.dataset_end()
+}
+
+/// Pass a field value to the processor, returning `true` if it asks to stop.
+fn process_property<P: FeatureProcessor>(
+    idx: usize,
+    name: &str,
+    value: &FieldValue,
+    processor: &mut P,
+) -> Result<bool> {
+    let to_json = |result: serde_json::Result<String>| {
+        result.map_err(|e| GeozeroError::Property(e.to_string()))
+    };
+    match value {
+        FieldValue::IntegerValue(value) => processor.property(idx, name, &ColumnValue::Int(*value)),
+        FieldValue::Integer64Value(value) => {
+            processor.property(idx, name, &ColumnValue::Long(*value))
+        }
+        FieldValue::RealValue(value) => processor.property(idx, name, &ColumnValue::Double(*value)),
+        FieldValue::StringValue(value) => {
+            processor.property(idx, name, &ColumnValue::String(value))
+        }
+        FieldValue::BinaryValue(value) => {
+            processor.property(idx, name, &ColumnValue::Binary(value))
+        }
+        FieldValue::DateValue(value) => {
+            processor.property(idx, name, &ColumnValue::DateTime(&format_iso_date(value)))
+        }
+        FieldValue::DateTimeValue(value) => {
+            processor.property(idx, name, &ColumnValue::DateTime(&format_rfc3339(value)))
+        }
+        FieldValue::TimeValue(value) => {
+            processor.property(idx, name, &ColumnValue::String(&format_iso_time(value)))
+        }
+        FieldValue::IntegerListValue(value) => {
+            let json = to_json(serde_json::to_string(value))?;
+            processor.property(idx, name, &ColumnValue::Json(&json))
+        }
+        FieldValue::Integer64ListValue(value) => {
+            let json = to_json(serde_json::to_string(value))?;
+            processor.property(idx, name, &ColumnValue::Json(&json))
+        }
+        FieldValue::RealListValue(value) => {
+            let json = to_json(serde_json::to_string(value))?;
+            processor.property(idx, name, &ColumnValue::Json(&json))
+        }
+        FieldValue::StringListValue(value) => {
+            let json = to_json(serde_json::to_string(value))?;
+            processor.property(idx, name, &ColumnValue::Json(&json))
+        }
+    }
+}
+
+impl<'a> GeozeroDatasource for Layer<'a> {
+    /// Process all the features of the layer, honoring its spatial and attribute filters.
+    fn process<P: FeatureProcessor>(&mut self, processor: &mut P) -> Result<()> {
+        process_layer(self, processor)
+    }
+}
+
+impl GeozeroDatasource for OwnedLayer {
+    /// Process all the features of the layer, honoring its spatial and attribute filters.
+    fn process<P: FeatureProcessor>(&mut self, processor: &mut P) -> Result<()> {
+        process_layer(self, processor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use geozero::error::Result;
+    use geozero::{GeomProcessor, GeozeroDatasource, GeozeroGeometry, ProcessToJson, ToJson};
+
+    use crate::test_utils::fixture;
+    use crate::vector::{FieldValue, Geometry, LayerAccess, LayerOptions, OGRFieldType};
+    use crate::{Dataset, DriverManager};
+
+    #[test]
+    fn test_geometry_to_json() {
+        let geom =
+            Geometry::from_wkt("POLYGON ((0 0,1 0,1 1,0 0),(0.2 0.1,0.8 0.1,0.8 0.7,0.2 0.1))")
+                .unwrap();
+        assert_eq!(
+            geom.to_json().unwrap(),
+            r#"{"type": "Polygon", "coordinates": [[[0,0],[1,0],[1,1],[0,0]],[[0.2,0.1],[0.8,0.1],[0.8,0.7],[0.2,0.1]]]}"#
+        );
+
+        let geom =
+            Geometry::from_wkt("GEOMETRYCOLLECTION (POINT (1 2),LINESTRING (3 4,5 6))").unwrap();
+        assert_eq!(
+            geom.to_json().unwrap(),
+            r#"{"type": "GeometryCollection", "geometries": [{"type": "Point", "coordinates": [1,2]},{"type": "LineString", "coordinates": [[3,4],[5,6]]}]}"#
+        );
+
+        let geom = Geometry::from_wkt("POINT Z (1 2 3)").unwrap();
+        assert!(geom.dims().z);
+        assert!(!geom.dims().m);
+        assert_eq!(geom.srid(), None);
+
+        let geom = Geometry::from_wkt("CIRCULARSTRING (0 0,1 1,2 0)").unwrap();
+        assert!(geom.to_json().is_err());
+    }
+
+    #[test]
+    fn test_layer_to_json() {
+        let driver = DriverManager::get_driver_by_name("Memory").unwrap();
+        let mut ds = driver.create_vector_only("").unwrap();
+        let mut layer = ds
+            .create_layer(LayerOptions {
+                name: "places",
+                ..Default::default()
+            })
+            .unwrap();
+        layer
+            .create_defn_fields(&[
+                ("name", OGRFieldType::OFTString),
+                ("population", OGRFieldType::OFTInteger64),
+                ("tags", OGRFieldType::OFTIntegerList),
+            ])
+            .unwrap();
+        layer
+            .create_feature_fields(
+                Geometry::from_wkt("POINT (1 2)").unwrap(),
+                &["name", "population"],
+                &[
+                    FieldValue::StringValue("A".to_string()),
+                    FieldValue::Integer64Value(10),
+                ],
+            )
+            .unwrap();
+        layer
+            .create_feature_fields(
+                Geometry::from_wkt("POINT (3 4)").unwrap(),
+                &["population", "tags"],
+                &[
+                    FieldValue::Integer64Value(20),
+                    FieldValue::IntegerListValue(vec![1, 2]),
+                ],
+            )
+            .unwrap();
+
+        assert_eq!(
+            layer.to_json().unwrap(),
+            r#"{
+"type": "FeatureCollection",
+"name": "places",
+"features": [{"type": "Feature", "properties": {"name": "A", "population": 10}, "geometry": {"type": "Point", "coordinates": [1,2]}},
+{"type": "Feature", "properties": {"population": 20, "tags": [1,2]}, "geometry": {"type": "Point", "coordinates": [3,4]}}]}"#
+        );
+    }
+
+    #[test]
+    fn test_layer_process_geom() {
+        struct LineStringCounter(usize);
+        impl GeomProcessor for LineStringCounter {
+            fn linestring_begin(&mut self, tagged: bool, _size: usize, _idx: usize) -> Result<()> {
+                if tagged {
+                    self.0 += 1;
+                }
+                Ok(())
+            }
+        }
+
+        let ds = Dataset::open(fixture("roads.geojson")).unwrap();
+        let mut layer = ds.layer(0).unwrap();
+        let mut counter = LineStringCounter(0);
+        layer.process_geom(&mut counter).unwrap();
+        assert_eq!(counter.0, 21);
+    }
+}
@@ -1,18 +1,31 @@
+use crate::config::ThreadLocalConfigGuard;
+use crate::cpl::CslStringList;
 use crate::metadata::Metadata;
-use crate::spatial_ref::SpatialRef;
-use crate::utils::{_last_null_pointer_err, _string};
-use crate::vector::defn::Defn;
-use crate::vector::{Envelope, Feature, FieldValue, Geometry, LayerOptions};
-use crate::{dataset::Dataset, gdal_major_object::MajorObject};
-use gdal_sys::{self, GDALMajorObjectH, OGRErr, OGRFieldDefnH, OGRFieldType, OGRLayerH};
+use crate::spatial_ref::{CoordTransform, SpatialRef};
+use crate::utils::{_last_cpl_err, _last_null_pointer_err, _string};
+use crate::vector::algebra::layer_algebra;
+use crate::vector::defn::{Defn, Field};
+use crate::vector::{
+    AlterFieldFlags, Envelope, Feature, FieldValue, Geometry, LayerAlgebraOptions,
+    LayerCreationOptions, LayerOptions, ReadTuning,
+};
+use crate::{dataset::Dataset, gdal_major_object::MajorObject, DatasetOptions};
+use gdal_sys::{
+    self, CPLErr, GDALMajorObjectH, OGRErr, OGRFieldDefnH, OGRFieldSubType, OGRFieldType,
+    OGRGeomFieldDefnH, OGRLayerH, OGRwkbGeometryType,
+};
 use libc::c_int;
 use std::ffi::NulError;
 use std::mem::MaybeUninit;
+use std::path::Path;
 use std::ptr::null_mut;
 use std::{ffi::CString, marker::PhantomData};
 
 use crate::errors::*;
-use crate::vector::feature::{FeatureIterator, OwnedFeatureIterator};
+use crate::vector::feature::{
+    FeatureIterator, IntersectingFeatureIterator, IntoFeatureIterator, OwnedFeatureIterator,
+    TransformedFeatureIterator,
+};
 
 /// Layer capabilities
 #[allow(clippy::upper_case_acronyms)]
@@ -55,6 +68,16 @@ pub enum LayerCaps {
     OLCMeasuredGeometries,
     /// Layer capability for a specialized implementation to ArrowArrayStream
     OLCFastGetArrowStream,
+    /// Layer capability for a specialized implementation to write Arrow batches
+    OLCFastWriteArrowBatch,
+    /// Layer capability for geometries with Z values
+    OLCZGeometries,
+    /// Layer capability for renaming
+    OLCRename,
+    /// Layer capability for feature upsert
+    OLCUpsertFeature,
+    /// Layer capability for partial feature update
+    OLCUpdateFeature,
 }
 
 // Manage conversion to Gdal values
@@ -80,6 +103,64 @@ impl LayerCaps {
             Self::OLCCurveGeometries => "CurveGeometries",
             Self::OLCMeasuredGeometries => "MeasuredGeometries",
             Self::OLCFastGetArrowStream => "FastGetArrowStream",
+            Self::OLCFastWriteArrowBatch => "FastWriteArrowBatch",
+            Self::OLCZGeometries => "ZGeometries",
+            Self::OLCRename => "Rename",
+            Self::OLCUpsertFeature => "UpsertFeature",
+            Self::OLCUpdateFeature => "UpdateFeature",
+        })
+        .unwrap()
+    }
+}
+
+/// Vector dataset capabilities
+#[allow(clippy::upper_case_acronyms)]
+pub enum DatasetCaps {
+    /// Dataset capability for layer creation
+    ODsCCreateLayer,
+    /// Dataset capability for layer deletion
+    ODsCDeleteLayer,
+    /// Dataset capability for geometry field creation after layer creation
+    ODsCCreateGeomFieldAfterCreateLayer,
+    /// Dataset capability for curve geometries support
+    ODsCCurveGeometries,
+    /// Dataset capability for transactions
+    ODsCTransactions,
+    /// Dataset capability for emulated transactions
+    ODsCEmulatedTransactions,
+    /// Dataset capability for measured geometries support
+    ODsCMeasuredGeometries,
+    /// Dataset capability for geometries with Z values
+    ODsCZGeometries,
+    /// Dataset capability for reading features from layers in random order
+    ODsCRandomLayerRead,
+    /// Dataset capability for writing features to layers in random order
+    ODsCRandomLayerWrite,
+    /// Dataset capability for field domain creation
+    ODsCAddFieldDomain,
+    /// Dataset capability for field domain deletion
+    ODsCDeleteFieldDomain,
+    /// Dataset capability for field domain update
+    ODsCUpdateFieldDomain,
+}
+
+// Manage conversion to Gdal values
+impl DatasetCaps {
+    fn into_cstring(self) -> CString {
+        CString::new(match self {
+            Self::ODsCCreateLayer => "CreateLayer",
+            Self::ODsCDeleteLayer => "DeleteLayer",
+            Self::ODsCCreateGeomFieldAfterCreateLayer => "CreateGeomFieldAfterCreateLayer",
+            Self::ODsCCurveGeometries => "CurveGeometries",
+            Self::ODsCTransactions => "Transactions",
+            Self::ODsCEmulatedTransactions => "EmulatedTransactions",
+            Self::ODsCMeasuredGeometries => "MeasuredGeometries",
+            Self::ODsCZGeometries => "ZGeometries",
+            Self::ODsCRandomLayerRead => "RandomLayerRead",
+            Self::ODsCRandomLayerWrite => "RandomLayerWrite",
+            Self::ODsCAddFieldDomain => "AddFieldDomain",
+            Self::ODsCDeleteFieldDomain => "DeleteFieldDomain",
+            Self::ODsCUpdateFieldDomain => "UpdateFieldDomain",
         })
         .unwrap()
     }
@@ -203,6 +284,19 @@ impl OwnedLayer {
         OwnedFeatureIterator::_with_layer(self)
     }
 
+    /// Returns an iterator over the features in this layer, as
+    /// [`OwnedFeature`](crate::vector::OwnedFeature)s, consuming
+    /// this layer.
+    ///
+    /// Unlike [`owned_features`](Self::owned_features), the iterator and its features can
+    /// be sent to other threads, e.g. to process the features in a pipeline.
+    ///
+    /// **Note.** This method resets the current index to the beginning before iteration.
+    pub fn into_features(mut self) -> IntoFeatureIterator {
+        self.reset_feature_reading();
+        IntoFeatureIterator::_with_layer(self)
+    }
+
     /// Returns the `Dataset` this layer belongs to and consumes this layer.
     pub fn into_dataset(self) -> Dataset {
         self._dataset
@@ -244,6 +338,30 @@ pub trait LayerAccess: Sized {
         }
     }
 
+    /// Returns the feature with the given feature id `fid`.
+    ///
+    /// Unlike [`feature`](Self::feature), this distinguishes a missing feature, reported as an
+    /// [`OgrError`](GdalError::OgrError) with `NON_EXISTING_FEATURE`, from a failure of the
+    /// driver to read it.
+    ///
+    /// See: [OGR_L_GetFeature](https://gdal.org/api/vector_c_api.html#_CPPv416OGR_L_GetFeature9OGRLayerH7GIntBig)
+    fn try_feature(&self, fid: u64) -> Result<Feature> {
+        let fid = i64::try_from(fid)?;
+        unsafe { gdal_sys::CPLErrorReset() };
+        let c_feature = unsafe { gdal_sys::OGR_L_GetFeature(self.c_layer(), fid) };
+        if c_feature.is_null() {
+            let cpl_err = unsafe { gdal_sys::CPLGetLastErrorType() };
+            if cpl_err == CPLErr::CE_Failure || cpl_err == CPLErr::CE_Fatal {
+                return Err(_last_cpl_err(cpl_err));
+            }
+            return Err(GdalError::OgrError {
+                err: OGRErr::NON_EXISTING_FEATURE,
+                method_name: "OGR_L_GetFeature",
+            });
+        }
+        Ok(unsafe { Feature::from_c_feature(self.defn(), c_feature) })
+    }
+
     /// Returns iterator over the features in this layer.
     ///
     /// **Note.** This method resets the current index to
@@ -254,11 +372,357 @@ pub trait LayerAccess: Sized {
         FeatureIterator::_with_layer(self)
     }
 
+    /// Move the read cursor so that the next feature read is the one at `index` (0-based).
+    ///
+    /// Drivers advertising [`LayerCaps::OLCFastSetNextByIndex`] can do this without reading
+    /// the preceding features, which makes it possible to resume an interrupted scan.
+    /// The index counts features matching the current spatial and attribute filters.
+    ///
+    /// See: [OGR_L_SetNextByIndex](https://gdal.org/api/vector_c_api.html#_CPPv420OGR_L_SetNextByIndex9OGRLayerH7GIntBig)
+    fn set_next_by_index(&mut self, index: u64) -> Result<()> {
+        let rv = unsafe { gdal_sys::OGR_L_SetNextByIndex(self.c_layer(), index.try_into()?) };
+        if rv != OGRErr::OGRERR_NONE {
+            return Err(GdalError::OgrError {
+                err: rv,
+                method_name: "OGR_L_SetNextByIndex",
+            });
+        }
+        Ok(())
+    }
+
+    /// Returns iterator over the features in this layer, starting at the feature at `index` (0-based).
+    ///
+    /// Like [`features`](Self::features), but positions the reading cursor with
+    /// [`set_next_by_index`](Self::set_next_by_index) after resetting it.
+    fn features_from(&mut self, index: u64) -> Result<FeatureIterator> {
+        self.reset_feature_reading();
+        self.set_next_by_index(index)?;
+        Ok(FeatureIterator::_with_layer(self)._skipping(index.try_into()?))
+    }
+
+    /// Returns iterator over the features in this layer, with their geometries transformed
+    /// into `spatial_ref`.
+    ///
+    /// A single coordinate transformation is created for all the features. Features whose
+    /// geometry can't be transformed, e.g. because some points are outside the domain of the
+    /// projection, are returned as errors.
+    ///
+    /// Only the default geometry field is transformed. The layer must have a spatial
+    /// reference.
+    ///
+    /// # Example
+    ///
+    /// ```rust, no_run
+    /// # fn main() -> gdal::errors::Result<()> {
+    /// use gdal::spatial_ref::SpatialRef;
+    /// use gdal::vector::LayerAccess;
+    /// use gdal::Dataset;
+    /// let dataset = Dataset::open("fixtures/roads.geojson")?;
+    /// let mut layer = dataset.layer(0)?;
+    /// let web_mercator = SpatialRef::from_epsg(3857)?;
+    /// for feature in layer.features_in(&web_mercator)? {
+    ///     let feature = feature?;
+    ///     println!("{:?}", feature.geometry());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn features_in(&mut self, spatial_ref: &SpatialRef) -> Result<TransformedFeatureIterator> {
+        let source = self.spatial_ref().ok_or_else(|| {
+            GdalError::BadArgument("the layer doesn't have a spatial reference".to_string())
+        })?;
+        let transform = CoordTransform::new(&source, spatial_ref)?;
+        Ok(TransformedFeatureIterator::new(self.features(), transform))
+    }
+
+    /// Returns an iterator over the features of this layer whose geometry intersects
+    /// `geometry`, e.g. the polygons containing a point.
+    ///
+    /// The spatial filter of the layer is set to `geometry`, which drivers only apply to the
+    /// bounding boxes of the features, and the remaining features are then tested exactly
+    /// with [`Geometry::intersects`]. This requires GEOS, otherwise only the bounding boxes
+    /// are compared.
+    ///
+    /// Any previous spatial filter is replaced, and cleared once the iterator is dropped.
+    ///
+    /// # Example
+    ///
+    /// ```rust, no_run
+    /// # fn main() -> gdal::errors::Result<()> {
+    /// use gdal::vector::{Geometry, LayerAccess};
+    /// use gdal::Dataset;
+    /// let dataset = Dataset::open("fixtures/roads.geojson")?;
+    /// let mut layer = dataset.layer(0)?;
+    /// let area = Geometry::from_wkt("POLYGON ((26.101 44.430, 26.102 44.430, 26.102 44.431, 26.101 44.430))")?;
+    /// for feature in layer.features_intersecting(&area) {
+    ///     println!("{:?}", feature.fid());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn features_intersecting<'a>(
+        &'a mut self,
+        geometry: &'a Geometry,
+    ) -> IntersectingFeatureIterator<'a> {
+        self.set_spatial_filter(geometry);
+        let c_layer = unsafe { self.c_layer() };
+        IntersectingFeatureIterator::new(self.features(), geometry, c_layer)
+    }
+
+    /// Write to `result` the parts of the features of this layer covered by those of `method`, with
+    /// the fields of both layers.
+    ///
+    /// See [`OGR_L_Intersection`](https://gdal.org/api/vector_c_api.html#_CPPv418OGR_L_Intersection9OGRLayerH9OGRLayerH9OGRLayerHPPc16GDALProgressFuncPv)
+    /// and [`LayerAlgebraOptions`]. The reading of both layers is reset.
+    fn intersection<M: LayerAccess, R: LayerAccess>(
+        &mut self,
+        method: &mut M,
+        result: &mut R,
+        options: &LayerAlgebraOptions,
+    ) -> Result<()> {
+        layer_algebra(
+            gdal_sys::OGR_L_Intersection,
+            "OGR_L_Intersection",
+            self,
+            method,
+            result,
+            options,
+        )
+    }
+
+    /// Write to `result` the union of the features of this layer and `method`, split where they
+    /// overlap, with the fields of both layers.
+    ///
+    /// See [`OGR_L_Union`](https://gdal.org/api/vector_c_api.html#_CPPv411OGR_L_Union9OGRLayerH9OGRLayerH9OGRLayerHPPc16GDALProgressFuncPv)
+    /// and [`LayerAlgebraOptions`]. The reading of both layers is reset.
+    fn union<M: LayerAccess, R: LayerAccess>(
+        &mut self,
+        method: &mut M,
+        result: &mut R,
+        options: &LayerAlgebraOptions,
+    ) -> Result<()> {
+        layer_algebra(
+            gdal_sys::OGR_L_Union,
+            "OGR_L_Union",
+            self,
+            method,
+            result,
+            options,
+        )
+    }
+
+    /// Write to `result` the parts of the features of this layer and `method` not covered by the
+    /// other layer, with the fields of both layers.
+    ///
+    /// See [`OGR_L_SymDifference`](https://gdal.org/api/vector_c_api.html#_CPPv419OGR_L_SymDifference9OGRLayerH9OGRLayerH9OGRLayerHPPc16GDALProgressFuncPv)
+    /// and [`LayerAlgebraOptions`]. The reading of both layers is reset.
+    fn sym_difference<M: LayerAccess, R: LayerAccess>(
+        &mut self,
+        method: &mut M,
+        result: &mut R,
+        options: &LayerAlgebraOptions,
+    ) -> Result<()> {
+        layer_algebra(
+            gdal_sys::OGR_L_SymDifference,
+            "OGR_L_SymDifference",
+            self,
+            method,
+            result,
+            options,
+        )
+    }
+
+    /// Write to `result` the features of this layer, split where they are covered by those of
+    /// `method` and with the fields of both layers.
+    ///
+    /// See [`OGR_L_Identity`](https://gdal.org/api/vector_c_api.html#_CPPv414OGR_L_Identity9OGRLayerH9OGRLayerH9OGRLayerHPPc16GDALProgressFuncPv)
+    /// and [`LayerAlgebraOptions`]. The reading of both layers is reset.
+    fn identity<M: LayerAccess, R: LayerAccess>(
+        &mut self,
+        method: &mut M,
+        result: &mut R,
+        options: &LayerAlgebraOptions,
+    ) -> Result<()> {
+        layer_algebra(
+            gdal_sys::OGR_L_Identity,
+            "OGR_L_Identity",
+            self,
+            method,
+            result,
+            options,
+        )
+    }
+
+    /// Write to `result` the features of this layer, with their parts covered by `method` replaced
+    /// by the features of `method`.
+    ///
+    /// See [`OGR_L_Update`](https://gdal.org/api/vector_c_api.html#_CPPv412OGR_L_Update9OGRLayerH9OGRLayerH9OGRLayerHPPc16GDALProgressFuncPv)
+    /// and [`LayerAlgebraOptions`]. The reading of both layers is reset.
+    fn update<M: LayerAccess, R: LayerAccess>(
+        &mut self,
+        method: &mut M,
+        result: &mut R,
+        options: &LayerAlgebraOptions,
+    ) -> Result<()> {
+        layer_algebra(
+            gdal_sys::OGR_L_Update,
+            "OGR_L_Update",
+            self,
+            method,
+            result,
+            options,
+        )
+    }
+
+    /// Write to `result` the parts of the features of this layer covered by those of `method`, with
+    /// the fields of this layer only.
+    ///
+    /// See [`OGR_L_Clip`](https://gdal.org/api/vector_c_api.html#_CPPv410OGR_L_Clip9OGRLayerH9OGRLayerH9OGRLayerHPPc16GDALProgressFuncPv)
+    /// and [`LayerAlgebraOptions`]. The reading of both layers is reset.
+    fn clip<M: LayerAccess, R: LayerAccess>(
+        &mut self,
+        method: &mut M,
+        result: &mut R,
+        options: &LayerAlgebraOptions,
+    ) -> Result<()> {
+        layer_algebra(
+            gdal_sys::OGR_L_Clip,
+            "OGR_L_Clip",
+            self,
+            method,
+            result,
+            options,
+        )
+    }
+
+    /// Write to `result` the parts of the features of this layer not covered by those of `method`.
+    ///
+    /// See [`OGR_L_Erase`](https://gdal.org/api/vector_c_api.html#_CPPv411OGR_L_Erase9OGRLayerH9OGRLayerH9OGRLayerHPPc16GDALProgressFuncPv)
+    /// and [`LayerAlgebraOptions`]. The reading of both layers is reset.
+    fn erase<M: LayerAccess, R: LayerAccess>(
+        &mut self,
+        method: &mut M,
+        result: &mut R,
+        options: &LayerAlgebraOptions,
+    ) -> Result<()> {
+        layer_algebra(
+            gdal_sys::OGR_L_Erase,
+            "OGR_L_Erase",
+            self,
+            method,
+            result,
+            options,
+        )
+    }
+
     /// Set a feature on this layer layer.
     ///
     /// See: [SetFeature](https://gdal.org/doxygen/classOGRLayer.html#a681139bfd585b74d7218e51a32144283)
     fn set_feature(&self, feature: Feature) -> Result<()> {
-        unsafe { gdal_sys::OGR_L_SetFeature(self.c_layer(), feature.c_feature()) };
+        let rv = unsafe { gdal_sys::OGR_L_SetFeature(self.c_layer(), feature.c_feature()) };
+        if rv != OGRErr::OGRERR_NONE {
+            return Err(GdalError::OgrError {
+                err: rv,
+                method_name: "OGR_L_SetFeature",
+            });
+        }
+        Ok(())
+    }
+
+    /// Rewrite an existing feature of this layer, or create it if there is no feature with
+    /// the same FID.
+    ///
+    /// Not all drivers support this, see [`LayerCaps::OLCUpsertFeature`].
+    ///
+    /// See: [OGR_L_UpsertFeature](https://gdal.org/api/vector_c_api.html#_CPPv419OGR_L_UpsertFeature9OGRLayerH10OGRFeatureH)
+    #[cfg(any(major_ge_4, all(major_is_3, minor_ge_6)))]
+    fn upsert_feature(&self, feature: &Feature) -> Result<()> {
+        let rv = unsafe { gdal_sys::OGR_L_UpsertFeature(self.c_layer(), feature.c_feature()) };
+        if rv != OGRErr::OGRERR_NONE {
+            return Err(GdalError::OgrError {
+                err: rv,
+                method_name: "OGR_L_UpsertFeature",
+            });
+        }
+        Ok(())
+    }
+
+    /// Update some of the fields of an existing feature of this layer, leaving the others
+    /// untouched.
+    ///
+    /// Only the fields named in `field_names`, and the geometries if `update_geometries` is
+    /// `true`, are written. The feature is matched by its FID.
+    ///
+    /// See: [OGR_L_UpdateFeature](https://gdal.org/api/vector_c_api.html#_CPPv419OGR_L_UpdateFeature9OGRLayerH10OGRFeatureHiPKiiPKib)
+    #[cfg(any(major_ge_4, all(major_is_3, minor_ge_7)))]
+    fn update_feature(
+        &self,
+        feature: &Feature,
+        field_names: &[&str],
+        update_geometries: bool,
+    ) -> Result<()> {
+        let field_indices = field_names
+            .iter()
+            .map(|name| feature.field_idx_from_name(name))
+            .collect::<Result<Vec<_>>>()?;
+        let geom_field_indices: Vec<libc::c_int> = if update_geometries {
+            let count = unsafe { gdal_sys::OGR_F_GetGeomFieldCount(feature.c_feature()) };
+            (0..count).collect()
+        } else {
+            Vec::new()
+        };
+        let rv = unsafe {
+            gdal_sys::OGR_L_UpdateFeature(
+                self.c_layer(),
+                feature.c_feature(),
+                field_indices.len() as libc::c_int,
+                field_indices.as_ptr(),
+                geom_field_indices.len() as libc::c_int,
+                geom_field_indices.as_ptr(),
+                false,
+            )
+        };
+        if rv != OGRErr::OGRERR_NONE {
+            return Err(GdalError::OgrError {
+                err: rv,
+                method_name: "OGR_L_UpdateFeature",
+            });
+        }
+        Ok(())
+    }
+
+    /// Rename this layer.
+    ///
+    /// Not all drivers support this, see [`LayerCaps::OLCRename`].
+    ///
+    /// See: [OGR_L_Rename](https://gdal.org/api/vector_c_api.html#_CPPv412OGR_L_Rename9OGRLayerHPKc)
+    #[cfg(any(major_ge_4, all(major_is_3, minor_ge_5)))]
+    fn rename(&mut self, new_name: &str) -> Result<()> {
+        let c_name = CString::new(new_name)?;
+        let rv = unsafe { gdal_sys::OGR_L_Rename(self.c_layer(), c_name.as_ptr()) };
+        if rv != OGRErr::OGRERR_NONE {
+            return Err(GdalError::OgrError {
+                err: rv,
+                method_name: "OGR_L_Rename",
+            });
+        }
+        Ok(())
+    }
+
+    /// Delete the feature with the given FID from this layer.
+    ///
+    /// Returns an [`OgrError`](GdalError::OgrError) with `NON_EXISTING_FEATURE` if
+    /// there is no such feature.
+    ///
+    /// See: [OGR_L_DeleteFeature](https://gdal.org/api/vector_c_api.html#_CPPv419OGR_L_DeleteFeature9OGRLayerH7GIntBig)
+    fn delete_feature(&self, fid: u64) -> Result<()> {
+        let rv = unsafe { gdal_sys::OGR_L_DeleteFeature(self.c_layer(), fid as i64) };
+        if rv != OGRErr::OGRERR_NONE {
+            return Err(GdalError::OgrError {
+                err: rv,
+                method_name: "OGR_L_DeleteFeature",
+            });
+        }
         Ok(())
     }
 
@@ -274,6 +738,62 @@ pub trait LayerAccess: Sized {
         unsafe { gdal_sys::OGR_L_SetSpatialFilterRect(self.c_layer(), min_x, min_y, max_x, max_y) };
     }
 
+    /// Set a spatial filter on the geometry field at index `geom_field_index` of this layer.
+    ///
+    /// Unlike [`set_spatial_filter`](Self::set_spatial_filter), which applies to the default
+    /// geometry field, this is useful for layers having several geometry fields.
+    ///
+    /// See: [OGR_L_SetSpatialFilterEx](https://gdal.org/api/vector_c_api.html#_CPPv424OGR_L_SetSpatialFilterEx9OGRLayerHi12OGRGeometryH)
+    fn set_spatial_filter_on_field(
+        &mut self,
+        geom_field_index: usize,
+        geometry: &Geometry,
+    ) -> Result<()> {
+        check_geom_field_index(self, geom_field_index, "set_spatial_filter_on_field")?;
+        unsafe {
+            gdal_sys::OGR_L_SetSpatialFilterEx(
+                self.c_layer(),
+                geom_field_index as libc::c_int,
+                geometry.c_geometry(),
+            )
+        };
+        Ok(())
+    }
+
+    /// Set a spatial rectangle filter on the geometry field at index `geom_field_index` of this
+    /// layer by specifying the bounds of a rectangle.
+    fn set_spatial_filter_rect_on_field(
+        &mut self,
+        geom_field_index: usize,
+        min_x: f64,
+        min_y: f64,
+        max_x: f64,
+        max_y: f64,
+    ) -> Result<()> {
+        check_geom_field_index(self, geom_field_index, "set_spatial_filter_rect_on_field")?;
+        unsafe {
+            gdal_sys::OGR_L_SetSpatialFilterRectEx(
+                self.c_layer(),
+                geom_field_index as libc::c_int,
+                min_x,
+                min_y,
+                max_x,
+                max_y,
+            )
+        };
+        Ok(())
+    }
+
+    /// Returns a copy of the spatial filter set on this layer, if any.
+    fn spatial_filter(&self) -> Option<Geometry> {
+        let c_geom = unsafe { gdal_sys::OGR_L_GetSpatialFilter(self.c_layer()) };
+        if c_geom.is_null() {
+            return None;
+        }
+        let c_clone = unsafe { gdal_sys::OGR_G_Clone(c_geom) };
+        Some(unsafe { Geometry::with_c_geometry(c_clone, true) })
+    }
+
     /// Clear spatial filters set on this layer.
     fn clear_spatial_filter(&mut self) {
         unsafe { gdal_sys::OGR_L_SetSpatialFilter(self.c_layer(), null_mut()) };
@@ -285,6 +805,23 @@ pub trait LayerAccess: Sized {
         _string(rv)
     }
 
+    /// Get the name of the FID column of this layer, or an empty string if the FIDs aren't
+    /// stored in a column.
+    fn fid_column(&self) -> String {
+        let rv = unsafe { gdal_sys::OGR_L_GetFIDColumn(self.c_layer()) };
+        _string(rv)
+    }
+
+    /// Get the name of the geometry column of this layer, or an empty string if the driver
+    /// doesn't name it.
+    fn geometry_column(&self) -> String {
+        let rv = unsafe { gdal_sys::OGR_L_GetGeometryColumn(self.c_layer()) };
+        _string(rv)
+    }
+
+    /// Returns whether this layer supports `capability`.
+    ///
+    /// See: [OGR_L_TestCapability](https://gdal.org/api/vector_c_api.html#_CPPv420OGR_L_TestCapability9OGRLayerHPKc)
     fn has_capability(&self, capability: LayerCaps) -> bool {
         unsafe {
             gdal_sys::OGR_L_TestCapability(self.c_layer(), capability.into_cstring().as_ptr()) == 1
@@ -298,6 +835,153 @@ pub trait LayerAccess: Sized {
         }
         Ok(())
     }
+
+    /// Delete the field at index `field_index` from this layer.
+    ///
+    /// Not all drivers support this, see [`LayerCaps::OLCDeleteField`].
+    ///
+    /// See: [OGR_L_DeleteField](https://gdal.org/api/vector_c_api.html#_CPPv417OGR_L_DeleteField9OGRLayerHi)
+    fn delete_field(&mut self, field_index: usize) -> Result<()> {
+        let rv = unsafe { gdal_sys::OGR_L_DeleteField(self.c_layer(), field_index.try_into()?) };
+        if rv != OGRErr::OGRERR_NONE {
+            return Err(GdalError::OgrError {
+                err: rv,
+                method_name: "OGR_L_DeleteField",
+            });
+        }
+        Ok(())
+    }
+
+    /// Change the definition of the field at index `field_index` of this layer.
+    ///
+    /// Only the parts of `new_defn` selected by `flags` are applied, e.g.
+    /// [`ALTER_NAME_FLAG`](AlterFieldFlags::ALTER_NAME_FLAG) to rename the field. Not all
+    /// drivers support this, and some only support some of the changes, see
+    /// [`LayerCaps::OLCAlterFieldDefn`].
+    ///
+    /// # Example
+    ///
+    /// ```rust, no_run
+    /// use gdal::vector::{AlterFieldFlags, FieldDefn, LayerAccess, OGRFieldType};
+    /// use gdal::{Dataset, DatasetOptions, GdalOpenFlags};
+    /// # fn main() -> gdal::errors::Result<()> {
+    /// let options = DatasetOptions {
+    ///     open_flags: GdalOpenFlags::GDAL_OF_UPDATE,
+    ///     ..Default::default()
+    /// };
+    /// let dataset = Dataset::open_ex("stations.gpkg", options)?;
+    /// let mut layer = dataset.layer_by_name("stations")?;
+    /// let index = layer.defn().field_index("nom")?;
+    /// let new_defn = FieldDefn::new("name", OGRFieldType::OFTString)?;
+    /// layer.alter_field_defn(index, &new_defn, AlterFieldFlags::ALTER_NAME_FLAG)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// See: [OGR_L_AlterFieldDefn](https://gdal.org/api/vector_c_api.html#_CPPv420OGR_L_AlterFieldDefn9OGRLayerHi12OGRFieldDefnHi)
+    fn alter_field_defn(
+        &mut self,
+        field_index: usize,
+        new_defn: &FieldDefn,
+        flags: AlterFieldFlags,
+    ) -> Result<()> {
+        let rv = unsafe {
+            gdal_sys::OGR_L_AlterFieldDefn(
+                self.c_layer(),
+                field_index.try_into()?,
+                new_defn.c_obj,
+                flags.bits(),
+            )
+        };
+        if rv != OGRErr::OGRERR_NONE {
+            return Err(GdalError::OgrError {
+                err: rv,
+                method_name: "OGR_L_AlterFieldDefn",
+            });
+        }
+        Ok(())
+    }
+
+    /// Change the definition of the geometry field at index `geom_field_index` of this layer.
+    ///
+    /// Only the parts of `new_defn` selected by `flags` are applied. Not all drivers support
+    /// this, and some only support some of the changes.
+    ///
+    /// See: [OGR_L_AlterGeomFieldDefn](https://gdal.org/api/vector_c_api.html#_CPPv424OGR_L_AlterGeomFieldDefn9OGRLayerHi17OGRGeomFieldDefnHi)
+    #[cfg(any(major_ge_4, all(major_is_3, minor_ge_6)))]
+    fn alter_geom_field_defn(
+        &mut self,
+        geom_field_index: usize,
+        new_defn: &GeomFieldDefn,
+        flags: crate::vector::AlterGeomFieldFlags,
+    ) -> Result<()> {
+        let rv = unsafe {
+            gdal_sys::OGR_L_AlterGeomFieldDefn(
+                self.c_layer(),
+                geom_field_index.try_into()?,
+                new_defn.c_obj,
+                flags.bits(),
+            )
+        };
+        if rv != OGRErr::OGRERR_NONE {
+            return Err(GdalError::OgrError {
+                err: rv,
+                method_name: "OGR_L_AlterGeomFieldDefn",
+            });
+        }
+        Ok(())
+    }
+
+    /// Reorder all the fields of this layer.
+    ///
+    /// `new_order` must be a permutation of the field indices, where `new_order[i]` is the
+    /// index of the field that is moved to position `i`. Not all drivers support this, see
+    /// [`LayerCaps::OLCReorderFields`].
+    ///
+    /// See: [OGR_L_ReorderFields](https://gdal.org/api/vector_c_api.html#_CPPv419OGR_L_ReorderFields9OGRLayerHPi)
+    fn reorder_fields(&mut self, new_order: &[usize]) -> Result<()> {
+        let field_count = unsafe { gdal_sys::OGR_FD_GetFieldCount(self.defn().c_defn()) };
+        if new_order.len() != field_count as usize {
+            return Err(GdalError::BadArgument(format!(
+                "new_order has {} items, but the layer has {} fields",
+                new_order.len(),
+                field_count
+            )));
+        }
+        let mut new_order = new_order
+            .iter()
+            .map(|&i| c_int::try_from(i))
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        let rv = unsafe { gdal_sys::OGR_L_ReorderFields(self.c_layer(), new_order.as_mut_ptr()) };
+        if rv != OGRErr::OGRERR_NONE {
+            return Err(GdalError::OgrError {
+                err: rv,
+                method_name: "OGR_L_ReorderFields",
+            });
+        }
+        Ok(())
+    }
+
+    /// Move the field at index `old_index` of this layer to `new_index`, shifting the fields
+    /// in between.
+    ///
+    /// See: [OGR_L_ReorderField](https://gdal.org/api/vector_c_api.html#_CPPv418OGR_L_ReorderField9OGRLayerHii)
+    fn reorder_field(&mut self, old_index: usize, new_index: usize) -> Result<()> {
+        let rv = unsafe {
+            gdal_sys::OGR_L_ReorderField(
+                self.c_layer(),
+                old_index.try_into()?,
+                new_index.try_into()?,
+            )
+        };
+        if rv != OGRErr::OGRERR_NONE {
+            return Err(GdalError::OgrError {
+                err: rv,
+                method_name: "OGR_L_ReorderField",
+            });
+        }
+        Ok(())
+    }
     fn create_feature(&mut self, geometry: Geometry) -> Result<()> {
         let feature = Feature::new(self.defn())?;
 
@@ -411,6 +1095,71 @@ pub trait LayerAccess: Sized {
         }
     }
 
+    /// Returns the extent of the geometry field at index `geom_field_index` of this layer.
+    ///
+    /// If `force` is `false`, the extent is only returned if the driver can compute it
+    /// efficiently, e.g. from a header or an index, and [`None`] is returned otherwise. If
+    /// `force` is `true`, the layer may be scanned, and [`None`] is returned if no meaningful
+    /// extent could be collected, e.g. because the layer has no geometries.
+    ///
+    /// See: [OGR_L_GetExtentEx](https://gdal.org/api/vector_c_api.html#_CPPv417OGR_L_GetExtentEx9OGRLayerHiP11OGREnvelopei)
+    fn get_extent_of_field(
+        &self,
+        geom_field_index: usize,
+        force: bool,
+    ) -> Result<Option<Envelope>> {
+        check_geom_field_index(self, geom_field_index, "get_extent_of_field")?;
+        let mut envelope = MaybeUninit::uninit();
+        let rv = unsafe {
+            gdal_sys::OGR_L_GetExtentEx(
+                self.c_layer(),
+                geom_field_index as libc::c_int,
+                envelope.as_mut_ptr(),
+                libc::c_int::from(force),
+            )
+        };
+        match rv {
+            OGRErr::OGRERR_NONE => Ok(Some(unsafe { envelope.assume_init() })),
+            OGRErr::OGRERR_FAILURE => Ok(None),
+            _ => Err(GdalError::OgrError {
+                err: rv,
+                method_name: "OGR_L_GetExtentEx",
+            }),
+        }
+    }
+
+    /// Returns the 3D extent of the geometry field at index `geom_field_index` of this layer.
+    ///
+    /// `force` has the same meaning as for [`get_extent_of_field`](Self::get_extent_of_field).
+    /// For layers without Z values, the Z bounds of the returned envelope are infinite.
+    ///
+    /// See: [OGR_L_GetExtent3D](https://gdal.org/api/vector_c_api.html#_CPPv417OGR_L_GetExtent3D9OGRLayerHiP13OGREnvelope3Di)
+    #[cfg(any(major_ge_4, all(major_is_3, minor_ge_9)))]
+    fn get_extent_3d(
+        &self,
+        geom_field_index: usize,
+        force: bool,
+    ) -> Result<Option<crate::vector::Envelope3D>> {
+        check_geom_field_index(self, geom_field_index, "get_extent_3d")?;
+        let mut envelope = MaybeUninit::uninit();
+        let rv = unsafe {
+            gdal_sys::OGR_L_GetExtent3D(
+                self.c_layer(),
+                geom_field_index as libc::c_int,
+                envelope.as_mut_ptr(),
+                libc::c_int::from(force),
+            )
+        };
+        match rv {
+            OGRErr::OGRERR_NONE => Ok(Some(unsafe { envelope.assume_init() })),
+            OGRErr::OGRERR_FAILURE => Ok(None),
+            _ => Err(GdalError::OgrError {
+                err: rv,
+                method_name: "OGR_L_GetExtent3D",
+            }),
+        }
+    }
+
     /// Get the spatial reference system for this layer.
     ///
     /// Returns `Some(SpatialRef)`, or `None` if one isn't defined.
@@ -452,6 +1201,32 @@ pub trait LayerAccess: Sized {
         Ok(())
     }
 
+    /// Skip reading the fields called `fields`, which are left unset in the returned features.
+    ///
+    /// This saves fetching and decoding expensive fields, such as large blobs or strings,
+    /// when iterating the features. `OGR_GEOMETRY` and `OGR_STYLE` can be used to skip the
+    /// geometry and the style string. The previously ignored fields are replaced.
+    ///
+    /// Not all drivers support it, see [`LayerCaps::OLCIgnoreFields`].
+    fn set_ignored_fields(&mut self, fields: &[&str]) -> Result<()> {
+        let c_fields = fields.iter().copied().collect::<CslStringList>();
+        let rv = unsafe {
+            gdal_sys::OGR_L_SetIgnoredFields(self.c_layer(), c_fields.as_ptr() as *mut *const _)
+        };
+        if rv != OGRErr::OGRERR_NONE {
+            return Err(GdalError::OgrError {
+                err: rv,
+                method_name: "OGR_L_SetIgnoredFields",
+            });
+        }
+        Ok(())
+    }
+
+    /// Read all the fields again, after [`set_ignored_fields`](Self::set_ignored_fields).
+    fn clear_ignored_fields(&mut self) {
+        unsafe { gdal_sys::OGR_L_SetIgnoredFields(self.c_layer(), null_mut()) };
+    }
+
     /// Clear the attribute filter set on this layer
     ///
     /// From the GDAL docs: Note that installing a query string will generally result in resetting the current reading position
@@ -503,6 +1278,134 @@ pub trait LayerAccess: Sized {
 
         Ok(())
     }
+
+    /// Read the features of this layer as batches of columnar [Arrow](https://arrow.apache.org/)
+    /// data, which is much faster than iterating over them for large layers.
+    ///
+    /// The `options` are those of [`read_arrow_stream`](Self::read_arrow_stream), e.g.
+    /// `MAX_FEATURES_IN_BATCH`. The geometries are encoded as WKB.
+    ///
+    /// This API is new as of GDAL 3.6.
+    ///
+    /// # Example
+    ///
+    /// ```rust, no_run
+    /// use gdal::cpl::CslStringList;
+    /// use gdal::vector::LayerAccess;
+    /// use gdal::Dataset;
+    /// # fn main() -> gdal::errors::Result<()> {
+    /// let dataset = Dataset::open("fixtures/roads.geojson")?;
+    /// let mut layer = dataset.layer(0)?;
+    /// let mut rows = 0;
+    /// for batch in layer.read_arrow(&CslStringList::new())? {
+    ///     rows += batch?.num_rows();
+    /// }
+    /// assert_eq!(rows, 21);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(all(feature = "arrow", any(major_ge_4, all(major_is_3, minor_ge_6))))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "arrow")))]
+    fn read_arrow(
+        &mut self,
+        options: &crate::cpl::CslStringList,
+    ) -> Result<crate::vector::ArrowStreamReader<'_>> {
+        let mut stream = arrow::ffi_stream::FFI_ArrowArrayStream::empty();
+        // GDAL includes its own definition of the ArrowArrayStream struct, with the same layout
+        let c_stream: *mut gdal_sys::ArrowArrayStream =
+            (&mut stream as *mut arrow::ffi_stream::FFI_ArrowArrayStream).cast();
+        unsafe { self.read_arrow_stream(c_stream, options)? };
+        crate::vector::ArrowStreamReader::new(stream)
+    }
+
+    /// Add a field to this layer from the definition of an Arrow column, e.g. one of the
+    /// [schema](crate::vector::ArrowStreamReader::schema) of a layer read with
+    /// [`read_arrow`](Self::read_arrow).
+    ///
+    /// This API is new as of GDAL 3.8.
+    #[cfg(all(feature = "arrow", any(major_ge_4, all(major_is_3, minor_ge_8))))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "arrow")))]
+    fn create_field_from_arrow(
+        &mut self,
+        field: &arrow::datatypes::Field,
+        options: &crate::cpl::CslStringList,
+    ) -> Result<()> {
+        let c_schema = crate::vector::arrow::field_to_ffi(field)?;
+        let success = unsafe {
+            gdal_sys::OGR_L_CreateFieldFromArrowSchema(
+                self.c_layer(),
+                (&c_schema as *const arrow::ffi::FFI_ArrowSchema).cast(),
+                options.as_ptr(),
+            )
+        };
+        if !success {
+            return Err(GdalError::OgrError {
+                err: OGRErr::OGRERR_FAILURE,
+                method_name: "OGR_L_CreateFieldFromArrowSchema",
+            });
+        }
+        Ok(())
+    }
+
+    /// Write a batch of columnar [Arrow](https://arrow.apache.org/) data to this layer, creating
+    /// one feature per row.
+    ///
+    /// The columns are matched by name to the fields of the layer, which must exist, e.g. created
+    /// with [`create_field_from_arrow`](Self::create_field_from_arrow). The geometries must be
+    /// encoded as WKB.
+    ///
+    /// As defined in the OGR documentation for [`WriteArrowBatch`](https://gdal.org/api/ogrlayer_cpp.html#_CPPv4N8OGRLayer15WriteArrowBatchEPK11ArrowSchemaP10ArrowArray12CSLConstList),
+    /// the options include:
+    ///
+    /// * `FID=name`. Name of the FID column in the batch. Defaults to the FID column of the layer.
+    /// * `GEOMETRY_NAME=name`. Name of the geometry column in the batch. Defaults to the geometry
+    ///   column of the layer, or `wkb_geometry`.
+    /// * `IF_FID_NOT_PRESERVED=NOTHING/ERROR/WARNING`. What to do if the driver can't keep the
+    ///   FIDs of the batch.
+    ///
+    /// This API is new as of GDAL 3.8.
+    #[cfg(all(feature = "arrow", any(major_ge_4, all(major_is_3, minor_ge_8))))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "arrow")))]
+    fn write_arrow(
+        &mut self,
+        batch: &arrow::record_batch::RecordBatch,
+        options: &crate::cpl::CslStringList,
+    ) -> Result<()> {
+        let (mut c_array, c_schema) = crate::vector::arrow::batch_to_ffi(batch)?;
+        // GDAL takes ownership of the array if it succeeds, otherwise it's released on drop
+        let success = unsafe {
+            gdal_sys::OGR_L_WriteArrowBatch(
+                self.c_layer(),
+                (&c_schema as *const arrow::ffi::FFI_ArrowSchema).cast(),
+                (&mut c_array as *mut arrow::ffi::FFI_ArrowArray).cast(),
+                options.as_ptr(),
+            )
+        };
+        if !success {
+            return Err(GdalError::OgrError {
+                err: OGRErr::OGRERR_FAILURE,
+                method_name: "OGR_L_WriteArrowBatch",
+            });
+        }
+        Ok(())
+    }
+}
+
+fn check_geom_field_index<L: LayerAccess>(
+    layer: &L,
+    geom_field_index: usize,
+    method_name: &'static str,
+) -> Result<()> {
+    let count = unsafe {
+        gdal_sys::OGR_FD_GetGeomFieldCount(gdal_sys::OGR_L_GetLayerDefn(layer.c_layer()))
+    };
+    if geom_field_index >= count as usize {
+        return Err(GdalError::InvalidFieldIndex {
+            index: geom_field_index,
+            method_name,
+        });
+    }
+    Ok(())
 }
 
 pub struct LayerIterator<'a> {
@@ -560,27 +1463,166 @@ impl MajorObject for FieldDefn {
     }
 }
 
-impl FieldDefn {
-    pub fn new(name: &str, field_type: OGRFieldType::Type) -> Result<FieldDefn> {
+impl FieldDefn {
+    pub fn new(name: &str, field_type: OGRFieldType::Type) -> Result<FieldDefn> {
+        let c_str = CString::new(name)?;
+        let c_obj = unsafe { gdal_sys::OGR_Fld_Create(c_str.as_ptr(), field_type) };
+        if c_obj.is_null() {
+            return Err(_last_null_pointer_err("OGR_Fld_Create"));
+        };
+        Ok(FieldDefn { c_obj })
+    }
+    pub fn set_width(&self, width: i32) {
+        unsafe { gdal_sys::OGR_Fld_SetWidth(self.c_obj, width as c_int) };
+    }
+    pub fn set_precision(&self, precision: i32) {
+        unsafe { gdal_sys::OGR_Fld_SetPrecision(self.c_obj, precision as c_int) };
+    }
+
+    /// Create a copy of the definition of `field`, e.g. to add it to another layer.
+    ///
+    /// Everything but the field domain is copied, including the sub-type, constraints and
+    /// default value.
+    pub fn from_field(field: &Field) -> Result<FieldDefn> {
+        let c_field_defn = field.c_field_defn();
+        let field_defn = FieldDefn::new(&field.name(), field.field_type())?;
+        unsafe {
+            let c_obj = field_defn.c_obj;
+            gdal_sys::OGR_Fld_SetSubType(c_obj, gdal_sys::OGR_Fld_GetSubType(c_field_defn));
+            gdal_sys::OGR_Fld_SetWidth(c_obj, gdal_sys::OGR_Fld_GetWidth(c_field_defn));
+            gdal_sys::OGR_Fld_SetPrecision(c_obj, gdal_sys::OGR_Fld_GetPrecision(c_field_defn));
+            gdal_sys::OGR_Fld_SetJustify(c_obj, gdal_sys::OGR_Fld_GetJustify(c_field_defn));
+            gdal_sys::OGR_Fld_SetNullable(c_obj, gdal_sys::OGR_Fld_IsNullable(c_field_defn));
+            gdal_sys::OGR_Fld_SetDefault(c_obj, gdal_sys::OGR_Fld_GetDefault(c_field_defn));
+            #[cfg(any(all(major_is_3, minor_ge_2), major_ge_4))]
+            {
+                gdal_sys::OGR_Fld_SetUnique(c_obj, gdal_sys::OGR_Fld_IsUnique(c_field_defn));
+                gdal_sys::OGR_Fld_SetAlternativeName(
+                    c_obj,
+                    gdal_sys::OGR_Fld_GetAlternativeNameRef(c_field_defn),
+                );
+            }
+            #[cfg(any(all(major_ge_3, minor_ge_8), major_ge_4))]
+            gdal_sys::OGR_Fld_SetTZFlag(c_obj, gdal_sys::OGR_Fld_GetTZFlag(c_field_defn));
+        }
+        Ok(field_defn)
+    }
+
+    /// Set the sub-type of the field, which restricts its [type](OGRFieldType), e.g.
+    /// [`OFSTBoolean`](OGRFieldSubType::OFSTBoolean) or [`OFSTInt16`](OGRFieldSubType::OFSTInt16)
+    /// for [`OFTInteger`](OGRFieldType::OFTInteger) fields, [`OFSTFloat32`](OGRFieldSubType::OFSTFloat32)
+    /// for [`OFTReal`](OGRFieldType::OFTReal) fields, or [`OFSTJSON`](OGRFieldSubType::OFSTJSON)
+    /// for [`OFTString`](OGRFieldType::OFTString) fields.
+    ///
+    /// The sub-type is ignored if it isn't compatible with the type of the field.
+    pub fn set_sub_type(&self, sub_type: OGRFieldSubType::Type) {
+        unsafe { gdal_sys::OGR_Fld_SetSubType(self.c_obj, sub_type) };
+    }
+
+    /// Set whether the field may be null. Fields are nullable by default.
+    pub fn set_nullable(&self, nullable: bool) {
+        unsafe { gdal_sys::OGR_Fld_SetNullable(self.c_obj, c_int::from(nullable)) };
+    }
+
+    /// Set whether the field has a unique constraint.
+    #[cfg(any(all(major_is_3, minor_ge_2), major_ge_4))]
+    pub fn set_unique(&self, unique: bool) {
+        unsafe { gdal_sys::OGR_Fld_SetUnique(self.c_obj, c_int::from(unique)) };
+    }
+
+    /// Set the default value of the field, or remove it with `None`.
+    ///
+    /// The value is an SQL expression: a number, a string literal between single quotes
+    /// (e.g. `'unknown'`), `CURRENT_TIMESTAMP`, `CURRENT_DATE`, `CURRENT_TIME`, or a date
+    /// and time like `'2024/01/31 12:34:56'`.
+    pub fn set_default(&self, default: Option<&str>) -> Result<()> {
+        let c_default = default.map(CString::new).transpose()?;
+        unsafe {
+            gdal_sys::OGR_Fld_SetDefault(
+                self.c_obj,
+                c_default.as_ref().map_or(std::ptr::null(), |d| d.as_ptr()),
+            )
+        };
+        Ok(())
+    }
+
+    /// Set the time zone flag of an [`OFTDateTime`](OGRFieldType::OFTDateTime) field, that
+    /// is, whether all its values have the same time zone:
+    /// * `0`: unknown
+    /// * `1`: local time
+    /// * `2`: mixed time zones
+    /// * `100`: UTC
+    /// * `100 + n` (or `100 - n`): a time zone `n` quarters of an hour ahead of
+    ///   (or behind) UTC
+    #[cfg(any(all(major_ge_3, minor_ge_8), major_ge_4))]
+    pub fn set_tz_flag(&self, tz_flag: i32) {
+        unsafe { gdal_sys::OGR_Fld_SetTZFlag(self.c_obj, tz_flag as c_int) };
+    }
+    /// Set the name of the [field domain](crate::vector::FieldDomain) of the field, or remove
+    /// it with an empty string.
+    ///
+    /// The field domain is looked up by name in the dataset of the layer the field is added to.
+    #[cfg(any(major_ge_4, all(major_is_3, minor_ge_3)))]
+    pub fn set_domain_name(&self, domain_name: &str) -> Result<()> {
+        let c_str = CString::new(domain_name)?;
+        unsafe { gdal_sys::OGR_Fld_SetDomainName(self.c_obj, c_str.as_ptr()) };
+        Ok(())
+    }
+
+    pub fn add_to_layer<L: LayerAccess>(&self, layer: &L) -> Result<()> {
+        let rv = unsafe { gdal_sys::OGR_L_CreateField(layer.c_layer(), self.c_obj, 1) };
+        if rv != OGRErr::OGRERR_NONE {
+            return Err(GdalError::OgrError {
+                err: rv,
+                method_name: "OGR_L_CreateFeature",
+            });
+        }
+        Ok(())
+    }
+}
+
+/// The definition of a geometry field, to add or alter the geometry fields of a layer.
+#[derive(Debug)]
+pub struct GeomFieldDefn {
+    c_obj: OGRGeomFieldDefnH,
+}
+
+impl Drop for GeomFieldDefn {
+    fn drop(&mut self) {
+        unsafe { gdal_sys::OGR_GFld_Destroy(self.c_obj) };
+    }
+}
+
+impl GeomFieldDefn {
+    /// Create the definition of a geometry field called `name`, with the given geometry type.
+    pub fn new(name: &str, geometry_type: OGRwkbGeometryType::Type) -> Result<GeomFieldDefn> {
         let c_str = CString::new(name)?;
-        let c_obj = unsafe { gdal_sys::OGR_Fld_Create(c_str.as_ptr(), field_type) };
+        let c_obj = unsafe { gdal_sys::OGR_GFld_Create(c_str.as_ptr(), geometry_type) };
         if c_obj.is_null() {
-            return Err(_last_null_pointer_err("OGR_Fld_Create"));
+            return Err(_last_null_pointer_err("OGR_GFld_Create"));
         };
-        Ok(FieldDefn { c_obj })
+        Ok(GeomFieldDefn { c_obj })
     }
-    pub fn set_width(&self, width: i32) {
-        unsafe { gdal_sys::OGR_Fld_SetWidth(self.c_obj, width as c_int) };
+
+    /// Set the spatial reference of the geometry field.
+    pub fn set_spatial_ref(&self, spatial_ref: &SpatialRef) {
+        unsafe { gdal_sys::OGR_GFld_SetSpatialRef(self.c_obj, spatial_ref.to_c_hsrs()) };
     }
-    pub fn set_precision(&self, precision: i32) {
-        unsafe { gdal_sys::OGR_Fld_SetPrecision(self.c_obj, precision as c_int) };
+
+    /// Set whether the geometry field may be null. Fields are nullable by default.
+    pub fn set_nullable(&self, nullable: bool) {
+        unsafe { gdal_sys::OGR_GFld_SetNullable(self.c_obj, c_int::from(nullable)) };
     }
+
+    /// Add this geometry field to `layer`, next to its existing geometry fields.
+    ///
+    /// Not all drivers support this, see [`LayerCaps::OLCCreateGeomField`].
     pub fn add_to_layer<L: LayerAccess>(&self, layer: &L) -> Result<()> {
-        let rv = unsafe { gdal_sys::OGR_L_CreateField(layer.c_layer(), self.c_obj, 1) };
+        let rv = unsafe { gdal_sys::OGR_L_CreateGeomField(layer.c_layer(), self.c_obj, 1) };
         if rv != OGRErr::OGRERR_NONE {
             return Err(GdalError::OgrError {
                 err: rv,
-                method_name: "OGR_L_CreateFeature",
+                method_name: "OGR_L_CreateGeomField",
             });
         }
         Ok(())
@@ -597,6 +1639,32 @@ impl Dataset {
         unsafe { OwnedLayer::from_c_layer(self, c_layer) }
     }
 
+    /// Returns whether this dataset supports the vector `capability`.
+    ///
+    /// # Example
+    ///
+    /// ```rust, no_run
+    /// use gdal::vector::DatasetCaps;
+    /// use gdal::Dataset;
+    /// # fn main() -> gdal::errors::Result<()> {
+    /// let dataset = Dataset::open("fixtures/roads.geojson")?;
+    /// if !dataset.has_capability(DatasetCaps::ODsCCreateLayer) {
+    ///     println!("layers can't be added to this dataset");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// See: [GDALDatasetTestCapability](https://gdal.org/api/raster_c_api.html#_CPPv425GDALDatasetTestCapability12GDALDatasetHPKc)
+    pub fn has_capability(&self, capability: DatasetCaps) -> bool {
+        unsafe {
+            gdal_sys::GDALDatasetTestCapability(
+                self.c_dataset(),
+                capability.into_cstring().as_ptr(),
+            ) == 1
+        }
+    }
+
     /// Get the number of layers in this dataset.
     pub fn layer_count(&self) -> usize {
         (unsafe { gdal_sys::OGR_DS_GetLayerCount(self.c_dataset()) }) as usize
@@ -648,11 +1716,139 @@ impl Dataset {
         Ok(self.into_child_layer(c_layer))
     }
 
+    /// Delete the layer at index `idx` from this dataset.
+    ///
+    /// Not all drivers support this, see [`DatasetCaps::ODsCDeleteLayer`].
+    ///
+    /// See: [GDALDatasetDeleteLayer](https://gdal.org/api/raster_c_api.html#_CPPv422GDALDatasetDeleteLayer12GDALDatasetHi)
+    pub fn delete_layer(&mut self, idx: usize) -> Result<()> {
+        let idx = libc::c_int::try_from(idx)?;
+        let rv = unsafe { gdal_sys::GDALDatasetDeleteLayer(self.c_dataset(), idx) };
+        if rv != OGRErr::OGRERR_NONE {
+            return Err(GdalError::OgrError {
+                err: rv,
+                method_name: "GDALDatasetDeleteLayer",
+            });
+        }
+        Ok(())
+    }
+
+    /// Copy `source`, which may belong to another dataset, into a new layer of this dataset
+    /// called `new_name`, along with its fields and features.
+    ///
+    /// `options` are the driver-specific layer creation options of the new layer.
+    ///
+    /// # Example
+    ///
+    /// ```rust, no_run
+    /// use gdal::cpl::CslStringList;
+    /// use gdal::vector::LayerAccess;
+    /// use gdal::{Dataset, DriverManager};
+    /// # fn main() -> gdal::errors::Result<()> {
+    /// let source = Dataset::open("fixtures/roads.geojson")?;
+    /// let driver = DriverManager::get_driver_by_name("GPKG")?;
+    /// let mut dataset = driver.create_vector_only("/vsimem/roads.gpkg")?;
+    /// let layer = dataset.copy_layer(&source.layer(0)?, "roads", &CslStringList::new())?;
+    /// assert_eq!(layer.feature_count(), 21);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// See: [GDALDatasetCopyLayer](https://gdal.org/api/raster_c_api.html#_CPPv420GDALDatasetCopyLayer12GDALDatasetH9OGRLayerHPKc12CSLConstList)
+    pub fn copy_layer<L: LayerAccess>(
+        &mut self,
+        source: &L,
+        new_name: &str,
+        options: &crate::cpl::CslStringList,
+    ) -> Result<Layer> {
+        let c_name = CString::new(new_name)?;
+        let c_layer = unsafe {
+            gdal_sys::GDALDatasetCopyLayer(
+                self.c_dataset(),
+                source.c_layer(),
+                c_name.as_ptr(),
+                options.as_ptr(),
+            )
+        };
+        if c_layer.is_null() {
+            return Err(_last_null_pointer_err("GDALDatasetCopyLayer"));
+        }
+        Ok(self.child_layer(c_layer))
+    }
+
     /// Returns an iterator over the layers of the dataset.
     pub fn layers(&self) -> LayerIterator {
         LayerIterator::with_dataset(self)
     }
 
+    /// Open a vector dataset, applying the paging settings in `tuning` to the drivers that support them.
+    ///
+    /// See [`ReadTuning`] for the drivers affected. The settings are passed as thread-local
+    /// configuration options for the duration of the open call, so other threads and
+    /// later opens are unaffected.
+    ///
+    /// # Example
+    ///
+    /// ```rust, no_run
+    /// # fn main() -> gdal::errors::Result<()> {
+    /// use gdal::{Dataset, DatasetOptions};
+    /// use gdal::vector::{LayerAccess, ReadTuning};
+    /// let tuning = ReadTuning {
+    ///     page_size: Some(10_000),
+    /// };
+    /// let ds = Dataset::open_with_read_tuning("PG:dbname=gis", DatasetOptions::default(), &tuning)?;
+    /// let mut layer = ds.layer_by_name("parcels")?;
+    /// // resume a scan that was interrupted after 1 million features
+    /// for feature in layer.features_from(1_000_000)? {
+    ///     // ...
+    /// #   let _ = feature;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn open_with_read_tuning<P: AsRef<Path>>(
+        path: P,
+        options: DatasetOptions,
+        tuning: &ReadTuning,
+    ) -> Result<Dataset> {
+        let path = path.as_ref();
+        let mut open_options = options
+            .open_options
+            .unwrap_or_default()
+            .iter()
+            .map(|s| s.to_string())
+            .collect::<Vec<_>>();
+        let mut config = Vec::new();
+
+        if let Some(page_size) = tuning.page_size {
+            let page_size = page_size.to_string();
+            config.push(("OGR_PG_CURSOR_PAGE", page_size.clone()));
+            config.push(("OGR_WFS_PAGING_ALLOWED", "ON".to_string()));
+            config.push(("OGR_WFS_PAGE_SIZE", page_size.clone()));
+
+            // OAPIF only has an open option, which other drivers would warn about.
+            let is_oapif = path
+                .to_str()
+                .map_or(false, |p| p.starts_with("OAPIF:") || p.starts_with("WFS3:"))
+                || options
+                    .allowed_drivers
+                    .map_or(false, |d| d.iter().any(|d| d.eq_ignore_ascii_case("OAPIF")));
+            if is_oapif {
+                open_options.push(format!("PAGE_SIZE={page_size}"));
+            }
+        }
+
+        let _config = ThreadLocalConfigGuard::set(&config)?;
+        let open_options = open_options.iter().map(String::as_str).collect::<Vec<_>>();
+        Dataset::open_ex(
+            path,
+            DatasetOptions {
+                open_options: (!open_options.is_empty()).then(|| open_options.as_slice()),
+                ..options
+            },
+        )
+    }
+
     /// Creates a new layer. The [`LayerOptions`] struct implements `Default`, so you only need to
     /// specify those options that deviate from the default.
     ///
@@ -726,6 +1922,52 @@ impl Dataset {
         };
         Ok(self.child_layer(c_layer))
     }
+
+    /// Creates a new layer from a [`LayerCreationOptions`] builder, along with its fields.
+    ///
+    /// See also: [`create_layer`](Self::create_layer).
+    pub fn create_layer_with_options(
+        &mut self,
+        options: &LayerCreationOptions<'_>,
+    ) -> Result<Layer> {
+        let c_name = CString::new(options.name.as_str())?;
+        let c_srs = match options.srs {
+            Some(srs) => srs.to_c_hsrs(),
+            None => null_mut(),
+        };
+
+        let c_layer = unsafe {
+            gdal_sys::GDALDatasetCreateLayer(
+                self.c_dataset(),
+                c_name.as_ptr(),
+                c_srs,
+                options.geometry_type,
+                options.options.as_ptr(),
+            )
+        };
+        if c_layer.is_null() {
+            return Err(_last_null_pointer_err("GDALDatasetCreateLayer"));
+        };
+
+        for (name, field_type) in &options.fields {
+            let field_defn = FieldDefn::new(name, *field_type)?;
+            let rv = unsafe {
+                gdal_sys::OGR_L_CreateField(
+                    c_layer,
+                    field_defn.c_obj,
+                    libc::c_int::from(options.approx_ok),
+                )
+            };
+            if rv != OGRErr::OGRERR_NONE {
+                return Err(GdalError::OgrError {
+                    err: rv,
+                    method_name: "OGR_L_CreateField",
+                });
+            }
+        }
+
+        Ok(self.child_layer(c_layer))
+    }
 }
 
 #[cfg(test)]
@@ -794,6 +2036,161 @@ mod tests {
         assert!(ds.create_layer(options).is_ok());
     }
 
+    #[test]
+    fn test_create_layer_with_options() {
+        use gdal_sys::OGRwkbGeometryType::{wkbPoint, wkbPointZM};
+
+        let driver = DriverManager::get_driver_by_name("GPKG").unwrap();
+        let path = "/vsimem/test_create_layer_with_options.gpkg";
+        let mut ds = driver.create_vector_only(path).unwrap();
+        let srs = SpatialRef::from_epsg(4326).unwrap();
+
+        let mut options = LayerCreationOptions::new("stations");
+        options
+            .with_srs(&srs)
+            .with_geometry_type(wkbPoint)
+            .with_dimensions(true, true)
+            .with_fid_column("station_id")
+            .with_geometry_name("location")
+            .with_spatial_index(false)
+            .with_field("name", OGRFieldType::OFTString)
+            .with_field("elevation", OGRFieldType::OFTReal);
+        let layer = ds.create_layer_with_options(&options).unwrap();
+
+        assert_eq!(layer.name(), "stations");
+        assert_eq!(layer.fid_column(), "station_id");
+        assert_eq!(layer.geometry_column(), "location");
+        assert_eq!(
+            layer.defn().geom_fields().next().unwrap().field_type(),
+            wkbPointZM
+        );
+        assert_eq!(layer.spatial_ref().unwrap().auth_code().unwrap(), 4326);
+        let fields = layer
+            .defn()
+            .fields()
+            .map(|field| (field.name(), field.field_type()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            fields,
+            [
+                ("name".to_string(), OGRFieldType::OFTString),
+                ("elevation".to_string(), OGRFieldType::OFTReal)
+            ]
+        );
+
+        ds.close().unwrap();
+        crate::vsi::unlink_mem_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_create_layer_with_options_approx_ok() {
+        let driver = DriverManager::get_driver_by_name("ESRI Shapefile").unwrap();
+        let path = "/vsimem/test_create_layer_with_options_approx_ok";
+        let mut ds = driver.create_vector_only(path).unwrap();
+
+        let mut options = LayerCreationOptions::new("strict");
+        options.with_field("a_very_long_field_name", OGRFieldType::OFTInteger);
+        {
+            let _nolog = SuppressGDALErrorLog::new();
+            assert!(ds.create_layer_with_options(&options).is_err());
+        }
+
+        let mut options = LayerCreationOptions::new("approx");
+        options
+            .with_field("a_very_long_field_name", OGRFieldType::OFTInteger)
+            .with_approx_ok(true);
+        let layer = ds.create_layer_with_options(&options).unwrap();
+        let field = layer.defn().fields().next().unwrap();
+        assert_eq!(field.name(), "a_very_lon");
+    }
+
+    #[test]
+    fn test_field_defn_types_and_constraints() {
+        use OGRFieldSubType::*;
+        use OGRFieldType::*;
+
+        let driver = DriverManager::get_driver_by_name("Memory").unwrap();
+        let mut ds = driver.create_vector_only("").unwrap();
+        let layer = ds.create_layer(Default::default()).unwrap();
+
+        let types = [
+            ("int64", OFTInteger64, OFSTNone),
+            ("ints", OFTIntegerList, OFSTNone),
+            ("int64s", OFTInteger64List, OFSTNone),
+            ("reals", OFTRealList, OFSTNone),
+            ("strings", OFTStringList, OFSTNone),
+            ("binary", OFTBinary, OFSTNone),
+            ("date", OFTDate, OFSTNone),
+            ("time", OFTTime, OFSTNone),
+            ("bool", OFTInteger, OFSTBoolean),
+            ("int16", OFTInteger, OFSTInt16),
+            ("float32", OFTReal, OFSTFloat32),
+            ("json", OFTString, OFSTJSON),
+        ];
+        for (name, field_type, sub_type) in types {
+            let field_defn = FieldDefn::new(name, field_type).unwrap();
+            field_defn.set_sub_type(sub_type);
+            field_defn.add_to_layer(&layer).unwrap();
+        }
+
+        let field_defn = FieldDefn::new("code", OFTString).unwrap();
+        field_defn.set_width(8);
+        field_defn.set_nullable(false);
+        #[cfg(any(all(major_is_3, minor_ge_2), major_ge_4))]
+        field_defn.set_unique(true);
+        field_defn.set_default(Some("'none'")).unwrap();
+        field_defn.add_to_layer(&layer).unwrap();
+
+        let field_defn = FieldDefn::new("created", OFTDateTime).unwrap();
+        field_defn.set_default(Some("CURRENT_TIMESTAMP")).unwrap();
+        #[cfg(any(all(major_ge_3, minor_ge_8), major_ge_4))]
+        field_defn.set_tz_flag(100);
+        field_defn.add_to_layer(&layer).unwrap();
+
+        let defn = layer.defn();
+        for (field, (name, field_type, sub_type)) in defn.fields().zip(types) {
+            assert_eq!(field.name(), name);
+            assert_eq!(field.field_type(), field_type);
+            assert_eq!(field.sub_type(), sub_type);
+            assert!(field.is_nullable());
+            assert_eq!(field.default_value(), None);
+        }
+
+        let code = defn.fields().find(|f| f.name() == "code").unwrap();
+        assert_eq!(code.width(), 8);
+        assert!(!code.is_nullable());
+        #[cfg(any(all(major_is_3, minor_ge_2), major_ge_4))]
+        assert!(code.is_unique());
+        assert_eq!(code.default_value().as_deref(), Some("'none'"));
+
+        let created = defn.fields().find(|f| f.name() == "created").unwrap();
+        assert_eq!(
+            created.default_value().as_deref(),
+            Some("CURRENT_TIMESTAMP")
+        );
+        #[cfg(any(all(major_ge_3, minor_ge_8), major_ge_4))]
+        assert_eq!(created.tz_flag(), 100);
+
+        // copies keep the full definition
+        let mut copy_ds = driver.create_vector_only("").unwrap();
+        let copy = copy_ds.create_layer(Default::default()).unwrap();
+        for field in layer.defn().fields() {
+            FieldDefn::from_field(&field)
+                .unwrap()
+                .add_to_layer(&copy)
+                .unwrap();
+        }
+        let copied = copy.defn();
+        let code = copied.fields().find(|f| f.name() == "code").unwrap();
+        assert_eq!(code.width(), 8);
+        assert!(!code.is_nullable());
+        #[cfg(any(all(major_is_3, minor_ge_2), major_ge_4))]
+        assert!(code.is_unique());
+        assert_eq!(code.default_value().as_deref(), Some("'none'"));
+        let json = copied.fields().find(|f| f.name() == "json").unwrap();
+        assert_eq!(json.sub_type(), OFSTJSON);
+    }
+
     #[test]
     fn test_layer_count() {
         let ds = Dataset::open(fixture("roads.geojson")).unwrap();
@@ -828,6 +2225,34 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_layer_get_extent_of_field() {
+        with_layer("roads.geojson", |layer| {
+            let extent = layer.get_extent_of_field(0, true).unwrap().unwrap();
+            assert_almost_eq(extent.MinX, 26.100768);
+            assert_almost_eq(extent.MaxY, 44.431818);
+            assert!(matches!(
+                layer.get_extent_of_field(1, true).unwrap_err(),
+                GdalError::InvalidFieldIndex { index: 1, .. }
+            ));
+        });
+
+        let driver = DriverManager::get_driver_by_name("Memory").unwrap();
+        let mut ds = driver.create_vector_only("").unwrap();
+        let layer = ds.create_layer(Default::default()).unwrap();
+        assert!(layer.get_extent_of_field(0, true).unwrap().is_none());
+    }
+
+    #[test]
+    #[cfg(any(major_ge_4, all(major_is_3, minor_ge_9)))]
+    fn test_layer_get_extent_3d() {
+        with_layer("roads.geojson", |layer| {
+            let extent = layer.get_extent_3d(0, true).unwrap().unwrap();
+            assert_almost_eq(extent.MinX, 26.100768);
+            assert_almost_eq(extent.MaxY, 44.431818);
+        });
+    }
+
     #[test]
     fn test_layer_spatial_ref() {
         let ds = Dataset::open(fixture("roads.geojson")).unwrap();
@@ -850,6 +2275,20 @@ mod tests {
         }
         assert!(layer.has_capability(OLCRandomRead));
         assert!(layer.has_capability(OLCStringsAsUTF8));
+        assert!(!layer.has_capability(OLCUpsertFeature));
+    }
+
+    #[test]
+    fn test_dataset_capabilities() {
+        let ds = Dataset::open(fixture("roads.geojson")).unwrap();
+        assert!(!ds.has_capability(DatasetCaps::ODsCCreateLayer));
+        assert!(!ds.has_capability(DatasetCaps::ODsCTransactions));
+
+        let (_temp_path, ds) = open_gpkg_for_update(&fixture("poly.gpkg"));
+        assert!(ds.has_capability(DatasetCaps::ODsCCreateLayer));
+        assert!(ds.has_capability(DatasetCaps::ODsCDeleteLayer));
+        assert!(ds.has_capability(DatasetCaps::ODsCTransactions));
+        assert!(ds.has_capability(DatasetCaps::ODsCCurveGeometries));
     }
 
     #[test]
@@ -880,6 +2319,20 @@ mod tests {
             assert!(layer.feature(23489660).is_some());
             assert!(layer.feature(0).is_none());
             assert!(layer.feature(404).is_none());
+
+            let feature = layer.try_feature(236194095).unwrap();
+            assert_eq!(feature.fid(), Some(236194095));
+            assert!(matches!(
+                layer.try_feature(404).unwrap_err(),
+                GdalError::OgrError {
+                    err: gdal_sys::OGRErr::NON_EXISTING_FEATURE,
+                    method_name: "OGR_L_GetFeature",
+                }
+            ));
+            assert!(matches!(
+                layer.try_feature(u64::MAX).unwrap_err(),
+                GdalError::IntConversionError(_)
+            ));
         });
     }
 
@@ -1361,6 +2814,79 @@ mod tests {
         fs::remove_file(fixture("output.geojson")).unwrap();
     }
 
+    #[test]
+    fn test_features_from() {
+        with_layer("roads.geojson", |mut layer| {
+            let all = layer.features().map(|f| f.fid()).collect::<Vec<_>>();
+            let rest = layer
+                .features_from(5)
+                .unwrap()
+                .map(|f| f.fid())
+                .collect::<Vec<_>>();
+            assert_eq!(rest, all[5..]);
+
+            layer.set_next_by_index(20).unwrap();
+            let mut iter = FeatureIterator::_with_layer(&layer);
+            assert_eq!(iter.next().unwrap().fid(), all[20]);
+            assert!(iter.next().is_none());
+        });
+    }
+
+    #[test]
+    fn test_features_in() {
+        with_layer("roads.geojson", |mut layer| {
+            let mut web_mercator = SpatialRef::from_epsg(3857).unwrap();
+            web_mercator.set_axis_mapping_strategy(AxisMappingStrategy::TraditionalGisOrder);
+
+            let original = layer
+                .features()
+                .map(|f| f.geometry().unwrap().clone())
+                .collect::<Vec<_>>();
+            let transformed = layer
+                .features_in(&web_mercator)
+                .unwrap()
+                .map(|f| f.unwrap().geometry().unwrap().clone())
+                .collect::<Vec<_>>();
+            assert_eq!(transformed.len(), original.len());
+            for (original, transformed) in original.iter().zip(&transformed) {
+                assert_eq!(
+                    transformed.spatial_ref().unwrap().auth_code().unwrap(),
+                    3857
+                );
+                let (x, _, _) = original.get_point(0);
+                let (mercator_x, _, _) = transformed.get_point(0);
+                assert_almost_eq(mercator_x, x * 6378137.0_f64.to_radians());
+            }
+        });
+
+        let driver = DriverManager::get_driver_by_name("Memory").unwrap();
+        let mut ds = driver.create_vector_only("").unwrap();
+        let mut layer = ds.create_layer(Default::default()).unwrap();
+        let wgs84 = SpatialRef::from_epsg(4326).unwrap();
+        assert!(layer.features_in(&wgs84).is_err());
+    }
+
+    #[test]
+    fn test_open_with_read_tuning() {
+        use crate::config::get_thread_local_config_option;
+
+        let tuning = ReadTuning {
+            page_size: Some(100),
+        };
+        let ds = Dataset::open_with_read_tuning(
+            fixture("roads.geojson"),
+            DatasetOptions::default(),
+            &tuning,
+        )
+        .unwrap();
+        assert_eq!(ds.layer(0).unwrap().feature_count(), 21);
+        // the config options are only set while opening
+        assert_eq!(
+            get_thread_local_config_option("OGR_PG_CURSOR_PAGE", "unset").unwrap(),
+            "unset"
+        );
+    }
+
     #[test]
     fn test_features_reset() {
         with_layer("roads.geojson", |mut layer| {
@@ -1412,6 +2938,68 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_features_intersecting() {
+        with_layer("roads.geojson", |mut layer| {
+            // A thin triangle along the diagonal of its bounding box.
+            let triangle = Geometry::from_wkt(
+                "POLYGON ((26.1005 44.4295, 26.1036 44.4319, 26.1036 44.4318, 26.1005 44.4295))",
+            )
+            .unwrap();
+            // Every road crosses the bounding box, but only four cross the triangle.
+            layer.set_spatial_filter_rect(26.1005, 44.4295, 26.1036, 44.4319);
+            assert_eq!(layer.features().count(), 21);
+            layer.clear_spatial_filter();
+
+            let mut fids = layer
+                .features_intersecting(&triangle)
+                .map(|f| f.fid().unwrap())
+                .collect::<Vec<_>>();
+            fids.sort_unstable();
+            assert_eq!(fids, [23489649, 23489659, 218268093, 252725993]);
+            assert_eq!(layer.features().count(), 21);
+
+            // A small square around the first point of the first feature.
+            let square = Geometry::bbox(26.10192, 44.43027, 26.10193, 44.43028).unwrap();
+            let fids = layer
+                .features_intersecting(&square)
+                .map(|f| f.fid().unwrap())
+                .collect::<Vec<_>>();
+            assert!(fids.contains(&236194095));
+        });
+    }
+
+    #[test]
+    fn test_ignored_fields() {
+        with_layer("roads.geojson", |mut layer| {
+            layer
+                .set_ignored_fields(&["highway", "OGR_GEOMETRY"])
+                .unwrap();
+            {
+                let feature = layer.features().next().unwrap();
+                assert!(feature.geometry().is_none());
+                assert_eq!(feature.field("highway").unwrap(), None);
+                assert_eq!(
+                    feature.field_as_string_by_name("kind").unwrap().unwrap(),
+                    "path"
+                );
+            }
+
+            layer.clear_ignored_fields();
+            {
+                let feature = layer.features().next().unwrap();
+                assert!(feature.geometry().is_some());
+                assert_eq!(
+                    feature.field_as_string_by_name("highway").unwrap().unwrap(),
+                    "footway"
+                );
+            }
+
+            let _nolog = SuppressGDALErrorLog::new();
+            assert!(layer.set_ignored_fields(&["not_a_field"]).is_err());
+        });
+    }
+
     #[test]
     fn test_set_feature() {
         let ds_options = DatasetOptions {
@@ -1435,6 +3023,228 @@ mod tests {
         let value = feature.field("id").unwrap().unwrap().into_int().unwrap();
         assert_eq!(value, 1);
     }
+    #[test]
+    fn test_edit_features() {
+        let driver = DriverManager::get_driver_by_name("GPKG").unwrap();
+        let mut ds = driver
+            .create_vector_only("/vsimem/test_edit_features.gpkg")
+            .unwrap();
+        let mut options = LayerCreationOptions::new("edits");
+        options
+            .with_geometry_type(OGRwkbGeometryType::wkbPoint)
+            .with_field("name", OGRFieldType::OFTString)
+            .with_field("value", OGRFieldType::OFTInteger);
+        let mut layer = ds.create_layer_with_options(&options).unwrap();
+        for (name, value) in [("first", 1), ("second", 2)] {
+            layer
+                .create_feature_fields(
+                    Geometry::from_wkt("POINT (1 2)").unwrap(),
+                    &["name", "value"],
+                    &[
+                        FieldValue::StringValue(name.to_string()),
+                        FieldValue::IntegerValue(value),
+                    ],
+                )
+                .unwrap();
+        }
+        let name = |layer: &Layer, fid| {
+            layer
+                .feature(fid)
+                .unwrap()
+                .field_as_string_by_name("name")
+                .unwrap()
+                .unwrap()
+        };
+
+        let mut feature = layer.feature(1).unwrap();
+        feature.set_field_string("name", "changed").unwrap();
+        layer.set_feature(feature).unwrap();
+        assert_eq!(name(&layer, 1), "changed");
+
+        #[cfg(any(major_ge_4, all(major_is_3, minor_ge_7)))]
+        {
+            let mut feature = layer.feature(2).unwrap();
+            feature.set_field_string("name", "updated").unwrap();
+            feature.set_field_integer("value", 42).unwrap();
+            layer.update_feature(&feature, &["name"], false).unwrap();
+            assert!(layer.update_feature(&feature, &["missing"], false).is_err());
+            let feature = layer.feature(2).unwrap();
+            assert_eq!(
+                feature.field_as_string_by_name("name").unwrap().unwrap(),
+                "updated"
+            );
+            assert_eq!(feature.field_as_integer_by_name("value").unwrap(), Some(2));
+        }
+
+        #[cfg(any(major_ge_4, all(major_is_3, minor_ge_6)))]
+        {
+            let mut feature = Feature::new(layer.defn()).unwrap();
+            feature.set_fid(Some(2)).unwrap();
+            feature.set_field_string("name", "upserted").unwrap();
+            layer.upsert_feature(&feature).unwrap();
+            assert_eq!(name(&layer, 2), "upserted");
+            feature.set_fid(Some(3)).unwrap();
+            layer.upsert_feature(&feature).unwrap();
+            assert_eq!(layer.feature_count(), 3);
+        }
+
+        let count = layer.feature_count();
+        layer.delete_feature(1).unwrap();
+        assert_eq!(layer.feature_count(), count - 1);
+        assert!(layer.feature(1).is_none());
+        assert!(matches!(
+            layer.delete_feature(1).unwrap_err(),
+            GdalError::OgrError {
+                err: gdal_sys::OGRErr::NON_EXISTING_FEATURE,
+                method_name: "OGR_L_DeleteFeature",
+            }
+        ));
+    }
+
+    #[test]
+    fn test_alter_fields() {
+        let driver = DriverManager::get_driver_by_name("Memory").unwrap();
+        let mut ds = driver.create_vector_only("").unwrap();
+        let mut layer = ds.create_layer(Default::default()).unwrap();
+        layer
+            .create_defn_fields(&[
+                ("a", OGRFieldType::OFTInteger),
+                ("b", OGRFieldType::OFTString),
+                ("c", OGRFieldType::OFTReal),
+            ])
+            .unwrap();
+        let field_names =
+            |layer: &Layer| -> Vec<String> { layer.defn().fields().map(|f| f.name()).collect() };
+
+        let index = layer.defn().field_index("b").unwrap();
+        layer.delete_field(index).unwrap();
+        assert_eq!(field_names(&layer), ["a", "c"]);
+        assert!(layer.defn().field_index("b").is_err());
+
+        layer.reorder_fields(&[1, 0]).unwrap();
+        assert_eq!(field_names(&layer), ["c", "a"]);
+        assert!(layer.reorder_fields(&[0]).is_err());
+        layer.reorder_field(1, 0).unwrap();
+        assert_eq!(field_names(&layer), ["a", "c"]);
+
+        let new_defn = FieldDefn::new("d", OGRFieldType::OFTString).unwrap();
+        layer
+            .alter_field_defn(1, &new_defn, AlterFieldFlags::ALTER_NAME_FLAG)
+            .unwrap();
+        let field = layer.defn().fields().nth(1).unwrap();
+        assert_eq!(field.name(), "d");
+        assert_eq!(field.field_type(), OGRFieldType::OFTReal);
+        layer
+            .alter_field_defn(1, &new_defn, AlterFieldFlags::ALTER_TYPE_FLAG)
+            .unwrap();
+        let field = layer.defn().fields().nth(1).unwrap();
+        assert_eq!(field.field_type(), OGRFieldType::OFTString);
+
+        #[cfg(any(major_ge_4, all(major_is_3, minor_ge_6)))]
+        {
+            use crate::vector::AlterGeomFieldFlags;
+
+            let new_defn = GeomFieldDefn::new("geom", OGRwkbGeometryType::wkbPoint).unwrap();
+            new_defn.set_spatial_ref(&SpatialRef::from_epsg(4326).unwrap());
+            layer
+                .alter_geom_field_defn(
+                    0,
+                    &new_defn,
+                    AlterGeomFieldFlags::ALTER_GEOM_FIELD_DEFN_NAME_FLAG
+                        | AlterGeomFieldFlags::ALTER_GEOM_FIELD_DEFN_SRS_FLAG,
+                )
+                .unwrap();
+            let geom_field = layer.defn().geom_fields().next().unwrap();
+            assert_eq!(geom_field.name(), "geom");
+            assert_eq!(geom_field.spatial_ref().unwrap().auth_code().unwrap(), 4326);
+        }
+    }
+
+    #[test]
+    fn test_multiple_geom_fields() {
+        let driver = DriverManager::get_driver_by_name("Memory").unwrap();
+        let mut ds = driver.create_vector_only("").unwrap();
+        let mut layer = ds
+            .create_layer(LayerOptions {
+                ty: OGRwkbGeometryType::wkbPolygon,
+                ..Default::default()
+            })
+            .unwrap();
+        let geom_field = GeomFieldDefn::new("centroid", OGRwkbGeometryType::wkbPoint).unwrap();
+        geom_field.set_spatial_ref(&SpatialRef::from_epsg(4326).unwrap());
+        geom_field.add_to_layer(&layer).unwrap();
+
+        let geom_fields = layer
+            .defn()
+            .geom_fields()
+            .map(|f| (f.name(), f.field_type()))
+            .collect::<Vec<_>>();
+        assert_eq!(geom_fields.len(), 2);
+        assert_eq!(
+            geom_fields[1],
+            ("centroid".to_string(), OGRwkbGeometryType::wkbPoint)
+        );
+        assert_eq!(layer.defn().geom_field_index("centroid").unwrap(), 1);
+        assert!(layer.defn().geom_field_index("missing").is_err());
+
+        let polygon = Geometry::from_wkt("POLYGON ((0 0, 0 2, 2 2, 2 0, 0 0))").unwrap();
+        let centroid = Geometry::from_wkt("POINT (1 1)").unwrap();
+        {
+            let mut feature = Feature::new(layer.defn()).unwrap();
+            feature.set_geometry(polygon.clone()).unwrap();
+            feature
+                .set_geometry_by_name("centroid", centroid.clone())
+                .unwrap();
+            assert!(feature.set_geometry_by_index(2, centroid.clone()).is_err());
+            feature.create(&layer).unwrap();
+        }
+
+        let feature = layer.features().next().unwrap();
+        assert_eq!(feature.geometry().unwrap(), &polygon);
+        assert_eq!(feature.geometry_by_index(1).unwrap(), &centroid);
+        assert_eq!(feature.geometry_by_name("centroid").unwrap(), &centroid);
+    }
+
+    #[test]
+    fn test_layer_management() {
+        let driver = DriverManager::get_driver_by_name("GPKG").unwrap();
+        let mut ds = driver
+            .create_vector_only("/vsimem/test_layer_management.gpkg")
+            .unwrap();
+        let source = Dataset::open(fixture("roads.geojson")).unwrap();
+        let copy = ds
+            .copy_layer(
+                &source.layer(0).unwrap(),
+                "roads",
+                &crate::cpl::CslStringList::new(),
+            )
+            .unwrap();
+        assert_eq!(copy.feature_count(), 21);
+        assert_eq!(
+            copy.defn().fields().count(),
+            source.layer(0).unwrap().defn().fields().count()
+        );
+        ds.create_layer(LayerOptions {
+            name: "other",
+            ..Default::default()
+        })
+        .unwrap();
+        assert_eq!(ds.layer_count(), 2);
+
+        #[cfg(any(major_ge_4, all(major_is_3, minor_ge_5)))]
+        {
+            let mut layer = ds.layer_by_name("other").unwrap();
+            layer.rename("renamed").unwrap();
+            assert_eq!(layer.name(), "renamed");
+            assert!(ds.layer_by_name("renamed").is_ok());
+        }
+
+        ds.delete_layer(0).unwrap();
+        assert_eq!(ds.layer_count(), 1);
+        assert!(ds.layer_by_name("roads").is_err());
+        assert!(ds.delete_layer(5).is_err());
+    }
+
     #[test]
     fn test_schema() {
         let ds = Dataset::open(fixture("roads.geojson")).unwrap();
@@ -1496,6 +3306,34 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_spatial_filter_on_field() {
+        with_layer("roads.geojson", |mut layer| {
+            assert!(layer.spatial_filter().is_none());
+
+            let bbox = Geometry::bbox(26.1017, 44.4297, 26.1025, 44.4303).unwrap();
+            layer.set_spatial_filter_on_field(0, &bbox).unwrap();
+            assert_eq!(layer.features().count(), 7);
+            let filter = layer.spatial_filter().unwrap();
+            assert_eq!(filter, bbox);
+
+            layer.clear_spatial_filter();
+            assert!(layer.spatial_filter().is_none());
+            layer
+                .set_spatial_filter_rect_on_field(0, 26.1017, 44.4297, 26.1025, 44.4303)
+                .unwrap();
+            assert_eq!(layer.features().count(), 7);
+
+            assert!(matches!(
+                layer.set_spatial_filter_on_field(1, &bbox).unwrap_err(),
+                GdalError::InvalidFieldIndex {
+                    index: 1,
+                    method_name: "set_spatial_filter_on_field",
+                }
+            ));
+        });
+    }
+
     #[test]
     fn test_spatial_filter() {
         let ds = Dataset::open(fixture("roads.geojson")).unwrap();
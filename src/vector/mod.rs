@@ -64,25 +64,56 @@
 //! ```
 //!
 
+mod algebra;
+#[cfg(all(feature = "arrow", any(major_ge_4, all(major_is_3, minor_ge_6))))]
+mod arrow;
 mod defn;
 mod feature;
+#[cfg(any(major_ge_4, all(major_is_3, minor_ge_3)))]
+mod field_domain;
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+mod geojson;
 mod geometry;
+#[cfg(feature = "geozero")]
+#[cfg_attr(docsrs, doc(cfg(feature = "geozero")))]
+mod geozero;
 mod layer;
 mod ops;
 mod options;
 pub mod sql;
+mod style;
 mod transaction;
+mod writer;
 
-pub use defn::{Defn, Field, FieldIterator};
+pub use algebra::LayerAlgebraOptions;
+#[cfg(all(feature = "arrow", any(major_ge_4, all(major_is_3, minor_ge_6))))]
+pub use arrow::ArrowStreamReader;
+pub use defn::{Defn, Field, FieldIterator, GeomField, GeomFieldIterator};
 pub use feature::{
     field_type_to_name, Feature, FeatureIterator, FieldValue, FieldValueIterator,
-    OwnedFeatureIterator,
+    IntersectingFeatureIterator, IntoFeatureIterator, OwnedFeature, OwnedFeatureIterator,
+    TransformedFeatureIterator,
 };
-pub use gdal_sys::{OGRFieldType, OGRwkbGeometryType};
-pub use geometry::{geometry_type_to_name, Geometry};
-pub use layer::{FieldDefn, Layer, LayerAccess, LayerCaps, LayerIterator, OwnedLayer};
-pub use options::LayerOptions;
+#[cfg(any(major_ge_4, all(major_is_3, minor_ge_3)))]
+pub use field_domain::{FieldDomain, RangeBound};
+#[cfg(any(major_ge_4, all(major_is_3, minor_ge_3)))]
+pub use gdal_sys::{OGRFieldDomainMergePolicy, OGRFieldDomainSplitPolicy, OGRFieldDomainType};
+pub use gdal_sys::{OGRFieldSubType, OGRFieldType, OGRwkbGeometryType};
+pub use geometry::{
+    geometry_type_flatten, geometry_type_has_m, geometry_type_has_z, geometry_type_to_name,
+    non_linear_geometries_enabled, set_non_linear_geometries_enabled, Geometry, PointBuffer,
+    PointIterator,
+};
+pub use layer::{
+    DatasetCaps, FieldDefn, GeomFieldDefn, Layer, LayerAccess, LayerCaps, LayerIterator, OwnedLayer,
+};
+pub use options::{
+    AlterFieldFlags, AlterGeomFieldFlags, LayerCreationOptions, LayerOptions, ReadTuning,
+};
+pub use style::{StyleString, StyleTable, StyleTool, StyleToolKind};
 pub use transaction::Transaction;
+pub use writer::{LayerWriter, WriteStats};
 
 /// Axis aligned 2D bounding box.
 pub type Envelope = gdal_sys::OGREnvelope;
@@ -90,4 +121,6 @@ pub type Envelope = gdal_sys::OGREnvelope;
 /// Axis aligned 3D bounding box.
 pub type Envelope3D = gdal_sys::OGREnvelope3D;
 
-pub use ops::ToGdal;
+#[cfg(any(major_ge_4, all(major_is_3, minor_ge_9)))]
+pub use ops::PrecisionFlags;
+pub use ops::{ToGdal, WkbVariant, ZPolicy};
@@ -1,12 +1,62 @@
+use crate::cpl::CslStringList;
 use crate::errors::GdalError;
 use crate::errors::Result;
+use crate::spatial_ref::SpatialRef;
 use crate::utils::{_last_null_pointer_err, _string};
 use crate::vector::Geometry;
 use gdal_sys::OGRErr;
 use libc::c_char;
+use std::borrow::Cow;
 use std::ffi::{c_void, CString};
 use std::ptr::null_mut;
 
+/// The flavors of WKB supported by [`Geometry::to_wkb`].
+///
+/// They differ in how geometries with Z or M coordinates are encoded.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WkbVariant {
+    /// The ISO SQL/MM variant, where e.g. a 3D point has the type code 1001.
+    Iso,
+    /// The variant of the OGC 99-049 specification, which sets the high bit of the type code
+    /// for 3D geometries. This is the one used by [`Geometry::wkb`].
+    OldOgc,
+    /// The PostGIS extended WKB (EWKB) variant, which also includes the EPSG code of the
+    /// spatial reference of the geometry as SRID, if it has one.
+    Ewkb,
+}
+
+const EWKB_Z_FLAG: u32 = 0x8000_0000;
+const EWKB_M_FLAG: u32 = 0x4000_0000;
+const EWKB_SRID_FLAG: u32 = 0x2000_0000;
+
+/// If `wkb` is an EWKB geometry with a SRID, returns it without the SRID, and the SRID.
+fn split_ewkb_srid(wkb: &[u8]) -> Option<(Vec<u8>, u32)> {
+    if wkb.len() < 9 {
+        return None;
+    }
+    let read_u32 = |bytes: &[u8]| {
+        let bytes = bytes.try_into().unwrap();
+        match wkb[0] {
+            0 => u32::from_be_bytes(bytes),
+            _ => u32::from_le_bytes(bytes),
+        }
+    };
+    let geometry_type = read_u32(&wkb[1..5]);
+    if geometry_type & EWKB_SRID_FLAG == 0 {
+        return None;
+    }
+    let srid = read_u32(&wkb[5..9]);
+    let geometry_type = geometry_type & !EWKB_SRID_FLAG;
+    let mut stripped = Vec::with_capacity(wkb.len() - 4);
+    stripped.push(wkb[0]);
+    match wkb[0] {
+        0 => stripped.extend_from_slice(&geometry_type.to_be_bytes()),
+        _ => stripped.extend_from_slice(&geometry_type.to_le_bytes()),
+    }
+    stripped.extend_from_slice(&wkb[9..]);
+    Some((stripped, srid))
+}
+
 /// Methods supporting translation between GDAL [`Geometry`] and various text representations.
 ///
 /// These include:
@@ -38,7 +88,14 @@ impl Geometry {
     /// Creates a geometry by parsing a slice of bytes in
     /// [WKB](https://en.wikipedia.org/wiki/Well-known_text_representation_of_geometry#Well-known_binary)
     /// (Well-Known Binary) format.
+    ///
+    /// All the [`WkbVariant`]s are supported. If the geometry is PostGIS EWKB with a SRID, the
+    /// spatial reference of the result is set to the matching EPSG code.
     pub fn from_wkb(wkb: &[u8]) -> Result<Geometry> {
+        let (wkb, srid) = match split_ewkb_srid(wkb) {
+            Some((wkb, srid)) => (Cow::Owned(wkb), Some(srid)),
+            None => (Cow::Borrowed(wkb), None),
+        };
         let mut c_geom = null_mut();
         let rv = unsafe {
             gdal_sys::OGR_G_CreateFromWkb(
@@ -54,7 +111,12 @@ impl Geometry {
                 method_name: "OGR_G_CreateFromWkb",
             });
         }
-        Ok(unsafe { Geometry::with_c_geometry(c_geom, true) })
+        let mut geometry = unsafe { Geometry::with_c_geometry(c_geom, true) };
+        // PostGIS uses 0 for an unknown SRID
+        if let Some(srid) = srid.filter(|&srid| srid != 0) {
+            geometry.set_spatial_ref(SpatialRef::from_epsg(srid)?);
+        }
+        Ok(geometry)
     }
 
     /// Create a geometry by parsing a
@@ -94,26 +156,125 @@ impl Geometry {
         Ok(wkt)
     }
 
+    /// Serialize the geometry as WKT, after transforming it into `spatial_ref`.
+    ///
+    /// The geometry must have a spatial reference. It is left unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```rust, no_run
+    /// # fn main() -> gdal::errors::Result<()> {
+    /// use gdal::spatial_ref::SpatialRef;
+    /// use gdal::vector::Geometry;
+    /// let mut point = Geometry::from_wkt("POINT (1 2)")?;
+    /// point.set_spatial_ref(SpatialRef::from_definition("OGC:CRS84")?);
+    /// let wkt = point.to_wkt_in(&SpatialRef::from_epsg(3857)?)?;
+    /// assert!(wkt.starts_with("POINT (111319.49"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn to_wkt_in(&self, spatial_ref: &SpatialRef) -> Result<String> {
+        self.transform_to(spatial_ref)?.wkt()
+    }
+
     /// Serializes the geometry to
     /// [WKB](https://en.wikipedia.org/wiki/Well-known_text_representation_of_geometry#Well-known_binary)
     /// (Well-Known Binary) format.
+    ///
+    /// This uses the [`WkbVariant::OldOgc`] variant, see [`to_wkb`](Self::to_wkb) for the others.
     pub fn wkb(&self) -> Result<Vec<u8>> {
+        self.to_wkb(WkbVariant::OldOgc)
+    }
+
+    /// Serializes the geometry to WKB, using the given variant.
+    ///
+    /// The result is little-endian.
+    ///
+    /// # Example
+    ///
+    /// ```rust, no_run
+    /// # fn main() -> gdal::errors::Result<()> {
+    /// use gdal::spatial_ref::SpatialRef;
+    /// use gdal::vector::{Geometry, WkbVariant};
+    /// let mut point = Geometry::from_wkt("POINT Z (1 2 3)")?;
+    /// point.set_spatial_ref(SpatialRef::from_epsg(4326)?);
+    /// let ewkb = point.to_wkb(WkbVariant::Ewkb)?;
+    /// assert_eq!(ewkb.len(), point.wkb_size(WkbVariant::Ewkb));
+    /// let copy = Geometry::from_wkb(&ewkb)?;
+    /// assert_eq!(copy.spatial_ref().unwrap().auth_code()?, 4326);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn to_wkb(&self, variant: WkbVariant) -> Result<Vec<u8>> {
         let wkb_size = unsafe { gdal_sys::OGR_G_WkbSize(self.c_geometry()) as usize };
         // We default to little-endian for now. A WKB string explicitly indicates the byte
         // order, so this is not a problem for interoperability.
         let byte_order = gdal_sys::OGRwkbByteOrder::wkbNDR;
         let mut wkb = vec![0; wkb_size];
-        let rv =
-            unsafe { gdal_sys::OGR_G_ExportToWkb(self.c_geometry(), byte_order, wkb.as_mut_ptr()) };
+        let (rv, method_name) = match variant {
+            WkbVariant::Iso => (
+                unsafe {
+                    gdal_sys::OGR_G_ExportToIsoWkb(self.c_geometry(), byte_order, wkb.as_mut_ptr())
+                },
+                "OGR_G_ExportToIsoWkb",
+            ),
+            WkbVariant::OldOgc | WkbVariant::Ewkb => (
+                unsafe {
+                    gdal_sys::OGR_G_ExportToWkb(self.c_geometry(), byte_order, wkb.as_mut_ptr())
+                },
+                "OGR_G_ExportToWkb",
+            ),
+        };
         if rv != gdal_sys::OGRErr::OGRERR_NONE {
             return Err(GdalError::OgrError {
                 err: rv,
-                method_name: "OGR_G_ExportToWkb",
+                method_name,
             });
         }
+        if variant == WkbVariant::Ewkb {
+            self.set_ewkb_header(&mut wkb);
+        }
         Ok(wkb)
     }
 
+    /// Returns the size in bytes of the WKB representation of the geometry, in the given
+    /// variant.
+    pub fn wkb_size(&self, variant: WkbVariant) -> usize {
+        let wkb_size = unsafe { gdal_sys::OGR_G_WkbSize(self.c_geometry()) as usize };
+        if variant == WkbVariant::Ewkb && self.epsg_code().is_some() {
+            wkb_size + 4
+        } else {
+            wkb_size
+        }
+    }
+
+    /// Returns the EPSG code of the spatial reference of the geometry, if it has one.
+    fn epsg_code(&self) -> Option<i32> {
+        let spatial_ref = self.spatial_ref()?;
+        match spatial_ref.auth_name() {
+            Ok(name) if name == "EPSG" => spatial_ref.auth_code().ok(),
+            _ => None,
+        }
+    }
+
+    /// Rewrite the type of a little-endian WKB geometry with the EWKB flags, and insert its
+    /// SRID after it.
+    fn set_ewkb_header(&self, wkb: &mut Vec<u8>) {
+        let geometry_type = self.geometry_type();
+        let mut ewkb_type = unsafe { gdal_sys::OGR_GT_Flatten(geometry_type) };
+        if unsafe { gdal_sys::OGR_GT_HasZ(geometry_type) } != 0 {
+            ewkb_type |= EWKB_Z_FLAG;
+        }
+        if unsafe { gdal_sys::OGR_GT_HasM(geometry_type) } != 0 {
+            ewkb_type |= EWKB_M_FLAG;
+        }
+        if let Some(srid) = self.epsg_code() {
+            ewkb_type |= EWKB_SRID_FLAG;
+            wkb.splice(5..5, srid.to_le_bytes());
+        }
+        wkb[1..5].copy_from_slice(&ewkb_type.to_le_bytes());
+    }
+
     /// Serialize the geometry as GeoJSON.
     ///
     /// See: [`OGR_G_ExportToJson`](https://gdal.org/api/vector_c_api.html#_CPPv418OGR_G_ExportToJson12OGRGeometryH)
@@ -126,6 +287,75 @@ impl Geometry {
         unsafe { gdal_sys::VSIFree(c_json as *mut c_void) };
         Ok(rv)
     }
+
+    /// Serialize the geometry as GeoJSON, with export options.
+    ///
+    /// The supported options include:
+    /// * `COORDINATE_PRECISION=int`: the maximum number of figures after the decimal separator
+    /// * `SIGNIFICANT_FIGURES=int`: the maximum number of significant figures
+    ///
+    /// See: [`OGR_G_ExportToJsonEx`](https://gdal.org/api/vector_c_api.html#_CPPv420OGR_G_ExportToJsonEx12OGRGeometryHPPc)
+    ///
+    /// # Example
+    ///
+    /// ```rust, no_run
+    /// # fn main() -> gdal::errors::Result<()> {
+    /// use gdal::cpl::CslStringList;
+    /// use gdal::vector::Geometry;
+    /// let point = Geometry::from_wkt("POINT (1.23456 2.34567)")?;
+    /// let mut options = CslStringList::new();
+    /// options.set_name_value("COORDINATE_PRECISION", "2")?;
+    /// assert!(point.to_geojson(&options)?.contains("1.23"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn to_geojson(&self, options: &CslStringList) -> Result<String> {
+        let c_json = unsafe { gdal_sys::OGR_G_ExportToJsonEx(self.c_geometry(), options.as_ptr()) };
+        if c_json.is_null() {
+            return Err(_last_null_pointer_err("OGR_G_ExportToJsonEx"));
+        };
+        let rv = _string(c_json);
+        unsafe { gdal_sys::VSIFree(c_json as *mut c_void) };
+        Ok(rv)
+    }
+
+    /// Serialize the geometry as GML, with export options.
+    ///
+    /// The supported options include:
+    /// * `FORMAT=GML2|GML3|GML32`: the GML version, GML 2.1.1 by default
+    /// * `GML3_LONGSRS=YES|NO`: whether to write the SRS as an URN, for GML 3
+    /// * `SRSNAME_FORMAT=SHORT|OGC_URN|OGC_URL`: how to write the SRS, for GML 3
+    /// * `COORD_SWAP=AUTO|YES|NO`: whether to swap the coordinates to match the axis order of
+    ///   the SRS
+    /// * `GMLID=string`: the `gml:id` of the geometry, for GML 3.2
+    /// * `NAMESPACE_DECL=YES|NO`: whether to declare the GML namespace
+    ///
+    /// See: [`OGR_G_ExportToGMLEx`](https://gdal.org/api/vector_c_api.html#_CPPv419OGR_G_ExportToGMLEx12OGRGeometryHPPc)
+    pub fn to_gml(&self, options: &CslStringList) -> Result<String> {
+        let c_gml = unsafe { gdal_sys::OGR_G_ExportToGMLEx(self.c_geometry(), options.as_ptr()) };
+        if c_gml.is_null() {
+            return Err(_last_null_pointer_err("OGR_G_ExportToGMLEx"));
+        };
+        let rv = _string(c_gml);
+        unsafe { gdal_sys::VSIFree(c_gml as *mut c_void) };
+        Ok(rv)
+    }
+
+    /// Serialize the geometry as a
+    /// [KML](https://en.wikipedia.org/wiki/Keyhole_Markup_Language) geometry element.
+    ///
+    /// The coordinates are written as is, they should be in WGS 84 longitude and latitude.
+    ///
+    /// See: [`OGR_G_ExportToKML`](https://gdal.org/api/vector_c_api.html#_CPPv417OGR_G_ExportToKML12OGRGeometryHPKc)
+    pub fn to_kml(&self) -> Result<String> {
+        let c_kml = unsafe { gdal_sys::OGR_G_ExportToKML(self.c_geometry(), null_mut()) };
+        if c_kml.is_null() {
+            return Err(_last_null_pointer_err("OGR_G_ExportToKML"));
+        };
+        let rv = _string(c_kml);
+        unsafe { gdal_sys::VSIFree(c_kml as *mut c_void) };
+        Ok(rv)
+    }
 }
 
 #[cfg(test)]
@@ -141,6 +371,45 @@ mod tests {
         assert_eq!(new_geom, orig_geom);
     }
 
+    #[test]
+    pub fn test_wkb_variants() {
+        let mut geom = Geometry::from_wkt("POINT Z (1 2 3)").unwrap();
+        assert_eq!(geom.wkb_size(WkbVariant::Iso), 29);
+
+        let iso = geom.to_wkb(WkbVariant::Iso).unwrap();
+        assert_eq!(iso[..5], [1, 0xE9, 0x03, 0, 0]);
+        let old_ogc = geom.to_wkb(WkbVariant::OldOgc).unwrap();
+        assert_eq!(old_ogc[..5], [1, 1, 0, 0, 0x80]);
+        assert_eq!(old_ogc, geom.wkb().unwrap());
+        // without a spatial reference, EWKB is the same as the OGC variant
+        assert_eq!(geom.to_wkb(WkbVariant::Ewkb).unwrap(), old_ogc);
+
+        geom.set_spatial_ref(SpatialRef::from_epsg(4326).unwrap());
+        assert_eq!(geom.wkb_size(WkbVariant::Ewkb), 33);
+        let ewkb = geom.to_wkb(WkbVariant::Ewkb).unwrap();
+        assert_eq!(ewkb.len(), 33);
+        assert_eq!(ewkb[..9], [1, 1, 0, 0, 0xA0, 0xE6, 0x10, 0, 0]);
+        assert_eq!(ewkb[9..], old_ogc[5..]);
+
+        for wkb in [iso, old_ogc, ewkb.clone()] {
+            assert_eq!(Geometry::from_wkb(&wkb).unwrap(), geom);
+        }
+        let copy = Geometry::from_wkb(&ewkb).unwrap();
+        assert_eq!(copy.spatial_ref().unwrap().auth_code().unwrap(), 4326);
+    }
+
+    #[test]
+    pub fn test_wkt_in() {
+        let web_mercator = SpatialRef::from_epsg(3857).unwrap();
+        let mut geom = Geometry::from_wkt("POINT (1 2)").unwrap();
+        assert!(geom.to_wkt_in(&web_mercator).is_err());
+
+        geom.set_spatial_ref(SpatialRef::from_definition("OGC:CRS84").unwrap());
+        let wkt = geom.to_wkt_in(&web_mercator).unwrap();
+        assert!(wkt.starts_with("POINT (111319.49"), "{wkt}");
+        assert_eq!(geom.wkt().unwrap(), "POINT (1 2)");
+    }
+
     #[test]
     pub fn test_geojson() {
         let json = r#"{ "type": "Point", "coordinates": [10, 20] }"#;
@@ -154,6 +423,38 @@ mod tests {
         assert!(res.is_err());
     }
 
+    #[test]
+    pub fn test_to_geojson() {
+        let geom = Geometry::from_wkt("POINT (1.23456 2.34567)").unwrap();
+        let json = geom.to_geojson(&CslStringList::new()).unwrap();
+        assert_eq!(json, geom.json().unwrap());
+
+        let mut options = CslStringList::new();
+        options.set_name_value("COORDINATE_PRECISION", "2").unwrap();
+        let json = geom.to_geojson(&options).unwrap();
+        assert!(json.contains("1.23") && !json.contains("1.234"), "{json}");
+        assert_eq!(Geometry::from_geojson(&json).unwrap().get_point(0).1, 2.35);
+    }
+
+    #[test]
+    pub fn test_to_gml_and_kml() {
+        let geom = Geometry::from_wkt("POINT (1 2)").unwrap();
+        let gml = geom.to_gml(&CslStringList::new()).unwrap();
+        assert!(
+            gml.contains("<gml:coordinates>1,2</gml:coordinates>"),
+            "{gml}"
+        );
+
+        let mut options = CslStringList::new();
+        options.set_name_value("FORMAT", "GML3").unwrap();
+        let gml = geom.to_gml(&options).unwrap();
+        assert!(gml.contains("<gml:pos>1 2</gml:pos>"), "{gml}");
+        assert_eq!(Geometry::from_gml(&gml).unwrap(), geom);
+
+        let kml = geom.to_kml().unwrap();
+        assert!(kml.contains("<coordinates>1,2</coordinates>"), "{kml}");
+    }
+
     #[test]
     pub fn test_gml() {
         let json = r#"<gml:Point xmlns:gml="http://www.opengis.net/gml"><gml:coordinates>10,20</gml:coordinates></gml:Point>"#;
@@ -2,7 +2,13 @@ mod formats;
 mod gdal_to_geo;
 mod geo_to_gdal;
 
-use crate::errors::Result;
+pub use formats::WkbVariant;
+
+use std::convert::TryFrom;
+
+use geo_types::CoordFloat;
+
+use crate::errors::{GdalError, Result};
 use crate::vector::Geometry;
 
 /// Convert object to a GDAL geometry.
@@ -10,11 +16,58 @@ pub trait ToGdal {
     fn to_gdal(&self) -> Result<Geometry>;
 }
 
+/// How to convert geometries with Z or M coordinates to `geo-types` geometries, which are 2D.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ZPolicy {
+    /// Fail with [`GdalError::UnsupportedGdalGeometryType`].
+    Reject,
+    /// Keep the X and Y coordinates only.
+    Drop,
+}
+
 impl Geometry {
     /// Create a copy of self as a `geo-types` geometry.
+    ///
+    /// Fails if the geometry has Z or M coordinates, see [`to_geo_with`](Self::to_geo_with) to
+    /// drop them instead.
     pub fn to_geo(&self) -> Result<geo_types::Geometry<f64>> {
         self.try_into()
     }
+
+    /// Create a copy of self as a `geo-types` geometry, handling Z and M coordinates according
+    /// to `z_policy`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use gdal::vector::{Geometry, ZPolicy};
+    /// # fn main() -> gdal::errors::Result<()> {
+    /// let geom = Geometry::from_wkt("POINT Z (1 2 3)")?;
+    /// assert!(geom.to_geo().is_err());
+    /// let point = geom.to_geo_with(ZPolicy::Drop)?;
+    /// assert_eq!(point, geo_types::Geometry::Point(geo_types::point!(x: 1., y: 2.)));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn to_geo_with(&self, z_policy: ZPolicy) -> Result<geo_types::Geometry<f64>> {
+        gdal_to_geo::geometry_to_geo(self, z_policy)
+    }
+}
+
+impl<T: CoordFloat> TryFrom<&geo_types::Geometry<T>> for Geometry {
+    type Error = GdalError;
+
+    fn try_from(geo: &geo_types::Geometry<T>) -> Result<Geometry> {
+        geo.to_gdal()
+    }
+}
+
+impl<T: CoordFloat> TryFrom<geo_types::Geometry<T>> for Geometry {
+    type Error = GdalError;
+
+    fn try_from(geo: geo_types::Geometry<T>) -> Result<Geometry> {
+        geo.to_gdal()
+    }
 }
 
 #[cfg(test)]
@@ -164,6 +217,39 @@ mod tests {
         assert_eq!(geo.to_gdal().unwrap().wkt().unwrap(), wkt);
     }
 
+    #[test]
+    fn test_z_policy() {
+        use super::ZPolicy;
+
+        let geom = Geometry::from_wkt("MULTIPOLYGON Z (((0 0 1,0 1 1,1 1 1,0 0 1)))").unwrap();
+        assert!(geo_types::Geometry::<f64>::try_from(&geom).is_err());
+        assert!(geom.to_geo_with(ZPolicy::Reject).is_err());
+
+        let polygon = geo_types::Polygon::new(
+            geo_types::LineString::from(vec![(0., 0.), (0., 1.), (1., 1.), (0., 0.)]),
+            vec![],
+        );
+        assert_eq!(
+            geom.to_geo_with(ZPolicy::Drop).unwrap(),
+            geo_types::Geometry::MultiPolygon(geo_types::MultiPolygon(vec![polygon]))
+        );
+
+        let geom = Geometry::from_wkt("LINESTRING M (0 0 5,1 1 6)").unwrap();
+        assert_eq!(
+            geom.to_geo_with(ZPolicy::Drop).unwrap(),
+            geo_types::Geometry::LineString(vec![(0., 0.), (1., 1.)].into())
+        );
+    }
+
+    #[test]
+    fn test_try_from_geo() {
+        let geo = geo_types::Geometry::Point(geo_types::Point::new(1., 2.));
+        let geom = Geometry::try_from(&geo).unwrap();
+        assert_eq!(geom.wkt().unwrap(), "POINT (1 2)");
+        let geom = Geometry::try_from(geo).unwrap();
+        assert_eq!(geom.wkt().unwrap(), "POINT (1 2)");
+    }
+
     #[test]
     fn test_import_export_geometrycollection() {
         let wkt = "GEOMETRYCOLLECTION (POINT (1 2),LINESTRING (0 0,0 1,1 2))";
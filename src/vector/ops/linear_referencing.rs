@@ -0,0 +1,181 @@
+use gdal_sys::OGRwkbGeometryType;
+
+use crate::errors::{GdalError, Result};
+use crate::utils::_last_null_pointer_err;
+use crate::vector::Geometry;
+
+/// # Linear Referencing
+///
+/// These methods locate points and sections along linear geometries, by their distance
+/// from the start of the line.
+impl Geometry {
+    /// Get the point at `distance` along this curve, clamped to its ends.
+    ///
+    /// See: [`OGR_G_Value`](https://gdal.org/api/vector_c_api.html#_CPPv411OGR_G_Value12OGRGeometryHd)
+    pub fn value_at_distance(&self, distance: f64) -> Result<Geometry> {
+        let c_geom = unsafe { gdal_sys::OGR_G_Value(self.c_geometry(), distance) };
+        if c_geom.is_null() {
+            return Err(_last_null_pointer_err("OGR_G_Value"));
+        }
+        Ok(unsafe { Geometry::with_c_geometry(c_geom, true) })
+    }
+
+    /// Get the distance along this line string of the point of the line closest to `point`.
+    ///
+    /// Only line strings are supported, and the distance is computed in 2D.
+    pub fn project_point(&self, point: &Geometry) -> Result<f64> {
+        let vertices = self.line_string_vertices("project_point")?;
+        let (px, py, _) = point.get_point(0);
+
+        let mut best = (f64::INFINITY, 0.);
+        let mut start_distance = 0.;
+        for segment in vertices.windows(2) {
+            let (ax, ay, _) = segment[0];
+            let (bx, by, _) = segment[1];
+            let (dx, dy) = (bx - ax, by - ay);
+            let length = dx.hypot(dy);
+            let t = if length > 0. {
+                (((px - ax) * dx + (py - ay) * dy) / (length * length)).clamp(0., 1.)
+            } else {
+                0.
+            };
+            let offset = (ax + t * dx - px).hypot(ay + t * dy - py);
+            if offset < best.0 {
+                best = (offset, start_distance + t * length);
+            }
+            start_distance += length;
+        }
+        Ok(best.1)
+    }
+
+    /// Get the section of this line string between the distances `start` and `end`
+    /// from its start, clamped to its ends.
+    ///
+    /// Only line strings are supported. Z values are interpolated along with the coordinates.
+    pub fn substring(&self, start: f64, end: f64) -> Result<Geometry> {
+        let vertices = self.line_string_vertices("substring")?;
+        if start > end {
+            return Err(GdalError::BadArgument(format!(
+                "substring start {start} is after its end {end}"
+            )));
+        }
+
+        let interpolate = |a: (f64, f64, f64), b: (f64, f64, f64), t: f64| {
+            (
+                a.0 + t * (b.0 - a.0),
+                a.1 + t * (b.1 - a.1),
+                a.2 + t * (b.2 - a.2),
+            )
+        };
+        let mut points = Vec::new();
+        let mut start_distance = 0.;
+        for (i, segment) in vertices.windows(2).enumerate() {
+            let (a, b) = (segment[0], segment[1]);
+            let length = (b.0 - a.0).hypot(b.1 - a.1);
+            let end_distance = start_distance + length;
+            let is_last = i == vertices.len() - 2;
+            if points.is_empty() && (start <= end_distance || is_last) {
+                let t = if length > 0. {
+                    ((start - start_distance) / length).clamp(0., 1.)
+                } else {
+                    0.
+                };
+                points.push(interpolate(a, b, t));
+            }
+            if end <= end_distance || is_last {
+                let t = if length > 0. {
+                    ((end - start_distance) / length).clamp(0., 1.)
+                } else {
+                    1.
+                };
+                points.push(interpolate(a, b, t));
+                break;
+            }
+            if !points.is_empty() {
+                points.push(b);
+            }
+            start_distance = end_distance;
+        }
+
+        let mut substring = Geometry::empty(self.geometry_type())?;
+        let has_z = unsafe { gdal_sys::OGR_GT_HasZ(self.geometry_type()) } != 0;
+        for (x, y, z) in points {
+            if has_z {
+                substring.add_point((x, y, z));
+            } else {
+                substring.add_point_2d((x, y));
+            }
+        }
+        if let Some(spatial_ref) = self.spatial_ref() {
+            substring.set_spatial_ref(spatial_ref);
+        }
+        Ok(substring)
+    }
+
+    /// Get the vertices of this geometry, which must be a non-empty line string.
+    fn line_string_vertices(&self, method: &str) -> Result<Vec<(f64, f64, f64)>> {
+        let flat_type = unsafe { gdal_sys::OGR_GT_Flatten(self.geometry_type()) };
+        if flat_type != OGRwkbGeometryType::wkbLineString {
+            return Err(GdalError::BadArgument(format!(
+                "{method} is only supported on line strings, not {}",
+                self.geometry_name()
+            )));
+        }
+        let vertices = self.get_point_vec();
+        if vertices.len() < 2 {
+            return Err(GdalError::BadArgument(format!(
+                "{method} requires a line string with at least two points"
+            )));
+        }
+        Ok(vertices)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_value_at_distance() -> Result<()> {
+        let line = Geometry::from_wkt("LINESTRING (0 0,10 0,10 10)")?;
+        assert_eq!(line.value_at_distance(15.)?.wkt()?, "POINT (10 5)");
+        assert_eq!(line.value_at_distance(50.)?.wkt()?, "POINT (10 10)");
+        Ok(())
+    }
+
+    #[test]
+    fn test_project_point() -> Result<()> {
+        let line = Geometry::from_wkt("LINESTRING (0 0,10 0,10 10)")?;
+        let point = Geometry::from_wkt("POINT (12 4)")?;
+        assert_eq!(line.project_point(&point)?, 14.);
+        let point = Geometry::from_wkt("POINT (-5 -5)")?;
+        assert_eq!(line.project_point(&point)?, 0.);
+
+        let polygon = Geometry::from_wkt("POLYGON ((0 0,1 0,1 1,0 0))")?;
+        assert!(polygon.project_point(&point).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_substring() -> Result<()> {
+        let line = Geometry::from_wkt("LINESTRING (0 0,10 0,10 10)")?;
+        assert_eq!(
+            line.substring(5., 15.)?.wkt()?,
+            "LINESTRING (5 0,10 0,10 5)"
+        );
+        assert_eq!(line.substring(2., 4.)?.wkt()?, "LINESTRING (2 0,4 0)");
+        assert_eq!(
+            line.substring(-1., 100.)?.wkt()?,
+            "LINESTRING (0 0,10 0,10 10)"
+        );
+
+        let line = Geometry::from_wkt("LINESTRING Z (0 0 0,10 0 100)")?;
+        assert_eq!(
+            line.substring(5., 10.)?.wkt()?,
+            "LINESTRING (5 0 50,10 0 100)"
+        );
+
+        assert!(line.substring(5., 1.).is_err());
+        Ok(())
+    }
+}
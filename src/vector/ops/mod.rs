@@ -1,6 +1,9 @@
 mod conversions;
+mod linear_referencing;
 mod predicates;
 mod set;
 mod transformations;
 
-pub use conversions::ToGdal;
+pub use conversions::{ToGdal, WkbVariant, ZPolicy};
+#[cfg(any(major_ge_4, all(major_is_3, minor_ge_9)))]
+pub use transformations::PrecisionFlags;
@@ -109,6 +109,42 @@ impl Geometry {
             Some(Geometry::with_c_geometry(ogr_geom, true))
         }
     }
+
+    /// Computes the [symmetric difference][sym_difference] of `self` and `other`.
+    ///
+    /// Generates a new geometry which is the region covered by exactly one of the two geometries
+    /// operated on.
+    ///
+    /// # Notes
+    /// * Geometry validity is not checked, and invalid geometry will generate unpredictable results.
+    ///   Use [`Geometry::is_valid`] if validity might be in question.
+    /// * If GEOS is *not* enabled, this function will always return `None`.
+    ///   You may check for GEOS support with [`VersionInfo::has_geos`][has_geos].
+    ///
+    /// # Returns
+    /// * `Some(geometry)`: a new `Geometry` representing the computed symmetric difference
+    /// * `None`: when the symmetric difference could not be computed
+    ///
+    /// See: [`OGR_G_SymDifference`][OGR_G_SymDifference]
+    ///
+    /// [OGR_G_SymDifference]: https://gdal.org/api/vector_c_api.html#_CPPv419OGR_G_SymDifference12OGRGeometryH12OGRGeometryH
+    /// [sym_difference]: https://en.wikipedia.org/wiki/Symmetric_difference
+    /// [has_geos]: crate::version::VersionInfo::has_geos
+    pub fn sym_difference(&self, other: &Self) -> Option<Self> {
+        if !self.has_gdal_ptr() {
+            return None;
+        }
+        if !other.has_gdal_ptr() {
+            return None;
+        }
+        unsafe {
+            let ogr_geom = gdal_sys::OGR_G_SymDifference(self.c_geometry(), other.c_geometry());
+            if ogr_geom.is_null() {
+                return None;
+            }
+            Some(Geometry::with_c_geometry(ogr_geom, true))
+        }
+    }
 }
 
 #[cfg(test)]
@@ -244,4 +280,19 @@ mod tests {
         let res = geom.difference(&other);
         assert_eq!(res.unwrap().area(), 25.0);
     }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn test_sym_difference() {
+        let geom =
+            Geometry::from_wkt("POLYGON ((0.0 10.0, 0.0 0.0, 10.0 0.0, 10.0 10.0, 0.0 10.0))")
+                .unwrap();
+        let other = Geometry::from_wkt("POLYGON ((1 -5, 1 1, -5 1, -5 -5, 1 -5))").unwrap();
+
+        let res = geom.sym_difference(&other).unwrap();
+        assert_eq!(res.area(), 134.0);
+
+        let other = unsafe { Geometry::lazy_feature_geometry() };
+        assert!(geom.sym_difference(&other).is_none());
+    }
 }
@@ -1,4 +1,7 @@
+#[cfg(any(major_ge_4, all(major_is_3, minor_ge_9)))]
+use bitflags::bitflags;
 use gdal_sys::OGRErr;
+use libc::c_int;
 
 use crate::cpl::CslStringList;
 use crate::errors::{GdalError, Result};
@@ -7,6 +10,20 @@ use crate::spatial_ref::SpatialRef;
 use crate::utils::_last_null_pointer_err;
 use crate::vector::Geometry;
 
+#[cfg(any(major_ge_4, all(major_is_3, minor_ge_9)))]
+bitflags! {
+    /// Flags of [`Geometry::set_precision`].
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+    pub struct PrecisionFlags: libc::c_int {
+        /// Round the coordinates without preserving the topology, which is faster but may
+        /// make the geometry invalid.
+        const NO_TOPO = 1;
+        /// Keep the parts collapsed by the rounding, as geometries of lower dimension,
+        /// instead of removing them.
+        const KEEP_COLLAPSED = 2;
+    }
+}
+
 /// # Geometry Transformations
 ///
 /// These methods provide geometric transformations on a `Geometry`.
@@ -80,10 +97,49 @@ impl Geometry {
         Ok(unsafe { Geometry::with_c_geometry(c_geom, true) })
     }
 
-    /// Return a [Delaunay triangulation of][dt] the vertices of the geometry.
+    /// Compute the centroid of this geometry, as a point.
+    ///
+    /// For an empty geometry, the result is an empty point.
+    ///
+    /// See: [`OGR_G_Centroid`](https://gdal.org/api/vector_c_api.html#_CPPv414OGR_G_Centroid12OGRGeometryH12OGRGeometryH)
+    pub fn centroid(&self) -> Result<Geometry> {
+        let centroid = Geometry::empty(gdal_sys::OGRwkbGeometryType::wkbPoint)?;
+        let rv = unsafe { gdal_sys::OGR_G_Centroid(self.c_geometry(), centroid.c_geometry()) }
+            as OGRErr::Type;
+        if rv != OGRErr::OGRERR_NONE {
+            return Err(GdalError::OgrError {
+                err: rv,
+                method_name: "OGR_G_Centroid",
+            });
+        }
+        Ok(centroid)
+    }
+
+    /// Compute the boundary of this geometry, e.g. the rings of a polygon as a line string
+    /// or the end points of a line string.
+    ///
+    /// # Notes
+    /// This function requires the GEOS library, except for simple cases.
+    /// Check with [`VersionInfo::has_geos`][has_geos].
+    ///
+    /// See: [`OGR_G_Boundary`](https://gdal.org/api/vector_c_api.html#_CPPv414OGR_G_Boundary12OGRGeometryH)
+    ///
+    /// [has_geos]: crate::version::VersionInfo::has_geos
+    pub fn boundary(&self) -> Result<Geometry> {
+        let c_geom = unsafe { gdal_sys::OGR_G_Boundary(self.c_geometry()) };
+        if c_geom.is_null() {
+            return Err(_last_null_pointer_err("OGR_G_Boundary"));
+        };
+        Ok(unsafe { Geometry::with_c_geometry(c_geom, true) })
+    }
+
+    /// Return a [Delaunay triangulation of][dt] the vertices of the geometry, e.g. to build
+    /// a TIN from elevation points.
     ///
     /// # Arguments
     /// * `tolerance`: optional snapping tolerance to use for improved robustness
+    /// * `edges_only`: return the edges of the triangles, as a multi line string, instead of
+    ///   a collection of polygons
     ///
     /// # Notes
     /// This function requires GEOS library, v3.4 or above.
@@ -94,9 +150,13 @@ impl Geometry {
     ///
     /// [dt]: https://en.wikipedia.org/wiki/Delaunay_triangulation
     /// [has_geos]: crate::version::VersionInfo::has_geos
-    pub fn delaunay_triangulation(&self, tolerance: Option<f64>) -> Result<Self> {
+    pub fn delaunay_triangulation(&self, tolerance: Option<f64>, edges_only: bool) -> Result<Self> {
         let c_geom = unsafe {
-            gdal_sys::OGR_G_DelaunayTriangulation(self.c_geometry(), tolerance.unwrap_or(0.0), 0)
+            gdal_sys::OGR_G_DelaunayTriangulation(
+                self.c_geometry(),
+                tolerance.unwrap_or(0.0),
+                c_int::from(edges_only),
+            )
         };
         if c_geom.is_null() {
             return Err(_last_null_pointer_err("OGR_G_DelaunayTriangulation"));
@@ -105,6 +165,27 @@ impl Geometry {
         Ok(unsafe { Geometry::with_c_geometry(c_geom, true) })
     }
 
+    /// Build polygons from the line strings of this geometry collection or multi line
+    /// string, returned as a geometry collection of polygons.
+    ///
+    /// The lines must be noded, meaning that they only touch at their end points. Those
+    /// not forming closed rings are ignored.
+    ///
+    /// # Notes
+    /// This function requires the GEOS library.
+    /// Check with [`VersionInfo::has_geos`][has_geos].
+    ///
+    /// See: [`OGR_G_Polygonize`](https://gdal.org/api/vector_c_api.html#_CPPv416OGR_G_Polygonize12OGRGeometryH)
+    ///
+    /// [has_geos]: crate::version::VersionInfo::has_geos
+    pub fn polygonize(&self) -> Result<Geometry> {
+        let c_geom = unsafe { gdal_sys::OGR_G_Polygonize(self.c_geometry()) };
+        if c_geom.is_null() {
+            return Err(_last_null_pointer_err("OGR_G_Polygonize"));
+        };
+        Ok(unsafe { Geometry::with_c_geometry(c_geom, true) })
+    }
+
     /// Compute a simplified geometry.
     ///
     /// # Arguments
@@ -136,6 +217,49 @@ impl Geometry {
         Ok(unsafe { Geometry::with_c_geometry(c_geom, true) })
     }
 
+    /// Add vertices to the segments of this geometry, mutating it in-place, so that no
+    /// segment is longer than `max_length`.
+    ///
+    /// Curves are left unchanged, only their straight segments are split.
+    ///
+    /// See: [`OGR_G_Segmentize`](https://gdal.org/api/vector_c_api.html#_CPPv416OGR_G_Segmentize12OGRGeometryHd)
+    pub fn segmentize_inplace(&mut self, max_length: f64) {
+        unsafe { gdal_sys::OGR_G_Segmentize(self.c_geometry(), max_length) };
+    }
+
+    /// Return a copy of this geometry with vertices added so that no segment is longer
+    /// than `max_length`, e.g. to keep the shape of long lines once reprojected.
+    ///
+    /// See: [`OGR_G_Segmentize`](https://gdal.org/api/vector_c_api.html#_CPPv416OGR_G_Segmentize12OGRGeometryHd)
+    pub fn segmentize(&self, max_length: f64) -> Geometry {
+        let mut geometry = self.clone();
+        geometry.segmentize_inplace(max_length);
+        geometry
+    }
+
+    /// Return a copy of this geometry with its coordinates snapped to a grid of
+    /// `grid_size`, e.g. to store it in a format with a fixed precision.
+    ///
+    /// By default, the topology is preserved and the parts collapsed by the rounding are
+    /// removed, see [`PrecisionFlags`]. A `grid_size` of 0 keeps the full precision.
+    ///
+    /// # Notes
+    /// This function requires the GEOS library, v3.6 or above.
+    /// Check with [`VersionInfo::has_geos`][has_geos].
+    ///
+    /// See: [`OGR_G_SetPrecision`](https://gdal.org/api/vector_c_api.html#_CPPv418OGR_G_SetPrecision12OGRGeometryHdi)
+    ///
+    /// [has_geos]: crate::version::VersionInfo::has_geos
+    #[cfg(any(major_ge_4, all(major_is_3, minor_ge_9)))]
+    pub fn set_precision(&self, grid_size: f64, flags: PrecisionFlags) -> Result<Geometry> {
+        let c_geom =
+            unsafe { gdal_sys::OGR_G_SetPrecision(self.c_geometry(), grid_size, flags.bits()) };
+        if c_geom.is_null() {
+            return Err(_last_null_pointer_err("OGR_G_SetPrecision"));
+        };
+        Ok(unsafe { Geometry::with_c_geometry(c_geom, true) })
+    }
+
     /// Compute buffer of geometry
     ///
     /// # Arguments
@@ -205,12 +329,50 @@ impl Geometry {
             Ok(unsafe { Geometry::with_c_geometry(c_geom, true) })
         }
     }
+
+    /// Return a copy of the geometry in its normalized form, with the rings, vertices and
+    /// sub-geometries in a canonical order, so that equal geometries can be compared
+    /// vertex by vertex.
+    ///
+    /// # Notes
+    /// This function requires the GEOS library.
+    /// Check with [`VersionInfo::has_geos`][has_geos].
+    ///
+    /// See: [`OGR_G_Normalize`](https://gdal.org/api/vector_c_api.html#_CPPv415OGR_G_Normalize12OGRGeometryH)
+    ///
+    /// [has_geos]: crate::version::VersionInfo::has_geos
+    #[cfg(any(major_ge_4, all(major_is_3, minor_ge_3)))]
+    pub fn normalize(&self) -> Result<Geometry> {
+        let c_geom = unsafe { gdal_sys::OGR_G_Normalize(self.c_geometry()) };
+        if c_geom.is_null() {
+            return Err(_last_null_pointer_err("OGR_G_Normalize"));
+        };
+        Ok(unsafe { Geometry::with_c_geometry(c_geom, true) })
+    }
+
+    /// Return a copy of a geometry collection without the members of lower dimension than
+    /// the others, e.g. without the points and line strings of a collection that also has
+    /// polygons, as left behind by [`make_valid`](Self::make_valid) or
+    /// [`intersection`](Self::intersection).
+    ///
+    /// Other geometries are returned unchanged.
+    ///
+    /// See: [`OGR_G_RemoveLowerDimensionSubGeoms`](https://gdal.org/api/vector_c_api.html#_CPPv434OGR_G_RemoveLowerDimensionSubGeoms12OGRGeometryH)
+    #[cfg(any(major_ge_4, all(major_is_3, minor_ge_2)))]
+    pub fn remove_lower_dimension_sub_geoms(&self) -> Result<Geometry> {
+        let c_geom = unsafe { gdal_sys::OGR_G_RemoveLowerDimensionSubGeoms(self.c_geometry()) };
+        if c_geom.is_null() {
+            return Err(_last_null_pointer_err("OGR_G_RemoveLowerDimensionSubGeoms"));
+        };
+        Ok(unsafe { Geometry::with_c_geometry(c_geom, true) })
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::test_utils::SuppressGDALErrorLog;
+    use gdal_sys::OGRwkbGeometryType;
 
     #[test]
     fn test_convex_hull() {
@@ -233,7 +395,24 @@ mod tests {
         let triangles = Geometry::from_wkt(
             "GEOMETRYCOLLECTION (POLYGON ((0 1,0 0,1 0,0 1)),POLYGON ((0 1,1 0,1 1,0 1)))",
         )?;
-        assert_eq!(square.delaunay_triangulation(None)?, triangles);
+        assert_eq!(square.delaunay_triangulation(None, false)?, triangles);
+
+        let edges = square.delaunay_triangulation(None, true)?;
+        assert_eq!(
+            edges.geometry_type(),
+            OGRwkbGeometryType::wkbMultiLineString
+        );
+        assert_eq!(edges.geometry_count(), 5);
+        Ok(())
+    }
+
+    #[test]
+    fn test_polygonize() -> Result<()> {
+        let lines =
+            Geometry::from_wkt("MULTILINESTRING ((0 0,1 0),(1 0,1 1),(1 1,0 0),(1 1,2 2))")?;
+        let polygons = lines.polygonize()?;
+        assert_eq!(polygons.geometry_count(), 1);
+        assert_eq!(polygons.get_geometry(0).area(), 0.5);
         Ok(())
     }
 
@@ -253,6 +432,73 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_segmentize() -> Result<()> {
+        let line = Geometry::from_wkt("LINESTRING (0 0,10 0)")?;
+        let segmented = line.segmentize(2.5);
+        assert_eq!(segmented.point_count(), 5);
+        assert_eq!(segmented.get_point(1), (2.5, 0.0, 0.0));
+        assert_eq!(line.point_count(), 2);
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(any(major_ge_4, all(major_is_3, minor_ge_9)))]
+    fn test_set_precision() -> Result<()> {
+        let line = Geometry::from_wkt("LINESTRING (0.12 0.37,1.91 2.02)")?;
+        let expected = Geometry::from_wkt("LINESTRING (0 0.5,2 2)")?;
+        assert_eq!(
+            line.set_precision(0.5, PrecisionFlags::default())?,
+            expected
+        );
+
+        let polygon = Geometry::from_wkt("POLYGON ((0 0,0.1 0,0.1 0.1,0 0.1,0 0))")?;
+        assert!(polygon
+            .set_precision(1.0, PrecisionFlags::default())?
+            .is_empty());
+        Ok(())
+    }
+
+    #[test]
+    pub fn test_centroid_and_boundary() -> Result<()> {
+        let square = Geometry::from_wkt("POLYGON ((0 0,2 0,2 2,0 2,0 0))")?;
+        assert_eq!(square.centroid()?.wkt()?, "POINT (1 1)");
+        assert_eq!(
+            square.boundary()?.wkt()?,
+            "LINESTRING (0 0,2 0,2 2,0 2,0 0)"
+        );
+
+        let line = Geometry::from_wkt("LINESTRING (0 0,1 1,2 0)")?;
+        assert_eq!(line.boundary()?.wkt()?, "MULTIPOINT (0 0,2 0)");
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(any(major_ge_4, all(major_is_3, minor_ge_3)))]
+    pub fn test_normalize() -> Result<()> {
+        let square = Geometry::from_wkt("POLYGON ((1 1,0 1,0 0,1 0,1 1))")?;
+        assert_eq!(
+            square.normalize()?.wkt()?,
+            "POLYGON ((0 0,0 1,1 1,1 0,0 0))"
+        );
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(any(major_ge_4, all(major_is_3, minor_ge_2)))]
+    pub fn test_remove_lower_dimension_sub_geoms() -> Result<()> {
+        let collection = Geometry::from_wkt(
+            "GEOMETRYCOLLECTION (POINT (0 0),LINESTRING (0 0,1 1),POLYGON ((0 0,1 0,1 1,0 0)))",
+        )?;
+        assert_eq!(
+            collection.remove_lower_dimension_sub_geoms()?.wkt()?,
+            "GEOMETRYCOLLECTION (POLYGON ((0 0,1 0,1 1,0 0)))"
+        );
+        let line = Geometry::from_wkt("LINESTRING (0 0,1 1)")?;
+        assert_eq!(line.remove_lower_dimension_sub_geoms()?, line);
+        Ok(())
+    }
+
     #[test]
     pub fn test_buffer() {
         let geom = Geometry::from_wkt("POINT(0 0)").unwrap();
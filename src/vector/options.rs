@@ -1,5 +1,8 @@
+use bitflags::bitflags;
+
+use crate::cpl::CslStringList;
 use crate::spatial_ref::SpatialRef;
-use gdal_sys::OGRwkbGeometryType;
+use gdal_sys::{OGRFieldType, OGRwkbGeometryType};
 
 /// Parameters for [`crate::Dataset::create_layer`].
 #[derive(Clone, Debug)]
@@ -27,3 +30,196 @@ impl<'a> Default for LayerOptions<'a> {
         }
     }
 }
+
+/// A builder of the parameters of a new layer, for
+/// [`Dataset::create_layer_with_options`](crate::Dataset::create_layer_with_options).
+///
+/// Unlike [`LayerOptions`], it owns the layer creation options and sets the common ones
+/// through typed methods. Fields can be added too, so that the layer is created with its
+/// full schema.
+///
+/// # Example
+///
+/// ```rust, no_run
+/// use gdal::spatial_ref::SpatialRef;
+/// use gdal::vector::{LayerAccess, LayerCreationOptions, OGRFieldType, OGRwkbGeometryType};
+/// use gdal::DriverManager;
+/// # fn main() -> gdal::errors::Result<()> {
+/// let driver = DriverManager::get_driver_by_name("GPKG")?;
+/// let mut dataset = driver.create_vector_only("/vsimem/stations.gpkg")?;
+/// let srs = SpatialRef::from_epsg(4326)?;
+///
+/// let mut options = LayerCreationOptions::new("stations");
+/// options
+///     .with_srs(&srs)
+///     .with_geometry_type(OGRwkbGeometryType::wkbPoint)
+///     .with_dimensions(true, false)
+///     .with_fid_column("station_id")
+///     .with_geometry_name("location")
+///     .with_spatial_index(false)
+///     .with_field("name", OGRFieldType::OFTString);
+/// let layer = dataset.create_layer_with_options(&options)?;
+/// assert_eq!(layer.fid_column(), "station_id");
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct LayerCreationOptions<'a> {
+    pub(crate) name: String,
+    pub(crate) srs: Option<&'a SpatialRef>,
+    pub(crate) geometry_type: OGRwkbGeometryType::Type,
+    pub(crate) options: CslStringList,
+    pub(crate) fields: Vec<(String, OGRFieldType::Type)>,
+    pub(crate) approx_ok: bool,
+}
+
+impl<'a> LayerCreationOptions<'a> {
+    /// Returns creation options for a new layer called `name`, with no SRS and unknown
+    /// geometry type.
+    pub fn new(name: &str) -> Self {
+        LayerCreationOptions {
+            name: name.to_string(),
+            srs: None,
+            geometry_type: OGRwkbGeometryType::wkbUnknown,
+            options: CslStringList::new(),
+            fields: Vec::new(),
+            approx_ok: false,
+        }
+    }
+
+    /// Set the SRS of the layer.
+    pub fn with_srs(&mut self, srs: &'a SpatialRef) -> &mut Self {
+        self.srs = Some(srs);
+        self
+    }
+
+    /// Set the geometry type of the layer, e.g. [`wkbPolygon`](OGRwkbGeometryType::wkbPolygon)
+    /// or [`wkbNone`](OGRwkbGeometryType::wkbNone) for a table without geometries.
+    pub fn with_geometry_type(&mut self, geometry_type: OGRwkbGeometryType::Type) -> &mut Self {
+        self.geometry_type = geometry_type;
+        self
+    }
+
+    /// Set whether the geometries of the layer have Z and M coordinates, turning e.g. a
+    /// [`wkbPoint`](OGRwkbGeometryType::wkbPoint) layer into a
+    /// [`wkbPointZM`](OGRwkbGeometryType::wkbPointZM) one.
+    ///
+    /// Call it after [`with_geometry_type`](Self::with_geometry_type).
+    pub fn with_dimensions(&mut self, has_z: bool, has_m: bool) -> &mut Self {
+        self.geometry_type = unsafe {
+            gdal_sys::OGR_GT_SetModifier(
+                self.geometry_type,
+                libc::c_int::from(has_z),
+                libc::c_int::from(has_m),
+            )
+        };
+        self
+    }
+
+    /// Set the name of the geometry column (`GEOMETRY_NAME`), for the drivers that support it.
+    pub fn with_geometry_name(&mut self, name: &str) -> &mut Self {
+        self.with_option("GEOMETRY_NAME", name)
+    }
+
+    /// Set the name of the FID column (`FID`), for the drivers that support it.
+    pub fn with_fid_column(&mut self, name: &str) -> &mut Self {
+        self.with_option("FID", name)
+    }
+
+    /// Set whether a spatial index is created (`SPATIAL_INDEX`), for the drivers that support it.
+    pub fn with_spatial_index(&mut self, spatial_index: bool) -> &mut Self {
+        self.with_option("SPATIAL_INDEX", if spatial_index { "YES" } else { "NO" })
+    }
+
+    /// Set the driver-specific layer creation option `name`.
+    ///
+    /// See [`Driver::layer_creation_option_list`](crate::Driver::layer_creation_option_list)
+    /// for the supported options.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `name` or `value` contain NUL bytes, or `name` contains `=`.
+    pub fn with_option(&mut self, name: &str, value: &str) -> &mut Self {
+        self.options
+            .set_name_value(name, value)
+            .expect("invalid layer creation option");
+        self
+    }
+
+    /// Add a field called `name` to the layer once it's created.
+    pub fn with_field(&mut self, name: &str, field_type: OGRFieldType::Type) -> &mut Self {
+        self.fields.push((name.to_string(), field_type));
+        self
+    }
+
+    /// Set whether the driver may alter the names or types of the fields when it doesn't
+    /// support them as is, such as when truncating long names for shapefiles.
+    /// If `false` (the default), creating such fields fails.
+    pub fn with_approx_ok(&mut self, approx_ok: bool) -> &mut Self {
+        self.approx_ok = approx_ok;
+        self
+    }
+}
+
+// These are defined as macros in `ogr_core.h`, so they are skipped by bindgen.
+bitflags! {
+    /// The parts of a field definition to change in
+    /// [`LayerAccess::alter_field_defn`](crate::vector::LayerAccess::alter_field_defn).
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct AlterFieldFlags: libc::c_int {
+        /// Change the name.
+        const ALTER_NAME_FLAG = 0x1;
+        /// Change the type.
+        const ALTER_TYPE_FLAG = 0x2;
+        /// Change the width and precision.
+        const ALTER_WIDTH_PRECISION_FLAG = 0x4;
+        /// Change the nullable flag.
+        const ALTER_NULLABLE_FLAG = 0x8;
+        /// Change the default value.
+        const ALTER_DEFAULT_FLAG = 0x10;
+        /// Change the unique flag.
+        const ALTER_UNIQUE_FLAG = 0x20;
+        /// Change the field domain.
+        const ALTER_DOMAIN_FLAG = 0x40;
+        /// Change the alternative name.
+        const ALTER_ALTERNATIVE_NAME_FLAG = 0x80;
+        /// Change the comment.
+        const ALTER_COMMENT_FLAG = 0x100;
+        /// Change everything.
+        const ALTER_ALL_FLAG = 0x1ff;
+    }
+}
+
+bitflags! {
+    /// The parts of a geometry field definition to change in
+    /// [`LayerAccess::alter_geom_field_defn`](crate::vector::LayerAccess::alter_geom_field_defn).
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct AlterGeomFieldFlags: libc::c_int {
+        /// Change the name.
+        const ALTER_GEOM_FIELD_DEFN_NAME_FLAG = 0x1000;
+        /// Change the geometry type.
+        const ALTER_GEOM_FIELD_DEFN_TYPE_FLAG = 0x2000;
+        /// Change the nullable flag.
+        const ALTER_GEOM_FIELD_DEFN_NULLABLE_FLAG = 0x4000;
+        /// Change the spatial reference.
+        const ALTER_GEOM_FIELD_DEFN_SRS_FLAG = 0x8000;
+        /// Change the coordinate epoch of the spatial reference.
+        const ALTER_GEOM_FIELD_DEFN_SRS_COORD_EPOCH_FLAG = 0x10000;
+        /// Change everything.
+        const ALTER_GEOM_FIELD_DEFN_ALL_FLAG = 0x1f000;
+    }
+}
+
+/// Paging settings for [`crate::Dataset::open_with_read_tuning`], for scanning large
+/// (usually remote) layers efficiently.
+///
+/// Each setting is mapped to the corresponding option of the drivers that support it.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ReadTuning {
+    /// Number of features fetched per request or cursor batch. Applies to:
+    /// * PostgreSQL: the `OGR_PG_CURSOR_PAGE` configuration option
+    /// * WFS: the `OGR_WFS_PAGE_SIZE` configuration option, also enabling paging with `OGR_WFS_PAGING_ALLOWED`
+    /// * OGC API - Features: the `PAGE_SIZE` open option, when the dataset is opened with an
+    ///   `OAPIF:` connection string or with `OAPIF` as an allowed driver
+    pub page_size: Option<usize>,
+}
@@ -39,7 +39,8 @@ impl<'a> Drop for ResultSet<'a> {
 
 /// Represents valid SQL dialects to use in SQL queries. See
 /// <https://gdal.org/user/ogr_sql_sqlite_dialect.html>
-#[allow(clippy::upper_case_acronyms)]
+#[allow(clippy::upper_case_acronyms, non_camel_case_types)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum Dialect {
     /// Use the default dialect. This is OGR SQL unless the underlying driver has a native dialect,
     /// such as MySQL, Postgres, Oracle, etc.
@@ -51,10 +52,17 @@ pub enum Dialect {
     /// SQLite dialect. If the data set is not actually a SQLite database, then a virtual SQLite
     /// table is created to execute the query.
     SQLITE,
+
+    /// SQLite dialect, always going through a virtual SQLite table, even for datasets with a
+    /// native SQLite dialect such as GeoPackage. This makes OGR features like the
+    /// [`ogr_*` functions](https://gdal.org/user/sql_sqlite_dialect.html#ogr-specific-functions)
+    /// available to them.
+    INDIRECT_SQLITE,
 }
 
 pub(crate) const OGRSQL: &[u8] = b"OGRSQL\0";
 pub(crate) const SQLITE: &[u8] = b"SQLITE\0";
+pub(crate) const INDIRECT_SQLITE: &[u8] = b"INDIRECT_SQLITE\0";
 
 /// [Dataset] methods relating to SQL over vector datasets.
 impl Dataset {
@@ -109,6 +117,9 @@ impl Dataset {
             Dialect::DEFAULT => None,
             Dialect::OGR => Some(unsafe { CStr::from_bytes_with_nul_unchecked(OGRSQL) }),
             Dialect::SQLITE => Some(unsafe { CStr::from_bytes_with_nul_unchecked(SQLITE) }),
+            Dialect::INDIRECT_SQLITE => {
+                Some(unsafe { CStr::from_bytes_with_nul_unchecked(INDIRECT_SQLITE) })
+            }
         };
 
         self._execute_sql(query, spatial_filter, dialect_c_str)
@@ -258,6 +269,19 @@ mod tests {
         assert_eq!("pedestrian", highway);
     }
 
+    #[test]
+    fn test_sql_with_indirect_sqlite_dialect() {
+        let query = "SELECT * FROM roads WHERE highway = 'pedestrian' and NumPoints(GEOMETRY) = 3";
+        let ds = Dataset::open(fixture("roads.geojson")).unwrap();
+        let bbox = Geometry::bbox(26.1017, 44.4297, 26.1025, 44.4303).unwrap();
+        let result_set = ds
+            .execute_sql(query, Some(&bbox), sql::Dialect::INDIRECT_SQLITE)
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(1, result_set.feature_count());
+    }
+
     #[test]
     fn test_sql_empty_result() {
         let ds = Dataset::open(fixture("roads.geojson")).unwrap();
@@ -0,0 +1,447 @@
+use std::ffi::CString;
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+use gdal_sys::OGRStyleTableH;
+
+use crate::errors::*;
+use crate::utils::{_last_null_pointer_err, _string};
+use crate::Dataset;
+
+/// The kind of a [`StyleTool`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StyleToolKind {
+    /// Line styles, e.g. the color and width of lines and polygon outlines.
+    Pen,
+    /// Polygon fills.
+    Brush,
+    /// Point symbols.
+    Symbol,
+    /// Text labels.
+    Label,
+}
+
+impl StyleToolKind {
+    /// The name of the tool in style strings, e.g. `PEN`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            StyleToolKind::Pen => "PEN",
+            StyleToolKind::Brush => "BRUSH",
+            StyleToolKind::Symbol => "SYMBOL",
+            StyleToolKind::Label => "LABEL",
+        }
+    }
+}
+
+impl FromStr for StyleToolKind {
+    type Err = GdalError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_ascii_uppercase().as_str() {
+            "PEN" => Ok(StyleToolKind::Pen),
+            "BRUSH" => Ok(StyleToolKind::Brush),
+            "SYMBOL" => Ok(StyleToolKind::Symbol),
+            "LABEL" => Ok(StyleToolKind::Label),
+            _ => Err(GdalError::BadArgument(format!("unknown style tool '{s}'"))),
+        }
+    }
+}
+
+/// A tool of a [`StyleString`], e.g. `PEN(c:#FF0000,w:2px)`, with its parameters.
+///
+/// The parameters are kept as strings, as written in the style string, e.g. `c` (color)
+/// `#FF0000` or `w` (width) `2px`. See the [style docs] for the parameters of each tool.
+///
+/// [style docs]: https://gdal.org/user/ogr_feature_style.html
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StyleTool {
+    kind: StyleToolKind,
+    params: Vec<(String, String)>,
+}
+
+impl StyleTool {
+    /// Create a tool of the given kind, without parameters.
+    pub fn new(kind: StyleToolKind) -> Self {
+        StyleTool {
+            kind,
+            params: Vec::new(),
+        }
+    }
+
+    /// The kind of this tool.
+    pub fn kind(&self) -> StyleToolKind {
+        self.kind
+    }
+
+    /// Set the parameter `name` to `value`, replacing its previous value if any.
+    pub fn with_param(&mut self, name: &str, value: &str) -> &mut Self {
+        match self.params.iter_mut().find(|(n, _)| n == name) {
+            Some((_, v)) => *v = value.to_owned(),
+            None => self.params.push((name.to_owned(), value.to_owned())),
+        }
+        self
+    }
+
+    /// The value of the parameter `name`, if set.
+    pub fn param(&self, name: &str) -> Option<&str> {
+        self.params
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, v)| v.as_str())
+    }
+
+    /// The parameters of this tool, as `(name, value)`, in order.
+    pub fn params(&self) -> &[(String, String)] {
+        &self.params
+    }
+}
+
+impl Display for StyleTool {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}(", self.kind.as_str())?;
+        for (i, (name, value)) in self.params.iter().enumerate() {
+            if i > 0 {
+                write!(f, ",")?;
+            }
+            let needs_quotes = value.is_empty()
+                || value
+                    .chars()
+                    .any(|c| c.is_whitespace() || matches!(c, ',' | ';' | '(' | ')' | '"'));
+            if needs_quotes {
+                let escaped = value.replace('\\', "\\\\").replace('"', "\\\"");
+                write!(f, "{name}:\"{escaped}\"")?;
+            } else {
+                write!(f, "{name}:{value}")?;
+            }
+        }
+        write!(f, ")")
+    }
+}
+
+impl FromStr for StyleTool {
+    type Err = GdalError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let s = s.trim();
+        let invalid = || GdalError::BadArgument(format!("invalid style tool '{s}'"));
+        let (name, rest) = s.split_once('(').ok_or_else(invalid)?;
+        let body = rest.strip_suffix(')').ok_or_else(invalid)?;
+        let mut tool = StyleTool::new(name.trim().parse()?);
+        for param in split_unquoted(body, ',') {
+            if param.trim().is_empty() {
+                continue;
+            }
+            let (name, value) = param.split_once(':').ok_or_else(invalid)?;
+            tool.with_param(name.trim(), &unquote(value.trim()));
+        }
+        Ok(tool)
+    }
+}
+
+/// An [OGR style string], describing how a feature is drawn, as a list of tools.
+///
+/// It can be parsed from and formatted to the string of
+/// [`Feature::style_string`](crate::vector::Feature::style_string) or of a [`StyleTable`].
+/// References to named styles of a style table (`@name`) aren't supported.
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() -> gdal::errors::Result<()> {
+/// use gdal::vector::{StyleString, StyleTool, StyleToolKind};
+///
+/// let style: StyleString = "PEN(c:#FF0000,w:2px);BRUSH(fc:#00FF00)".parse()?;
+/// assert_eq!(style.tool(StyleToolKind::Pen).unwrap().param("w"), Some("2px"));
+///
+/// let mut label = StyleTool::new(StyleToolKind::Label);
+/// label.with_param("t", "Main street").with_param("s", "12pt");
+/// let mut style = StyleString::new();
+/// style.with_tool(label);
+/// assert_eq!(style.to_string(), "LABEL(t:\"Main street\",s:12pt)");
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [OGR style string]: https://gdal.org/user/ogr_feature_style.html
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct StyleString {
+    tools: Vec<StyleTool>,
+}
+
+impl StyleString {
+    /// Create an empty style string.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Add `tool` to this style string.
+    pub fn with_tool(&mut self, tool: StyleTool) -> &mut Self {
+        self.tools.push(tool);
+        self
+    }
+
+    /// The tools of this style string, in order.
+    pub fn tools(&self) -> &[StyleTool] {
+        &self.tools
+    }
+
+    /// The first tool of the given kind, if any.
+    pub fn tool(&self, kind: StyleToolKind) -> Option<&StyleTool> {
+        self.tools.iter().find(|t| t.kind == kind)
+    }
+}
+
+impl Display for StyleString {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for (i, tool) in self.tools.iter().enumerate() {
+            if i > 0 {
+                write!(f, ";")?;
+            }
+            write!(f, "{tool}")?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for StyleString {
+    type Err = GdalError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let tools = split_unquoted(s, ';')
+            .into_iter()
+            .filter(|t| !t.trim().is_empty())
+            .map(str::parse)
+            .collect::<Result<_>>()?;
+        Ok(StyleString { tools })
+    }
+}
+
+/// Split `s` on `sep`, except inside double quotes.
+fn split_unquoted(s: &str, sep: char) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut start = 0;
+    let mut in_quotes = false;
+    let mut escaped = false;
+    for (i, c) in s.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if in_quotes => escaped = true,
+            '"' => in_quotes = !in_quotes,
+            _ if c == sep && !in_quotes => {
+                parts.push(&s[start..i]);
+                start = i + c.len_utf8();
+            }
+            _ => {}
+        }
+    }
+    parts.push(&s[start..]);
+    parts
+}
+
+/// Remove the double quotes around `value`, if any, and unescape it.
+fn unquote(value: &str) -> String {
+    match value.strip_prefix('"').and_then(|v| v.strip_suffix('"')) {
+        Some(inner) => {
+            let mut unescaped = String::with_capacity(inner.len());
+            let mut chars = inner.chars();
+            while let Some(c) = chars.next() {
+                if c == '\\' {
+                    unescaped.extend(chars.next());
+                } else {
+                    unescaped.push(c);
+                }
+            }
+            unescaped
+        }
+        None => value.to_owned(),
+    }
+}
+
+/// A table of named [OGR style strings](https://gdal.org/user/ogr_feature_style.html),
+/// shared by the features of a dataset.
+///
+/// Features refer to the styles of the table with a `@name` style string.
+#[derive(Debug)]
+pub struct StyleTable {
+    c_style_table: OGRStyleTableH,
+}
+
+impl Drop for StyleTable {
+    fn drop(&mut self) {
+        unsafe { gdal_sys::OGR_STBL_Destroy(self.c_style_table) };
+    }
+}
+
+impl StyleTable {
+    /// Create an empty style table.
+    pub fn new() -> Result<Self> {
+        let c_style_table = unsafe { gdal_sys::OGR_STBL_Create() };
+        if c_style_table.is_null() {
+            return Err(_last_null_pointer_err("OGR_STBL_Create"));
+        }
+        Ok(StyleTable { c_style_table })
+    }
+
+    /// Copy the styles of the table `c_style_table`, which is left owned by the caller.
+    ///
+    /// # Safety
+    /// `c_style_table` must be a valid style table.
+    unsafe fn copy_from(c_style_table: OGRStyleTableH) -> Result<Self> {
+        let mut table = StyleTable::new()?;
+        for (name, style) in styles_of(c_style_table) {
+            table.add_style(&name, &style)?;
+        }
+        Ok(table)
+    }
+
+    /// Returns the wrapped C pointer
+    ///
+    /// # Safety
+    /// This method returns a raw C pointer
+    pub unsafe fn c_style_table(&self) -> OGRStyleTableH {
+        self.c_style_table
+    }
+
+    /// Add the style string `style`, called `name`.
+    ///
+    /// Returns [`GdalError::BadArgument`] if a style with the same name already exists.
+    pub fn add_style(&mut self, name: &str, style: &str) -> Result<()> {
+        let c_name = CString::new(name)?;
+        let c_style = CString::new(style)?;
+        let rv = unsafe {
+            gdal_sys::OGR_STBL_AddStyle(self.c_style_table, c_name.as_ptr(), c_style.as_ptr())
+        };
+        if rv == 0 {
+            return Err(GdalError::BadArgument(format!(
+                "style '{name}' already exists"
+            )));
+        }
+        Ok(())
+    }
+
+    /// The style string called `name`, if any.
+    pub fn find(&self, name: &str) -> Option<String> {
+        let c_name = CString::new(name).ok()?;
+        let c_style = unsafe { gdal_sys::OGR_STBL_Find(self.c_style_table, c_name.as_ptr()) };
+        if c_style.is_null() {
+            None
+        } else {
+            Some(_string(c_style))
+        }
+    }
+
+    /// The styles of this table, as `(name, style string)`.
+    pub fn styles(&self) -> Vec<(String, String)> {
+        unsafe { styles_of(self.c_style_table) }
+    }
+}
+
+/// Read all the styles of `c_style_table`.
+///
+/// # Safety
+/// `c_style_table` must be a valid style table.
+unsafe fn styles_of(c_style_table: OGRStyleTableH) -> Vec<(String, String)> {
+    let mut styles = Vec::new();
+    gdal_sys::OGR_STBL_ResetStyleStringReading(c_style_table);
+    loop {
+        let c_style = gdal_sys::OGR_STBL_GetNextStyle(c_style_table);
+        if c_style.is_null() {
+            break;
+        }
+        let name = _string(gdal_sys::OGR_STBL_GetLastStyleName(c_style_table));
+        styles.push((name, _string(c_style)));
+    }
+    styles
+}
+
+/// # Style Table
+impl Dataset {
+    /// Get a copy of the style table of this dataset, if it has one.
+    pub fn style_table(&self) -> Result<Option<StyleTable>> {
+        let c_style_table = unsafe { gdal_sys::GDALDatasetGetStyleTable(self.c_dataset()) };
+        if c_style_table.is_null() {
+            return Ok(None);
+        }
+        Ok(Some(unsafe { StyleTable::copy_from(c_style_table)? }))
+    }
+
+    /// Set the style table of this dataset, to a copy of `style_table`.
+    ///
+    /// Only some drivers, such as MapInfo and DXF, write it to the dataset.
+    pub fn set_style_table(&mut self, style_table: &StyleTable) {
+        unsafe { gdal_sys::GDALDatasetSetStyleTable(self.c_dataset(), style_table.c_style_table) };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::fixture;
+    use crate::vector::{Feature, LayerAccess};
+    use crate::DriverManager;
+
+    #[test]
+    fn test_style_string() {
+        let s = r#"PEN(c:#FF0000,w:2px,p:"4px 2px");BRUSH(fc:#00FF00);LABEL(t:"a, \"b\"",f:Arial)"#;
+        let style: StyleString = s.parse().unwrap();
+        assert_eq!(style.tools().len(), 3);
+        let pen = style.tool(StyleToolKind::Pen).unwrap();
+        assert_eq!(pen.param("c"), Some("#FF0000"));
+        assert_eq!(pen.param("p"), Some("4px 2px"));
+        assert_eq!(pen.param("id"), None);
+        let label = style.tool(StyleToolKind::Label).unwrap();
+        assert_eq!(label.param("t"), Some("a, \"b\""));
+        assert!(style.tool(StyleToolKind::Symbol).is_none());
+        assert_eq!(style.to_string(), s);
+        assert_eq!(style.to_string().parse::<StyleString>().unwrap(), style);
+
+        assert!("PEN(c:#FF0000".parse::<StyleString>().is_err());
+        assert!("PENCIL(c:#FF0000)".parse::<StyleString>().is_err());
+        assert!("".parse::<StyleString>().unwrap().tools().is_empty());
+    }
+
+    #[test]
+    fn test_feature_style() {
+        let ds = Dataset::open(fixture("roads.geojson")).unwrap();
+        let layer = ds.layer(0).unwrap();
+        let mut feature = Feature::new(layer.defn()).unwrap();
+        assert!(feature.style_string().is_none());
+
+        let mut symbol = StyleTool::new(StyleToolKind::Symbol);
+        symbol
+            .with_param("id", "ogr-sym-3")
+            .with_param("c", "#0000FF");
+        let mut style = StyleString::new();
+        style.with_tool(symbol);
+        feature.set_style_string(&style.to_string()).unwrap();
+        assert_eq!(
+            feature.style_string().unwrap(),
+            "SYMBOL(id:ogr-sym-3,c:#0000FF)"
+        );
+    }
+
+    #[test]
+    fn test_style_table() {
+        let driver = DriverManager::get_driver_by_name("Memory").unwrap();
+        let mut ds = driver.create_vector_only("").unwrap();
+        assert!(ds.style_table().unwrap().is_none());
+
+        let mut table = StyleTable::new().unwrap();
+        table.add_style("red", "PEN(c:#FF0000)").unwrap();
+        table.add_style("fill", "BRUSH(fc:#00FF00)").unwrap();
+        assert!(table.add_style("red", "PEN(c:#FF0000)").is_err());
+        assert_eq!(table.find("fill").unwrap(), "BRUSH(fc:#00FF00)");
+        assert!(table.find("blue").is_none());
+
+        ds.set_style_table(&table);
+        drop(table);
+        let table = ds.style_table().unwrap().unwrap();
+        assert_eq!(
+            table.styles(),
+            vec![
+                ("red".to_string(), "PEN(c:#FF0000)".to_string()),
+                ("fill".to_string(), "BRUSH(fc:#00FF00)".to_string()),
+            ]
+        );
+    }
+}
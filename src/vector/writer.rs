@@ -0,0 +1,409 @@
+use std::fmt::{self, Debug, Formatter};
+use std::time::{Duration, Instant};
+
+use gdal_sys::{OGRErr, OGRFeatureH};
+
+use crate::errors::*;
+use crate::vector::{Feature, FieldValue, Geometry, Layer, LayerAccess};
+use crate::Dataset;
+
+type ProgressFn<'a> = Box<dyn FnMut(&WriteStats) + 'a>;
+
+/// Progress of a [`LayerWriter`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct WriteStats {
+    /// Number of features written.
+    pub features: u64,
+    /// Number of committed batches.
+    pub batches: u64,
+    /// Time since the first feature was written.
+    pub elapsed: Duration,
+}
+
+impl WriteStats {
+    /// The number of features written per second.
+    pub fn features_per_second(&self) -> f64 {
+        let secs = self.elapsed.as_secs_f64();
+        if secs > 0. {
+            self.features as f64 / secs
+        } else {
+            0.
+        }
+    }
+}
+
+/// A writer of many features to a layer, grouping them in transactions.
+///
+/// Writing features one by one with [`LayerAccess::create_feature`] commits each of them
+/// separately on transactional drivers, such as GPKG or PostgreSQL, which is slow. The writer
+/// instead starts a transaction, commits it every [`batch_size`](Self::with_batch_size)
+/// features and starts a new one. On drivers without transactions, the features are written
+/// directly.
+///
+/// Rows of values can be written with [`write_row`](Self::write_row), after listing their
+/// fields with [`with_fields`](Self::with_fields). The indices of these fields are looked up
+/// once, instead of for each feature.
+///
+/// [`finish`](Self::finish) must be called to commit the last batch; dropping the writer
+/// rolls it back instead.
+///
+/// # Example
+///
+/// ```rust, no_run
+/// # fn main() -> gdal::errors::Result<()> {
+/// use gdal::vector::{FieldValue, Geometry, LayerWriter};
+/// use gdal::Dataset;
+///
+/// let dataset = Dataset::open_ex(
+///     "points.gpkg",
+///     gdal::DatasetOptions {
+///         open_flags: gdal::GdalOpenFlags::GDAL_OF_UPDATE,
+///         ..Default::default()
+///     },
+/// )?;
+/// let mut writer = LayerWriter::new(&dataset, "points")?;
+/// writer
+///     .with_batch_size(50_000)
+///     .with_fields(&["name", "count"])?
+///     .with_progress(|stats| println!("{:.0} features/s", stats.features_per_second()));
+/// for i in 0..1_000_000 {
+///     let mut point = Geometry::empty(gdal::vector::OGRwkbGeometryType::wkbPoint)?;
+///     point.add_point_2d((i as f64, 0.));
+///     writer.write_row(
+///         Some(point),
+///         &[Some(FieldValue::StringValue(format!("p{i}"))), Some(FieldValue::IntegerValue(i))],
+///     )?;
+/// }
+/// let stats = writer.finish()?;
+/// println!("{} features in {:?}", stats.features, stats.elapsed);
+/// # Ok(())
+/// # }
+/// ```
+pub struct LayerWriter<'a> {
+    dataset: &'a Dataset,
+    layer: Layer<'a>,
+    field_indices: Vec<i32>,
+    batch_size: usize,
+    use_transactions: bool,
+    in_transaction: bool,
+    pending: usize,
+    started: Option<Instant>,
+    stats: WriteStats,
+    progress: Option<ProgressFn<'a>>,
+}
+
+impl<'a> Debug for LayerWriter<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LayerWriter")
+            .field("layer", &self.layer)
+            .field("field_indices", &self.field_indices)
+            .field("batch_size", &self.batch_size)
+            .field("use_transactions", &self.use_transactions)
+            .field("in_transaction", &self.in_transaction)
+            .field("stats", &self.stats)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'a> LayerWriter<'a> {
+    /// The default number of features per transaction.
+    pub const DEFAULT_BATCH_SIZE: usize = 10_000;
+
+    /// Create a writer to the layer called `layer_name` of `dataset`, which must be opened
+    /// in update mode.
+    pub fn new(dataset: &'a Dataset, layer_name: &str) -> Result<Self> {
+        Ok(LayerWriter {
+            dataset,
+            layer: dataset.layer_by_name(layer_name)?,
+            field_indices: Vec::new(),
+            batch_size: Self::DEFAULT_BATCH_SIZE,
+            use_transactions: true,
+            in_transaction: false,
+            pending: 0,
+            started: None,
+            stats: WriteStats::default(),
+            progress: None,
+        })
+    }
+
+    /// The layer written to.
+    pub fn layer(&self) -> &Layer<'a> {
+        &self.layer
+    }
+
+    /// Set the number of features committed per transaction.
+    pub fn with_batch_size(&mut self, batch_size: usize) -> &mut Self {
+        self.batch_size = batch_size.max(1);
+        self
+    }
+
+    /// Set whether to group the features in transactions, if the driver supports them.
+    pub fn with_transactions(&mut self, use_transactions: bool) -> &mut Self {
+        self.use_transactions = use_transactions;
+        self
+    }
+
+    /// Set the fields of the values passed to [`write_row`](Self::write_row), in order.
+    ///
+    /// If a field is missing, returns [`GdalError::InvalidFieldName`].
+    pub fn with_fields(&mut self, field_names: &[&str]) -> Result<&mut Self> {
+        self.field_indices = field_names
+            .iter()
+            .map(|name| Ok(self.layer.defn().field_index(name)?.try_into()?))
+            .collect::<Result<_>>()?;
+        Ok(self)
+    }
+
+    /// Call `progress` with the statistics after each committed batch.
+    pub fn with_progress<F: FnMut(&WriteStats) + 'a>(&mut self, progress: F) -> &mut Self {
+        self.progress = Some(Box::new(progress));
+        self
+    }
+
+    /// Write `feature`, which must have the schema of the layer.
+    pub fn write_feature(&mut self, feature: &Feature) -> Result<()> {
+        self.begin_batch()?;
+        self.write_c_feature(unsafe { feature.c_feature() })
+    }
+
+    /// Write all the `features`, which must have the schema of the layer.
+    pub fn write_features<'f, I: IntoIterator<Item = Feature<'f>>>(
+        &mut self,
+        features: I,
+    ) -> Result<()> {
+        for feature in features {
+            self.write_feature(&feature)?;
+        }
+        Ok(())
+    }
+
+    /// Write a feature with the geometry `geometry` and the field `values`, in the order of
+    /// [`with_fields`](Self::with_fields). `None` values are left unset.
+    ///
+    /// If the number of values doesn't match the number of fields, returns
+    /// [`GdalError::BadArgument`].
+    pub fn write_row(
+        &mut self,
+        geometry: Option<Geometry>,
+        values: &[Option<FieldValue>],
+    ) -> Result<()> {
+        if values.len() != self.field_indices.len() {
+            return Err(GdalError::BadArgument(format!(
+                "expected {} values, got {}",
+                self.field_indices.len(),
+                values.len()
+            )));
+        }
+        self.begin_batch()?;
+
+        let mut feature = Feature::new(self.layer.defn())?;
+        for (&idx, value) in self.field_indices.iter().zip(values) {
+            if let Some(value) = value {
+                feature.set_field_by_index(idx, value)?;
+            }
+        }
+        if let Some(geometry) = geometry {
+            feature.set_geometry(geometry)?;
+        }
+        let rv =
+            unsafe { gdal_sys::OGR_L_CreateFeature(self.layer.c_layer(), feature.c_feature()) };
+        drop(feature);
+        self.end_write(rv)
+    }
+
+    /// Write all the `rows`, as with [`write_row`](Self::write_row).
+    pub fn write_rows<I: IntoIterator<Item = (Option<Geometry>, Vec<Option<FieldValue>>)>>(
+        &mut self,
+        rows: I,
+    ) -> Result<()> {
+        for (geometry, values) in rows {
+            self.write_row(geometry, &values)?;
+        }
+        Ok(())
+    }
+
+    /// The statistics of the features written so far.
+    pub fn stats(&self) -> WriteStats {
+        WriteStats {
+            elapsed: self.started.map_or(Duration::ZERO, |s| s.elapsed()),
+            ..self.stats
+        }
+    }
+
+    /// Commit the last batch and return the final statistics.
+    pub fn finish(mut self) -> Result<WriteStats> {
+        if self.pending > 0 {
+            self.commit_batch()?;
+        }
+        Ok(self.stats())
+    }
+
+    fn begin_batch(&mut self) -> Result<()> {
+        self.started.get_or_insert_with(Instant::now);
+        if !self.use_transactions || self.in_transaction {
+            return Ok(());
+        }
+        let rv = unsafe { gdal_sys::GDALDatasetStartTransaction(self.dataset.c_dataset(), 0) };
+        match rv {
+            OGRErr::OGRERR_NONE => self.in_transaction = true,
+            OGRErr::OGRERR_UNSUPPORTED_OPERATION => self.use_transactions = false,
+            _ => {
+                return Err(GdalError::OgrError {
+                    err: rv,
+                    method_name: "GDALDatasetStartTransaction",
+                })
+            }
+        }
+        Ok(())
+    }
+
+    fn write_c_feature(&mut self, c_feature: OGRFeatureH) -> Result<()> {
+        let rv = unsafe { gdal_sys::OGR_L_CreateFeature(self.layer.c_layer(), c_feature) };
+        self.end_write(rv)
+    }
+
+    fn end_write(&mut self, rv: OGRErr::Type) -> Result<()> {
+        if rv != OGRErr::OGRERR_NONE {
+            return Err(GdalError::OgrError {
+                err: rv,
+                method_name: "OGR_L_CreateFeature",
+            });
+        }
+        self.stats.features += 1;
+        self.pending += 1;
+        if self.pending >= self.batch_size {
+            self.commit_batch()?;
+        }
+        Ok(())
+    }
+
+    fn commit_batch(&mut self) -> Result<()> {
+        if self.in_transaction {
+            self.in_transaction = false;
+            let rv = unsafe { gdal_sys::GDALDatasetCommitTransaction(self.dataset.c_dataset()) };
+            if rv != OGRErr::OGRERR_NONE {
+                return Err(GdalError::OgrError {
+                    err: rv,
+                    method_name: "GDALDatasetCommitTransaction",
+                });
+            }
+        }
+        self.pending = 0;
+        self.stats.batches += 1;
+        let stats = self.stats();
+        if let Some(progress) = &mut self.progress {
+            progress(&stats);
+        }
+        Ok(())
+    }
+}
+
+impl<'a> Drop for LayerWriter<'a> {
+    fn drop(&mut self) {
+        if self.in_transaction {
+            // As with `Transaction`, errors can't be reported from here.
+            unsafe { gdal_sys::GDALDatasetRollbackTransaction(self.dataset.c_dataset()) };
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use super::*;
+    use crate::vector::{FieldDefn, LayerOptions, OGRFieldType, OGRwkbGeometryType};
+    use crate::DriverManager;
+
+    fn create_dataset(driver: &str, path: &str) -> Dataset {
+        let driver = DriverManager::get_driver_by_name(driver).unwrap();
+        let mut ds = driver.create_vector_only(path).unwrap();
+        let layer = ds
+            .create_layer(LayerOptions {
+                name: "points",
+                ty: OGRwkbGeometryType::wkbPoint,
+                ..Default::default()
+            })
+            .unwrap();
+        for (name, field_type) in [
+            ("name", OGRFieldType::OFTString),
+            ("count", OGRFieldType::OFTInteger),
+        ] {
+            FieldDefn::new(name, field_type)
+                .unwrap()
+                .add_to_layer(&layer)
+                .unwrap();
+        }
+        ds
+    }
+
+    fn row(i: i32) -> (Option<Geometry>, Vec<Option<FieldValue>>) {
+        let mut point = Geometry::empty(OGRwkbGeometryType::wkbPoint).unwrap();
+        point.add_point_2d((i as f64, 0.));
+        let name = (i % 2 == 0).then(|| FieldValue::StringValue(format!("p{i}")));
+        (Some(point), vec![name, Some(FieldValue::IntegerValue(i))])
+    }
+
+    #[test]
+    fn test_layer_writer() {
+        let ds = create_dataset("GPKG", "/vsimem/test_layer_writer.gpkg");
+        let batches = Cell::new(0);
+        let mut writer = LayerWriter::new(&ds, "points").unwrap();
+        writer
+            .with_batch_size(3)
+            .with_fields(&["count", "name"])
+            .unwrap()
+            .with_fields(&["name", "count"])
+            .unwrap()
+            .with_progress(|stats| batches.set(stats.batches));
+        writer.write_rows((0..10).map(row)).unwrap();
+        assert_eq!(writer.stats().features, 10);
+        assert_eq!(batches.get(), 3);
+        assert!(matches!(
+            writer.write_row(None, &[None]),
+            Err(GdalError::BadArgument(_))
+        ));
+        let stats = writer.finish().unwrap();
+        assert_eq!((stats.features, stats.batches), (10, 4));
+        assert_eq!(batches.get(), 4);
+
+        let mut layer = ds.layer_by_name("points").unwrap();
+        assert_eq!(layer.feature_count(), 10);
+        {
+            let feature = layer.features().nth(3).unwrap();
+            assert_eq!(feature.field_as_integer_by_name("count").unwrap(), Some(3));
+            assert_eq!(feature.field_as_string_by_name("name").unwrap(), None);
+            assert_eq!(feature.geometry().unwrap().get_point(0), (3., 0., 0.));
+        }
+
+        let mut feature = Feature::new(layer.defn()).unwrap();
+        feature.set_field_integer("count", 42).unwrap();
+        let mut writer = LayerWriter::new(&ds, "points").unwrap();
+        writer.write_features([feature]).unwrap();
+        assert_eq!(writer.stats().features, 1);
+        drop(writer);
+        assert_eq!(layer.feature_count(), 10);
+    }
+
+    #[test]
+    fn test_layer_writer_without_transactions() {
+        let ds = create_dataset("Memory", "");
+        let mut writer = LayerWriter::new(&ds, "points").unwrap();
+        writer.with_fields(&["count"]).unwrap();
+        for i in 0..5 {
+            writer
+                .write_row(None, &[Some(FieldValue::IntegerValue(i))])
+                .unwrap();
+        }
+        let stats = writer.finish().unwrap();
+        assert_eq!((stats.features, stats.batches), (5, 1));
+        assert_eq!(ds.layer(0).unwrap().feature_count(), 5);
+
+        assert!(LayerWriter::new(&ds, "missing").is_err());
+        let mut writer = LayerWriter::new(&ds, "points").unwrap();
+        assert!(matches!(
+            writer.with_fields(&["missing"]),
+            Err(GdalError::InvalidFieldName { .. })
+        ));
+    }
+}
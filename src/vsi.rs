@@ -3,14 +3,24 @@
 //! This module provides safe access to a subset of the [GDAL VSI Functions](https://gdal.org/doxygen/cpl__vsi_8h.html).
 //! See [GDAL Virtual File Systems document](https://gdal.org/user/virtual_file_systems.html) for details.
 
+use std::ffi::CString;
+use std::io::{self, Read, Seek, SeekFrom, Write};
 use std::marker::PhantomData;
-use std::mem::ManuallyDrop;
+use std::mem::{ManuallyDrop, MaybeUninit};
 use std::path::{Path, PathBuf};
+use std::ptr::{self, NonNull};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
-use gdal_sys::{VSIFCloseL, VSIFileFromMemBuffer, VSIFree, VSIGetMemFileBuffer, VSIUnlink};
+use gdal_sys::{
+    CPLErr, VSIFCloseL, VSIFileFromMemBuffer, VSIFree, VSIGetMemFileBuffer, VSIUnlink, VSIDIR,
+    VSILFILE,
+};
+use libc::{c_int, c_void};
 
 use crate::errors::{GdalError, Result};
-use crate::utils::{_last_null_pointer_err, _path_to_c_string, _pathbuf_array};
+use crate::utils::{
+    _last_cpl_err, _last_null_pointer_err, _path_to_c_string, _pathbuf, _pathbuf_array,
+};
 
 /// Read the file names from a virtual file system with optional recursion.
 pub fn read_dir<P: AsRef<Path>>(path: P, recursive: bool) -> Result<Vec<PathBuf>> {
@@ -192,6 +202,398 @@ where
     Ok(fun(slice))
 }
 
+/// Storage for a [`gdal_sys::VSIStatBufL`], which is an opaque, zero-sized struct in most of
+/// the prebuilt bindings and so can't be allocated itself.
+#[cfg(target_os = "linux")]
+type StatBuf = libc::stat64;
+#[cfg(not(target_os = "linux"))]
+type StatBuf = libc::stat;
+
+/// A file of a virtual file system, opened with [`VsiFile::open`] or [`VsiFile::create`].
+///
+/// It implements [`Read`], [`Write`] and [`Seek`] on any path supported by GDAL, e.g. under
+/// `/vsimem/`, `/vsizip/` or `/vsicurl/`. The file is closed when dropped.
+///
+/// # Example
+///
+/// ```rust, no_run
+/// use std::io::{Read, Write};
+/// use gdal::vsi::VsiFile;
+/// # fn main() -> gdal::errors::Result<()> {
+/// let mut file = VsiFile::create("/vsimem/hello.txt")?;
+/// file.write_all(b"Hello").unwrap();
+/// drop(file);
+///
+/// let mut text = String::new();
+/// VsiFile::open("/vsimem/hello.txt")?.read_to_string(&mut text).unwrap();
+/// assert_eq!(text, "Hello");
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct VsiFile {
+    c_file: NonNull<VSILFILE>,
+    path: PathBuf,
+}
+
+impl VsiFile {
+    /// Open the file at `path` for reading.
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self> {
+        Self::open_with_access(path, "rb")
+    }
+
+    /// Create the file at `path` for writing, truncating it if it exists.
+    pub fn create<P: AsRef<Path>>(path: P) -> Result<Self> {
+        Self::open_with_access(path, "wb")
+    }
+
+    /// Open the file at `path` with an `fopen()` access mode, such as `"r+b"` to read and
+    /// write it or `"ab"` to append to it.
+    ///
+    /// See: [`VSIFOpenExL`](https://gdal.org/api/cpl.html#_CPPv411VSIFOpenExLPKcPKci)
+    pub fn open_with_access<P: AsRef<Path>>(path: P, access: &str) -> Result<Self> {
+        let path = path.as_ref();
+        let c_path = _path_to_c_string(path)?;
+        let c_access = CString::new(access)?;
+        let c_file = unsafe { gdal_sys::VSIFOpenExL(c_path.as_ptr(), c_access.as_ptr(), 1) };
+        match NonNull::new(c_file) {
+            Some(c_file) => Ok(VsiFile {
+                c_file,
+                path: path.to_path_buf(),
+            }),
+            None => Err(_last_null_pointer_err("VSIFOpenExL")),
+        }
+    }
+
+    /// Truncate or extend the file to `size` bytes.
+    ///
+    /// See: [`VSIFTruncateL`](https://gdal.org/api/cpl.html#_CPPv413VSIFTruncateLP8VSILFILE12vsi_l_offset)
+    pub fn set_len(&mut self, size: u64) -> Result<()> {
+        let rv = unsafe { gdal_sys::VSIFTruncateL(self.c_file.as_ptr(), size) };
+        if rv != 0 {
+            return Err(vsi_error("VSIFTruncateL", &self.path));
+        }
+        Ok(())
+    }
+
+    /// Move to the absolute position `offset` relative to `whence`.
+    fn seek_raw(&mut self, offset: u64, whence: c_int) -> io::Result<u64> {
+        let rv = unsafe { gdal_sys::VSIFSeekL(self.c_file.as_ptr(), offset, whence) };
+        if rv != 0 {
+            return Err(last_io_error());
+        }
+        Ok(unsafe { gdal_sys::VSIFTellL(self.c_file.as_ptr()) })
+    }
+}
+
+impl Read for VsiFile {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let c_file = self.c_file.as_ptr();
+        let read =
+            unsafe { gdal_sys::VSIFReadL(buf.as_mut_ptr() as *mut c_void, 1, buf.len(), c_file) };
+        if read < buf.len() && unsafe { gdal_sys::VSIFEofL(c_file) } == 0 {
+            return Err(last_io_error());
+        }
+        Ok(read)
+    }
+}
+
+impl Write for VsiFile {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let written = unsafe {
+            gdal_sys::VSIFWriteL(
+                buf.as_ptr() as *const c_void,
+                1,
+                buf.len(),
+                self.c_file.as_ptr(),
+            )
+        };
+        if written == 0 && !buf.is_empty() {
+            return Err(last_io_error());
+        }
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        if unsafe { gdal_sys::VSIFFlushL(self.c_file.as_ptr()) } != 0 {
+            return Err(last_io_error());
+        }
+        Ok(())
+    }
+}
+
+impl Seek for VsiFile {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        let (base, offset) = match pos {
+            SeekFrom::Start(offset) => return self.seek_raw(offset, libc::SEEK_SET),
+            SeekFrom::End(offset) => (self.seek_raw(0, libc::SEEK_END)?, offset),
+            SeekFrom::Current(offset) => {
+                (unsafe { gdal_sys::VSIFTellL(self.c_file.as_ptr()) }, offset)
+            }
+        };
+        let target = if offset >= 0 {
+            base.checked_add(offset as u64)
+        } else {
+            base.checked_sub(offset.unsigned_abs())
+        };
+        match target {
+            Some(target) => self.seek_raw(target, libc::SEEK_SET),
+            None => Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "invalid seek to a negative or overflowing position",
+            )),
+        }
+    }
+}
+
+impl Drop for VsiFile {
+    fn drop(&mut self) {
+        unsafe { VSIFCloseL(self.c_file.as_ptr()) };
+    }
+}
+
+/// Wrap the last GDAL error into an [`io::Error`].
+fn last_io_error() -> io::Error {
+    io::Error::new(io::ErrorKind::Other, _last_cpl_err(CPLErr::CE_Failure))
+}
+
+fn vsi_error(method_name: &'static str, path: &Path) -> GdalError {
+    GdalError::VsiError {
+        method_name,
+        path: path.display().to_string(),
+    }
+}
+
+/// Metadata of a file or directory, returned by [`stat`] and [`VsiDirEntry::metadata`].
+///
+/// The file systems listing directories, such as `/vsis3/`, may not know all of them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VsiMetadata {
+    mode: Option<u32>,
+    size: Option<u64>,
+    modified: Option<SystemTime>,
+}
+
+impl VsiMetadata {
+    /// Returns `true` if this is a directory.
+    #[allow(clippy::unnecessary_cast)]
+    pub fn is_dir(&self) -> bool {
+        self.mode.map_or(false, |mode| {
+            mode & libc::S_IFMT as u32 == libc::S_IFDIR as u32
+        })
+    }
+
+    /// Returns `true` if this is a regular file.
+    #[allow(clippy::unnecessary_cast)]
+    pub fn is_file(&self) -> bool {
+        self.mode.map_or(false, |mode| {
+            mode & libc::S_IFMT as u32 == libc::S_IFREG as u32
+        })
+    }
+
+    /// Returns the Unix mode, with the file type and permission bits, if known.
+    pub fn mode(&self) -> Option<u32> {
+        self.mode
+    }
+
+    /// Returns the size of the file in bytes, if known.
+    pub fn size(&self) -> Option<u64> {
+        self.size
+    }
+
+    /// Returns the last modification time, if known.
+    pub fn modified(&self) -> Option<SystemTime> {
+        self.modified
+    }
+}
+
+/// Convert a modification time, in seconds since the Unix epoch.
+fn system_time(seconds: i64) -> SystemTime {
+    if seconds >= 0 {
+        UNIX_EPOCH + Duration::from_secs(seconds as u64)
+    } else {
+        UNIX_EPOCH - Duration::from_secs(seconds.unsigned_abs())
+    }
+}
+
+/// Returns the metadata of the file or directory at `path`.
+///
+/// See: [`VSIStatExL`](https://gdal.org/api/cpl.html#_CPPv410VSIStatExLPKcP11VSIStatBufLi)
+// The field types of `StatBuf` depend on the platform.
+#[allow(clippy::unnecessary_cast)]
+pub fn stat<P: AsRef<Path>>(path: P) -> Result<VsiMetadata> {
+    let path = path.as_ref();
+    let c_path = _path_to_c_string(path)?;
+    let mut buf = MaybeUninit::<StatBuf>::zeroed();
+    let rv = unsafe {
+        gdal_sys::VSIStatExL(
+            c_path.as_ptr(),
+            buf.as_mut_ptr().cast::<gdal_sys::VSIStatBufL>(),
+            0,
+        )
+    };
+    if rv != 0 {
+        return Err(vsi_error("VSIStatExL", path));
+    }
+    let buf = unsafe { buf.assume_init() };
+    Ok(VsiMetadata {
+        mode: Some(buf.st_mode as u32),
+        size: Some(buf.st_size as u64),
+        modified: Some(system_time(buf.st_mtime as i64)),
+    })
+}
+
+/// Create the directory `path`, whose parent must exist.
+///
+/// See: [`VSIMkdir`](https://gdal.org/api/cpl.html#_CPPv48VSIMkdirPKcl)
+pub fn mkdir<P: AsRef<Path>>(path: P) -> Result<()> {
+    let path = path.as_ref();
+    let c_path = _path_to_c_string(path)?;
+    if unsafe { gdal_sys::VSIMkdir(c_path.as_ptr(), 0o755) } != 0 {
+        return Err(vsi_error("VSIMkdir", path));
+    }
+    Ok(())
+}
+
+/// Create the directory `path` and its missing parents.
+///
+/// See: [`VSIMkdirRecursive`](https://gdal.org/api/cpl.html#_CPPv417VSIMkdirRecursivePKcl)
+pub fn mkdir_all<P: AsRef<Path>>(path: P) -> Result<()> {
+    let path = path.as_ref();
+    let c_path = _path_to_c_string(path)?;
+    if unsafe { gdal_sys::VSIMkdirRecursive(c_path.as_ptr(), 0o755) } != 0 {
+        return Err(vsi_error("VSIMkdirRecursive", path));
+    }
+    Ok(())
+}
+
+/// Remove the empty directory `path`.
+///
+/// See: [`VSIRmdir`](https://gdal.org/api/cpl.html#_CPPv48VSIRmdirPKc)
+pub fn rmdir<P: AsRef<Path>>(path: P) -> Result<()> {
+    let path = path.as_ref();
+    let c_path = _path_to_c_string(path)?;
+    if unsafe { gdal_sys::VSIRmdir(c_path.as_ptr()) } != 0 {
+        return Err(vsi_error("VSIRmdir", path));
+    }
+    Ok(())
+}
+
+/// Remove the directory `path` with all its content.
+///
+/// See: [`VSIRmdirRecursive`](https://gdal.org/api/cpl.html#_CPPv417VSIRmdirRecursivePKc)
+pub fn rmdir_all<P: AsRef<Path>>(path: P) -> Result<()> {
+    let path = path.as_ref();
+    let c_path = _path_to_c_string(path)?;
+    if unsafe { gdal_sys::VSIRmdirRecursive(c_path.as_ptr()) } != 0 {
+        return Err(vsi_error("VSIRmdirRecursive", path));
+    }
+    Ok(())
+}
+
+/// Delete the file `path`, on any file system, unlike [`unlink_mem_file`].
+///
+/// See: [`VSIUnlink`](https://gdal.org/api/cpl.html#_CPPv49VSIUnlinkPKc)
+pub fn unlink<P: AsRef<Path>>(path: P) -> Result<()> {
+    let path = path.as_ref();
+    let c_path = _path_to_c_string(path)?;
+    if unsafe { VSIUnlink(c_path.as_ptr()) } != 0 {
+        return Err(vsi_error("VSIUnlink", path));
+    }
+    Ok(())
+}
+
+/// Rename the file or directory `from` to `to`, within the same file system.
+///
+/// See: [`VSIRename`](https://gdal.org/api/cpl.html#_CPPv49VSIRenamePKcPKc)
+pub fn rename<P: AsRef<Path>, Q: AsRef<Path>>(from: P, to: Q) -> Result<()> {
+    let from = from.as_ref();
+    let c_from = _path_to_c_string(from)?;
+    let c_to = _path_to_c_string(to.as_ref())?;
+    if unsafe { gdal_sys::VSIRename(c_from.as_ptr(), c_to.as_ptr()) } != 0 {
+        return Err(vsi_error("VSIRename", from));
+    }
+    Ok(())
+}
+
+/// An entry of a directory listed by [`open_dir`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VsiDirEntry {
+    path: PathBuf,
+    metadata: VsiMetadata,
+}
+
+impl VsiDirEntry {
+    /// Returns the path of the entry, relative to the listed directory.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns the metadata of the entry, as far as known by the file system.
+    pub fn metadata(&self) -> &VsiMetadata {
+        &self.metadata
+    }
+}
+
+/// Iterator over the entries of a directory, created by [`open_dir`].
+#[derive(Debug)]
+pub struct VsiDir {
+    c_dir: NonNull<VSIDIR>,
+}
+
+impl Iterator for VsiDir {
+    type Item = VsiDirEntry;
+
+    fn next(&mut self) -> Option<VsiDirEntry> {
+        let entry = unsafe { gdal_sys::VSIGetNextDirEntry(self.c_dir.as_ptr()).as_ref()? };
+        Some(VsiDirEntry {
+            path: _pathbuf(entry.pszName),
+            metadata: VsiMetadata {
+                mode: (entry.bModeKnown != 0).then(|| entry.nMode as u32),
+                size: (entry.bSizeKnown != 0).then(|| entry.nSize),
+                modified: (entry.bMTimeKnown != 0).then(|| system_time(entry.nMTime)),
+            },
+        })
+    }
+}
+
+impl Drop for VsiDir {
+    fn drop(&mut self) {
+        unsafe { gdal_sys::VSICloseDir(self.c_dir.as_ptr()) };
+    }
+}
+
+/// List the entries of the directory `path`, with their metadata, recursing into the
+/// sub-directories up to `max_depth` levels, or without limit if `None`.
+///
+/// Unlike [`read_dir`], the entries are read as the iterator advances, which is faster on
+/// the network file systems.
+///
+/// See: [`VSIOpenDir`](https://gdal.org/api/cpl.html#_CPPv410VSIOpenDirPKciPPKc)
+///
+/// # Example
+///
+/// ```rust, no_run
+/// # fn main() -> gdal::errors::Result<()> {
+/// for entry in gdal::vsi::open_dir("/vsizip/fixtures/test_vsi_read_dir.zip", None)? {
+///     println!("{} {:?}", entry.path().display(), entry.metadata().size());
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub fn open_dir<P: AsRef<Path>>(path: P, max_depth: Option<usize>) -> Result<VsiDir> {
+    let c_path = _path_to_c_string(path.as_ref())?;
+    let depth = match max_depth {
+        Some(depth) => c_int::try_from(depth)?,
+        None => -1,
+    };
+    let c_dir = unsafe { gdal_sys::VSIOpenDir(c_path.as_ptr(), depth, ptr::null()) };
+    match NonNull::new(c_dir) {
+        Some(c_dir) => Ok(VsiDir { c_dir }),
+        None => Err(_last_null_pointer_err("VSIOpenDir")),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -280,6 +682,73 @@ mod tests {
         drop(ref_handle);
     }
 
+    #[test]
+    fn test_vsi_file() {
+        let path = "/vsimem/5d0a4e5f-2f1c-4c41-9a0e-3e0f3b8e4d21.txt";
+        let mut file = VsiFile::create(path).unwrap();
+        file.write_all(b"Hello, world").unwrap();
+        file.flush().unwrap();
+        drop(file);
+
+        let mut file = VsiFile::open_with_access(path, "r+b").unwrap();
+        assert_eq!(file.seek(SeekFrom::End(-5)).unwrap(), 7);
+        let mut text = String::new();
+        file.read_to_string(&mut text).unwrap();
+        assert_eq!(text, "world");
+        file.seek(SeekFrom::Start(0)).unwrap();
+        file.seek(SeekFrom::Current(5)).unwrap();
+        file.write_all(b"!").unwrap();
+        file.set_len(6).unwrap();
+        assert!(file.seek(SeekFrom::Current(-10)).is_err());
+        drop(file);
+
+        let metadata = stat(path).unwrap();
+        assert!(metadata.is_file());
+        assert!(!metadata.is_dir());
+        assert_eq!(metadata.size(), Some(6));
+        assert_eq!(get_vsi_mem_file_bytes_owned(path).unwrap(), b"Hello!");
+
+        assert!(VsiFile::open(path).is_err());
+        assert!(stat(path).is_err());
+    }
+
+    #[test]
+    fn test_vsi_dirs() {
+        let root = Path::new("/vsimem/9b7c5c0e-8d9f-4b0a-a3a2-61c4d1e0f6b7");
+        mkdir_all(root.join("a/b")).unwrap();
+        mkdir(root.join("c")).unwrap();
+        assert!(stat(root.join("a/b")).unwrap().is_dir());
+
+        VsiFile::create(root.join("a/b/file.txt"))
+            .unwrap()
+            .write_all(b"abc")
+            .unwrap();
+        rename(root.join("a/b/file.txt"), root.join("a/file.txt")).unwrap();
+        assert!(stat(root.join("a/b/file.txt")).is_err());
+
+        let mut entries = open_dir(root, None)
+            .unwrap()
+            .map(|entry| (entry.path().to_path_buf(), entry.metadata().is_dir()))
+            .collect::<Vec<_>>();
+        entries.sort();
+        assert_eq!(
+            entries,
+            [
+                (PathBuf::from("a"), true),
+                (PathBuf::from("a/b"), true),
+                (PathBuf::from("a/file.txt"), false),
+                (PathBuf::from("c"), true),
+            ]
+        );
+        assert_eq!(open_dir(root, Some(0)).unwrap().count(), 2);
+
+        rmdir(root.join("c")).unwrap();
+        unlink(root.join("a/file.txt")).unwrap();
+        rmdir_all(root).unwrap();
+        assert!(stat(root).is_err());
+        assert!(open_dir(root, None).is_err());
+    }
+
     #[test]
     fn test_vsi_read_dir() {
         use std::path::Path;